@@ -4,12 +4,15 @@ import type {
   TradesResponse,
   HealthResponse,
   HotMarketsResponse,
+  OrderBook,
+  AssetResolution,
   LiveFeedResponse,
   PositionsResponse,
   PnlBarChartResponse,
   ResolvedMarket,
   SmartMoneyResponse,
   TraderProfile,
+  TraderCopyScore,
   BacktestResponse,
   BacktestTimeframe,
   CopyPortfolioResponse,
@@ -95,6 +98,26 @@ export async function fetchTrader(address: string): Promise<TraderSummary> {
   return res.json();
 }
 
+export async function fetchTraderStatsBatch(addresses: string[]): Promise<TraderSummary[]> {
+  const res = await authFetch(`${BASE}/traders/stats-batch`, {
+    method: "POST",
+    headers: { "Content-Type": "application/json" },
+    body: JSON.stringify({ addresses }),
+  });
+  if (!res.ok) throw new Error(`Trader stats batch failed: ${res.status}`);
+  return res.json();
+}
+
+export async function fetchResolvedStatus(assetIds: string[]): Promise<AssetResolution[]> {
+  const res = await authFetch(`${BASE}/markets/resolved-status`, {
+    method: "POST",
+    headers: { "Content-Type": "application/json" },
+    body: JSON.stringify({ asset_ids: assetIds }),
+  });
+  if (!res.ok) throw new Error(`Resolved status fetch failed: ${res.status}`);
+  return res.json();
+}
+
 export async function fetchTraderTrades(
   address: string,
   params: { limit?: number; offset?: number; side?: string },
@@ -126,6 +149,14 @@ export async function fetchHotMarkets(params?: {
   return res.json();
 }
 
+export async function fetchOrderBook(assetId: string, depthCents?: number): Promise<OrderBook> {
+  const sp = new URLSearchParams();
+  if (depthCents) sp.set("depth_cents", String(depthCents));
+  const res = await authFetch(`${BASE}/markets/${assetId}/book?${sp}`);
+  if (!res.ok) throw new Error(`Order book fetch failed: ${res.status}`);
+  return res.json();
+}
+
 export async function fetchRecentTrades(params?: {
   limit?: number;
   token_id?: string;
@@ -175,6 +206,12 @@ export async function fetchTraderProfile(address: string): Promise<TraderProfile
   return res.json();
 }
 
+export async function fetchTraderCopyScore(address: string): Promise<TraderCopyScore> {
+  const res = await authFetch(`${BASE}/trader/${address}/copy-score`);
+  if (!res.ok) throw new Error(`Copy score fetch failed: ${res.status}`);
+  return res.json();
+}
+
 export async function fetchBacktest(params: {
   topN?: number;
   listId?: string;
@@ -266,11 +303,15 @@ export async function deleteTraderList(id: string): Promise<void> {
   if (!res.ok) throw new Error(`Delete list failed: ${res.status}`);
 }
 
-export async function addListMembers(id: string, addresses: string[]): Promise<void> {
+export async function addListMembers(
+  id: string,
+  addresses: string[],
+  weights?: (number | null)[],
+): Promise<void> {
   const res = await authFetch(`${BASE}/lists/${id}/members`, {
     method: "POST",
     headers: { "Content-Type": "application/json" },
-    body: JSON.stringify({ addresses }),
+    body: JSON.stringify({ addresses, weights }),
   });
   if (!res.ok) throw new Error(`Add members failed: ${res.status}`);
 }
@@ -379,6 +420,19 @@ export async function createSession(body: import("./types").CreateSessionRequest
   return res.json();
 }
 
+export async function sessionPreflight(body: import("./types").CreateSessionRequest): Promise<import("./types").SessionPreflight> {
+  const res = await authFetch(`${BASE}/copytrade/sessions/preflight`, {
+    method: "POST",
+    headers: { "Content-Type": "application/json" },
+    body: JSON.stringify(body),
+  });
+  if (!res.ok) {
+    const text = await res.text();
+    throw new Error(text || `Session preflight failed: ${res.status}`);
+  }
+  return res.json();
+}
+
 export async function listSessions(): Promise<import("./types").CopyTradeSession[]> {
   const res = await authFetch(`${BASE}/copytrade/sessions`);
   if (!res.ok) throw new Error(`List sessions failed: ${res.status}`);
@@ -391,6 +445,22 @@ export async function getSession(id: string): Promise<import("./types").CopyTrad
   return res.json();
 }
 
+export async function updateSessionCategories(
+  id: string,
+  filters: { allowed_categories?: string; blocked_categories?: string; copy_uncategorized?: boolean },
+): Promise<import("./types").CopyTradeSession> {
+  const res = await authFetch(`${BASE}/copytrade/sessions/${id}`, {
+    method: "PATCH",
+    headers: { "Content-Type": "application/json" },
+    body: JSON.stringify(filters),
+  });
+  if (!res.ok) {
+    const text = await res.text();
+    throw new Error(text || `Update session failed: ${res.status}`);
+  }
+  return res.json();
+}
+
 export async function updateSession(id: string, action: "pause" | "resume" | "stop"): Promise<import("./types").CopyTradeSession> {
   const res = await authFetch(`${BASE}/copytrade/sessions/${id}`, {
     method: "PATCH",
@@ -404,17 +474,67 @@ export async function updateSession(id: string, action: "pause" | "resume" | "st
   return res.json();
 }
 
+export async function stopAllSessions(): Promise<import("./types").StopAllResponse> {
+  const res = await authFetch(`${BASE}/copytrade/stop-all`, { method: "POST" });
+  if (!res.ok) throw new Error(`Stop all sessions failed: ${res.status}`);
+  return res.json();
+}
+
 export async function deleteSession(id: string): Promise<void> {
   const res = await authFetch(`${BASE}/copytrade/sessions/${id}`, { method: "DELETE" });
   if (!res.ok) throw new Error(`Delete session failed: ${res.status}`);
 }
 
+export async function listSessionTemplates(): Promise<import("./types").SessionTemplate[]> {
+  const res = await authFetch(`${BASE}/copytrade/templates`);
+  if (!res.ok) throw new Error(`List templates failed: ${res.status}`);
+  return res.json();
+}
+
+export async function saveSessionTemplate(
+  name: string,
+  config: Partial<import("./types").CreateSessionRequest>,
+): Promise<import("./types").SessionTemplate> {
+  const res = await authFetch(`${BASE}/copytrade/templates`, {
+    method: "POST",
+    headers: { "Content-Type": "application/json" },
+    body: JSON.stringify({ name, config }),
+  });
+  if (!res.ok) {
+    const text = await res.text();
+    throw new Error(text || `Save template failed: ${res.status}`);
+  }
+  return res.json();
+}
+
+export async function deleteSessionTemplate(id: string): Promise<void> {
+  const res = await authFetch(`${BASE}/copytrade/templates/${id}`, { method: "DELETE" });
+  if (!res.ok) throw new Error(`Delete template failed: ${res.status}`);
+}
+
 export async function listSessionOrders(sessionId: string, limit = 50, offset = 0): Promise<import("./types").CopyTradeOrder[]> {
   const res = await authFetch(`${BASE}/copytrade/sessions/${sessionId}/orders?limit=${limit}&offset=${offset}`);
   if (!res.ok) throw new Error(`List orders failed: ${res.status}`);
   return res.json();
 }
 
+export async function listOpenOrders(sessionId: string): Promise<import("./types").SessionOpenOrders> {
+  const res = await authFetch(`${BASE}/copytrade/sessions/${sessionId}/open-orders`);
+  if (!res.ok) throw new Error(`List open orders failed: ${res.status}`);
+  return res.json();
+}
+
+export async function cancelOrder(sessionId: string, orderId: string): Promise<import("./types").CopyTradeOrder> {
+  const res = await authFetch(`${BASE}/copytrade/sessions/${sessionId}/orders/${orderId}/cancel`, {
+    method: "POST",
+  });
+  if (!res.ok) {
+    const text = await res.text();
+    throw new Error(text || `Cancel order failed: ${res.status}`);
+  }
+  return res.json();
+}
+
 export async function closePosition(sessionId: string, assetId: string): Promise<{ order_id: string; status: string }> {
   const res = await authFetch(`${BASE}/copytrade/close-position`, {
     method: "POST",
@@ -447,12 +567,25 @@ export async function getSessionStats(sessionId: string): Promise<import("./type
   return res.json();
 }
 
+export async function compareSessions(a: string, b: string): Promise<import("./types").SessionComparison> {
+  const params = new URLSearchParams({ a, b });
+  const res = await authFetch(`${BASE}/copytrade/compare?${params}`);
+  if (!res.ok) throw new Error(`Compare sessions failed: ${res.status}`);
+  return res.json();
+}
+
 export async function getSessionPositions(sessionId: string): Promise<import("./types").CopyTradePosition[]> {
   const res = await authFetch(`${BASE}/copytrade/sessions/${sessionId}/positions`);
   if (!res.ok) throw new Error(`Get session positions failed: ${res.status}`);
   return res.json();
 }
 
+export async function getLiquidationPreview(sessionId: string): Promise<import("./types").LiquidationPreview> {
+  const res = await authFetch(`${BASE}/copytrade/sessions/${sessionId}/liquidation-preview`);
+  if (!res.ok) throw new Error(`Get liquidation preview failed: ${res.status}`);
+  return res.json();
+}
+
 export async function getCopyTradeSummary(): Promise<import("./types").CopyTradeSummary> {
   const res = await authFetch(`${BASE}/copytrade/summary`);
   if (!res.ok) throw new Error(`Get copytrade summary failed: ${res.status}`);
@@ -4,7 +4,7 @@ import { useCreateSession } from "../../hooks/useCopyTrade";
 import { useTraderLists } from "../../hooks/useTraderLists";
 import { useWallets } from "../../hooks/useWallet";
 import { useTerminal } from "./TerminalProvider";
-import type { CopyOrderType, CreateSessionRequest, CreateSessionPrefill } from "../../types";
+import type { CopyOrderType, CreateSessionRequest, CreateSessionPrefill, GtcPriceRef, SellBehavior } from "../../types";
 
 /* ── event bus ─────────────────────────────────────────────── */
 const OPEN_EVENT = "session:open-create-modal";
@@ -89,6 +89,8 @@ export function CreateSessionModal() {
   const [maxPosition, setMaxPosition] = useState(100);
   const [maxSlippage, setMaxSlippage] = useState(200);
   const [orderType, setOrderType] = useState<CopyOrderType>("FOK");
+  const [gtcPriceRef, setGtcPriceRef] = useState<GtcPriceRef>("source");
+  const [gtcOffsetBps, setGtcOffsetBps] = useState(0);
   const [maxLossPct, setMaxLossPct] = useState(20);
   const [simulate, setSimulate] = useState(true);
   const [showLiveConfirm, setShowLiveConfirm] = useState(false);
@@ -102,9 +104,12 @@ export function CreateSessionModal() {
   const [takeProfitPct, setTakeProfitPct] = useState<number | "">("");
   const [stopLossPct, setStopLossPct] = useState<number | "">("");
   const [mirrorClose, setMirrorClose] = useState(true);
+  const [sellBehavior, setSellBehavior] = useState<SellBehavior>("proportional");
   const [healthInterval, setHealthInterval] = useState(30);
   const [maxSourcePrice, setMaxSourcePrice] = useState(95);
   const [minSourcePrice, setMinSourcePrice] = useState(5);
+  const [webhookUrl, setWebhookUrl] = useState("");
+  const [digestHours, setDigestHours] = useState<number | "">("");
 
   const [error, setError] = useState("");
   const prefillRef = useRef<CreateSessionPrefill | null>(null);
@@ -181,6 +186,7 @@ export function CreateSessionModal() {
       max_position_usdc: maxPosition,
       max_slippage_bps: maxSlippage,
       order_type: orderType,
+      ...(orderType === "GTC" ? { gtc_price_ref: gtcPriceRef, gtc_price_offset_bps: gtcOffsetBps } : {}),
       initial_capital: capital,
       simulate,
       max_loss_pct: maxLossPct,
@@ -190,10 +196,13 @@ export function CreateSessionModal() {
       ...(takeProfitPct !== "" ? { take_profit_pct: takeProfitPct } : {}),
       ...(stopLossPct !== "" ? { stop_loss_pct: stopLossPct } : {}),
       mirror_close: mirrorClose,
+      sell_behavior: sellBehavior,
       health_interval_secs: healthInterval,
       max_source_price: maxSourcePrice / 100,
       min_source_price: minSourcePrice / 100,
       ...(source === "top_n" ? { top_n: topN } : { list_id: listId }),
+      ...(webhookUrl.trim() ? { webhook_url: webhookUrl.trim() } : {}),
+      ...(webhookUrl.trim() && digestHours !== "" ? { digest_interval_hours: digestHours } : {}),
     };
     create.mutate(body, {
       onSuccess: () => {
@@ -348,6 +357,7 @@ export function CreateSessionModal() {
                     <label className={labelCls}>Order Type</label>
                     <select className={selectCls} value={orderType} onChange={(e) => setOrderType(e.target.value as CopyOrderType)}>
                       <option value="FOK">FOK (Fill or Kill)</option>
+                      <option value="FAK">FAK (Fill and Kill, partial fills)</option>
                       <option value="GTC">GTC (Good til Canceled)</option>
                     </select>
                   </div>
@@ -362,6 +372,28 @@ export function CreateSessionModal() {
                       onChange={(e) => setMaxLossPct(Number(e.target.value))}
                     />
                   </div>
+                  {orderType === "GTC" && (
+                    <>
+                      <div>
+                        <label className={labelCls}>GTC Limit From</label>
+                        <select className={selectCls} value={gtcPriceRef} onChange={(e) => setGtcPriceRef(e.target.value as GtcPriceRef)}>
+                          <option value="source">Source fill price</option>
+                          <option value="current">Current midpoint</option>
+                        </select>
+                      </div>
+                      <div>
+                        <label className={labelCls}>Limit Offset (bps)</label>
+                        <input
+                          type="number"
+                          className={inputCls}
+                          value={gtcOffsetBps}
+                          min={-1000}
+                          max={1000}
+                          onChange={(e) => setGtcOffsetBps(Number(e.target.value))}
+                        />
+                      </div>
+                    </>
+                  )}
                 </div>
               </Section>
 
@@ -443,6 +475,23 @@ export function CreateSessionModal() {
                           </span>
                         </label>
                         <input type="checkbox" className="hidden" checked={mirrorClose} onChange={(e) => setMirrorClose(e.target.checked)} />
+                        <div>
+                          <label className={labelCls}>On Source Sell</label>
+                          <select className={selectCls} value={sellBehavior} onChange={(e) => setSellBehavior(e.target.value as SellBehavior)}>
+                            <option value="proportional">Sell the same proportion</option>
+                            <option value="full_exit">Exit the whole position</option>
+                          </select>
+                        </div>
+                        <div className="grid grid-cols-[1fr_auto] gap-3">
+                          <div>
+                            <label className={labelCls}>Digest Webhook (https)</label>
+                            <input type="url" className={inputCls} value={webhookUrl} placeholder="https://hooks.example.com/..." onChange={(e) => setWebhookUrl(e.target.value)} />
+                          </div>
+                          <div className="w-28">
+                            <label className={labelCls}>Every (hours)</label>
+                            <input type="number" className={inputCls} value={digestHours} min={1} max={168} placeholder="Off" onChange={(e) => setDigestHours(e.target.value ? Number(e.target.value) : "")} />
+                          </div>
+                        </div>
                       </div>
                     </motion.div>
                   )}
@@ -78,7 +78,7 @@ function SessionCard({ session }: { session: CopyTradeSession }) {
         <div className="grid grid-cols-2 gap-2 text-xs mb-2">
           <div>
             <div className="text-[var(--text-muted)]">Source</div>
-            <div className="font-mono">{session.list_id ? `List` : `Top ${session.top_n}`}</div>
+            <div className="font-mono">{session.list_id ? (session.top_n ? `List · Top ${session.top_n}` : `List`) : `Top ${session.top_n}`}</div>
           </div>
           <div>
             <div className="text-[var(--text-muted)]">Slippage</div>
@@ -72,12 +72,20 @@ export function TerminalShell() {
             return `filled:${update.order_id}`;
           case "OrderFailed":
             return `failed:${update.order_id}`;
+          case "OrderSkipped":
+            return `skipped:${update.session_id}:${update.source_tx_hash}`;
           case "SessionPaused":
             return `paused:${update.session_id}`;
           case "SessionResumed":
             return `resumed:${update.session_id}`;
           case "SessionStopped":
             return `stopped:${update.session_id}:${update.reason ?? "none"}`;
+          case "PositionClosed":
+            return `closed:${update.order_id}`;
+          case "SignalsDropped":
+            return `dropped:${update.session_id}:${update.at}`;
+          case "DepositConfirmed":
+            return `deposit:${update.wallet_id}:${update.tx_hash ?? update.amount}`;
           case "BalanceUpdate":
             return `balance:${update.balance}`;
         }
@@ -49,6 +49,16 @@ function InfoNote({ children }: { children: React.ReactNode }) {
 
 function StatusBadge({ status }: { status: string }) {
   const isCredentialed = status === "credentialed";
+  if (status === "decrypt_error") {
+    return (
+      <span
+        className="px-1.5 py-0.5 text-[10px] rounded bg-red-500/15 text-red-400"
+        title="Key can't be decrypted with the server's encryption key. Restore the original key or re-import the wallet."
+      >
+        Decrypt error
+      </span>
+    );
+  }
   return (
     <span className={`px-1.5 py-0.5 text-[10px] rounded ${
       isCredentialed ? "bg-green-500/15 text-green-400" : "bg-yellow-500/15 text-yellow-400"
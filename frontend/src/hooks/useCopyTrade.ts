@@ -133,6 +133,8 @@ export function useCopyTradeWs(options: CopyTradeWsOptions = {}) {
   const [connected, setConnected] = useState(false);
   const wsRef = useRef<WebSocket | null>(null);
   const retryRef = useRef(0);
+  // seq of the last update received; sent as ?since= so a reconnect replays what was missed
+  const lastSeqRef = useRef<number | null>(null);
   const reconnectTimerRef = useRef<number | null>(null);
   const connectRef = useRef<() => void>(() => {});
 
@@ -146,7 +148,8 @@ export function useCopyTradeWs(options: CopyTradeWsOptions = {}) {
     const wsBase = base
       ? new URL(base).origin.replace(/^http/, "ws")
       : `${window.location.protocol === "https:" ? "wss:" : "ws:"}//${window.location.host}`;
-    const url = `${wsBase}/ws/copytrade?token=${encodeURIComponent(token)}`;
+    const since = lastSeqRef.current !== null ? `&since=${lastSeqRef.current}` : "";
+    const url = `${wsBase}/ws/copytrade?token=${encodeURIComponent(token)}${since}`;
 
     const ws = new WebSocket(url);
     wsRef.current = ws;
@@ -159,6 +162,7 @@ export function useCopyTradeWs(options: CopyTradeWsOptions = {}) {
     ws.onmessage = (event) => {
       try {
         const update: CopyTradeUpdate = JSON.parse(event.data);
+        if (typeof update.seq === "number") lastSeqRef.current = update.seq;
         setUpdates((prev) => [update, ...prev].slice(0, MAX_UPDATES));
       } catch {
         // Ignore malformed messages
@@ -198,6 +202,7 @@ export function useCopyTradeWs(options: CopyTradeWsOptions = {}) {
       setConnected(false);
       setUpdates([]);
       retryRef.current = 0;
+      lastSeqRef.current = null;
       return;
     }
 
@@ -16,12 +16,20 @@ function updateKey(u: CopyTradeUpdate): string | null {
       return `filled:${u.order_id}`;
     case "OrderFailed":
       return `failed:${u.order_id}`;
+    case "OrderSkipped":
+      return `skipped:${u.session_id}:${u.source_tx_hash}`;
     case "SessionPaused":
       return `paused:${u.session_id}`;
     case "SessionResumed":
       return `resumed:${u.session_id}`;
     case "SessionStopped":
       return `stopped:${u.session_id}`;
+    case "PositionClosed":
+      return `closed:${u.order_id}`;
+    case "SignalsDropped":
+      return `dropped:${u.session_id}:${u.at}`;
+    case "DepositConfirmed":
+      return `deposit:${u.wallet_id}:${u.tx_hash ?? u.amount}`;
     case "BalanceUpdate":
       return null; // handled separately
   }
@@ -106,8 +114,29 @@ export function useCopyTradeLogger(updates: CopyTradeUpdate[]) {
           queryClient.invalidateQueries({ queryKey: ["copytrade", "orders"] });
           queryClient.invalidateQueries({ queryKey: ["copytrade", "stats"] });
           break;
+        case "OrderSkipped":
+          addLog(
+            "info",
+            `Trade skipped on ${truncateAssetId(u.asset_id)}: ${u.reason}`,
+            {
+              session_id: u.session_id,
+              source_trader: u.source_trader,
+              asset_id: u.asset_id,
+              reason: u.reason,
+            },
+            "copytrade",
+          );
+          break;
         case "SessionPaused":
-          addLog("warn", "Session paused", { session_id: u.session_id }, "copytrade");
+          addLog(
+            "warn",
+            `Session paused${u.reason && u.reason !== "user" ? ": " + u.reason : ""}`,
+            {
+              session_id: u.session_id,
+              ...(u.reason ? { reason: u.reason } : {}),
+            },
+            "copytrade",
+          );
           break;
         case "SessionResumed":
           addLog("info", "Session resumed", { session_id: u.session_id }, "copytrade");
@@ -123,6 +152,42 @@ export function useCopyTradeLogger(updates: CopyTradeUpdate[]) {
             "copytrade",
           );
           break;
+        case "PositionClosed":
+          addLog(
+            "info",
+            `Position closed: ${u.reason}`,
+            {
+              session_id: u.session_id,
+              asset_id: u.asset_id,
+              shares: String(u.shares),
+            },
+            "copytrade",
+          );
+          queryClient.invalidateQueries({ queryKey: ["copytrade", "orders"] });
+          break;
+        case "SignalsDropped":
+          addLog(
+            "warn",
+            `Engine fell behind: ${u.count} signal(s) dropped, copies may have been missed`,
+            { session_id: u.session_id, count: String(u.count) },
+            "copytrade",
+          );
+          break;
+        case "DepositConfirmed":
+          addLog(
+            "success",
+            "Deposit confirmed",
+            {
+              wallet_id: u.wallet_id,
+              amount: u.amount,
+              token: u.token,
+              ...(u.tx_hash ? { tx_hash: u.tx_hash } : {}),
+            },
+            "copytrade",
+          );
+          queryClient.invalidateQueries({ queryKey: ["wallet-balance", u.wallet_id] });
+          queryClient.invalidateQueries({ queryKey: ["deposit-status", u.wallet_id] });
+          break;
       }
     }
   }, [updates, addLog]);
@@ -66,6 +66,11 @@ export function useOrderToast(updates: CopyTradeUpdate[]) {
             break;
           }
 
+          if (normalized === "max_runtime") {
+            toast("info", "Session reached its max runtime and stopped.");
+            break;
+          }
+
           if (normalized === "user" || !normalized) {
             toast("warn", "Session stopped.");
             break;
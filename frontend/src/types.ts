@@ -70,6 +70,25 @@ export interface HotMarketsResponse {
   markets: HotMarket[];
 }
 
+export interface BookLevel {
+  price: number;
+  size: number;
+}
+
+/** CLOB L2 book for one token, best levels first */
+export interface OrderBook {
+  asset_id: string;
+  bids: BookLevel[];
+  asks: BookLevel[];
+  mid: number | null;
+  spread: number | null;
+  depth_cents: number;
+  bid_depth_shares: number;
+  bid_depth_usdc: number;
+  ask_depth_shares: number;
+  ask_depth_usdc: number;
+}
+
 export interface FeedTrade {
   tx_hash: string;
   block_timestamp: string;
@@ -217,6 +236,27 @@ export interface TraderProfile {
   readiness?: TraderReadiness;
 }
 
+export interface CopyScoreComponents {
+  pnl: number;
+  win_rate: number;
+  volume: number;
+  diversity: number;
+  recency: number;
+}
+
+export interface TraderCopyScore {
+  address: string;
+  score: number;
+  components: CopyScoreComponents;
+  weights: CopyScoreComponents;
+  window_days: number;
+  recent_pnl: number;
+  win_rate: number;
+  total_volume: number;
+  markets_traded: number;
+  days_since_last_trade: number;
+}
+
 // Copytrade Readiness (spec 28)
 
 export type DiscoveryCategory =
@@ -411,6 +451,7 @@ export interface TraderListMember {
   address: string;
   label?: string;
   added_at: string;
+  weight: number;
 }
 
 export interface TraderListDetail {
@@ -460,7 +501,8 @@ export interface TradingWalletInfo {
   id: string;
   address: string;
   proxy_address: string | null;
-  status: "created" | "credentialed" | "active" | "disabled";
+  signature_type: "proxy" | "eoa";
+  status: "created" | "credentialed" | "active" | "disabled" | "decrypt_error";
   has_clob_credentials: boolean;
   created_at: string;
 }
@@ -575,10 +617,22 @@ export interface CreateSessionPrefill {
 
 // Copy-Trade Engine (spec 15)
 export type SessionStatus = "running" | "paused" | "stopped";
-export type CopyOrderType = "FOK" | "GTC";
+export type CopyOrderType = "FOK" | "FAK" | "GTC";
 export type OrderStatus = "pending" | "submitted" | "filled" | "partial" | "failed" | "canceled" | "simulated";
+export type OrderOrigin = "signal" | "manual" | "close" | "redeem" | "auto";
+export type SizingMode = "dollar" | "portfolio";
+export type SizingBase = "remaining" | "initial";
+export type GtcPriceRef = "source" | "current";
+export type SellBehavior = "proportional" | "full_exit";
+export type SizeRounding = "down" | "nearest";
+export type CopyMode = "copy" | "fade";
+export type CopySides = "both" | "buy" | "sell";
+export type SignalSource = "traders" | "whale_follow";
+export type TopNMetric = "pnl" | "roi" | "volume" | "consistency";
 
 export interface CreateSessionRequest {
+  /** Pre-fill from a saved template; fields set here override it */
+  template_id?: string;
   wallet_id?: string;
   list_id?: string;
   top_n?: number;
@@ -594,10 +648,67 @@ export interface CreateSessionRequest {
   max_open_positions?: number;
   take_profit_pct?: number;
   stop_loss_pct?: number;
+  mode?: CopyMode;
+  allowed_categories?: string;
+  blocked_categories?: string;
+  copy_uncategorized?: boolean;
+  copy_sides?: CopySides;
+  fok_fallback_gtc?: boolean;
   mirror_close?: boolean;
   health_interval_secs?: number;
   max_source_price?: number;
   min_source_price?: number;
+  sizing_mode?: SizingMode;
+  webhook_url?: string;
+  digest_interval_hours?: number;
+  sizing_base?: SizingBase;
+  max_runtime_secs?: number;
+  asset_allowlist?: string[];
+  near_resolution_price?: number;
+  gtc_price_ref?: GtcPriceRef;
+  gtc_price_offset_bps?: number;
+  sell_behavior?: SellBehavior;
+  trail_activate_pct?: number;
+  trail_distance_pct?: number;
+  mark_stale_secs?: number;
+  signal_source?: SignalSource;
+  min_whale_usdc?: number;
+  slippage_fee_bps?: number;
+  max_signal_age_secs?: number;
+  top_n_metric?: TopNMetric;
+  min_hold_secs?: number;
+  auto_approve?: boolean;
+  slippage_overrides?: Record<string, number>;
+  max_orders_per_minute?: number;
+  size_rounding?: SizeRounding;
+  min_liquidity_usdc?: number;
+  liquidity_window_mins?: number;
+}
+
+export interface SessionPreflight {
+  ready: boolean;
+  simulate: boolean;
+  config_error: string | null;
+  wallet_id: string | null;
+  wallet_address: string | null;
+  credentialed: boolean;
+  ctf_exchange_approved: boolean | null;
+  neg_risk_exchange_approved: boolean | null;
+  usdc_balance: number | null;
+  required_usdc: number;
+  usdc_shortfall: number | null;
+  pol_balance: string | null;
+  needs_gas: boolean;
+  balance_checked_secs_ago: number | null;
+  issues: string[];
+}
+
+export interface SessionTemplate {
+  id: string;
+  name: string;
+  config: Partial<CreateSessionRequest>;
+  created_at: string;
+  updated_at: string;
 }
 
 export interface CopyTradeSession {
@@ -612,6 +723,7 @@ export interface CopyTradeSession {
   initial_capital: number;
   remaining_capital: number;
   positions_value: number;
+  fees_paid: number;
   simulate: boolean;
   max_loss_pct: number | null;
   min_source_usdc: number;
@@ -619,13 +731,44 @@ export interface CopyTradeSession {
   max_open_positions: number;
   take_profit_pct: number | null;
   stop_loss_pct: number | null;
+  mode: CopyMode;
+  allowed_categories: string | null;
+  blocked_categories: string | null;
+  copy_uncategorized: boolean;
+  copy_sides: CopySides;
+  fok_fallback_gtc: boolean;
   mirror_close: boolean;
   health_interval_secs: number;
   max_source_price: number;
   min_source_price: number;
+  sizing_mode: SizingMode;
+  webhook_url: string | null;
+  digest_interval_hours: number | null;
+  sizing_base: SizingBase;
+  max_runtime_secs: number | null;
+  asset_allowlist: string[] | null;
+  near_resolution_price: number | null;
+  gtc_price_ref: GtcPriceRef;
+  gtc_price_offset_bps: number;
+  sell_behavior: SellBehavior;
+  trail_activate_pct: number | null;
+  trail_distance_pct: number | null;
+  mark_stale_secs: number | null;
+  signal_source: SignalSource;
+  min_whale_usdc: number | null;
+  slippage_fee_bps: number | null;
+  max_signal_age_secs: number | null;
+  top_n_metric: TopNMetric;
+  min_hold_secs: number;
+  slippage_overrides: Record<string, number> | null;
+  max_orders_per_minute: number | null;
+  size_rounding: SizeRounding;
+  min_liquidity_usdc: number | null;
+  liquidity_window_mins: number | null;
   status: SessionStatus;
   created_at: string;
   updated_at: string;
+  approval: ApprovalResult | null;
 }
 
 export interface CopyTradeOrder {
@@ -647,6 +790,22 @@ export interface CopyTradeOrder {
   tx_hash: string | null;
   created_at: string;
   updated_at: string;
+  origin: OrderOrigin;
+  max_slippage_bps: number | null;
+  fallback_for: string | null;
+}
+
+export interface OpenOrder extends CopyTradeOrder {
+  age_secs: number;
+  reserved_usdc: number;
+  tracked: boolean;
+}
+
+export interface SessionOpenOrders {
+  session_id: string;
+  engine_loaded: boolean;
+  total_reserved_usdc: number;
+  orders: OpenOrder[];
 }
 
 export interface CopyTradeOrderSummary {
@@ -659,14 +818,20 @@ export interface CopyTradeOrderSummary {
   simulate: boolean;
 }
 
-export type CopyTradeUpdate =
+/** `seq` is the replay cursor for /ws/copytrade?since= */
+export type CopyTradeUpdate = { seq?: number } & (
   | { kind: "OrderPlaced"; session_id: string; order: CopyTradeOrderSummary }
   | { kind: "OrderFilled"; session_id: string; order_id: string; fill_price: number; slippage_bps: number }
   | { kind: "OrderFailed"; session_id: string; order_id: string; error: string }
-  | { kind: "SessionPaused"; session_id: string }
+  | { kind: "OrderSkipped"; session_id: string; source_tx_hash: string; source_trader: string; asset_id: string; reason: string }
+  | { kind: "SessionPaused"; session_id: string; reason: string | null }
   | { kind: "SessionResumed"; session_id: string }
   | { kind: "SessionStopped"; session_id: string; reason: string | null }
-  | { kind: "BalanceUpdate"; balance: string };
+  | { kind: "PositionClosed"; session_id: string; order_id: string; asset_id: string; shares: number; reason: string }
+  | { kind: "SignalsDropped"; session_id: string; count: number; at: string }
+  | { kind: "DepositConfirmed"; wallet_id: string; amount: string; token: string; tx_hash: string | null }
+  | { kind: "BalanceUpdate"; balance: string }
+);
 
 // Copy-Trade Dashboard (spec 16)
 
@@ -679,6 +844,7 @@ export interface SessionStats {
   total_invested: number;
   total_returned: number;
   realized_pnl: number;
+  realized_pnl_by_origin: Partial<Record<OrderOrigin, number>>;
   unrealized_pnl: number;
   total_pnl: number;
   return_pct: number;
@@ -688,7 +854,34 @@ export interface SessionStats {
   avg_slippage_bps: number;
   max_slippage_bps: number;
   capital_utilization: number;
+  fees_paid: number;
   runtime_seconds: number;
+  open_positions: number;
+}
+
+/** Differences between two sessions' stats, always `a` minus `b` */
+export interface SessionStatsDelta {
+  total_pnl: number;
+  realized_pnl: number;
+  unrealized_pnl: number;
+  return_pct: number;
+  win_rate: number;
+  avg_slippage_bps: number;
+  capital_utilization: number;
+  total_orders: number;
+}
+
+export interface ComparedSession {
+  session_id: string;
+  simulate: boolean;
+  status: SessionStatus;
+  stats: SessionStats;
+}
+
+export interface SessionComparison {
+  a: ComparedSession;
+  b: ComparedSession;
+  delta: SessionStatsDelta;
 }
 
 export interface CopyTradePosition {
@@ -712,6 +905,44 @@ export interface CopyTradePosition {
   last_order_at: string;
 }
 
+export interface LiquidationPosition {
+  asset_id: string;
+  question: string;
+  outcome: string;
+  net_shares: number;
+  avg_entry_price: number;
+  mark_price: number;
+  vwap_price: number | null;
+  fillable_shares: number;
+  expected_proceeds: number;
+  slippage_bps: number;
+  realized_pnl: number;
+  pnl_vs_mark: number;
+  from_book: boolean;
+}
+
+export interface LiquidationPreview {
+  session_id: string;
+  total_expected_proceeds: number;
+  total_realized_pnl: number;
+  total_mark_value: number;
+  total_pnl_vs_mark: number;
+  unfillable_shares: number;
+  positions: LiquidationPosition[];
+}
+
+export interface StopAllResult {
+  session_id: string;
+  previous_status: "running" | "paused";
+  stopped: boolean;
+  error: string | null;
+}
+
+export interface StopAllResponse {
+  stopped: number;
+  results: StopAllResult[];
+}
+
 export interface CopyTradeSummary {
   active_sessions: number;
   total_pnl: number;
@@ -743,3 +974,12 @@ export interface RedemptionsResponse<T> {
   redemptions: T[];
   total: number;
 }
+
+// Market resolution status (POST /api/markets/resolved-status)
+
+export interface AssetResolution {
+  asset_id: string;
+  resolved: boolean;
+  resolved_price: number | null;
+  condition_id: string | null;
+}
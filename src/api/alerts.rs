@@ -1,5 +1,6 @@
 use std::collections::{HashMap, HashSet};
 use std::env;
+use std::sync::Arc;
 use std::time::{Duration, Instant};
 
 use axum::{
@@ -14,13 +15,17 @@ use axum::{
 use serde::{Deserialize, Serialize};
 use tokio::sync::broadcast;
 
-use super::types::CopyTradeUpdate;
+use super::update_replay::SequencedUpdate;
 use super::{markets, server::AppState};
 
 // ---------------------------------------------------------------------------
 // Alert types
 // ---------------------------------------------------------------------------
 
+/// Fills at or above this size are broadcast as whale alerts: $25k USDC
+pub const WHALE_MIN_USDC: f64 = 25_000.0;
+const WHALE_MIN_USDC_RAW: u128 = 25_000_000_000; // 6 decimals
+
 #[derive(Clone, Debug, Serialize)]
 #[serde(tag = "kind")]
 pub enum Alert {
@@ -83,6 +88,47 @@ pub struct LiveTrade {
     pub cache_key: String,
 }
 
+impl LiveTrade {
+    /// The fill behind a whale alert, shaped like a live-feed trade so the copy-trade
+    /// engine can run it through the same pipeline. None for other alert kinds.
+    pub fn from_whale_alert(alert: &Alert) -> Option<LiveTrade> {
+        let Alert::WhaleTrade {
+            timestamp,
+            side,
+            trader,
+            asset_id,
+            usdc_amount,
+            token_amount,
+            tx_hash,
+            block_number,
+            question,
+            outcome,
+            ..
+        } = alert
+        else {
+            return None;
+        };
+        let usdc: f64 = usdc_amount.parse().unwrap_or(0.0);
+        let tokens: f64 = token_amount.parse().unwrap_or(0.0);
+        let price = if tokens > 0.0 { usdc / tokens } else { 0.0 };
+        Some(LiveTrade {
+            tx_hash: tx_hash.clone(),
+            block_timestamp: timestamp.clone(),
+            trader: trader.clone(),
+            side: side.clone(),
+            asset_id: markets::to_integer_id(asset_id),
+            amount: token_amount.clone(),
+            price: format!("{price:.6}"),
+            usdc_amount: usdc_amount.clone(),
+            question: question.clone().unwrap_or_default(),
+            outcome: outcome.clone().unwrap_or_default(),
+            category: String::new(),
+            block_number: *block_number,
+            cache_key: markets::cache_key(asset_id),
+        })
+    }
+}
+
 // ---------------------------------------------------------------------------
 // rindexer webhook payload
 // ---------------------------------------------------------------------------
@@ -134,6 +180,10 @@ pub async fn webhook_handler(
 
             // Broadcast trades + queue metadata persistence.
             // Webhook is the primary source for live feed and whale alerts.
+            if payload.event_name == "OrderFilled" && is_token_swap_fill(event) {
+                state.engine_metrics.record_token_swap_fill();
+                tracing::debug!("Skipping token-for-token fill (mint/merge)");
+            }
             if payload.event_name == "OrderFilled" && is_live {
                 if let Some(live_trade) = build_live_trade(event, &cache) {
                     if let Some(info) = cache.get(&live_trade.cache_key) {
@@ -241,7 +291,7 @@ fn parse_trade_data<'a>(
     } else if taker_asset_id == "0" {
         ("sell", maker_asset_id, taker_amount, maker_amount)
     } else {
-        return None; // MINT
+        return None; // token-for-token (mint/merge), see `is_token_swap_fill`
     };
 
     let contract = event
@@ -270,15 +320,24 @@ fn parse_trade_data<'a>(
     })
 }
 
+/// Both legs are outcome tokens: complementary orders settled through a mint or
+/// merge of a full set. There is no USDC leg to derive a side or price from.
+fn is_token_swap_fill(event: &serde_json::Value) -> bool {
+    let asset = |k: &str| event.get(k).and_then(|v| v.as_str());
+    match (asset("makerAssetId"), asset("takerAssetId")) {
+        (Some(maker), Some(taker)) => maker != "0" && taker != "0",
+        _ => false,
+    }
+}
+
 fn parse_order_filled(
     event: &serde_json::Value,
     cache: &std::collections::HashMap<String, markets::MarketInfo>,
 ) -> Option<Alert> {
     let td = parse_trade_data(event, cache)?;
 
-    // Whale threshold: $25k USDC = 25_000_000_000 raw (6 decimals)
     let usdc_raw_n: u128 = td.usdc_raw.parse().unwrap_or(0);
-    if usdc_raw_n < 25_000_000_000 {
+    if usdc_raw_n < WHALE_MIN_USDC_RAW {
         return None;
     }
 
@@ -395,18 +454,11 @@ async fn fetch_resolution_context(
     } else {
         format!("0x{condition_id}")
     };
-    let url = format!(
-        "https://gamma-api.polymarket.com/markets?condition_ids={}",
-        cid_hex
-    );
-    let resp = http
-        .get(&url)
-        .timeout(std::time::Duration::from_secs(5))
-        .send()
-        .await
-        .ok()?;
-
-    let body: Vec<serde_json::Value> = resp.json().await.ok()?;
+    let path = format!("/markets?condition_ids={cid_hex}");
+    let body: Vec<serde_json::Value> =
+        markets::gamma_get(http, &path, std::time::Duration::from_secs(5))
+            .await
+            .ok()?;
 
     // Find the market whose conditionId actually matches — Gamma may return
     // unrelated results if the filter param is silently ignored.
@@ -485,6 +537,67 @@ fn is_event_live(event: &serde_json::Value) -> bool {
     delta < 300
 }
 
+// ---------------------------------------------------------------------------
+// WebSocket keepalive (shared by every WS handler)
+// ---------------------------------------------------------------------------
+
+/// Default for WS_PING_INTERVAL_SECS; 0 disables server pings.
+const DEFAULT_WS_PING_INTERVAL_SECS: u64 = 30;
+
+/// Server-initiated pings keep idle connections alive behind proxies with short idle
+/// timeouts. A peer that sent nothing (not even a pong) for two intervals is dead.
+struct Heartbeat {
+    interval: Option<tokio::time::Interval>,
+    timeout: Duration,
+    last_seen: Instant,
+}
+
+impl Heartbeat {
+    fn from_env() -> Self {
+        let secs = env::var("WS_PING_INTERVAL_SECS")
+            .ok()
+            .and_then(|v| v.trim().parse::<u64>().ok())
+            .unwrap_or(DEFAULT_WS_PING_INTERVAL_SECS);
+        let period = Duration::from_secs(secs);
+        let interval = (secs > 0).then(|| {
+            let mut interval =
+                tokio::time::interval_at(tokio::time::Instant::now() + period, period);
+            interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+            interval
+        });
+        Heartbeat {
+            interval,
+            timeout: period * 2,
+            last_seen: Instant::now(),
+        }
+    }
+
+    /// Resolves at the next ping deadline; never when pings are disabled.
+    async fn tick(&mut self) {
+        match self.interval.as_mut() {
+            Some(interval) => {
+                interval.tick().await;
+            }
+            None => std::future::pending().await,
+        }
+    }
+
+    fn saw_activity(&mut self) {
+        self.last_seen = Instant::now();
+    }
+}
+
+/// Pings the peer, or closes the socket if it stopped answering. Returns false when
+/// the handler should exit.
+async fn send_ping(socket: &mut WebSocket, heartbeat: &Heartbeat) -> bool {
+    if heartbeat.last_seen.elapsed() > heartbeat.timeout {
+        tracing::debug!("WebSocket peer missed its pong window, closing");
+        let _ = socket.send(Message::Close(None)).await;
+        return false;
+    }
+    socket.send(Message::Ping(Default::default())).await.is_ok()
+}
+
 // ---------------------------------------------------------------------------
 // GET /ws/alerts — WebSocket upgrade
 // ---------------------------------------------------------------------------
@@ -494,6 +607,7 @@ pub async fn ws_handler(State(state): State<AppState>, ws: WebSocketUpgrade) ->
 }
 
 async fn handle_ws(mut socket: WebSocket, mut rx: broadcast::Receiver<Alert>) {
+    let mut heartbeat = Heartbeat::from_env();
     loop {
         tokio::select! {
             result = rx.recv() => {
@@ -513,12 +627,17 @@ async fn handle_ws(mut socket: WebSocket, mut rx: broadcast::Receiver<Alert>) {
                     Err(broadcast::error::RecvError::Closed) => break,
                 }
             }
+            _ = heartbeat.tick() => {
+                if !send_ping(&mut socket, &heartbeat).await {
+                    break;
+                }
+            }
             // Handle incoming messages (ping/pong/close)
             msg = socket.recv() => {
                 match msg {
                     Some(Ok(Message::Close(_))) | None => break,
                     Some(Err(_)) => break,
-                    _ => {} // Ignore text/binary from client
+                    Some(Ok(_)) => heartbeat.saw_activity(), // pong, or text/binary we ignore
                 }
             }
         }
@@ -566,6 +685,7 @@ async fn handle_trades_ws(
     prefixes: HashSet<String>,
     trader_filter: HashSet<String>,
 ) {
+    let mut heartbeat = Heartbeat::from_env();
     loop {
         tokio::select! {
             result = rx.recv() => {
@@ -593,11 +713,16 @@ async fn handle_trades_ws(
                     Err(broadcast::error::RecvError::Closed) => break,
                 }
             }
+            _ = heartbeat.tick() => {
+                if !send_ping(&mut socket, &heartbeat).await {
+                    break;
+                }
+            }
             msg = socket.recv() => {
                 match msg {
                     Some(Ok(Message::Close(_))) | None => break,
                     Some(Err(_)) => break,
-                    _ => {}
+                    Some(Ok(_)) => heartbeat.saw_activity(),
                 }
             }
         }
@@ -668,11 +793,11 @@ pub async fn signals_ws_handler(
         let query = format!(
             "WITH resolved AS (
                 SELECT asset_id, toNullable(toFloat64(resolved_price)) AS resolved_price
-                FROM poly_dearboard.resolved_prices FINAL
+                FROM resolved_prices FINAL
             )
             SELECT toString(p.trader) AS address
-            FROM poly_dearboard.trader_positions p
-            LEFT JOIN (SELECT asset_id, latest_price FROM poly_dearboard.asset_latest_price FINAL) AS lp ON p.asset_id = lp.asset_id
+            FROM trader_positions p
+            LEFT JOIN (SELECT asset_id, latest_price FROM asset_latest_price FINAL) AS lp ON p.asset_id = lp.asset_id
             LEFT JOIN resolved rp ON p.asset_id = rp.asset_id
             WHERE p.trader NOT IN ({exclude})
             GROUP BY p.trader
@@ -818,6 +943,7 @@ async fn handle_signal_ws(
     let mut sweep_interval = tokio::time::interval(tokio::time::Duration::from_secs(60));
     sweep_interval.tick().await; // skip immediate tick
 
+    let mut heartbeat = Heartbeat::from_env();
     loop {
         tokio::select! {
             result = rx.recv() => {
@@ -860,11 +986,16 @@ async fn handle_signal_ws(
             _ = sweep_interval.tick() => {
                 detector.sweep();
             }
+            _ = heartbeat.tick() => {
+                if !send_ping(&mut socket, &heartbeat).await {
+                    break;
+                }
+            }
             msg = socket.recv() => {
                 match msg {
                     Some(Ok(Message::Close(_))) | None => break,
                     Some(Err(_)) => break,
-                    _ => {}
+                    Some(Ok(_)) => heartbeat.saw_activity(),
                 }
             }
         }
@@ -878,6 +1009,9 @@ async fn handle_signal_ws(
 #[derive(Deserialize)]
 pub struct CopyTradeWsParams {
     token: String,
+    /// `seq` of the last update the client saw; retained updates after it are
+    /// replayed before live ones (see `update_replay`)
+    since: Option<u64>,
 }
 
 pub async fn copytrade_ws_handler(
@@ -888,28 +1022,49 @@ pub async fn copytrade_ws_handler(
     let owner = super::auth::validate_jwt(&params.token, &state.jwt_secret)
         .map_err(|_| (StatusCode::UNAUTHORIZED, "Invalid token".into()))?;
 
-    let rx = state.copytrade_update_tx.subscribe();
-    Ok(ws.on_upgrade(move |socket| handle_copytrade_ws(socket, rx, owner)))
+    // Subscribe before reading the log so nothing falls between replay and live
+    let rx = state.copytrade_sequenced_tx.subscribe();
+    let replay = match params.since {
+        Some(since) => state
+            .copytrade_replay
+            .lock()
+            .unwrap_or_else(|p| p.into_inner())
+            .since(&owner, since),
+        None => Vec::new(),
+    };
+    Ok(ws.on_upgrade(move |socket| handle_copytrade_ws(socket, rx, owner, replay)))
 }
 
 async fn handle_copytrade_ws(
     mut socket: WebSocket,
-    mut rx: broadcast::Receiver<CopyTradeUpdate>,
+    mut rx: broadcast::Receiver<Arc<SequencedUpdate>>,
     owner: String,
+    replay: Vec<Arc<SequencedUpdate>>,
 ) {
+    let mut last_seq = 0;
+    for update in replay {
+        if socket
+            .send(Message::Text(update.json.clone().into()))
+            .await
+            .is_err()
+        {
+            return;
+        }
+        last_seq = update.seq;
+    }
+
+    let mut heartbeat = Heartbeat::from_env();
     loop {
         tokio::select! {
             result = rx.recv() => {
                 match result {
                     Ok(update) => {
-                        // Filter by owner
-                        if update.owner() != owner {
+                        // Filter by owner; skip anything the replay already sent
+                        if update.owner != owner || update.seq <= last_seq {
                             continue;
                         }
-                        if let Ok(json) = serde_json::to_string(&update) {
-                            if socket.send(Message::Text(json.into())).await.is_err() {
-                                break;
-                            }
+                        if socket.send(Message::Text(update.json.clone().into())).await.is_err() {
+                            break;
                         }
                     }
                     Err(broadcast::error::RecvError::Lagged(n)) => {
@@ -918,11 +1073,16 @@ async fn handle_copytrade_ws(
                     Err(_) => break,
                 }
             }
+            _ = heartbeat.tick() => {
+                if !send_ping(&mut socket, &heartbeat).await {
+                    break;
+                }
+            }
             msg = socket.recv() => {
                 match msg {
                     Some(Ok(Message::Close(_))) | None => break,
                     Some(Err(_)) => break,
-                    _ => {}
+                    Some(Ok(_)) => heartbeat.saw_activity(),
                 }
             }
         }
@@ -0,0 +1,246 @@
+//! Confirmation gate between the targeted WS feed and the copy-trade engine.
+//!
+//! With `COPYTRADE_MIN_CONFIRMATIONS=N` (N > 0) trades are buffered until their
+//! block is N blocks behind the chain head, then re-checked against the receipt:
+//! fills whose transaction reverted or was reorged out are dropped instead of
+//! copied. N = 0 (default) bypasses the gate entirely.
+
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::time::Duration;
+
+use futures_util::StreamExt;
+use tokio::sync::{broadcast, mpsc};
+
+use super::alerts::LiveTrade;
+use super::engine::CopyTradeCommand;
+use super::scanner;
+
+const HEAD_POLL_INTERVAL: Duration = Duration::from_secs(2);
+/// Oldest buffered trades are dropped beyond this (RPC outage backstop)
+const MAX_PENDING: usize = 10_000;
+/// Receipt lookups in flight at once while releasing a tick's trades
+const RECEIPT_CONCURRENCY: usize = 8;
+
+pub fn min_confirmations_from_env() -> u64 {
+    match std::env::var("COPYTRADE_MIN_CONFIRMATIONS") {
+        Ok(v) => v.trim().parse().unwrap_or_else(|_| {
+            tracing::warn!("Invalid COPYTRADE_MIN_CONFIRMATIONS={v}, using 0");
+            0
+        }),
+        Err(_) => 0,
+    }
+}
+
+pub async fn run(
+    http: reqwest::Client,
+    rpc: scanner::RpcUrls,
+    min_confirmations: u64,
+    mut rx: broadcast::Receiver<LiveTrade>,
+    tx: broadcast::Sender<LiveTrade>,
+    cmd_tx: mpsc::Sender<CopyTradeCommand>,
+) {
+    tracing::info!("Copy-trade confirmation gate: waiting for {min_confirmations} block(s)");
+
+    let mut pending: VecDeque<LiveTrade> = VecDeque::new();
+    let mut head: u64 = 0;
+    let mut interval = tokio::time::interval(HEAD_POLL_INTERVAL);
+    interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+
+    loop {
+        tokio::select! {
+            result = rx.recv() => {
+                match result {
+                    Ok(trade) => {
+                        if pending.len() >= MAX_PENDING
+                            && let Some(old) = pending.pop_front()
+                        {
+                            tracing::warn!("Confirmation gate full, dropping trade {}", old.tx_hash);
+                        }
+                        pending.push_back(trade);
+                    }
+                    Err(broadcast::error::RecvError::Lagged(n)) => {
+                        tracing::warn!("Confirmation gate lagged, dropped {n} trades");
+                        // The engine never sees these: let it count them and tell its sessions
+                        if cmd_tx.try_send(CopyTradeCommand::SignalsDropped { count: n }).is_err() {
+                            tracing::warn!("Engine command queue full, {n} dropped trade(s) not reported");
+                        }
+                    }
+                    Err(_) => {
+                        tracing::error!("copytrade_live_tx channel closed, confirmation gate shutting down");
+                        return;
+                    }
+                }
+            }
+
+            _ = interval.tick(), if !pending.is_empty() => {
+                match scanner::get_block_number(&http, &rpc).await {
+                    Ok(n) => head = head.max(n),
+                    Err(e) => {
+                        tracing::warn!("Confirmation gate: eth_blockNumber failed: {e}");
+                        continue;
+                    }
+                }
+                release_confirmed(&http, &rpc, min_confirmations, head, &mut pending, &tx).await;
+            }
+        }
+    }
+}
+
+/// Forwards buffered trades that are deep enough and whose transaction still
+/// succeeded on the canonical chain. Trades whose receipt can't be fetched stay
+/// buffered for the next tick.
+async fn release_confirmed(
+    http: &reqwest::Client,
+    rpc: &scanner::RpcUrls,
+    min_confirmations: u64,
+    head: u64,
+    pending: &mut VecDeque<LiveTrade>,
+    tx: &broadcast::Sender<LiveTrade>,
+) {
+    // block_number 0 means the log's block couldn't be parsed: nothing to wait for
+    let deep_enough = |trade: &LiveTrade| {
+        trade.block_number == 0 || trade.block_number + min_confirmations <= head
+    };
+
+    // One receipt lookup per transaction (a match emits several fills), a few at a time
+    let tx_hashes: HashSet<String> = pending
+        .iter()
+        .filter(|trade| deep_enough(trade))
+        .map(|trade| trade.tx_hash.clone())
+        .collect();
+    let outcomes: HashMap<String, Result<Option<bool>, ()>> = futures_util::stream::iter(tx_hashes)
+        .map(|tx_hash| async move {
+            let outcome = scanner::tx_succeeded(http, rpc, &tx_hash)
+                .await
+                .map_err(|e| {
+                    tracing::warn!("Confirmation gate: receipt for {tx_hash} failed: {e}");
+                });
+            (tx_hash, outcome)
+        })
+        .buffer_unordered(RECEIPT_CONCURRENCY)
+        .collect()
+        .await;
+
+    let mut waiting = VecDeque::with_capacity(pending.len());
+    while let Some(trade) = pending.pop_front() {
+        match outcomes.get(&trade.tx_hash) {
+            Some(Ok(Some(true))) => {
+                let _ = tx.send(trade);
+            }
+            Some(Ok(Some(false))) => {
+                tracing::warn!("Dropping copy of reverted fill {}", trade.tx_hash);
+            }
+            Some(Ok(None)) => {
+                tracing::warn!("Dropping copy of reorged-out fill {}", trade.tx_hash);
+            }
+            // Not deep enough yet, or the receipt lookup failed
+            Some(Err(())) | None => waiting.push_back(trade),
+        }
+    }
+
+    *pending = waiting;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+    fn fill(tx_hash: &str, block_number: u64) -> LiveTrade {
+        LiveTrade {
+            tx_hash: tx_hash.into(),
+            block_timestamp: String::new(),
+            trader: "0xtrader".into(),
+            side: "buy".into(),
+            asset_id: "asset".into(),
+            amount: String::new(),
+            price: String::new(),
+            usdc_amount: String::new(),
+            question: String::new(),
+            outcome: String::new(),
+            category: String::new(),
+            block_number,
+            cache_key: String::new(),
+        }
+    }
+
+    /// JSON-RPC node answering every receipt after a short delay: reverted for
+    /// transactions containing "bad", successful otherwise. Tracks how many lookups
+    /// ran and the most in flight at once.
+    async fn mock_rpc() -> (scanner::RpcUrls, Arc<AtomicUsize>, Arc<AtomicUsize>) {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let lookups = Arc::new(AtomicUsize::new(0));
+        let peak = Arc::new(AtomicUsize::new(0));
+        let in_flight = Arc::new(AtomicUsize::new(0));
+        let (lookups_srv, peak_srv) = (lookups.clone(), peak.clone());
+        tokio::spawn(async move {
+            while let Ok((mut socket, _)) = listener.accept().await {
+                let (lookups, peak, in_flight) =
+                    (lookups_srv.clone(), peak_srv.clone(), in_flight.clone());
+                tokio::spawn(async move {
+                    let now = in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+                    peak.fetch_max(now, Ordering::SeqCst);
+                    lookups.fetch_add(1, Ordering::SeqCst);
+
+                    let mut request = Vec::new();
+                    let mut buf = [0u8; 4096];
+                    while !String::from_utf8_lossy(&request).contains('}') {
+                        match socket.read(&mut buf).await {
+                            Ok(0) | Err(_) => break,
+                            Ok(n) => request.extend_from_slice(&buf[..n]),
+                        }
+                    }
+                    tokio::time::sleep(Duration::from_millis(50)).await;
+                    let status = if String::from_utf8_lossy(&request).contains("bad") {
+                        "0x0"
+                    } else {
+                        "0x1"
+                    };
+                    let body =
+                        format!(r#"{{"jsonrpc":"2.0","id":1,"result":{{"status":"{status}"}}}}"#);
+                    let response = format!(
+                        "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\n\
+                         content-length: {}\r\nconnection: close\r\n\r\n{body}",
+                        body.len()
+                    );
+                    in_flight.fetch_sub(1, Ordering::SeqCst);
+                    let _ = socket.write_all(response.as_bytes()).await;
+                });
+            }
+        });
+        (scanner::RpcUrls::from_urls(&[&url]), lookups, peak)
+    }
+
+    #[tokio::test]
+    async fn confirmed_fills_are_released_in_order() {
+        let (rpc, lookups, peak) = mock_rpc().await;
+        let http = reqwest::Client::builder().no_proxy().build().unwrap();
+        let (tx, mut rx) = broadcast::channel(16);
+        let mut pending = VecDeque::from([
+            fill("0xa", 100),
+            fill("0xbad", 100),
+            fill("0xb", 100),
+            fill("0xa", 100),
+            fill("0xc", 100),
+            fill("0xlate", 109),
+        ]);
+
+        release_confirmed(&http, &rpc, 5, 105, &mut pending, &tx).await;
+
+        let released: Vec<_> = std::iter::from_fn(|| rx.try_recv().ok())
+            .map(|t| t.tx_hash)
+            .collect();
+        assert_eq!(released, ["0xa", "0xb", "0xa", "0xc"]);
+        // Too shallow: still buffered, and never looked up
+        assert_eq!(pending.len(), 1);
+        assert_eq!(pending[0].tx_hash, "0xlate");
+        assert_eq!(lookups.load(Ordering::SeqCst), 4);
+        assert!(
+            peak.load(Ordering::SeqCst) > 1,
+            "receipts were fetched one by one"
+        );
+    }
+}
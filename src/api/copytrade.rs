@@ -1,15 +1,25 @@
 use axum::extract::{Json, Path, Query, State};
-use axum::http::StatusCode;
+use axum::http::{StatusCode, header};
 use axum::response::IntoResponse;
 
 use super::db::{self, CopyTradeSessionRow};
 use super::engine::CopyTradeCommand;
-use super::middleware::AuthUser;
+use super::middleware::{AdminUser, AuthUser};
 use super::server::AppState;
 use super::types::{
-    ClosePositionRequest, CopyOrderType, CopyTradeOrder, CopyTradeOrderSummary, CopyTradePosition,
-    CopyTradeSession, CopyTradeSummary, CopyTradeUpdate, CreateSessionRequest, OrderStatus,
-    SessionOrdersParams, SessionPatchRequest, SessionStats, SessionStatus,
+    BookLevel, CapacityEstimate, CapacityEstimateRequest, CapacityPoint, ClobOrderStatus,
+    CloneSessionRequest, ClosePositionRequest, CompareSessionsParams, ComparedSession,
+    CopiedMarket, CopyMode, CopyOrderType, CopySides, CopyTradeAnalyticsParams, CopyTradeOrder,
+    CopyTradeOrderSummary, CopyTradeOverview, CopyTradePosition, CopyTradeSession,
+    CopyTradeSummary, CopyTradeUpdate, CopyTradeVolumeDay, CreateSessionRequest, EngineOpenOrder,
+    GtcPriceRef, LiquidationPosition, LiquidationPreview, MarkPriceSource, OpenOrder,
+    OrderAuditEntry, OrderAuditParams, OrderDetail, OrderOrigin, OrderStatus, RecentOrder,
+    RecentOrdersParams, RecentOrdersResponse, SaveTemplateRequest, SellBehavior, SessionComparison,
+    SessionDiagnosis, SessionFidelity, SessionOpenOrders, SessionOrdersParams, SessionPatchRequest,
+    SessionPreflight, SessionStats, SessionStatsDelta, SessionStatus, SessionTemplate,
+    SessionThroughput, SignalSource, SizeRounding, SizingBase, SizingMode, StalePosition,
+    StalePositionsParams, StopAllResponse, StopAllResult, TaxReportParams, ThroughputParams,
+    TopNMetric, TrackedAddresses, TraderFidelity, WhatIfRequest, WhatIfResponse,
 };
 
 // ---------------------------------------------------------------------------
@@ -19,273 +29,1929 @@ use super::types::{
 pub async fn create_session(
     State(state): State<AppState>,
     AuthUser(owner): AuthUser,
-    Json(req): Json<CreateSessionRequest>,
+    Json(body): Json<serde_json::Value>,
 ) -> Result<impl IntoResponse, (StatusCode, String)> {
-    // Validate config
+    let req = apply_template(&state, &owner, body)?;
+    let session = create_and_start_session(&state, &owner, req).await?;
+    Ok(Json(session))
+}
+
+/// With `template_id`, the saved template's fields are the base and every field in
+/// the request body overrides them (an explicit null clears an optional field).
+fn apply_template(
+    state: &AppState,
+    owner: &str,
+    body: serde_json::Value,
+) -> Result<CreateSessionRequest, (StatusCode, String)> {
+    let serde_json::Value::Object(mut fields) = body else {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            "Request body must be a JSON object".into(),
+        ));
+    };
+    if let Some(template_id) = fields.remove("template_id") {
+        let template_id = template_id.as_str().ok_or((
+            StatusCode::BAD_REQUEST,
+            "template_id must be a string".to_string(),
+        ))?;
+        let template = {
+            let conn = state.user_db.lock().unwrap_or_else(|p| p.into_inner());
+            db::get_session_template(&conn, template_id, owner)
+                .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
+                .ok_or((StatusCode::NOT_FOUND, "Template not found".to_string()))?
+        };
+        let mut merged: serde_json::Map<String, serde_json::Value> =
+            serde_json::from_str(&template.config)
+                .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+        merged.extend(fields);
+        fields = merged;
+    }
+    serde_json::from_value(serde_json::Value::Object(fields))
+        .map_err(|e| (StatusCode::UNPROCESSABLE_ENTITY, e.to_string()))
+}
+
+const MAX_ALLOWLIST_ASSETS: usize = 200;
+const MAX_FILTER_CATEGORIES: usize = 50;
+const MAX_SLIPPAGE_OVERRIDE_BPS: u32 = 10_000;
+const MAX_GTC_PRICE_OFFSET_BPS: u32 = 1000;
+const MAX_SLIPPAGE_FEE_BPS: u32 = 1000;
+/// One week: longer holds would effectively disable copied exits
+const MAX_MIN_HOLD_SECS: u32 = 7 * 24 * 3600;
+
+const DEFAULT_MAX_ACTIVE_SESSIONS_PER_USER: u32 = 10;
+
+/// Running + paused sessions one user may hold at once (MAX_ACTIVE_SESSIONS_PER_USER).
+/// Every session trades through the shared CLOB account and its rate limit.
+fn max_active_sessions_per_user() -> u32 {
+    static MAX: std::sync::OnceLock<u32> = std::sync::OnceLock::new();
+    *MAX.get_or_init(|| match std::env::var("MAX_ACTIVE_SESSIONS_PER_USER") {
+        Ok(v) => match v.trim().parse::<u32>() {
+            Ok(n) if n >= 1 => n,
+            _ => {
+                tracing::warn!(
+                    "Invalid MAX_ACTIVE_SESSIONS_PER_USER={v}, using default {DEFAULT_MAX_ACTIVE_SESSIONS_PER_USER}"
+                );
+                DEFAULT_MAX_ACTIVE_SESSIONS_PER_USER
+            }
+        },
+        Err(_) => DEFAULT_MAX_ACTIVE_SESSIONS_PER_USER,
+    })
+}
+
+/// CLOB token ids are uint256 values in decimal
+pub(crate) fn valid_token_id(id: &str) -> bool {
+    !id.is_empty() && id.len() <= 78 && id.bytes().all(|b| b.is_ascii_digit())
+}
+
+/// Checks the list_id / top_n / top_pct selection of a "traders" session.
+fn validate_trader_source(req: &CreateSessionRequest) -> Result<(), (StatusCode, String)> {
+    // list_id + top_n is the one allowed combination: the list's top N members
+    if req.top_pct.is_some() && (req.list_id.is_some() || req.top_n.is_some()) {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            "top_pct can't be combined with list_id or top_n".into(),
+        ));
+    }
+    if req.list_id.is_none() && req.top_n.is_none() && req.top_pct.is_none() {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            "Specify one of list_id, top_n or top_pct".into(),
+        ));
+    }
+    if req.top_pct.is_some_and(|p| p <= 0.0 || p > 100.0) {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            "top_pct must be in (0, 100]".into(),
+        ));
+    }
+    if req.top_n == Some(0) {
+        return Err((StatusCode::BAD_REQUEST, "top_n must be at least 1".into()));
+    }
+    match TopNMetric::from_str(&req.top_n_metric) {
+        None => {
+            return Err((
+                StatusCode::BAD_REQUEST,
+                "top_n_metric must be pnl, roi, volume or consistency".into(),
+            ));
+        }
+        Some(TopNMetric::Pnl) => {}
+        Some(_) if req.top_n.is_none() && req.top_pct.is_none() => {
+            return Err((
+                StatusCode::BAD_REQUEST,
+                "top_n_metric requires top_n or top_pct".into(),
+            ));
+        }
+        Some(_) => {}
+    }
+    Ok(())
+}
+
+/// Trims and dedups a comma-separated category list (case-insensitively); None when
+/// it names no category, which clears the filter.
+fn normalize_categories(field: &str, raw: &str) -> Result<Option<String>, (StatusCode, String)> {
+    let mut categories: Vec<&str> = Vec::new();
+    for category in raw.split(',').map(str::trim).filter(|c| !c.is_empty()) {
+        if !categories.iter().any(|c| c.eq_ignore_ascii_case(category)) {
+            categories.push(category);
+        }
+    }
+    if categories.len() > MAX_FILTER_CATEGORIES {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            format!("{field} may list at most {MAX_FILTER_CATEGORIES} categories"),
+        ));
+    }
+    Ok((!categories.is_empty()).then(|| categories.join(",")))
+}
+
+/// Config checks shared by session creation and preflight.
+fn validate_session_request(req: &CreateSessionRequest) -> Result<(), (StatusCode, String)> {
+    if req.copy_pct < 0.05 || req.copy_pct > 1.0 {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            "copy_pct must be between 0.05 and 1.0".into(),
+        ));
+    }
+    if req.initial_capital <= 0.0 {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            "initial_capital must be positive".into(),
+        ));
+    }
+    if req.auto_approve && req.simulate {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            "auto_approve only applies to live sessions".into(),
+        ));
+    }
+    if req.max_position_usdc <= 0.0 {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            "max_position_usdc must be positive".into(),
+        ));
+    }
+    match SignalSource::from_str(&req.signal_source) {
+        Some(SignalSource::WhaleFollow) => {
+            if req.list_id.is_some() || req.top_n.is_some() || req.top_pct.is_some() {
+                return Err((
+                    StatusCode::BAD_REQUEST,
+                    "whale_follow can't be combined with list_id, top_n or top_pct".into(),
+                ));
+            }
+            if !req
+                .min_whale_usdc
+                .is_some_and(|v| v >= super::alerts::WHALE_MIN_USDC)
+            {
+                return Err((
+                    StatusCode::BAD_REQUEST,
+                    format!(
+                        "whale_follow requires min_whale_usdc of at least {}",
+                        super::alerts::WHALE_MIN_USDC
+                    ),
+                ));
+            }
+        }
+        Some(SignalSource::Traders) => {
+            if req.min_whale_usdc.is_some() {
+                return Err((
+                    StatusCode::BAD_REQUEST,
+                    "min_whale_usdc requires signal_source whale_follow".into(),
+                ));
+            }
+            validate_trader_source(req)?;
+        }
+        None => {
+            return Err((
+                StatusCode::BAD_REQUEST,
+                "signal_source must be traders or whale_follow".into(),
+            ));
+        }
+    }
+    if CopyOrderType::from_str(&req.order_type).is_none() {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            "order_type must be FOK, FAK or GTC".into(),
+        ));
+    }
+    for (field, value) in [
+        ("buy_order_type", &req.buy_order_type),
+        ("sell_order_type", &req.sell_order_type),
+    ] {
+        if value
+            .as_deref()
+            .is_some_and(|v| CopyOrderType::from_str(v).is_none())
+        {
+            return Err((
+                StatusCode::BAD_REQUEST,
+                format!("{field} must be FOK, FAK or GTC"),
+            ));
+        }
+    }
+
+    if req
+        .min_trader_winrate
+        .is_some_and(|w| !(0.0..=100.0).contains(&w))
+    {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            "min_trader_winrate must be between 0 and 100".into(),
+        ));
+    }
+    if req.slippage_pause_after == Some(0) {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            "slippage_pause_after must be at least 1".into(),
+        ));
+    }
+    if req.min_liquidity_usdc.is_some_and(|v| v < 0.0) {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            "min_liquidity_usdc must not be negative".into(),
+        ));
+    }
+    if req
+        .liquidity_window_mins
+        .is_some_and(|m| m == 0 || m > super::engine::MAX_LIQUIDITY_WINDOW_MINS)
+    {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            format!(
+                "liquidity_window_mins must be between 1 and {}",
+                super::engine::MAX_LIQUIDITY_WINDOW_MINS
+            ),
+        ));
+    }
+    if req.max_market_usdc.is_some_and(|v| v <= 0.0) {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            "max_market_usdc must be positive".into(),
+        ));
+    }
+    if MarkPriceSource::from_str(&req.mark_price_source).is_none() {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            "mark_price_source must be last_fill or live".into(),
+        ));
+    }
+    if SizingMode::from_str(&req.sizing_mode).is_none() {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            "sizing_mode must be dollar or portfolio".into(),
+        ));
+    }
+    if SizingBase::from_str(&req.sizing_base).is_none() {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            "sizing_base must be remaining or initial".into(),
+        ));
+    }
+    if req.max_runtime_secs == Some(0) {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            "max_runtime_secs must be positive".into(),
+        ));
+    }
+    if let Some(ids) = &req.asset_allowlist {
+        if ids.is_empty() || ids.len() > MAX_ALLOWLIST_ASSETS {
+            return Err((
+                StatusCode::BAD_REQUEST,
+                format!("asset_allowlist must hold 1-{MAX_ALLOWLIST_ASSETS} token ids"),
+            ));
+        }
+        if let Some(bad) = ids.iter().find(|id| !valid_token_id(id.trim())) {
+            return Err((
+                StatusCode::BAD_REQUEST,
+                format!("Invalid token id in asset_allowlist: {bad}"),
+            ));
+        }
+    }
+    if req
+        .near_resolution_price
+        .is_some_and(|p| p <= 0.5 || p >= 1.0)
+    {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            "near_resolution_price must be between 0.5 and 1 (exclusive)".into(),
+        ));
+    }
+    if GtcPriceRef::from_str(&req.gtc_price_ref).is_none() {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            "gtc_price_ref must be source or current".into(),
+        ));
+    }
+    normalize_categories(
+        "allowed_categories",
+        req.allowed_categories.as_deref().unwrap_or_default(),
+    )?;
+    normalize_categories(
+        "blocked_categories",
+        req.blocked_categories.as_deref().unwrap_or_default(),
+    )?;
+    if CopySides::from_str(&req.copy_sides).is_none() {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            "copy_sides must be both, buy or sell".into(),
+        ));
+    }
+    if CopyMode::from_str(&req.mode).is_none() {
+        return Err((StatusCode::BAD_REQUEST, "mode must be copy or fade".into()));
+    }
+    if SizeRounding::from_str(&req.size_rounding).is_none() {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            "size_rounding must be down or nearest".into(),
+        ));
+    }
+    if SellBehavior::from_str(&req.sell_behavior).is_none() {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            "sell_behavior must be proportional or full_exit".into(),
+        ));
+    }
+    match (req.trail_activate_pct, req.trail_distance_pct) {
+        (None, None) => {}
+        (Some(activate), Some(distance)) => {
+            if !(activate.is_finite() && activate > 0.0) {
+                return Err((
+                    StatusCode::BAD_REQUEST,
+                    "trail_activate_pct must be greater than 0".into(),
+                ));
+            }
+            if !(distance > 0.0 && distance < 100.0) {
+                return Err((
+                    StatusCode::BAD_REQUEST,
+                    "trail_distance_pct must be between 0 and 100 (exclusive)".into(),
+                ));
+            }
+        }
+        _ => {
+            return Err((
+                StatusCode::BAD_REQUEST,
+                "trail_activate_pct and trail_distance_pct must be set together".into(),
+            ));
+        }
+    }
+    if req
+        .take_profit_pct
+        .is_some_and(|pct| !(pct.is_finite() && pct > 0.0))
+    {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            "take_profit_pct must be greater than 0".into(),
+        ));
+    }
+    if req
+        .stop_loss_pct
+        .is_some_and(|pct| !(pct > 0.0 && pct < 100.0))
+    {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            "stop_loss_pct must be between 0 and 100 (exclusive)".into(),
+        ));
+    }
+    if req
+        .slippage_fee_bps
+        .is_some_and(|bps| bps > MAX_SLIPPAGE_FEE_BPS)
+    {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            format!("slippage_fee_bps must be at most {MAX_SLIPPAGE_FEE_BPS}"),
+        ));
+    }
+    if req.min_hold_secs > MAX_MIN_HOLD_SECS {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            format!("min_hold_secs must be at most {MAX_MIN_HOLD_SECS}"),
+        ));
+    }
+    if let Some(overrides) = &req.slippage_overrides {
+        if overrides.is_empty() || overrides.len() > MAX_ALLOWLIST_ASSETS {
+            return Err((
+                StatusCode::BAD_REQUEST,
+                format!("slippage_overrides must hold 1-{MAX_ALLOWLIST_ASSETS} token ids"),
+            ));
+        }
+        if let Some(bad) = overrides.keys().find(|id| !valid_token_id(id.trim())) {
+            return Err((
+                StatusCode::BAD_REQUEST,
+                format!("Invalid token id in slippage_overrides: {bad}"),
+            ));
+        }
+        if overrides
+            .values()
+            .any(|&bps| bps > MAX_SLIPPAGE_OVERRIDE_BPS)
+        {
+            return Err((
+                StatusCode::BAD_REQUEST,
+                format!(
+                    "slippage_overrides values must be at most {MAX_SLIPPAGE_OVERRIDE_BPS} bps"
+                ),
+            ));
+        }
+    }
+    if req
+        .max_orders_per_minute
+        .is_some_and(|n| n == 0 || n > super::engine::MAX_SESSION_ORDERS_PER_MINUTE)
+    {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            format!(
+                "max_orders_per_minute must be between 1 and {}",
+                super::engine::MAX_SESSION_ORDERS_PER_MINUTE
+            ),
+        ));
+    }
+    if req.max_signal_age_secs == Some(0) {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            "max_signal_age_secs must be greater than 0".into(),
+        ));
+    }
+    if req.mark_stale_secs == Some(0) {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            "mark_stale_secs must be greater than 0".into(),
+        ));
+    }
+    if req.gtc_price_offset_bps.unsigned_abs() > MAX_GTC_PRICE_OFFSET_BPS {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            format!("gtc_price_offset_bps must be within ±{MAX_GTC_PRICE_OFFSET_BPS}"),
+        ));
+    }
+    if let Some(url) = &req.webhook_url
+        && let Err(e) = super::digest::validate_webhook_url(url)
+    {
+        return Err((StatusCode::BAD_REQUEST, e));
+    }
+    if let Some(hours) = req.digest_interval_hours {
+        if req.webhook_url.is_none() {
+            return Err((
+                StatusCode::BAD_REQUEST,
+                "digest_interval_hours requires webhook_url".into(),
+            ));
+        }
+        if !(1..=168).contains(&hours) {
+            return Err((
+                StatusCode::BAD_REQUEST,
+                "digest_interval_hours must be between 1 and 168".into(),
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// `auto_approve` sends real on-chain approvals, so only for a session that will trade
+/// live: never for a simulated one or while FORCE_SIMULATE is on.
+fn approves_on_create(req: &CreateSessionRequest) -> bool {
+    req.auto_approve && !req.simulate && !super::engine::force_simulate()
+}
+
+/// Validate, persist and start a new session. Shared by create and clone.
+async fn create_and_start_session(
+    state: &AppState,
+    owner: &str,
+    req: CreateSessionRequest,
+) -> Result<CopyTradeSession, (StatusCode, String)> {
+    validate_session_request(&req)?;
+
+    let active = {
+        let conn = state.user_db.lock().unwrap_or_else(|p| p.into_inner());
+        db::count_active_copytrade_sessions(&conn, owner)
+            .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
+    };
+    let max_active = max_active_sessions_per_user();
+    if active >= max_active {
+        return Err((
+            StatusCode::CONFLICT,
+            format!(
+                "Active session limit reached ({max_active}); stop a running or paused session first"
+            ),
+        ));
+    }
+
+    if req.auto_approve && super::engine::force_simulate() {
+        tracing::warn!("FORCE_SIMULATE is on: skipping exchange approvals for {owner}");
+    }
+
+    // If not simulation, require funded wallet with CLOB credentials
+    let mut approval = None;
+    if !req.simulate {
+        let wallets = {
+            let conn = state.user_db.lock().unwrap_or_else(|p| p.into_inner());
+            db::get_trading_wallets(&conn, owner)
+                .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
+        };
+        // The engine trades through the first credentialed wallet
+        let Some(wallet) = wallets.iter().find(|w| w.clob_api_key.is_some()) else {
+            return Err((
+                StatusCode::BAD_REQUEST,
+                "No wallet with CLOB credentials. Derive credentials first.".into(),
+            ));
+        };
+        if approves_on_create(&req) {
+            let result = super::wallet::approve_wallet(state, owner, wallet)
+                .await
+                .map_err(|(status, msg)| (status, format!("Exchange approval failed: {msg}")))?;
+            approval = Some(result);
+        }
+    }
+
+    // Create session
+    let id = uuid::Uuid::new_v4().to_string();
+    let now = chrono::Utc::now().to_rfc3339();
+    let order_type_str = CopyOrderType::from_str(&req.order_type)
+        .unwrap_or(CopyOrderType::FOK)
+        .as_str()
+        .to_string();
+
+    let row = CopyTradeSessionRow {
+        id: id.clone(),
+        owner: owner.to_string(),
+        list_id: req.list_id.clone(),
+        top_n: req.top_n,
+        top_pct: req.top_pct,
+        copy_pct: req.copy_pct,
+        max_position_usdc: req.max_position_usdc,
+        max_slippage_bps: req.max_slippage_bps,
+        order_type: order_type_str,
+        initial_capital: req.initial_capital,
+        remaining_capital: req.initial_capital,
+        simulate: req.simulate,
+        max_loss_pct: req.max_loss_pct,
+        status: "running".to_string(),
+        created_at: now.clone(),
+        updated_at: now,
+        only_favorable: req.only_favorable,
+        buy_order_type: req
+            .buy_order_type
+            .as_deref()
+            .and_then(CopyOrderType::from_str)
+            .map(|t| t.as_str().to_string()),
+        sell_order_type: req
+            .sell_order_type
+            .as_deref()
+            .and_then(CopyOrderType::from_str)
+            .map(|t| t.as_str().to_string()),
+        mark_price_source: req.mark_price_source.clone(),
+        min_trader_pnl: req.min_trader_pnl,
+        min_trader_winrate: req.min_trader_winrate,
+        slippage_pause_after: req.slippage_pause_after,
+        min_liquidity_usdc: req.min_liquidity_usdc,
+        close_on_trader_removal: req.close_on_trader_removal,
+        max_market_usdc: req.max_market_usdc,
+        seed_existing_positions: req.seed_existing_positions,
+        sizing_mode: req.sizing_mode.clone(),
+        webhook_url: req.webhook_url.clone(),
+        digest_interval_hours: req.digest_interval_hours,
+        sizing_base: req.sizing_base.clone(),
+        max_runtime_secs: req.max_runtime_secs,
+        asset_allowlist: req.asset_allowlist.as_ref().map(|ids| {
+            let ids: Vec<&str> = ids.iter().map(|id| id.trim()).collect();
+            serde_json::to_string(&ids).unwrap_or_default()
+        }),
+        near_resolution_price: req.near_resolution_price,
+        gtc_price_ref: req.gtc_price_ref.clone(),
+        gtc_price_offset_bps: req.gtc_price_offset_bps,
+        sell_behavior: req.sell_behavior.clone(),
+        trail_activate_pct: req.trail_activate_pct,
+        trail_distance_pct: req.trail_distance_pct,
+        mark_stale_secs: req.mark_stale_secs,
+        signal_source: req.signal_source.clone(),
+        min_whale_usdc: req.min_whale_usdc,
+        slippage_fee_bps: req.slippage_fee_bps,
+        max_signal_age_secs: req.max_signal_age_secs,
+        top_n_metric: req.top_n_metric.clone(),
+        min_hold_secs: req.min_hold_secs,
+        slippage_overrides: req.slippage_overrides.as_ref().map(|overrides| {
+            let overrides: std::collections::BTreeMap<&str, u32> = overrides
+                .iter()
+                .map(|(id, &bps)| (id.trim(), bps))
+                .collect();
+            serde_json::to_string(&overrides).unwrap_or_default()
+        }),
+        max_orders_per_minute: req.max_orders_per_minute,
+        size_rounding: req.size_rounding.clone(),
+        take_profit_pct: req.take_profit_pct,
+        stop_loss_pct: req.stop_loss_pct,
+        mode: req.mode.clone(),
+        allowed_categories: req
+            .allowed_categories
+            .as_deref()
+            .map(|raw| normalize_categories("allowed_categories", raw))
+            .transpose()?
+            .flatten(),
+        blocked_categories: req
+            .blocked_categories
+            .as_deref()
+            .map(|raw| normalize_categories("blocked_categories", raw))
+            .transpose()?
+            .flatten(),
+        copy_uncategorized: req.copy_uncategorized,
+        copy_sides: req.copy_sides.clone(),
+        fok_fallback_gtc: req.fok_fallback_gtc,
+        liquidity_window_mins: req.liquidity_window_mins,
+        fees_paid: 0.0,
+    };
+
+    {
+        let conn = state.user_db.lock().unwrap_or_else(|p| p.into_inner());
+        db::create_copytrade_session(&conn, &row)
+            .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+    }
+
+    // Send Start command to engine
+    let _ = state
+        .copytrade_cmd_tx
+        .send(CopyTradeCommand::Start {
+            session_id: id.clone(),
+            owner: owner.to_string(),
+        })
+        .await;
+
+    let mut session = session_from_row(&row, 0.0); // New session, no positions yet
+    session.approval = approval;
+    Ok(session)
+}
+
+// ---------------------------------------------------------------------------
+// POST /api/copytrade/sessions/:id/clone
+// ---------------------------------------------------------------------------
+
+pub async fn clone_session(
+    State(state): State<AppState>,
+    AuthUser(owner): AuthUser,
+    Path(id): Path<String>,
+    Json(req): Json<CloneSessionRequest>,
+) -> Result<impl IntoResponse, (StatusCode, String)> {
+    let source = {
+        let conn = state.user_db.lock().unwrap_or_else(|p| p.into_inner());
+        db::get_copytrade_session(&conn, &id, &owner)
+            .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
+            .ok_or((StatusCode::NOT_FOUND, "Session not found".into()))?
+    };
+
+    // Config only — orders and positions stay with the source session
+    let create = CreateSessionRequest {
+        list_id: source.list_id,
+        top_n: source.top_n,
+        top_pct: source.top_pct,
+        copy_pct: source.copy_pct,
+        max_position_usdc: source.max_position_usdc,
+        max_slippage_bps: source.max_slippage_bps,
+        order_type: source.order_type,
+        initial_capital: req.initial_capital,
+        simulate: req.simulate.unwrap_or(source.simulate),
+        max_loss_pct: source.max_loss_pct,
+        only_favorable: source.only_favorable,
+        buy_order_type: source.buy_order_type,
+        sell_order_type: source.sell_order_type,
+        mark_price_source: source.mark_price_source,
+        min_trader_pnl: source.min_trader_pnl,
+        min_trader_winrate: source.min_trader_winrate,
+        slippage_pause_after: source.slippage_pause_after,
+        min_liquidity_usdc: source.min_liquidity_usdc,
+        close_on_trader_removal: source.close_on_trader_removal,
+        max_market_usdc: source.max_market_usdc,
+        seed_existing_positions: source.seed_existing_positions,
+        sizing_mode: source.sizing_mode,
+        webhook_url: source.webhook_url,
+        digest_interval_hours: source.digest_interval_hours,
+        sizing_base: source.sizing_base,
+        max_runtime_secs: source.max_runtime_secs,
+        asset_allowlist: source
+            .asset_allowlist
+            .as_deref()
+            .and_then(|json| serde_json::from_str(json).ok()),
+        near_resolution_price: source.near_resolution_price,
+        gtc_price_ref: source.gtc_price_ref,
+        gtc_price_offset_bps: source.gtc_price_offset_bps,
+        sell_behavior: source.sell_behavior,
+        trail_activate_pct: source.trail_activate_pct,
+        trail_distance_pct: source.trail_distance_pct,
+        mark_stale_secs: source.mark_stale_secs,
+        signal_source: source.signal_source,
+        min_whale_usdc: source.min_whale_usdc,
+        slippage_fee_bps: source.slippage_fee_bps,
+        max_signal_age_secs: source.max_signal_age_secs,
+        top_n_metric: source.top_n_metric,
+        min_hold_secs: source.min_hold_secs,
+        slippage_overrides: source
+            .slippage_overrides
+            .as_deref()
+            .and_then(|json| serde_json::from_str(json).ok()),
+        max_orders_per_minute: source.max_orders_per_minute,
+        size_rounding: source.size_rounding,
+        take_profit_pct: source.take_profit_pct,
+        stop_loss_pct: source.stop_loss_pct,
+        mode: source.mode,
+        allowed_categories: source.allowed_categories,
+        blocked_categories: source.blocked_categories,
+        copy_uncategorized: source.copy_uncategorized,
+        copy_sides: source.copy_sides,
+        fok_fallback_gtc: source.fok_fallback_gtc,
+        liquidity_window_mins: source.liquidity_window_mins,
+        auto_approve: false,
+    };
+    let session = create_and_start_session(&state, &owner, create).await?;
+    Ok(Json(session))
+}
+
+// ---------------------------------------------------------------------------
+// Session templates: /api/copytrade/templates
+// ---------------------------------------------------------------------------
+
+fn template_from_row(row: db::SessionTemplateRow) -> SessionTemplate {
+    SessionTemplate {
+        id: row.id,
+        name: row.name,
+        config: serde_json::from_str(&row.config).unwrap_or_default(),
+        created_at: row.created_at,
+        updated_at: row.updated_at,
+    }
+}
+
+pub async fn list_templates(
+    State(state): State<AppState>,
+    AuthUser(owner): AuthUser,
+) -> Result<impl IntoResponse, (StatusCode, String)> {
+    let rows = {
+        let conn = state.user_db.lock().unwrap_or_else(|p| p.into_inner());
+        db::get_session_templates(&conn, &owner)
+            .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
+    };
+    let templates: Vec<SessionTemplate> = rows.into_iter().map(template_from_row).collect();
+    Ok(Json(templates))
+}
+
+/// Saving under an existing name overwrites that template's config.
+pub async fn save_template(
+    State(state): State<AppState>,
+    AuthUser(owner): AuthUser,
+    Json(req): Json<SaveTemplateRequest>,
+) -> Result<impl IntoResponse, (StatusCode, String)> {
+    let name = req.name.trim();
+    if name.is_empty() || name.len() > 64 {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            "Template name must be 1-64 characters".into(),
+        ));
+    }
+    let serde_json::Value::Object(mut config) = req.config else {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            "config must be a JSON object".into(),
+        ));
+    };
+    // Capital is chosen per session; template_id can't nest
+    config.remove("initial_capital");
+    config.remove("template_id");
+
+    // Shape check only — values are validated when a session is created from it
+    let mut probe = config.clone();
+    probe.insert("initial_capital".into(), serde_json::json!(1.0));
+    if let Err(e) = serde_json::from_value::<CreateSessionRequest>(serde_json::Value::Object(probe))
+    {
+        return Err((StatusCode::BAD_REQUEST, format!("Invalid config: {e}")));
+    }
+
+    let config_json = serde_json::Value::Object(config).to_string();
+    let row = {
+        let conn = state.user_db.lock().unwrap_or_else(|p| p.into_inner());
+        db::save_session_template(&conn, &owner, name, &config_json)
+            .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
+            .ok_or((
+                StatusCode::BAD_REQUEST,
+                "Maximum 20 templates per user".to_string(),
+            ))?
+    };
+    Ok(Json(template_from_row(row)))
+}
+
+pub async fn get_template(
+    State(state): State<AppState>,
+    AuthUser(owner): AuthUser,
+    Path(id): Path<String>,
+) -> Result<impl IntoResponse, (StatusCode, String)> {
+    let row = {
+        let conn = state.user_db.lock().unwrap_or_else(|p| p.into_inner());
+        db::get_session_template(&conn, &id, &owner)
+            .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
+            .ok_or((StatusCode::NOT_FOUND, "Template not found".to_string()))?
+    };
+    Ok(Json(template_from_row(row)))
+}
+
+pub async fn delete_template(
+    State(state): State<AppState>,
+    AuthUser(owner): AuthUser,
+    Path(id): Path<String>,
+) -> Result<impl IntoResponse, (StatusCode, String)> {
+    let deleted = {
+        let conn = state.user_db.lock().unwrap_or_else(|p| p.into_inner());
+        db::delete_session_template(&conn, &id, &owner)
+            .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
+    };
+    if !deleted {
+        return Err((StatusCode::NOT_FOUND, "Template not found".into()));
+    }
+    Ok(StatusCode::NO_CONTENT)
+}
+
+// ---------------------------------------------------------------------------
+// GET /api/copytrade/sessions
+// ---------------------------------------------------------------------------
+
+pub async fn list_sessions(
+    State(state): State<AppState>,
+    AuthUser(owner): AuthUser,
+) -> Result<impl IntoResponse, (StatusCode, String)> {
+    let sessions = {
+        let conn = state.user_db.lock().unwrap_or_else(|p| p.into_inner());
+        let rows = db::get_copytrade_sessions(&conn, &owner)
+            .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+        rows.iter()
+            .map(|r| {
+                let pv = db::get_session_positions_value(&conn, &r.id).unwrap_or(0.0);
+                session_from_row(r, pv)
+            })
+            .collect::<Vec<CopyTradeSession>>()
+    };
+    Ok(Json(sessions))
+}
+
+// ---------------------------------------------------------------------------
+// GET /api/copytrade/sessions/:id
+// ---------------------------------------------------------------------------
+
+pub async fn get_session(
+    State(state): State<AppState>,
+    AuthUser(owner): AuthUser,
+    Path(id): Path<String>,
+) -> Result<impl IntoResponse, (StatusCode, String)> {
+    let conn = state.user_db.lock().unwrap_or_else(|p| p.into_inner());
+    let row = db::get_copytrade_session(&conn, &id, &owner)
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+    match row {
+        Some(r) => {
+            let pv = db::get_session_positions_value(&conn, &r.id).unwrap_or(0.0);
+            Ok(Json(session_from_row(&r, pv)))
+        }
+        None => Err((StatusCode::NOT_FOUND, "Session not found".into())),
+    }
+}
+
+// ---------------------------------------------------------------------------
+// PATCH /api/copytrade/sessions/:id
+// ---------------------------------------------------------------------------
+
+pub async fn update_session(
+    State(state): State<AppState>,
+    AuthUser(owner): AuthUser,
+    Path(id): Path<String>,
+    Json(req): Json<SessionPatchRequest>,
+) -> Result<impl IntoResponse, (StatusCode, String)> {
+    // Load session to verify ownership
+    let row = {
+        let conn = state.user_db.lock().unwrap_or_else(|p| p.into_inner());
+        db::get_copytrade_session(&conn, &id, &owner)
+            .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
+    };
+    let row = row.ok_or((StatusCode::NOT_FOUND, "Session not found".into()))?;
+    let current = SessionStatus::from_str(&row.status).ok_or((
+        StatusCode::INTERNAL_SERVER_ERROR,
+        "Invalid session status".into(),
+    ))?;
+
+    let filters_given = req.allowed_categories.is_some()
+        || req.blocked_categories.is_some()
+        || req.copy_uncategorized.is_some();
+    if req.action.is_none() && !filters_given {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            "Give an action or category filters to update".into(),
+        ));
+    }
+
+    // Validate the action before changing anything
+    let status_change = match req.action.as_deref() {
+        None => None,
+        Some(action) => Some(match action {
+            "pause" => {
+                if current != SessionStatus::Running {
+                    return Err((
+                        StatusCode::BAD_REQUEST,
+                        "Can only pause a running session".into(),
+                    ));
+                }
+                (
+                    "paused",
+                    CopyTradeCommand::Pause {
+                        session_id: id.clone(),
+                    },
+                )
+            }
+            "resume" => {
+                if current != SessionStatus::Paused {
+                    return Err((
+                        StatusCode::BAD_REQUEST,
+                        "Can only resume a paused session".into(),
+                    ));
+                }
+                (
+                    "running",
+                    CopyTradeCommand::Resume {
+                        session_id: id.clone(),
+                        owner: owner.clone(),
+                    },
+                )
+            }
+            "stop" => {
+                if current == SessionStatus::Stopped {
+                    return Err((StatusCode::BAD_REQUEST, "Session already stopped".into()));
+                }
+                (
+                    "stopped",
+                    CopyTradeCommand::Stop {
+                        session_id: id.clone(),
+                    },
+                )
+            }
+            _ => {
+                return Err((
+                    StatusCode::BAD_REQUEST,
+                    "action must be pause, resume, or stop".into(),
+                ));
+            }
+        }),
+    };
+
+    if filters_given {
+        let allowed_categories = match req.allowed_categories.as_deref() {
+            Some(raw) => normalize_categories("allowed_categories", raw)?,
+            None => row.allowed_categories.clone(),
+        };
+        let blocked_categories = match req.blocked_categories.as_deref() {
+            Some(raw) => normalize_categories("blocked_categories", raw)?,
+            None => row.blocked_categories.clone(),
+        };
+        let copy_uncategorized = req.copy_uncategorized.unwrap_or(row.copy_uncategorized);
+        {
+            let conn = state.user_db.lock().unwrap_or_else(|p| p.into_inner());
+            db::update_session_categories(
+                &conn,
+                &id,
+                allowed_categories.as_deref(),
+                blocked_categories.as_deref(),
+                copy_uncategorized,
+            )
+            .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+        }
+        let _ = state
+            .copytrade_cmd_tx
+            .send(CopyTradeCommand::UpdateCategories {
+                session_id: id.clone(),
+                allowed_categories,
+                blocked_categories,
+                copy_uncategorized,
+            })
+            .await;
+    }
+
+    if let Some((new_status, cmd)) = status_change {
+        apply_status_change(&state, &id, new_status, cmd).await?;
+    }
+
+    // Return updated session
+    let conn = state.user_db.lock().unwrap_or_else(|p| p.into_inner());
+    let updated = db::get_copytrade_session(&conn, &id, &owner)
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+    match updated {
+        Some(r) => {
+            let pv = db::get_session_positions_value(&conn, &r.id).unwrap_or(0.0);
+            Ok(Json(session_from_row(&r, pv)))
+        }
+        None => Err((StatusCode::NOT_FOUND, "Session not found".into())),
+    }
+}
+
+/// Writes the new status to SQLite immediately, then hands the command to the engine.
+async fn apply_status_change(
+    state: &AppState,
+    id: &str,
+    new_status: &str,
+    cmd: CopyTradeCommand,
+) -> Result<(), (StatusCode, String)> {
+    {
+        let conn = state.user_db.lock().unwrap_or_else(|p| p.into_inner());
+        db::update_session_status(&conn, id, new_status)
+            .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+    }
+    let _ = state.copytrade_cmd_tx.send(cmd).await;
+    Ok(())
+}
+
+// ---------------------------------------------------------------------------
+// POST /api/copytrade/stop-all
+// ---------------------------------------------------------------------------
+
+/// Stops every running or paused session of the caller, like PATCH action=stop on
+/// each: open GTC orders are canceled and the sessions can't be resumed. Positions
+/// stay open. Use action=pause per session to halt copying without ending it.
+pub async fn stop_all_sessions(
+    State(state): State<AppState>,
+    AuthUser(owner): AuthUser,
+) -> Result<impl IntoResponse, (StatusCode, String)> {
+    let sessions = {
+        let conn = state.user_db.lock().unwrap_or_else(|p| p.into_inner());
+        db::get_copytrade_sessions(&conn, &owner)
+            .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
+    };
+
+    let mut results = Vec::new();
+    for row in sessions {
+        if !matches!(
+            SessionStatus::from_str(&row.status),
+            Some(SessionStatus::Running | SessionStatus::Paused)
+        ) {
+            continue;
+        }
+        let cmd = CopyTradeCommand::Stop {
+            session_id: row.id.clone(),
+        };
+        let error = apply_status_change(&state, &row.id, "stopped", cmd)
+            .await
+            .err()
+            .map(|(_, e)| e);
+        if let Some(ref e) = error {
+            tracing::warn!("stop-all: failed to stop session {}: {e}", row.id);
+        }
+        results.push(StopAllResult {
+            session_id: row.id,
+            previous_status: row.status,
+            stopped: error.is_none(),
+            error,
+        });
+    }
+
+    Ok(Json(StopAllResponse {
+        stopped: results.iter().filter(|r| r.stopped).count(),
+        results,
+    }))
+}
+
+// ---------------------------------------------------------------------------
+// GET /api/copytrade/sessions/:id/orders
+// ---------------------------------------------------------------------------
+
+pub async fn list_session_orders(
+    State(state): State<AppState>,
+    AuthUser(owner): AuthUser,
+    Path(id): Path<String>,
+    Query(params): Query<SessionOrdersParams>,
+) -> Result<impl IntoResponse, (StatusCode, String)> {
+    // Verify session ownership
+    {
+        let conn = state.user_db.lock().unwrap_or_else(|p| p.into_inner());
+        let row = db::get_copytrade_session(&conn, &id, &owner)
+            .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+        if row.is_none() {
+            return Err((StatusCode::NOT_FOUND, "Session not found".into()));
+        }
+    }
+
+    let limit = params.limit.unwrap_or(50).min(200);
+    let offset = params.offset.unwrap_or(0);
+
+    let rows = {
+        let conn = state.user_db.lock().unwrap_or_else(|p| p.into_inner());
+        db::get_session_orders(&conn, &id, limit, offset)
+            .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
+    };
+
+    let orders: Vec<CopyTradeOrder> = rows.into_iter().map(order_from_row).collect();
+    Ok(Json(orders))
+}
+
+// ---------------------------------------------------------------------------
+// GET /api/copytrade/sessions/:id/open-orders
+// ---------------------------------------------------------------------------
+
+pub async fn list_open_orders(
+    State(state): State<AppState>,
+    AuthUser(owner): AuthUser,
+    Path(id): Path<String>,
+) -> Result<impl IntoResponse, (StatusCode, String)> {
+    let rows = {
+        let conn = state.user_db.lock().unwrap_or_else(|p| p.into_inner());
+        db::get_copytrade_session(&conn, &id, &owner)
+            .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
+            .ok_or((StatusCode::NOT_FOUND, "Session not found".into()))?;
+        db::get_session_open_orders(&conn, &id)
+            .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
+    };
+
+    // The engine knows which orders it is still watching; SQLite alone survives restarts
+    let (reply_tx, reply_rx) = tokio::sync::oneshot::channel();
+    state
+        .copytrade_cmd_tx
+        .send(CopyTradeCommand::OpenOrders {
+            session_id: id.clone(),
+            reply: reply_tx,
+        })
+        .await
+        .map_err(|_| {
+            (
+                StatusCode::SERVICE_UNAVAILABLE,
+                "Copy-trade engine unavailable".into(),
+            )
+        })?;
+    let engine_orders = reply_rx.await.map_err(|_| {
+        (
+            StatusCode::SERVICE_UNAVAILABLE,
+            "Copy-trade engine unavailable".into(),
+        )
+    })?;
+    let engine_loaded = engine_orders.is_some();
+    let tracked: std::collections::HashMap<String, EngineOpenOrder> = engine_orders
+        .unwrap_or_default()
+        .into_iter()
+        .map(|o| (o.order_id.clone(), o))
+        .collect();
+
+    let now = chrono::Utc::now();
+    let orders: Vec<OpenOrder> = rows
+        .into_iter()
+        .map(|row| {
+            let live = tracked.get(&row.id);
+            let age_secs = match live {
+                Some(o) => o.age_secs as i64,
+                None => chrono::DateTime::parse_from_rfc3339(&row.created_at)
+                    .map(|t| (now - t.with_timezone(&chrono::Utc)).num_seconds().max(0))
+                    .unwrap_or(0),
+            };
+            let size = live.map_or(row.size_usdc, |o| o.usdc);
+            let reserved_usdc = if row.side == "buy" { size } else { 0.0 };
+            OpenOrder {
+                order: order_from_row(row),
+                age_secs,
+                reserved_usdc,
+                tracked: live.is_some(),
+            }
+        })
+        .collect();
+    let total_reserved_usdc = orders.iter().map(|o| o.reserved_usdc).sum();
+
+    Ok(Json(SessionOpenOrders {
+        session_id: id,
+        engine_loaded,
+        total_reserved_usdc,
+        orders,
+    }))
+}
+
+// ---------------------------------------------------------------------------
+// GET /api/copytrade/orders
+// ---------------------------------------------------------------------------
+
+pub async fn list_recent_orders(
+    State(state): State<AppState>,
+    AuthUser(owner): AuthUser,
+    Query(params): Query<RecentOrdersParams>,
+) -> Result<impl IntoResponse, (StatusCode, String)> {
+    let limit = params.limit.unwrap_or(50).clamp(1, 200);
+    // Cursor is "{created_at}|{id}" of the last order already returned
+    let before = match params.cursor.as_deref() {
+        Some(c) => Some(
+            c.split_once('|')
+                .ok_or((StatusCode::BAD_REQUEST, "Invalid cursor".into()))?,
+        ),
+        None => None,
+    };
+
+    // Fetch one extra row to know whether another page exists
+    let mut rows = {
+        let conn = state.user_db.lock().unwrap_or_else(|p| p.into_inner());
+        db::get_owner_orders(&conn, &owner, before, limit + 1)
+            .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
+    };
+    let next_cursor = if rows.len() > limit as usize {
+        rows.truncate(limit as usize);
+        rows.last().map(|o| format!("{}|{}", o.created_at, o.id))
+    } else {
+        None
+    };
+
+    let mut asset_ids: Vec<String> = rows.iter().map(|o| o.asset_id.clone()).collect();
+    asset_ids.sort();
+    asset_ids.dedup();
+    let market_info =
+        super::markets::resolve_markets(&state.http, &state.db, &state.market_cache, &asset_ids)
+            .await;
+
+    let orders = rows
+        .into_iter()
+        .map(|row| {
+            let info = market_info.get(&row.asset_id);
+            RecentOrder {
+                question: info.map(|i| i.question.clone()).unwrap_or_default(),
+                outcome: info.map(|i| i.outcome.clone()).unwrap_or_default(),
+                order: order_from_row(row),
+            }
+        })
+        .collect();
+
+    Ok(Json(RecentOrdersResponse {
+        orders,
+        next_cursor,
+    }))
+}
+
+// ---------------------------------------------------------------------------
+// POST /api/copytrade/sessions/:id/what-if
+// ---------------------------------------------------------------------------
+
+/// Runs the engine's sizing and slippage decision for a hypothetical source trade
+/// against the live price, without placing anything. Capital comes from SQLite, so it
+/// can lag the running engine by up to one health-check interval.
+pub async fn what_if(
+    State(state): State<AppState>,
+    AuthUser(owner): AuthUser,
+    Path(id): Path<String>,
+    Json(req): Json<WhatIfRequest>,
+) -> Result<impl IntoResponse, (StatusCode, String)> {
+    use polymarket_client_sdk::clob::types::Side;
+
+    let source_side = match req.side.to_lowercase().as_str() {
+        "buy" => Side::Buy,
+        "sell" => Side::Sell,
+        _ => {
+            return Err((StatusCode::BAD_REQUEST, "side must be buy or sell".into()));
+        }
+    };
+    if !(req.source_price > 0.0 && req.source_price < 1.0) {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            "source_price must be between 0 and 1".into(),
+        ));
+    }
+    if req.source_usdc <= 0.0 {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            "source_usdc must be positive".into(),
+        ));
+    }
+
+    let (session, positions, opened_at) = {
+        let conn = state.user_db.lock().unwrap_or_else(|p| p.into_inner());
+        let session = db::get_copytrade_session(&conn, &id, &owner)
+            .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
+            .ok_or((StatusCode::NOT_FOUND, "Session not found".into()))?;
+        let positions = db::get_session_positions(&conn, &id)
+            .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+        let opened_at = db::get_position_open_times(&conn, &id)
+            .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
+            .into_iter()
+            .find(|(asset_id, _)| *asset_id == req.asset_id)
+            .map(|(_, created_at)| created_at);
+        (session, positions, opened_at)
+    };
+
+    // The same signal filters process_trade applies, fade mode included: the rest is
+    // evaluated for the side the session would actually trade
+    let category = state
+        .market_cache
+        .read()
+        .await
+        .get(&super::markets::cache_key(&req.asset_id))
+        .map(|m| m.category.clone())
+        .unwrap_or_default();
+    let filtered = super::engine::filter_signal(
+        &session,
+        super::engine::parse_asset_allowlist(&session).as_ref(),
+        &category,
+        &req.asset_id,
+        &req.source_timestamp
+            .map(|ts| ts.to_string())
+            .unwrap_or_default(),
+        source_side,
+    );
+    let side = filtered.unwrap_or(source_side);
+    let side_str = match side {
+        Side::Buy => "BUY",
+        _ => "SELL",
+    };
+
+    let traders = super::engine::resolve_session_traders(&state.user_db, &state.db, &session)
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e))?;
+    let trader_count = traders.len();
+    // The named source trader's share, else an even share (whole budget for whale_follow)
+    let trader_weight = req
+        .source_trader
+        .as_ref()
+        .and_then(|t| traders.get(&t.to_lowercase()).copied())
+        .unwrap_or(if trader_count > 0 {
+            1.0 / trader_count as f64
+        } else {
+            1.0
+        });
+    let market_exposure = match (side, session.max_market_usdc) {
+        (Side::Buy, Some(_)) => {
+            super::engine::market_exposure(&state.market_cache, &positions, &req.asset_id).await
+        }
+        _ => None,
+    };
+    let held_shares = positions
+        .get(&req.asset_id)
+        .map(|(shares, _, _)| *shares)
+        .unwrap_or(0.0);
+    let source_portfolio = match (
+        side,
+        SizingMode::from_str(&session.sizing_mode),
+        &req.source_trader,
+    ) {
+        (Side::Buy, Some(SizingMode::Portfolio), Some(trader)) => {
+            super::engine::trader_portfolio_value(
+                &state.db,
+                &mut std::collections::HashMap::new(),
+                trader,
+            )
+            .await
+        }
+        _ => None,
+    };
+
+    let inputs = super::engine::SizingInputs {
+        config: &session,
+        trader_weight,
+        remaining_capital: session.remaining_capital,
+        held_shares,
+        held_secs: opened_at
+            .and_then(|t| chrono::DateTime::parse_from_rfc3339(&t).ok())
+            .map(|t| {
+                (chrono::Utc::now() - t.with_timezone(&chrono::Utc))
+                    .num_seconds()
+                    .max(0) as u64
+            }),
+        market_exposure,
+        source_portfolio,
+    };
+    let sized = super::engine::size_order(&inputs, side, req.source_price, req.source_usdc);
+
+    let mut current_price = None;
+    let mut slippage_bps = None;
+    let decision = match (filtered, &sized) {
+        (Err(reason), _) => Err(reason),
+        (_, Err(reason)) => Err(reason.as_str()),
+        (Ok(_), Ok(_)) => match fetch_one_price(&state.http, &req.asset_id, side_str).await {
+            None => Err("no_price"),
+            Some(price) => {
+                let effective = super::engine::fee_adjusted_price(&session, side, price);
+                let bps = super::engine::signed_slippage_bps(side, req.source_price, effective);
+                current_price = Some(price);
+                slippage_bps = Some(bps);
+                let max_bps = super::engine::max_slippage_bps_for(&session, &req.asset_id);
+                super::engine::check_slippage(&session, max_bps, bps).map_err(|r| r.as_str())
+            }
+        },
+    };
+
+    Ok(Json(WhatIfResponse {
+        decision: if decision.is_ok() { "copy" } else { "skip" }.to_string(),
+        side: side_str.to_lowercase(),
+        skip_reason: decision.err().map(str::to_string),
+        order_usdc: sized.ok(),
+        current_price,
+        slippage_bps,
+        remaining_capital: session.remaining_capital,
+        held_shares,
+        trader_count,
+        trader_weight,
+        market_exposure,
+        source_portfolio,
+    }))
+}
+
+// ---------------------------------------------------------------------------
+// POST /api/copytrade/sessions/preflight
+// ---------------------------------------------------------------------------
+
+/// Readiness report for a planned session, taking the same body as create. Checks
+/// the config, then for live sessions the wallet the engine would trade from: CLOB
+/// credentials, exchange approvals (and POL to pay for them) and whether the USDC
+/// balance covers `initial_capital`. Balances come from the poll cache when fresh.
+pub async fn session_preflight(
+    State(state): State<AppState>,
+    AuthUser(owner): AuthUser,
+    Json(body): Json<serde_json::Value>,
+) -> Result<Json<SessionPreflight>, (StatusCode, String)> {
+    let req = apply_template(&state, &owner, body)?;
+
+    let mut report = SessionPreflight {
+        ready: false,
+        simulate: req.simulate,
+        config_error: validate_session_request(&req).err().map(|(_, msg)| msg),
+        wallet_id: None,
+        wallet_address: None,
+        credentialed: false,
+        ctf_exchange_approved: None,
+        neg_risk_exchange_approved: None,
+        usdc_balance: None,
+        required_usdc: req.initial_capital,
+        usdc_shortfall: None,
+        pol_balance: None,
+        needs_gas: false,
+        balance_checked_secs_ago: None,
+        issues: Vec::new(),
+    };
+    if let Some(e) = &report.config_error {
+        report.issues.push(format!("Invalid config: {e}"));
+    }
+    if req.simulate {
+        report.ready = report.issues.is_empty();
+        return Ok(Json(report));
+    }
+
+    // The engine trades from the first wallet with CLOB credentials
+    let wallets = {
+        let conn = state.user_db.lock().unwrap_or_else(|p| p.into_inner());
+        db::get_trading_wallets(&conn, &owner)
+            .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
+    };
+    let wallet = wallets
+        .iter()
+        .find(|w| w.clob_api_key.is_some())
+        .or(wallets.first());
+    let Some(wallet) = wallet else {
+        report
+            .issues
+            .push("No trading wallet. Generate or import one first.".into());
+        return Ok(Json(report));
+    };
+    report.wallet_id = Some(wallet.id.clone());
+    report.wallet_address = Some(wallet.wallet_address.clone());
+    report.credentialed = wallet.clob_api_key.is_some();
+    if wallet.status == "decrypt_error" {
+        report.issues.push(
+            "Wallet key can't be decrypted with the server's encryption key. Re-import the wallet."
+                .into(),
+        );
+    } else if !report.credentialed {
+        report
+            .issues
+            .push("Wallet has no CLOB credentials. Derive credentials first.".into());
+    }
+
+    match super::wallet::current_balance(&state, wallet).await {
+        Ok(balance) => {
+            let usdc = balance.usdc_raw.parse::<f64>().unwrap_or(0.0) / 1e6;
+            let shortfall = (req.initial_capital - usdc).max(0.0);
+            let approvals_pending =
+                !balance.ctf_exchange_approved || !balance.neg_risk_exchange_approved;
+            report.usdc_balance = Some(usdc);
+            report.usdc_shortfall = Some(shortfall);
+            report.ctf_exchange_approved = Some(balance.ctf_exchange_approved);
+            report.neg_risk_exchange_approved = Some(balance.neg_risk_exchange_approved);
+            report.needs_gas = approvals_pending && balance.needs_gas;
+            report.pol_balance = Some(balance.pol_balance);
+            report.balance_checked_secs_ago = balance.last_checked_secs_ago;
+
+            if report.needs_gas {
+                report.issues.push(format!(
+                    "Send ~0.01 POL to {} to pay for exchange approvals.",
+                    wallet.wallet_address
+                ));
+            }
+            if approvals_pending {
+                report
+                    .issues
+                    .push("Exchanges not approved. Approve USDC for both exchanges.".into());
+            }
+            if shortfall > 0.0 {
+                report.issues.push(format!(
+                    "USDC balance {usdc:.2} is short of initial_capital {:.2}. Deposit {shortfall:.2} more.",
+                    req.initial_capital
+                ));
+            }
+        }
+        Err((_, e)) => {
+            report.issues.push(format!("Balance check failed: {e}"));
+        }
+    }
+
+    report.ready = report.issues.is_empty();
+    Ok(Json(report))
+}
+
+// ---------------------------------------------------------------------------
+// POST /api/copytrade/capacity-estimate
+// ---------------------------------------------------------------------------
+
+const DEFAULT_CAPACITY_LEVELS: [f64; 12] = [
+    100.0,
+    250.0,
+    500.0,
+    1_000.0,
+    2_500.0,
+    5_000.0,
+    10_000.0,
+    25_000.0,
+    50_000.0,
+    100_000.0,
+    250_000.0,
+    1_000_000.0,
+];
+/// Cap on replayed trades; the most recent ones are kept
+const CAPACITY_MAX_TRADES: u32 = 50_000;
+
+/// Replays the list members' historical buys through the engine's sizing step at a
+/// range of capital levels. At low capital the per-trader budget binds and nearly all
+/// of it is deployed; past the saturation point trade sizes and max_position_usdc
+/// bind instead and extra capital sits idle. Liquidity uses each asset's average
+/// hourly volume over the window, not the volume at the time of the trade.
+pub async fn capacity_estimate(
+    State(state): State<AppState>,
+    AuthUser(owner): AuthUser,
+    Json(req): Json<CapacityEstimateRequest>,
+) -> Result<impl IntoResponse, (StatusCode, String)> {
+    use polymarket_client_sdk::clob::types::Side;
+
     if req.copy_pct < 0.05 || req.copy_pct > 1.0 {
         return Err((
             StatusCode::BAD_REQUEST,
             "copy_pct must be between 0.05 and 1.0".into(),
         ));
     }
-    if req.initial_capital <= 0.0 {
-        return Err((
-            StatusCode::BAD_REQUEST,
-            "initial_capital must be positive".into(),
-        ));
-    }
     if req.max_position_usdc <= 0.0 {
         return Err((
             StatusCode::BAD_REQUEST,
             "max_position_usdc must be positive".into(),
         ));
     }
-    if req.list_id.is_some() && req.top_n.is_some() {
+    let sizing_mode = SizingMode::from_str(&req.sizing_mode).ok_or((
+        StatusCode::BAD_REQUEST,
+        "sizing_mode must be dollar or portfolio".to_string(),
+    ))?;
+    let days = req.days.unwrap_or(30);
+    if !(1..=90).contains(&days) {
         return Err((
             StatusCode::BAD_REQUEST,
-            "Specify list_id or top_n, not both".into(),
+            "days must be between 1 and 90".into(),
         ));
     }
-    if req.list_id.is_none() && req.top_n.is_none() {
+    let mut levels = req
+        .capital_levels
+        .clone()
+        .unwrap_or_else(|| DEFAULT_CAPACITY_LEVELS.to_vec());
+    if levels.is_empty() || levels.len() > 50 || levels.iter().any(|c| c.is_nan() || *c <= 0.0) {
         return Err((
             StatusCode::BAD_REQUEST,
-            "Specify either list_id or top_n".into(),
+            "capital_levels must hold 1-50 positive values".into(),
         ));
     }
-    if CopyOrderType::from_str(&req.order_type).is_none() {
-        return Err((
-            StatusCode::BAD_REQUEST,
-            "order_type must be FOK or GTC".into(),
-        ));
+    levels.sort_by(|a, b| a.total_cmp(b));
+    levels.dedup();
+
+    let weights = {
+        let conn = state.user_db.lock().unwrap_or_else(|p| p.into_inner());
+        db::get_list_member_weights(&conn, &req.list_id, &owner)
+            .map_err(|_| (StatusCode::NOT_FOUND, "List not found".to_string()))?
+    };
+    if weights.is_empty() {
+        return Err((StatusCode::BAD_REQUEST, "List has no members".into()));
+    }
+    let traders: Vec<String> = weights.keys().cloned().collect();
+    // Normalised like the engine's resolved trader set
+    let total_weight: f64 = weights.values().sum();
+
+    #[derive(clickhouse::Row, serde::Deserialize)]
+    struct HistoricalBuy {
+        trader: String,
+        usdc: f64,
+        hourly_volume: f64,
     }
 
-    // If not simulation, require funded wallet with CLOB credentials
-    if !req.simulate {
-        let wallets = {
-            let conn = state.user_db.lock().unwrap_or_else(|p| p.into_inner());
-            db::get_trading_wallets(&conn, &owner)
-                .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
-        };
-        let has_credentialed = wallets.iter().any(|w| w.clob_api_key.is_some());
-        if !has_credentialed {
-            return Err((
-                StatusCode::BAD_REQUEST,
-                "No wallet with CLOB credentials. Derive credentials first.".into(),
-            ));
+    let hours = days * 24;
+    let query = format!(
+        "WITH buys AS (
+            SELECT lower(toString(trader)) AS trader, asset_id, toFloat64(usdc_amount) AS usdc
+            FROM trades
+            WHERE side = 'buy' AND has(?, lower(toString(trader)))
+              AND block_timestamp >= now() - INTERVAL {days} DAY
+            ORDER BY block_timestamp DESC
+            LIMIT {CAPACITY_MAX_TRADES}
+        )
+        SELECT b.trader AS trader, b.usdc AS usdc, ifNull(v.hourly_volume, 0) AS hourly_volume
+        FROM buys b
+        LEFT JOIN (
+            SELECT asset_id, toFloat64(sum(usdc_amount)) / {hours} AS hourly_volume
+            FROM trades
+            WHERE asset_id IN (SELECT asset_id FROM buys)
+              AND block_timestamp >= now() - INTERVAL {days} DAY
+            GROUP BY asset_id
+        ) AS v ON b.asset_id = v.asset_id"
+    );
+    let buys: Vec<HistoricalBuy> = state
+        .db
+        .query(&query)
+        .bind(traders.clone())
+        .fetch_all()
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    let total_buys = buys.len();
+    let buys: Vec<HistoricalBuy> = match req.min_liquidity_usdc {
+        Some(min) => buys
+            .into_iter()
+            .filter(|b| b.hourly_volume >= min)
+            .collect(),
+        None => buys,
+    };
+    let liquidity_skipped = total_buys - buys.len();
+
+    // Portfolio sizing scales buys by each trader's book
+    let mut books: std::collections::HashMap<String, f64> = std::collections::HashMap::new();
+    if sizing_mode == SizingMode::Portfolio {
+        let mut cache = std::collections::HashMap::new();
+        for trader in &traders {
+            if let Some(book) =
+                super::engine::trader_portfolio_value(&state.db, &mut cache, trader).await
+            {
+                books.insert(trader.clone(), book);
+            }
         }
     }
 
-    // Create session
-    let id = uuid::Uuid::new_v4().to_string();
-    let now = chrono::Utc::now().to_rfc3339();
-    let order_type_str = CopyOrderType::from_str(&req.order_type)
-        .unwrap_or(CopyOrderType::FOK)
-        .as_str()
-        .to_string();
-
-    let row = CopyTradeSessionRow {
-        id: id.clone(),
+    // Sizing only reads the config fields below; the rest are placeholders
+    let mut config = CopyTradeSessionRow {
+        id: String::new(),
         owner: owner.clone(),
-        list_id: req.list_id.clone(),
-        top_n: req.top_n,
+        list_id: Some(req.list_id.clone()),
+        top_n: None,
+        top_pct: None,
         copy_pct: req.copy_pct,
         max_position_usdc: req.max_position_usdc,
-        max_slippage_bps: req.max_slippage_bps,
-        order_type: order_type_str,
-        initial_capital: req.initial_capital,
-        remaining_capital: req.initial_capital,
-        simulate: req.simulate,
-        max_loss_pct: req.max_loss_pct,
-        status: "running".to_string(),
-        created_at: now.clone(),
-        updated_at: now,
+        max_slippage_bps: 0,
+        order_type: CopyOrderType::FOK.as_str().to_string(),
+        initial_capital: 0.0,
+        remaining_capital: 0.0,
+        simulate: true,
+        max_loss_pct: None,
+        status: SessionStatus::Running.as_str().to_string(),
+        created_at: String::new(),
+        updated_at: String::new(),
+        only_favorable: false,
+        buy_order_type: None,
+        sell_order_type: None,
+        mark_price_source: MarkPriceSource::LastFill.as_str().to_string(),
+        min_trader_pnl: None,
+        min_trader_winrate: None,
+        slippage_pause_after: None,
+        min_liquidity_usdc: req.min_liquidity_usdc,
+        close_on_trader_removal: false,
+        max_market_usdc: None,
+        seed_existing_positions: false,
+        sizing_mode: sizing_mode.as_str().to_string(),
+        webhook_url: None,
+        digest_interval_hours: None,
+        sizing_base: SizingBase::Remaining.as_str().to_string(),
+        max_runtime_secs: None,
+        asset_allowlist: None,
+        near_resolution_price: None,
+        gtc_price_ref: GtcPriceRef::Source.as_str().to_string(),
+        gtc_price_offset_bps: 0,
+        sell_behavior: SellBehavior::Proportional.as_str().to_string(),
+        trail_activate_pct: None,
+        trail_distance_pct: None,
+        mark_stale_secs: None,
+        signal_source: SignalSource::Traders.as_str().to_string(),
+        min_whale_usdc: None,
+        slippage_fee_bps: None,
+        max_signal_age_secs: None,
+        top_n_metric: TopNMetric::Pnl.as_str().to_string(),
+        min_hold_secs: 0,
+        slippage_overrides: None,
+        max_orders_per_minute: None,
+        size_rounding: SizeRounding::Down.as_str().to_string(),
+        take_profit_pct: None,
+        stop_loss_pct: None,
+        mode: CopyMode::Copy.as_str().to_string(),
+        allowed_categories: None,
+        blocked_categories: None,
+        copy_uncategorized: true,
+        copy_sides: CopySides::Both.as_str().to_string(),
+        fok_fallback_gtc: false,
+        liquidity_window_mins: None,
+        fees_paid: 0.0,
     };
 
-    {
-        let conn = state.user_db.lock().unwrap_or_else(|p| p.into_inner());
-        db::create_copytrade_session(&conn, &row)
-            .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
-    }
+    let trader_count = traders.len();
+    let mut curve = Vec::with_capacity(levels.len());
+    for capital in levels {
+        config.initial_capital = capital;
+        config.remaining_capital = capital;
+
+        let mut deployed = 0.0;
+        let mut budget = 0.0;
+        let mut placed = 0usize;
+        let mut capped = 0usize;
+        for buy in &buys {
+            let source_portfolio = books.get(&buy.trader).copied();
+            let trader_weight = weights.get(&buy.trader).copied().unwrap_or(0.0) / total_weight;
+            let per_trader_budget = capital * req.copy_pct * trader_weight;
+            // The capital-proportional part of sizing, before trade size and max_position bind
+            let demand = match source_portfolio {
+                Some(book) if sizing_mode == SizingMode::Portfolio && book > 0.0 => {
+                    (capital * buy.usdc / book.max(buy.usdc) * req.copy_pct).min(per_trader_budget)
+                }
+                _ => per_trader_budget,
+            };
+            let inputs = super::engine::SizingInputs {
+                config: &config,
+                trader_weight,
+                remaining_capital: capital,
+                held_shares: 0.0,
+                held_secs: None,
+                market_exposure: None,
+                source_portfolio,
+            };
+            let order = super::engine::size_order(&inputs, Side::Buy, 0.5, buy.usdc).unwrap_or(0.0);
+            deployed += order;
+            budget += demand;
+            if order > 0.0 {
+                placed += 1;
+            }
+            if order < demand * 0.999 {
+                capped += 1;
+            }
+        }
 
-    // Send Start command to engine
-    let _ = state
-        .copytrade_cmd_tx
-        .send(CopyTradeCommand::Start {
-            session_id: id.clone(),
-            owner: owner.clone(),
-        })
-        .await;
+        curve.push(CapacityPoint {
+            capital,
+            deployment_fraction: if budget > 0.0 { deployed / budget } else { 0.0 },
+            avg_order_usdc: if placed > 0 {
+                deployed / placed as f64
+            } else {
+                0.0
+            },
+            capped_pct: if buys.is_empty() {
+                0.0
+            } else {
+                capped as f64 / buys.len() as f64 * 100.0
+            },
+        });
+    }
 
-    Ok(Json(session_from_row(&row, 0.0))) // New session, no positions yet
+    let saturation_capital = curve
+        .iter()
+        .find(|p| !buys.is_empty() && p.deployment_fraction < 0.5)
+        .map(|p| p.capital);
+
+    Ok(Json(CapacityEstimate {
+        trader_count,
+        days,
+        sampled_trades: buys.len(),
+        liquidity_skipped,
+        saturation_capital,
+        curve,
+    }))
 }
 
 // ---------------------------------------------------------------------------
-// GET /api/copytrade/sessions
+// GET /api/copytrade/orders/:order_id
 // ---------------------------------------------------------------------------
 
-pub async fn list_sessions(
+pub async fn get_order(
     State(state): State<AppState>,
     AuthUser(owner): AuthUser,
+    Path(order_id): Path<String>,
 ) -> Result<impl IntoResponse, (StatusCode, String)> {
-    let sessions = {
+    let (row, simulate) = {
         let conn = state.user_db.lock().unwrap_or_else(|p| p.into_inner());
-        let rows = db::get_copytrade_sessions(&conn, &owner)
-            .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
-        rows.iter()
-            .map(|r| {
-                let pv = db::get_session_positions_value(&conn, &r.id).unwrap_or(0.0);
-                session_from_row(r, pv)
-            })
-            .collect::<Vec<CopyTradeSession>>()
+        db::get_owner_order(&conn, &owner, &order_id)
+            .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
+            .ok_or((StatusCode::NOT_FOUND, "Order not found".into()))?
     };
-    Ok(Json(sessions))
+
+    let market_info = super::markets::resolve_markets(
+        &state.http,
+        &state.db,
+        &state.market_cache,
+        std::slice::from_ref(&row.asset_id),
+    )
+    .await;
+    let info = market_info.get(&row.asset_id);
+    let question = info.map(|i| i.question.clone()).unwrap_or_default();
+    let outcome = info.map(|i| i.outcome.clone()).unwrap_or_default();
+
+    let clob_status = match (&row.clob_order_id, simulate) {
+        (Some(clob_order_id), false) => fetch_clob_order_status(&state, clob_order_id).await,
+        _ => None,
+    };
+
+    Ok(Json(OrderDetail {
+        order: order_from_row(row),
+        question,
+        outcome,
+        clob_status,
+    }))
 }
 
-// ---------------------------------------------------------------------------
-// GET /api/copytrade/sessions/:id
-// ---------------------------------------------------------------------------
+async fn fetch_clob_order_status(state: &AppState, clob_order_id: &str) -> Option<ClobOrderStatus> {
+    use rust_decimal::prelude::ToPrimitive;
 
-pub async fn get_session(
-    State(state): State<AppState>,
-    AuthUser(owner): AuthUser,
-    Path(id): Path<String>,
-) -> Result<impl IntoResponse, (StatusCode, String)> {
-    let conn = state.user_db.lock().unwrap_or_else(|p| p.into_inner());
-    let row = db::get_copytrade_session(&conn, &id, &owner)
-        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
-    match row {
-        Some(r) => {
-            let pv = db::get_session_positions_value(&conn, &r.id).unwrap_or(0.0);
-            Ok(Json(session_from_row(&r, pv)))
+    let clob = state.clob_client.read().await;
+    let cs = clob.as_ref()?;
+    match cs.client.order(clob_order_id).await {
+        Ok(resp) => Some(ClobOrderStatus {
+            status: resp.status.to_string(),
+            original_size: resp.original_size.to_f64().unwrap_or(0.0),
+            size_matched: resp.size_matched.to_f64().unwrap_or(0.0),
+        }),
+        Err(e) => {
+            tracing::warn!("CLOB order lookup failed for {clob_order_id}: {e}");
+            None
         }
-        None => Err((StatusCode::NOT_FOUND, "Session not found".into())),
     }
 }
 
 // ---------------------------------------------------------------------------
-// PATCH /api/copytrade/sessions/:id
+// POST /api/copytrade/sessions/:id/orders/:order_id/cancel
 // ---------------------------------------------------------------------------
 
-pub async fn update_session(
+pub async fn cancel_order(
     State(state): State<AppState>,
     AuthUser(owner): AuthUser,
-    Path(id): Path<String>,
-    Json(req): Json<SessionPatchRequest>,
+    Path((id, order_id)): Path<(String, String)>,
 ) -> Result<impl IntoResponse, (StatusCode, String)> {
-    // Load session to verify ownership
-    let row = {
+    let order = {
         let conn = state.user_db.lock().unwrap_or_else(|p| p.into_inner());
         db::get_copytrade_session(&conn, &id, &owner)
             .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
+            .ok_or((StatusCode::NOT_FOUND, "Session not found".into()))?;
+        db::get_copytrade_order(&conn, &id, &order_id)
+            .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
+            .ok_or((StatusCode::NOT_FOUND, "Order not found".into()))?
     };
-    let row = row.ok_or((StatusCode::NOT_FOUND, "Session not found".into()))?;
-    let current = SessionStatus::from_str(&row.status).ok_or((
-        StatusCode::INTERNAL_SERVER_ERROR,
-        "Invalid session status".into(),
+
+    // Only resting orders can be canceled — everything else is terminal
+    let status = OrderStatus::from_str(&order.status);
+    if !matches!(
+        status,
+        Some(OrderStatus::Pending | OrderStatus::Submitted | OrderStatus::Partial)
+    ) {
+        return Err((
+            StatusCode::CONFLICT,
+            format!("Order is already {}", order.status),
+        ));
+    }
+    let clob_order_id = order.clob_order_id.clone().ok_or((
+        StatusCode::CONFLICT,
+        "Order has no CLOB order id to cancel".into(),
     ))?;
 
-    let (new_status, cmd) = match req.action.as_str() {
-        "pause" => {
-            if current != SessionStatus::Running {
-                return Err((
-                    StatusCode::BAD_REQUEST,
-                    "Can only pause a running session".into(),
-                ));
-            }
-            (
-                "paused",
-                CopyTradeCommand::Pause {
-                    session_id: id.clone(),
-                },
-            )
-        }
-        "resume" => {
-            if current != SessionStatus::Paused {
-                return Err((
-                    StatusCode::BAD_REQUEST,
-                    "Can only resume a paused session".into(),
-                ));
-            }
+    // Buys reserved capital when placed; resting sells did not
+    let refund_usdc = if order.side == "buy" {
+        order.size_usdc
+    } else {
+        0.0
+    };
+
+    // Route through the engine so its open_gtc_orders + capital stay consistent
+    let (reply_tx, reply_rx) = tokio::sync::oneshot::channel();
+    state
+        .copytrade_cmd_tx
+        .send(CopyTradeCommand::CancelOrder {
+            session_id: id.clone(),
+            order_id: order_id.clone(),
+            clob_order_id,
+            refund_usdc,
+            reply: reply_tx,
+        })
+        .await
+        .map_err(|_| {
             (
-                "running",
-                CopyTradeCommand::Resume {
-                    session_id: id.clone(),
-                },
+                StatusCode::SERVICE_UNAVAILABLE,
+                "Copy-trade engine unavailable".into(),
             )
-        }
-        "stop" => {
-            if current == SessionStatus::Stopped {
-                return Err((StatusCode::BAD_REQUEST, "Session already stopped".into()));
-            }
+        })?;
+    reply_rx
+        .await
+        .map_err(|_| {
             (
-                "stopped",
-                CopyTradeCommand::Stop {
-                    session_id: id.clone(),
-                },
+                StatusCode::SERVICE_UNAVAILABLE,
+                "Copy-trade engine unavailable".into(),
             )
-        }
-        _ => {
-            return Err((
-                StatusCode::BAD_REQUEST,
-                "action must be pause, resume, or stop".into(),
-            ));
-        }
-    };
-
-    // Update DB immediately
-    {
-        let conn = state.user_db.lock().unwrap_or_else(|p| p.into_inner());
-        db::update_session_status(&conn, &id, new_status)
-            .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
-    }
+        })?
+        .map_err(|e| (StatusCode::BAD_GATEWAY, e))?;
 
-    // Send command to engine
-    let _ = state.copytrade_cmd_tx.send(cmd).await;
-
-    // Return updated session
     let conn = state.user_db.lock().unwrap_or_else(|p| p.into_inner());
-    let updated = db::get_copytrade_session(&conn, &id, &owner)
-        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
-    match updated {
-        Some(r) => {
-            let pv = db::get_session_positions_value(&conn, &r.id).unwrap_or(0.0);
-            Ok(Json(session_from_row(&r, pv)))
-        }
-        None => Err((StatusCode::NOT_FOUND, "Session not found".into())),
-    }
-}
-
-// ---------------------------------------------------------------------------
-// GET /api/copytrade/sessions/:id/orders
-// ---------------------------------------------------------------------------
-
-pub async fn list_session_orders(
-    State(state): State<AppState>,
-    AuthUser(owner): AuthUser,
-    Path(id): Path<String>,
-    Query(params): Query<SessionOrdersParams>,
-) -> Result<impl IntoResponse, (StatusCode, String)> {
-    // Verify session ownership
-    {
-        let conn = state.user_db.lock().unwrap_or_else(|p| p.into_inner());
-        let row = db::get_copytrade_session(&conn, &id, &owner)
-            .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
-        if row.is_none() {
-            return Err((StatusCode::NOT_FOUND, "Session not found".into()));
-        }
-    }
-
-    let limit = params.limit.unwrap_or(50).min(200);
-    let offset = params.offset.unwrap_or(0);
-
-    let rows = {
-        let conn = state.user_db.lock().unwrap_or_else(|p| p.into_inner());
-        db::get_session_orders(&conn, &id, limit, offset)
-            .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
-    };
-
-    let orders: Vec<CopyTradeOrder> = rows.into_iter().map(order_from_row).collect();
-    Ok(Json(orders))
+    let updated = db::get_copytrade_order(&conn, &id, &order_id)
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
+        .ok_or((StatusCode::NOT_FOUND, "Order not found".into()))?;
+    Ok(Json(order_from_row(updated)))
 }
 
 // ---------------------------------------------------------------------------
@@ -358,8 +2024,8 @@ pub async fn close_position(
         ));
     }
 
-    // For simulation sessions, simulate the close
-    if session_row.simulate {
+    // For simulation sessions (or under FORCE_SIMULATE), simulate the close
+    if super::engine::runs_simulated(&session_row) {
         let order_id = uuid::Uuid::new_v4().to_string();
         let now = chrono::Utc::now().to_rfc3339();
 
@@ -400,6 +2066,9 @@ pub async fn close_position(
             tx_hash: None,
             created_at: now.clone(),
             updated_at: now,
+            origin: OrderOrigin::Close.as_str().to_string(),
+            max_slippage_bps: None,
+            fallback_for: None,
         };
 
         {
@@ -408,7 +2077,7 @@ pub async fn close_position(
                 .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
             // Update remaining_capital: add sale proceeds
             let new_capital = session_row.remaining_capital + size_usdc;
-            db::update_session_capital(&conn, &req.session_id, new_capital)
+            db::update_session_capital(&conn, &req.session_id, new_capital, session_row.fees_paid)
                 .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
         }
 
@@ -530,6 +2199,9 @@ pub async fn close_position(
         tx_hash: resp.transaction_hashes.first().map(|h| h.to_string()),
         created_at: now.clone(),
         updated_at: now,
+        origin: OrderOrigin::Close.as_str().to_string(),
+        max_slippage_bps: None,
+        fallback_for: None,
     };
 
     {
@@ -555,21 +2227,87 @@ pub async fn get_session_stats(
     AuthUser(owner): AuthUser,
     Path(id): Path<String>,
 ) -> Result<impl IntoResponse, (StatusCode, String)> {
-    let (session_row, order_stats, positions) = {
+    let session_row = {
         let conn = state.user_db.lock().unwrap_or_else(|p| p.into_inner());
-        let row = db::get_copytrade_session(&conn, &id, &owner)
+        db::get_copytrade_session(&conn, &id, &owner)
             .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
-            .ok_or((StatusCode::NOT_FOUND, "Session not found".into()))?;
-        let stats = db::get_session_order_stats(&conn, &id)
-            .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
-        let positions = db::get_positions_raw(&conn, &id)
-            .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
-        (row, stats, positions)
+            .ok_or((StatusCode::NOT_FOUND, "Session not found".into()))?
+    };
+    let stats = compute_session_stats(&state.http, &state.user_db, &session_row)
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+    Ok(Json(stats))
+}
+
+// ---------------------------------------------------------------------------
+// GET /api/copytrade/compare?a=&b= — side-by-side stats for A/B testing configs
+// ---------------------------------------------------------------------------
+
+pub async fn compare_sessions(
+    State(state): State<AppState>,
+    AuthUser(owner): AuthUser,
+    Query(params): Query<CompareSessionsParams>,
+) -> Result<impl IntoResponse, (StatusCode, String)> {
+    let (row_a, row_b) = {
+        let conn = state.user_db.lock().unwrap_or_else(|p| p.into_inner());
+        let load = |id: &str| {
+            db::get_copytrade_session(&conn, id, &owner)
+                .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
+                .ok_or((StatusCode::NOT_FOUND, format!("Session {id} not found")))
+        };
+        (load(&params.a)?, load(&params.b)?)
+    };
+
+    let (stats_a, stats_b) = tokio::join!(
+        compute_session_stats(&state.http, &state.user_db, &row_a),
+        compute_session_stats(&state.http, &state.user_db, &row_b),
+    );
+    let stats_a = stats_a.map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+    let stats_b = stats_b.map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    let delta = SessionStatsDelta {
+        total_pnl: stats_a.total_pnl - stats_b.total_pnl,
+        realized_pnl: stats_a.realized_pnl - stats_b.realized_pnl,
+        unrealized_pnl: stats_a.unrealized_pnl - stats_b.unrealized_pnl,
+        return_pct: stats_a.return_pct - stats_b.return_pct,
+        win_rate: stats_a.win_rate - stats_b.win_rate,
+        avg_slippage_bps: stats_a.avg_slippage_bps - stats_b.avg_slippage_bps,
+        capital_utilization: stats_a.capital_utilization - stats_b.capital_utilization,
+        total_orders: stats_a.total_orders as i64 - stats_b.total_orders as i64,
+    };
+    let compared = |row: CopyTradeSessionRow, stats: SessionStats| ComparedSession {
+        session_id: row.id,
+        simulate: row.simulate,
+        status: row.status,
+        stats,
+    };
+    Ok(Json(SessionComparison {
+        a: compared(row_a, stats_a),
+        b: compared(row_b, stats_b),
+        delta,
+    }))
+}
+
+/// Order counts, P&L (open positions marked at live CLOB midpoints) and win rate
+/// for one session. Shared by the stats endpoint and the digest notifier.
+pub(crate) async fn compute_session_stats(
+    http: &reqwest::Client,
+    user_db: &std::sync::Mutex<rusqlite::Connection>,
+    session_row: &CopyTradeSessionRow,
+) -> Result<SessionStats, rusqlite::Error> {
+    let id = session_row.id.as_str();
+    let (order_stats, positions, sells_by_origin) = {
+        let conn = user_db.lock().unwrap_or_else(|p| p.into_inner());
+        (
+            db::get_session_order_stats(&conn, id)?,
+            db::get_positions_raw(&conn, id)?,
+            db::get_sell_totals_by_origin(&conn, id)?,
+        )
     };
 
     // Fetch live CLOB prices for all position assets
     let asset_ids: Vec<String> = positions.iter().map(|p| p.asset_id.clone()).collect();
-    let clob_prices = fetch_clob_midpoints(&state.http, &asset_ids).await;
+    let clob_prices = fetch_clob_midpoints(http, &asset_ids).await;
 
     // Compute per-asset P&L and win/loss using live prices
     let mut unrealized_pnl = 0.0;
@@ -610,6 +2348,21 @@ pub async fn get_session_stats(
         }
     }
 
+    // Realized P&L split by what placed the exit (same average-cost basis as above)
+    let cost_per_share: std::collections::HashMap<&str, f64> = positions
+        .iter()
+        .filter(|p| p.buy_shares > 0.0)
+        .map(|p| (p.asset_id.as_str(), p.cost_basis / p.buy_shares))
+        .collect();
+    let mut realized_pnl_by_origin = std::collections::BTreeMap::<String, f64>::new();
+    for (asset_id, origin, shares, proceeds) in &sells_by_origin {
+        let cost = cost_per_share
+            .get(asset_id.as_str())
+            .copied()
+            .unwrap_or(0.0);
+        *realized_pnl_by_origin.entry(origin.clone()).or_default() += proceeds - shares * cost;
+    }
+
     let total_pnl = realized_pnl + unrealized_pnl;
     let return_pct = if session_row.initial_capital > 0.0 {
         total_pnl / session_row.initial_capital * 100.0
@@ -623,8 +2376,11 @@ pub async fn get_session_stats(
         0.0
     };
 
+    // A balance left negative by fees (COPYTRADE_NEGATIVE_CAPITAL=allow) counts as fully deployed
     let capital_utilization = if session_row.initial_capital > 0.0 {
-        (session_row.initial_capital - session_row.remaining_capital) / session_row.initial_capital
+        ((session_row.initial_capital - session_row.remaining_capital.max(0.0))
+            / session_row.initial_capital)
+            .clamp(0.0, 1.0)
     } else {
         0.0
     };
@@ -632,8 +2388,9 @@ pub async fn get_session_stats(
     let runtime_seconds = chrono::DateTime::parse_from_rfc3339(&session_row.created_at)
         .map(|created| (chrono::Utc::now() - created.with_timezone(&chrono::Utc)).num_seconds())
         .unwrap_or(0);
+    let open_positions = positions.iter().filter(|p| p.net_shares > 0.001).count() as u32;
 
-    Ok(Json(SessionStats {
+    Ok(SessionStats {
         total_orders: order_stats.total_orders,
         filled_orders: order_stats.filled_orders,
         failed_orders: order_stats.failed_orders,
@@ -642,6 +2399,7 @@ pub async fn get_session_stats(
         total_invested: order_stats.total_invested,
         total_returned: order_stats.total_returned,
         realized_pnl,
+        realized_pnl_by_origin,
         unrealized_pnl,
         total_pnl,
         return_pct,
@@ -651,8 +2409,10 @@ pub async fn get_session_stats(
         avg_slippage_bps: order_stats.avg_slippage_bps,
         max_slippage_bps: order_stats.max_slippage_bps,
         capital_utilization,
+        fees_paid: session_row.fees_paid,
         runtime_seconds,
-    }))
+        open_positions,
+    })
 }
 
 // ---------------------------------------------------------------------------
@@ -669,63 +2429,615 @@ pub async fn get_session_positions(
         let _row = db::get_copytrade_session(&conn, &id, &owner)
             .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
             .ok_or((StatusCode::NOT_FOUND, "Session not found".into()))?;
-        db::get_positions_raw(&conn, &id)
+        db::get_positions_raw(&conn, &id)
+            .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
+    };
+
+    // Enrich with market metadata + live CLOB prices
+    let asset_ids: Vec<String> = positions.iter().map(|p| p.asset_id.clone()).collect();
+    let (market_info, clob_prices) = tokio::join!(
+        super::markets::resolve_markets(&state.http, &state.db, &state.market_cache, &asset_ids),
+        fetch_clob_midpoints(&state.http, &asset_ids),
+    );
+
+    let result: Vec<CopyTradePosition> = positions
+        .into_iter()
+        .map(|p| {
+            let info = market_info.get(&p.asset_id);
+            let cost_per_share = if p.buy_shares > 0.0 {
+                p.cost_basis / p.buy_shares
+            } else {
+                0.0
+            };
+            // Use live CLOB price when available, fall back to last fill price
+            let live_price = clob_prices
+                .get(&p.asset_id)
+                .copied()
+                .unwrap_or(p.last_fill_price);
+            let current_value = p.net_shares * live_price;
+            let remaining_cost = p.net_shares * cost_per_share;
+            let pos_realized = p.sell_proceeds - (p.sell_shares * cost_per_share);
+
+            CopyTradePosition {
+                asset_id: p.asset_id,
+                question: info.map(|i| i.question.clone()).unwrap_or_default(),
+                outcome: info.map(|i| i.outcome.clone()).unwrap_or_default(),
+                category: info.map(|i| i.category.clone()).unwrap_or_default(),
+                buy_shares: p.buy_shares,
+                sell_shares: p.sell_shares,
+                net_shares: p.net_shares,
+                avg_entry_price: cost_per_share,
+                current_price: live_price,
+                last_fill_price: p.last_fill_price,
+                cost_basis: p.cost_basis,
+                current_value,
+                unrealized_pnl: current_value - remaining_cost,
+                realized_pnl: pos_realized,
+                order_count: p.order_count,
+                source_traders: p
+                    .source_traders
+                    .split(',')
+                    .filter(|s| !s.is_empty())
+                    .map(|s| s.to_string())
+                    .collect(),
+                first_order_at: p.first_order_at,
+                last_order_at: p.last_order_at,
+            }
+        })
+        .collect();
+
+    Ok(Json(result))
+}
+
+// ---------------------------------------------------------------------------
+// GET /api/copytrade/sessions/:id/stale-positions?older_than=
+// Open positions with no order activity for `older_than` hours — usually a
+// source exit we never mirrored.
+// ---------------------------------------------------------------------------
+
+pub async fn get_stale_positions(
+    State(state): State<AppState>,
+    AuthUser(owner): AuthUser,
+    Path(id): Path<String>,
+    Query(params): Query<StalePositionsParams>,
+) -> Result<impl IntoResponse, (StatusCode, String)> {
+    let older_than_hours = params.older_than.unwrap_or(72);
+    let positions = {
+        let conn = state.user_db.lock().unwrap_or_else(|p| p.into_inner());
+        db::get_copytrade_session(&conn, &id, &owner)
+            .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
+            .ok_or((StatusCode::NOT_FOUND, "Session not found".into()))?;
+        db::get_positions_raw(&conn, &id)
+            .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
+    };
+
+    let now = chrono::Utc::now();
+    let stale: Vec<(db::PositionRaw, f64)> = positions
+        .into_iter()
+        .filter(|p| p.net_shares > 0.001)
+        .filter_map(|p| {
+            let last = chrono::DateTime::parse_from_rfc3339(&p.last_order_at).ok()?;
+            let idle_secs = (now - last.with_timezone(&chrono::Utc)).num_seconds();
+            let idle_hours = idle_secs as f64 / 3600.0;
+            (idle_hours >= older_than_hours as f64).then_some((p, idle_hours))
+        })
+        .collect();
+
+    let asset_ids: Vec<String> = stale.iter().map(|(p, _)| p.asset_id.clone()).collect();
+    let market_info =
+        super::markets::resolve_markets(&state.http, &state.db, &state.market_cache, &asset_ids)
+            .await;
+
+    let mut result: Vec<StalePosition> = stale
+        .into_iter()
+        .map(|(p, idle_hours)| {
+            let info = market_info.get(&p.asset_id);
+            StalePosition {
+                question: info.map(|i| i.question.clone()).unwrap_or_default(),
+                outcome: info.map(|i| i.outcome.clone()).unwrap_or_default(),
+                asset_id: p.asset_id,
+                net_shares: p.net_shares,
+                last_fill_price: p.last_fill_price,
+                estimated_value: p.net_shares * p.last_fill_price,
+                source_traders: p
+                    .source_traders
+                    .split(',')
+                    .filter(|s| !s.is_empty())
+                    .map(|s| s.to_string())
+                    .collect(),
+                first_order_at: p.first_order_at,
+                last_order_at: p.last_order_at,
+                idle_hours,
+            }
+        })
+        .collect();
+    // Oldest first
+    result.sort_by(|a, b| b.idle_hours.total_cmp(&a.idle_hours));
+
+    Ok(Json(result))
+}
+
+// ---------------------------------------------------------------------------
+// GET /api/copytrade/sessions/:id/liquidation-preview
+// What closing every open position right now would return: each position is
+// walked down the live bids for its full size. Read-only, places no orders.
+// ---------------------------------------------------------------------------
+
+pub async fn get_liquidation_preview(
+    State(state): State<AppState>,
+    AuthUser(owner): AuthUser,
+    Path(id): Path<String>,
+) -> Result<impl IntoResponse, (StatusCode, String)> {
+    let positions = {
+        let conn = state.user_db.lock().unwrap_or_else(|p| p.into_inner());
+        db::get_copytrade_session(&conn, &id, &owner)
+            .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
+            .ok_or((StatusCode::NOT_FOUND, "Session not found".into()))?;
+        db::get_positions_raw(&conn, &id)
+            .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
+    };
+    let open: Vec<db::PositionRaw> = positions
+        .into_iter()
+        .filter(|p| p.net_shares > 0.001)
+        .collect();
+
+    let asset_ids: Vec<String> = open.iter().map(|p| p.asset_id.clone()).collect();
+    let (market_info, marks, books) = tokio::join!(
+        super::markets::resolve_markets(&state.http, &state.db, &state.market_cache, &asset_ids),
+        fetch_clob_midpoints(&state.http, &asset_ids),
+        fetch_clob_bids(&state.http, &asset_ids),
+    );
+
+    let mut preview = LiquidationPreview {
+        session_id: id,
+        total_expected_proceeds: 0.0,
+        total_realized_pnl: 0.0,
+        total_mark_value: 0.0,
+        total_pnl_vs_mark: 0.0,
+        unfillable_shares: 0.0,
+        positions: Vec::with_capacity(open.len()),
+    };
+    for p in open {
+        let info = market_info.get(&p.asset_id);
+        let cost_per_share = if p.buy_shares > 0.0 {
+            p.cost_basis / p.buy_shares
+        } else {
+            0.0
+        };
+        let mark = marks.get(&p.asset_id).copied().unwrap_or(p.last_fill_price);
+        let (fillable, proceeds, from_book) = match books.get(&p.asset_id) {
+            Some(bids) => {
+                let (filled, proceeds) = sell_into_bids(bids, p.net_shares);
+                (filled, proceeds, true)
+            }
+            None => (p.net_shares, p.net_shares * mark, false),
+        };
+        let mark_value = fillable * mark;
+        let slippage_bps = if mark_value > 0.0 {
+            (mark_value - proceeds) / mark_value * 10000.0
+        } else {
+            0.0
+        };
+        let realized_pnl = proceeds - fillable * cost_per_share;
+
+        preview.total_expected_proceeds += proceeds;
+        preview.total_realized_pnl += realized_pnl;
+        preview.total_mark_value += p.net_shares * mark;
+        preview.total_pnl_vs_mark += proceeds - mark_value;
+        preview.unfillable_shares += p.net_shares - fillable;
+        preview.positions.push(LiquidationPosition {
+            question: info.map(|i| i.question.clone()).unwrap_or_default(),
+            outcome: info.map(|i| i.outcome.clone()).unwrap_or_default(),
+            asset_id: p.asset_id,
+            net_shares: p.net_shares,
+            avg_entry_price: cost_per_share,
+            mark_price: mark,
+            vwap_price: (from_book && fillable > 0.0).then(|| proceeds / fillable),
+            fillable_shares: fillable,
+            expected_proceeds: proceeds,
+            slippage_bps,
+            realized_pnl,
+            pnl_vs_mark: proceeds - mark_value,
+            from_book,
+        });
+    }
+
+    Ok(Json(preview))
+}
+
+/// Sells `shares` down the bid ladder (best first): (shares filled, USDC received).
+/// Fills fall short of `shares` when the book is too thin.
+fn sell_into_bids(bids: &[BookLevel], shares: f64) -> (f64, f64) {
+    let mut left = shares;
+    let mut proceeds = 0.0;
+    for level in bids {
+        if left <= 0.0 {
+            break;
+        }
+        let take = level.size.min(left);
+        proceeds += take * level.price;
+        left -= take;
+    }
+    (shares - left.max(0.0), proceeds)
+}
+
+// ---------------------------------------------------------------------------
+// GET /api/copytrade/sessions/:id/throughput?window=
+// Orders and skipped source trades per minute over the last `window` minutes.
+// ---------------------------------------------------------------------------
+
+pub async fn get_session_throughput(
+    State(state): State<AppState>,
+    AuthUser(owner): AuthUser,
+    Path(id): Path<String>,
+    Query(params): Query<ThroughputParams>,
+) -> Result<impl IntoResponse, (StatusCode, String)> {
+    let window = params.window.unwrap_or(60).clamp(1, 7 * 24 * 60);
+    let since = (chrono::Utc::now() - chrono::Duration::minutes(window as i64)).to_rfc3339();
+
+    let raw = {
+        let conn = state.user_db.lock().unwrap_or_else(|p| p.into_inner());
+        db::get_copytrade_session(&conn, &id, &owner)
+            .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
+            .ok_or((StatusCode::NOT_FOUND, "Session not found".into()))?;
+        db::get_session_throughput(&conn, &id, &since)
+            .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
+    };
+
+    let skipped: u32 = raw.skips.iter().map(|(_, n)| n).sum();
+    let completed = raw.orders - raw.failed_orders;
+    Ok(Json(SessionThroughput {
+        window_minutes: window,
+        orders: raw.orders,
+        failed_orders: raw.failed_orders,
+        skipped,
+        orders_per_minute: raw.orders as f64 / window as f64,
+        skipped_per_minute: skipped as f64 / window as f64,
+        avg_order_usdc: (completed > 0).then(|| raw.order_usdc / completed as f64),
+        skip_reasons: raw.skips.into_iter().collect(),
+    }))
+}
+
+// ---------------------------------------------------------------------------
+// GET /api/copytrade/sessions/:id/diagnosis
+// ---------------------------------------------------------------------------
+
+/// Window for recent orders, skips and source trades in a diagnosis
+const DIAGNOSIS_WINDOW_HOURS: u32 = 24;
+
+/// Answers "my session is running but nothing happens": engine state, trader
+/// tracking, CLOB readiness, capital, recent skips and source activity in one
+/// report, with the problems found spelled out in `issues`.
+pub async fn diagnose_session(
+    State(state): State<AppState>,
+    AuthUser(owner): AuthUser,
+    Path(id): Path<String>,
+) -> Result<impl IntoResponse, (StatusCode, String)> {
+    let since =
+        (chrono::Utc::now() - chrono::Duration::hours(DIAGNOSIS_WINDOW_HOURS as i64)).to_rfc3339();
+    let (row, recent) = {
+        let conn = state.user_db.lock().unwrap_or_else(|p| p.into_inner());
+        let row = db::get_copytrade_session(&conn, &id, &owner)
+            .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
+            .ok_or((StatusCode::NOT_FOUND, "Session not found".into()))?;
+        let recent = db::get_session_throughput(&conn, &id, &since)
+            .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+        (row, recent)
+    };
+
+    let unavailable = || {
+        (
+            StatusCode::SERVICE_UNAVAILABLE,
+            "Copy-trade engine unavailable".to_string(),
+        )
+    };
+    let (reply_tx, reply_rx) = tokio::sync::oneshot::channel();
+    state
+        .copytrade_cmd_tx
+        .send(CopyTradeCommand::Diagnose {
+            session_id: id.clone(),
+            reply: reply_tx,
+        })
+        .await
+        .map_err(|_| unavailable())?;
+    let engine = reply_rx.await.map_err(|_| unavailable())?;
+
+    let status = SessionStatus::from_str(&row.status).unwrap_or(SessionStatus::Stopped);
+    let signal_source = SignalSource::from_str(&row.signal_source).unwrap_or(SignalSource::Traders);
+    let whale_follow = signal_source == SignalSource::WhaleFollow;
+    let simulate = super::engine::runs_simulated(&row);
+    let traders: Vec<String> = engine
+        .as_ref()
+        .map(|e| e.traders.clone())
+        .unwrap_or_default();
+    let subscribed_addresses = {
+        let published = state.trader_watch_tx.borrow();
+        traders.iter().filter(|a| published.contains(*a)).count()
+    };
+    let clob_client_ready = if simulate {
+        None
+    } else {
+        Some(state.clob_client.read().await.is_some())
+    };
+    let remaining_capital = engine
+        .as_ref()
+        .map(|e| e.remaining_capital)
+        .unwrap_or(row.remaining_capital);
+
+    #[derive(clickhouse::Row, serde::Deserialize)]
+    struct SourceActivity {
+        trades: u64,
+        last_trade_at: String,
+    }
+    let mut issues = Vec::new();
+    let activity = if whale_follow || traders.is_empty() {
+        None
+    } else {
+        let result = state
+            .db
+            .query(&format!(
+                "SELECT count() AS trades,
+                    if(count() = 0, '', toString(max(block_timestamp))) AS last_trade_at
+                FROM trades
+                WHERE has(?, lower(toString(trader)))
+                  AND block_timestamp >= now() - INTERVAL {DIAGNOSIS_WINDOW_HOURS} HOUR"
+            ))
+            .bind(traders.clone())
+            .fetch_one::<SourceActivity>()
+            .await;
+        match result {
+            Ok(a) => Some(a),
+            Err(e) => {
+                tracing::warn!("Session {id}: diagnosis couldn't query source trades: {e}");
+                None
+            }
+        }
+    };
+
+    match status {
+        SessionStatus::Stopped => issues.push("Session is stopped".to_string()),
+        SessionStatus::Paused => issues.push("Session is paused".to_string()),
+        SessionStatus::Running if engine.is_none() => issues.push(
+            "Session is marked running but isn't loaded in the engine; stop and recreate it"
+                .to_string(),
+        ),
+        SessionStatus::Running => {}
+    }
+    if let Some(false) = clob_client_ready {
+        issues.push("CLOB client isn't initialized, so live orders can't be placed".to_string());
+    }
+    if engine.is_some() && !whale_follow && traders.is_empty() {
+        issues.push(
+            "No traders resolved: the list is empty or the ranking returned nobody".to_string(),
+        );
+    }
+    if subscribed_addresses < traders.len() {
+        issues.push(format!(
+            "{} of {} tracked address(es) aren't subscribed on the trade feed",
+            traders.len() - subscribed_addresses,
+            traders.len()
+        ));
+    }
+    if remaining_capital < super::engine::MIN_ORDER_USDC {
+        issues.push(format!(
+            "Remaining capital {remaining_capital:.2} USDC is below the {} USDC minimum order",
+            super::engine::MIN_ORDER_USDC
+        ));
+    }
+    if let Some(ref e) = engine {
+        if let Some(secs) = e.cooldown_remaining_secs {
+            issues.push(format!(
+                "Cooling down for {secs}s after {} consecutive failed order(s)",
+                e.consecutive_failures
+            ));
+        }
+        if let Some(secs) = e.low_balance_secs {
+            issues.push(format!(
+                "Wallet balance has been too low to trade for {secs}s"
+            ));
+        }
+        if e.orders_last_minute >= e.max_orders_per_minute as usize {
+            issues.push(format!(
+                "At the session's limit of {} orders/min",
+                e.max_orders_per_minute
+            ));
+        }
+    }
+    if activity.as_ref().is_some_and(|a| a.trades == 0) {
+        issues.push(format!(
+            "None of the tracked traders traded in the last {DIAGNOSIS_WINDOW_HOURS}h"
+        ));
+    }
+    let skipped: u32 = recent.skips.iter().map(|(_, n)| n).sum();
+    if skipped > 0 && recent.orders == 0 {
+        // Skips come back ordered by count
+        let top = &recent.skips[0];
+        issues.push(format!(
+            "All {skipped} recent signal(s) were skipped, mostly for {} ({})",
+            top.0, top.1
+        ));
+    }
+
+    Ok(Json(SessionDiagnosis {
+        session_id: id,
+        status,
+        simulate,
+        signal_source,
+        tracked_addresses: traders.len(),
+        subscribed_addresses,
+        engine,
+        clob_client_ready,
+        remaining_capital,
+        min_order_usdc: super::engine::MIN_ORDER_USDC,
+        window_hours: DIAGNOSIS_WINDOW_HOURS,
+        recent_orders: recent.orders,
+        recent_failed_orders: recent.failed_orders,
+        skip_reasons: recent.skips.into_iter().collect(),
+        source_trades: activity.as_ref().map(|a| a.trades),
+        last_source_trade_at: activity.map(|a| a.last_trade_at).filter(|t| !t.is_empty()),
+        issues,
+    }))
+}
+
+// ---------------------------------------------------------------------------
+// GET /api/copytrade/sessions/:id/tax-report?year=
+// Realized gains per disposal, buys and sells matched first-in first-out per asset.
+//
+// Caveats (not tax advice): lots still open at year-end are unrealized and left
+// out; wash-sale style rules are not applied; sells with no matching buy (e.g.
+// shares acquired outside the session) are reported with an empty acquisition
+// date and zero cost basis.
+// ---------------------------------------------------------------------------
+
+/// Below this many shares a lot counts as fully consumed (float dust)
+const LOT_EPSILON: f64 = 1e-9;
+
+struct Lot {
+    acquired_at: String,
+    shares: f64,
+    cost: f64,
+}
+
+struct Disposal {
+    asset_id: String,
+    acquired_at: String,
+    disposed_at: String,
+    shares: f64,
+    proceeds: f64,
+    cost_basis: f64,
+}
+
+/// Matches sells against the oldest open buy lots of the same asset. `fills` must be
+/// in chronological order.
+fn fifo_disposals(fills: &[db::CopyTradeOrderRow]) -> Vec<Disposal> {
+    let mut lots: std::collections::HashMap<&str, std::collections::VecDeque<Lot>> =
+        std::collections::HashMap::new();
+    let mut disposals = Vec::new();
+
+    for fill in fills {
+        let Some(shares) = fill.size_shares.filter(|s| *s > LOT_EPSILON) else {
+            continue;
+        };
+        let queue = lots.entry(fill.asset_id.as_str()).or_default();
+        if fill.side.eq_ignore_ascii_case("buy") {
+            queue.push_back(Lot {
+                acquired_at: fill.created_at.clone(),
+                shares,
+                cost: fill.size_usdc,
+            });
+            continue;
+        }
+
+        let mut remaining = shares;
+        while remaining > LOT_EPSILON {
+            let proceeds_per_share = fill.size_usdc / shares;
+            let Some(lot) = queue.front_mut() else {
+                // Sold more than the session ever bought
+                disposals.push(Disposal {
+                    asset_id: fill.asset_id.clone(),
+                    acquired_at: String::new(),
+                    disposed_at: fill.created_at.clone(),
+                    shares: remaining,
+                    proceeds: remaining * proceeds_per_share,
+                    cost_basis: 0.0,
+                });
+                break;
+            };
+            let take = remaining.min(lot.shares);
+            let cost = lot.cost * take / lot.shares;
+            disposals.push(Disposal {
+                asset_id: fill.asset_id.clone(),
+                acquired_at: lot.acquired_at.clone(),
+                disposed_at: fill.created_at.clone(),
+                shares: take,
+                proceeds: take * proceeds_per_share,
+                cost_basis: cost,
+            });
+            lot.shares -= take;
+            lot.cost -= cost;
+            remaining -= take;
+            if lot.shares <= LOT_EPSILON {
+                queue.pop_front();
+            }
+        }
+    }
+    disposals
+}
+
+fn csv_field(s: &str) -> String {
+    if s.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_string()
+    }
+}
+
+pub async fn get_tax_report(
+    State(state): State<AppState>,
+    AuthUser(owner): AuthUser,
+    Path(id): Path<String>,
+    Query(params): Query<TaxReportParams>,
+) -> Result<impl IntoResponse, (StatusCode, String)> {
+    let year = params
+        .year
+        .unwrap_or_else(|| chrono::Datelike::year(&chrono::Utc::now()));
+    if !(2000..=2100).contains(&year) {
+        return Err((StatusCode::BAD_REQUEST, "Invalid year".into()));
+    }
+
+    let fills = {
+        let conn = state.user_db.lock().unwrap_or_else(|p| p.into_inner());
+        db::get_copytrade_session(&conn, &id, &owner)
+            .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
+            .ok_or((StatusCode::NOT_FOUND, "Session not found".into()))?;
+        db::get_session_fills(&conn, &id)
             .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
     };
 
-    // Enrich with market metadata + live CLOB prices
-    let asset_ids: Vec<String> = positions.iter().map(|p| p.asset_id.clone()).collect();
-    let (market_info, clob_prices) = tokio::join!(
-        super::markets::resolve_markets(&state.http, &state.db, &state.market_cache, &asset_ids),
-        fetch_clob_midpoints(&state.http, &asset_ids),
-    );
-
-    let result: Vec<CopyTradePosition> = positions
+    // Lots are matched over the whole history; only the year's disposals are reported
+    let year_prefix = format!("{year}-");
+    let disposals: Vec<Disposal> = fifo_disposals(&fills)
         .into_iter()
-        .map(|p| {
-            let info = market_info.get(&p.asset_id);
-            let cost_per_share = if p.buy_shares > 0.0 {
-                p.cost_basis / p.buy_shares
-            } else {
-                0.0
-            };
-            // Use live CLOB price when available, fall back to last fill price
-            let live_price = clob_prices
-                .get(&p.asset_id)
-                .copied()
-                .unwrap_or(p.last_fill_price);
-            let current_value = p.net_shares * live_price;
-            let remaining_cost = p.net_shares * cost_per_share;
-            let pos_realized = p.sell_proceeds - (p.sell_shares * cost_per_share);
-
-            CopyTradePosition {
-                asset_id: p.asset_id,
-                question: info.map(|i| i.question.clone()).unwrap_or_default(),
-                outcome: info.map(|i| i.outcome.clone()).unwrap_or_default(),
-                category: info.map(|i| i.category.clone()).unwrap_or_default(),
-                buy_shares: p.buy_shares,
-                sell_shares: p.sell_shares,
-                net_shares: p.net_shares,
-                avg_entry_price: cost_per_share,
-                current_price: live_price,
-                last_fill_price: p.last_fill_price,
-                cost_basis: p.cost_basis,
-                current_value,
-                unrealized_pnl: current_value - remaining_cost,
-                realized_pnl: pos_realized,
-                order_count: p.order_count,
-                source_traders: p
-                    .source_traders
-                    .split(',')
-                    .filter(|s| !s.is_empty())
-                    .map(|s| s.to_string())
-                    .collect(),
-                last_order_at: p.last_order_at,
-            }
-        })
+        .filter(|d| d.disposed_at.starts_with(&year_prefix))
         .collect();
 
-    Ok(Json(result))
+    let mut asset_ids: Vec<String> = disposals.iter().map(|d| d.asset_id.clone()).collect();
+    asset_ids.sort();
+    asset_ids.dedup();
+    let market_info =
+        super::markets::resolve_markets(&state.http, &state.db, &state.market_cache, &asset_ids)
+            .await;
+
+    let mut csv = String::from(
+        "asset_id,market,outcome,acquired_at,disposed_at,shares,proceeds_usdc,cost_basis_usdc,gain_usdc\n",
+    );
+    for d in &disposals {
+        let info = market_info.get(&d.asset_id);
+        csv.push_str(&format!(
+            "{},{},{},{},{},{:.6},{:.6},{:.6},{:.6}\n",
+            csv_field(&d.asset_id),
+            csv_field(info.map(|i| i.question.as_str()).unwrap_or_default()),
+            csv_field(info.map(|i| i.outcome.as_str()).unwrap_or_default()),
+            d.acquired_at,
+            d.disposed_at,
+            d.shares,
+            d.proceeds,
+            d.cost_basis,
+            d.proceeds - d.cost_basis,
+        ));
+    }
+
+    let disposition = format!(
+        "attachment; filename=\"session-{}-tax-{year}.csv\"",
+        &id[..8.min(id.len())]
+    );
+    Ok((
+        [
+            (header::CONTENT_TYPE, "text/csv; charset=utf-8".to_string()),
+            (header::CONTENT_DISPOSITION, disposition),
+        ],
+        csv,
+    ))
 }
 
 // ---------------------------------------------------------------------------
@@ -832,7 +3144,7 @@ pub async fn get_active_traders(
     let mut all_traders = std::collections::HashSet::new();
     for session in &active_sessions {
         match super::engine::resolve_session_traders(&state.user_db, &state.db, session).await {
-            Ok(traders) => all_traders.extend(traders),
+            Ok(traders) => all_traders.extend(traders.into_keys()),
             Err(e) => tracing::warn!("Failed to resolve traders for session {}: {e}", session.id),
         }
     }
@@ -841,11 +3153,477 @@ pub async fn get_active_traders(
     Ok(Json(traders))
 }
 
+// ---------------------------------------------------------------------------
+// GET /api/copytrade/sessions/:id/fidelity
+// Joins our orders (SQLite) against the source traders' trades (ClickHouse)
+// to measure copy rate, size ratio and signal-to-fill lag.
+// ---------------------------------------------------------------------------
+
+/// Raw trades are TTL'd after 3 days, so older source trades can't be matched
+const FIDELITY_LOOKBACK_HOURS: i64 = 72;
+const FIDELITY_MAX_ORDERS: u32 = 10_000;
+
+pub async fn get_session_fidelity(
+    State(state): State<AppState>,
+    AuthUser(owner): AuthUser,
+    Path(id): Path<String>,
+) -> Result<impl IntoResponse, (StatusCode, String)> {
+    let (session, orders) = {
+        let conn = state.user_db.lock().unwrap_or_else(|p| p.into_inner());
+        let session = db::get_copytrade_session(&conn, &id, &owner)
+            .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
+            .ok_or((StatusCode::NOT_FOUND, "Session not found".into()))?;
+        let orders = db::get_session_orders(&conn, &id, FIDELITY_MAX_ORDERS, 0)
+            .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+        (session, orders)
+    };
+
+    // Window: session start (or retention horizon) → stop time (or now)
+    let now = chrono::Utc::now();
+    let horizon = now - chrono::Duration::hours(FIDELITY_LOOKBACK_HOURS);
+    let started = chrono::DateTime::parse_from_rfc3339(&session.created_at)
+        .map(|t| t.with_timezone(&chrono::Utc))
+        .unwrap_or(horizon);
+    let window_start = started.max(horizon);
+    let window_end = if SessionStatus::from_str(&session.status) == Some(SessionStatus::Stopped) {
+        chrono::DateTime::parse_from_rfc3339(&session.updated_at)
+            .map(|t| t.with_timezone(&chrono::Utc))
+            .unwrap_or(now)
+    } else {
+        now
+    };
+
+    // Tracked set = current resolution ∪ traders we actually copied (top_n sets drift)
+    let mut traders: std::collections::HashSet<String> =
+        super::engine::resolve_session_traders(&state.user_db, &state.db, &session)
+            .await
+            .unwrap_or_default()
+            .into_keys()
+            .collect();
+    traders.extend(orders.iter().map(|o| o.source_trader.to_lowercase()));
+    let traders: Vec<String> = traders
+        .into_iter()
+        .filter_map(|a| super::middleware::validate_eth_address(&a).ok())
+        .collect();
+
+    let mut per_trader: std::collections::HashMap<String, TraderFidelity> = traders
+        .iter()
+        .map(|a| {
+            (
+                a.clone(),
+                TraderFidelity {
+                    address: a.clone(),
+                    source_trades: 0,
+                    copied_trades: 0,
+                    filled_trades: 0,
+                },
+            )
+        })
+        .collect();
+
+    #[derive(clickhouse::Row, serde::Deserialize)]
+    struct SourceTrade {
+        tx_hash: String,
+        trader: String,
+        asset_id: String,
+        side: String,
+        usdc_amount: f64,
+        ts: u32,
+    }
+
+    let source_trades: Vec<SourceTrade> = if traders.is_empty() || window_start >= window_end {
+        Vec::new()
+    } else {
+        let in_list = traders
+            .iter()
+            .map(|a| format!("'{a}'"))
+            .collect::<Vec<_>>()
+            .join(",");
+        // One row per source fill-group: a tx can carry several fills for the same trader/asset/side
+        let query = format!(
+            "SELECT
+                lower(toString(tx_hash)) AS tx_hash,
+                lower(toString(trader)) AS trader,
+                asset_id,
+                side,
+                toFloat64(sum(usdc_amount)) AS usdc_amount,
+                toUInt32(ifNull(min(block_timestamp), toDateTime(0))) AS ts
+            FROM trades
+            WHERE lower(trader) IN ({in_list})
+              AND block_timestamp >= toDateTime(?) AND block_timestamp <= toDateTime(?)
+            GROUP BY tx_hash, trader, asset_id, side"
+        );
+        state
+            .db
+            .query(&query)
+            .bind(window_start.timestamp() as u32)
+            .bind(window_end.timestamp() as u32)
+            .fetch_all::<SourceTrade>()
+            .await
+            .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
+    };
+
+    // Index our orders by (source tx, asset, side)
+    let mut by_source: std::collections::HashMap<
+        (String, String, String),
+        Vec<&db::CopyTradeOrderRow>,
+    > = std::collections::HashMap::new();
+    for o in &orders {
+        by_source
+            .entry((
+                o.source_tx_hash.to_lowercase(),
+                o.asset_id.clone(),
+                o.side.to_lowercase(),
+            ))
+            .or_default()
+            .push(o);
+    }
+
+    let (mut copied, mut filled) = (0u64, 0u64);
+    let (mut size_ratios, mut lags) = (Vec::new(), Vec::new());
+    for t in &source_trades {
+        let entry = per_trader.get_mut(&t.trader);
+        let key = (t.tx_hash.clone(), t.asset_id.clone(), t.side.to_lowercase());
+        let matched = by_source.get(&key);
+        let fill = matched.and_then(|os| {
+            os.iter().find(|o| {
+                matches!(
+                    OrderStatus::from_str(&o.status),
+                    Some(OrderStatus::Filled | OrderStatus::Partial | OrderStatus::Simulated)
+                )
+            })
+        });
+
+        if let Some(e) = entry {
+            e.source_trades += 1;
+            if matched.is_some() {
+                e.copied_trades += 1;
+            }
+            if fill.is_some() {
+                e.filled_trades += 1;
+            }
+        }
+        if matched.is_some() {
+            copied += 1;
+        }
+        if let Some(o) = fill {
+            filled += 1;
+            if t.usdc_amount > 0.0 {
+                size_ratios.push(o.size_usdc / t.usdc_amount);
+            }
+            if let Ok(filled_at) = chrono::DateTime::parse_from_rfc3339(&o.updated_at) {
+                lags.push((filled_at.timestamp() - t.ts as i64) as f64);
+            }
+        }
+    }
+
+    let mean = |v: &[f64]| (!v.is_empty()).then(|| v.iter().sum::<f64>() / v.len() as f64);
+    let total = source_trades.len() as u64;
+    let rate = |n: u64| {
+        if total > 0 {
+            n as f64 / total as f64
+        } else {
+            0.0
+        }
+    };
+
+    let mut traders: Vec<TraderFidelity> = per_trader.into_values().collect();
+    traders.sort_by_key(|t| std::cmp::Reverse(t.source_trades));
+
+    Ok(Json(SessionFidelity {
+        session_id: id,
+        window_start: window_start.to_rfc3339(),
+        window_end: window_end.to_rfc3339(),
+        source_trades: total,
+        copied_trades: copied,
+        filled_trades: filled,
+        copy_rate: rate(copied),
+        fill_rate: rate(filled),
+        avg_size_ratio: mean(&size_ratios),
+        avg_lag_secs: mean(&lags),
+        traders,
+    }))
+}
+
+// ---------------------------------------------------------------------------
+// GET /api/copytrade/tracked-addresses
+// Compares the owner's running-session traders against the address set the
+// engine last published to ws_subscriber.
+// ---------------------------------------------------------------------------
+
+pub async fn get_tracked_addresses(
+    State(state): State<AppState>,
+    AuthUser(owner): AuthUser,
+) -> Result<impl IntoResponse, (StatusCode, String)> {
+    let sessions = {
+        let conn = state.user_db.lock().unwrap_or_else(|p| p.into_inner());
+        db::get_copytrade_sessions(&conn, &owner)
+            .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
+    };
+
+    let mut wanted = std::collections::HashSet::new();
+    for session in sessions.iter().filter(|s| s.status == "running") {
+        match super::engine::resolve_session_traders(&state.user_db, &state.db, session).await {
+            Ok(traders) => wanted.extend(traders.into_keys()),
+            Err(e) => tracing::warn!("Failed to resolve traders for session {}: {e}", session.id),
+        }
+    }
+
+    let published = state.trader_watch_tx.borrow().clone();
+    let (mut tracked, mut untracked): (Vec<String>, Vec<String>) = wanted
+        .into_iter()
+        .map(|a| a.to_lowercase())
+        .partition(|a| published.contains(a));
+    tracked.sort();
+    untracked.sort();
+
+    Ok(Json(TrackedAddresses {
+        tracked,
+        untracked,
+        total_tracked: published.len(),
+    }))
+}
+
+// ---------------------------------------------------------------------------
+// GET /api/admin/copytrade/tracked-addresses
+// Full union currently published to ws_subscriber.
+// ---------------------------------------------------------------------------
+
+pub async fn admin_tracked_addresses(
+    State(state): State<AppState>,
+    _admin: AdminUser,
+) -> Result<impl IntoResponse, (StatusCode, String)> {
+    let mut addresses: Vec<String> = state.trader_watch_tx.borrow().iter().cloned().collect();
+    addresses.sort();
+    Ok(Json(addresses))
+}
+
+// ---------------------------------------------------------------------------
+// GET /api/admin/copytrade/overview
+// SQLite totals across all owners + the engine's in-memory state.
+// ---------------------------------------------------------------------------
+
+pub async fn admin_copytrade_overview(
+    State(state): State<AppState>,
+    _admin: AdminUser,
+) -> Result<impl IntoResponse, (StatusCode, String)> {
+    let since = (chrono::Utc::now() - chrono::Duration::hours(1)).to_rfc3339();
+    let raw = {
+        let conn = state.user_db.lock().unwrap_or_else(|p| p.into_inner());
+        db::get_copytrade_overview(&conn, &since)
+            .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
+    };
+
+    let unavailable = || {
+        (
+            StatusCode::SERVICE_UNAVAILABLE,
+            "Copy-trade engine unavailable".to_string(),
+        )
+    };
+    let (reply_tx, reply_rx) = tokio::sync::oneshot::channel();
+    state
+        .copytrade_cmd_tx
+        .send(CopyTradeCommand::Inspect { reply: reply_tx })
+        .await
+        .map_err(|_| unavailable())?;
+    let engine = reply_rx.await.map_err(|_| unavailable())?;
+
+    Ok(Json(CopyTradeOverview {
+        owners: raw.owners,
+        running_sessions: raw.running_sessions,
+        paused_sessions: raw.paused_sessions,
+        stopped_sessions: raw.stopped_sessions,
+        tracked_addresses: state.trader_watch_tx.borrow().len(),
+        orders_last_hour: raw.orders,
+        failed_orders_last_hour: raw.failed_orders,
+        skipped_last_hour: raw.skipped,
+        engine,
+    }))
+}
+
+// ---------------------------------------------------------------------------
+// GET /api/admin/engine/metrics
+// ---------------------------------------------------------------------------
+
+pub async fn admin_engine_metrics(
+    State(state): State<AppState>,
+    _admin: AdminUser,
+) -> Result<impl IntoResponse, (StatusCode, String)> {
+    Ok(Json(state.engine_metrics.snapshot()))
+}
+
+// ---------------------------------------------------------------------------
+// GET /api/admin/copytrade/analytics/{volume,markets}
+// Cross-user aggregates over the ClickHouse order mirror. Filled = filled or
+// simulated; volume counts filled orders only.
+// ---------------------------------------------------------------------------
+
+fn require_order_mirror(state: &AppState) -> Result<(), (StatusCode, String)> {
+    if state.order_mirror_enabled {
+        Ok(())
+    } else {
+        Err((
+            StatusCode::SERVICE_UNAVAILABLE,
+            "Order mirror disabled (set COPYTRADE_CH_MIRROR=true)".into(),
+        ))
+    }
+}
+
+pub async fn admin_copytrade_volume(
+    State(state): State<AppState>,
+    _admin: AdminUser,
+    Query(params): Query<CopyTradeAnalyticsParams>,
+) -> Result<impl IntoResponse, (StatusCode, String)> {
+    require_order_mirror(&state)?;
+    let days = params.days.unwrap_or(30).clamp(1, 365);
+
+    let rows = state
+        .db
+        .query(
+            "SELECT
+                toString(toDate(created_at)) AS day,
+                count() AS orders,
+                countIf(status IN ('filled', 'partial', 'simulated')) AS filled_orders,
+                sumIf(size_usdc, status IN ('filled', 'partial', 'simulated')) AS volume_usdc,
+                uniqExact(session_id) AS sessions,
+                uniqExact(owner) AS owners
+            FROM copytrade_orders FINAL
+            WHERE created_at >= now() - INTERVAL ? DAY
+            GROUP BY day
+            ORDER BY day",
+        )
+        .bind(days)
+        .fetch_all::<CopyTradeVolumeDay>()
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    Ok(Json(rows))
+}
+
+pub async fn admin_copied_markets(
+    State(state): State<AppState>,
+    _admin: AdminUser,
+    Query(params): Query<CopyTradeAnalyticsParams>,
+) -> Result<impl IntoResponse, (StatusCode, String)> {
+    require_order_mirror(&state)?;
+    let days = params.days.unwrap_or(30).clamp(1, 365);
+    let limit = params.limit.unwrap_or(20).clamp(1, 100);
+
+    #[derive(clickhouse::Row, serde::Deserialize)]
+    struct MarketRow {
+        asset_id: String,
+        orders: u64,
+        volume_usdc: f64,
+        sessions: u64,
+        source_traders: u64,
+    }
+
+    let rows = state
+        .db
+        .query(
+            "SELECT
+                asset_id,
+                count() AS orders,
+                sumIf(size_usdc, status IN ('filled', 'partial', 'simulated')) AS volume_usdc,
+                uniqExact(session_id) AS sessions,
+                uniqExact(source_trader) AS source_traders
+            FROM copytrade_orders FINAL
+            WHERE created_at >= now() - INTERVAL ? DAY
+            GROUP BY asset_id
+            ORDER BY orders DESC
+            LIMIT ?",
+        )
+        .bind(days)
+        .bind(limit)
+        .fetch_all::<MarketRow>()
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    let asset_ids: Vec<String> = rows.iter().map(|r| r.asset_id.clone()).collect();
+    let market_info =
+        super::markets::resolve_markets(&state.http, &state.db, &state.market_cache, &asset_ids)
+            .await;
+
+    let markets: Vec<CopiedMarket> = rows
+        .into_iter()
+        .map(|r| {
+            let info = market_info.get(&r.asset_id);
+            CopiedMarket {
+                question: info.map(|i| i.question.clone()).unwrap_or_default(),
+                outcome: info.map(|i| i.outcome.clone()).unwrap_or_default(),
+                asset_id: r.asset_id,
+                orders: r.orders,
+                volume_usdc: r.volume_usdc,
+                sessions: r.sessions,
+                source_traders: r.source_traders,
+            }
+        })
+        .collect();
+
+    Ok(Json(markets))
+}
+
+// ---------------------------------------------------------------------------
+// GET /api/admin/copytrade/audit
+// Append-only order lifecycle log (written only with COPYTRADE_ORDER_AUDIT=true)
+// ---------------------------------------------------------------------------
+
+fn audit_bound(value: Option<&str>, name: &str) -> Result<Option<String>, (StatusCode, String)> {
+    value
+        .map(|v| {
+            chrono::DateTime::parse_from_rfc3339(v)
+                .map(|t| t.with_timezone(&chrono::Utc).to_rfc3339())
+                .map_err(|_| {
+                    (
+                        StatusCode::BAD_REQUEST,
+                        format!("{name} must be an RFC 3339 timestamp"),
+                    )
+                })
+        })
+        .transpose()
+}
+
+pub async fn admin_order_audit(
+    State(state): State<AppState>,
+    _admin: AdminUser,
+    Query(params): Query<OrderAuditParams>,
+) -> Result<impl IntoResponse, (StatusCode, String)> {
+    let from = audit_bound(params.from.as_deref(), "from")?;
+    let to = audit_bound(params.to.as_deref(), "to")?;
+    let limit = params.limit.unwrap_or(200).clamp(1, 1000);
+
+    let rows = {
+        let conn = state.user_db.lock().unwrap_or_else(|p| p.into_inner());
+        db::get_order_audit(
+            &conn,
+            params.session_id.as_deref(),
+            params.order_id.as_deref(),
+            from.as_deref(),
+            to.as_deref(),
+            limit,
+        )
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
+    };
+
+    let entries: Vec<OrderAuditEntry> = rows
+        .into_iter()
+        .map(|r| OrderAuditEntry {
+            id: r.id,
+            session_id: r.session_id,
+            order_id: r.order_id,
+            stage: r.stage,
+            context: serde_json::from_str(&r.context).unwrap_or(serde_json::Value::Null),
+            created_at: r.created_at,
+        })
+        .collect();
+    Ok(Json(entries))
+}
+
 // ---------------------------------------------------------------------------
 // Public CLOB price fetch (no auth required)
 // ---------------------------------------------------------------------------
 
-async fn fetch_clob_midpoints(
+pub(crate) async fn fetch_clob_midpoints(
     http: &reqwest::Client,
     token_ids: &[String],
 ) -> std::collections::HashMap<String, f64> {
@@ -875,7 +3653,11 @@ async fn fetch_clob_midpoints(
     result
 }
 
-async fn fetch_one_price(http: &reqwest::Client, token_id: &str, side: &str) -> Option<f64> {
+pub(crate) async fn fetch_one_price(
+    http: &reqwest::Client,
+    token_id: &str,
+    side: &str,
+) -> Option<f64> {
     #[derive(serde::Deserialize)]
     struct PriceResp {
         price: Option<String>,
@@ -894,6 +3676,89 @@ async fn fetch_one_price(http: &reqwest::Client, token_id: &str, side: &str) ->
     body.price?.parse::<f64>().ok()
 }
 
+/// Bid ladders for `token_ids`, fetched concurrently. Tokens whose book is missing
+/// or failed to load are left out.
+async fn fetch_clob_bids(
+    http: &reqwest::Client,
+    token_ids: &[String],
+) -> std::collections::HashMap<String, Vec<BookLevel>> {
+    let mut handles = Vec::with_capacity(token_ids.len());
+    for tid in token_ids {
+        let http = http.clone();
+        let tid = tid.clone();
+        handles.push(tokio::spawn(async move {
+            match fetch_clob_book(&http, &tid).await {
+                Ok(Some((bids, _))) => Some((tid, bids)),
+                Ok(None) => None,
+                Err(e) => {
+                    tracing::warn!("Liquidation preview: no book for {tid}: {e}");
+                    None
+                }
+            }
+        }));
+    }
+
+    let mut result = std::collections::HashMap::new();
+    for handle in handles {
+        if let Ok(Some((tid, bids))) = handle.await {
+            result.insert(tid, bids);
+        }
+    }
+    result
+}
+
+/// L2 book for one token from the public CLOB API, best levels first. Ok(None) when
+/// the CLOB has no book for the token.
+pub(crate) async fn fetch_clob_book(
+    http: &reqwest::Client,
+    token_id: &str,
+) -> Result<Option<(Vec<BookLevel>, Vec<BookLevel>)>, String> {
+    #[derive(serde::Deserialize)]
+    struct Level {
+        price: String,
+        size: String,
+    }
+    #[derive(serde::Deserialize)]
+    struct BookResp {
+        #[serde(default)]
+        bids: Vec<Level>,
+        #[serde(default)]
+        asks: Vec<Level>,
+    }
+    let url = format!("https://clob.polymarket.com/book?token_id={token_id}");
+    let resp = http
+        .get(&url)
+        .timeout(std::time::Duration::from_secs(3))
+        .send()
+        .await
+        .map_err(|e| format!("CLOB book request failed: {e}"))?;
+    if resp.status() == reqwest::StatusCode::NOT_FOUND {
+        return Ok(None);
+    }
+    let body: BookResp = resp
+        .error_for_status()
+        .map_err(|e| format!("CLOB book request failed: {e}"))?
+        .json()
+        .await
+        .map_err(|e| format!("CLOB book response invalid: {e}"))?;
+
+    let parse = |levels: Vec<Level>| -> Vec<BookLevel> {
+        levels
+            .into_iter()
+            .filter_map(|l| {
+                let price = l.price.parse::<f64>().ok()?;
+                let size = l.size.parse::<f64>().ok()?;
+                (size > 0.0).then_some(BookLevel { price, size })
+            })
+            .collect()
+    };
+    let mut bids = parse(body.bids);
+    let mut asks = parse(body.asks);
+    bids.sort_by(|a, b| b.price.total_cmp(&a.price));
+    asks.sort_by(|a, b| a.price.total_cmp(&b.price));
+    Ok(Some((bids, asks)))
+}
+
 // ---------------------------------------------------------------------------
 // Conversion helpers
 // ---------------------------------------------------------------------------
@@ -903,6 +3768,7 @@ fn session_from_row(row: &CopyTradeSessionRow, positions_value: f64) -> CopyTrad
         id: row.id.clone(),
         list_id: row.list_id.clone(),
         top_n: row.top_n,
+        top_pct: row.top_pct,
         copy_pct: row.copy_pct,
         max_position_usdc: row.max_position_usdc,
         max_slippage_bps: row.max_slippage_bps,
@@ -910,11 +3776,69 @@ fn session_from_row(row: &CopyTradeSessionRow, positions_value: f64) -> CopyTrad
         initial_capital: row.initial_capital,
         remaining_capital: row.remaining_capital,
         positions_value,
+        fees_paid: row.fees_paid,
         simulate: row.simulate,
         max_loss_pct: row.max_loss_pct,
+        only_favorable: row.only_favorable,
+        buy_order_type: row
+            .buy_order_type
+            .as_deref()
+            .and_then(CopyOrderType::from_str),
+        sell_order_type: row
+            .sell_order_type
+            .as_deref()
+            .and_then(CopyOrderType::from_str),
+        mark_price_source: MarkPriceSource::from_str(&row.mark_price_source)
+            .unwrap_or(MarkPriceSource::LastFill),
+        min_trader_pnl: row.min_trader_pnl,
+        min_trader_winrate: row.min_trader_winrate,
+        slippage_pause_after: row.slippage_pause_after,
+        min_liquidity_usdc: row.min_liquidity_usdc,
+        liquidity_window_mins: row.liquidity_window_mins,
+        close_on_trader_removal: row.close_on_trader_removal,
+        max_market_usdc: row.max_market_usdc,
+        seed_existing_positions: row.seed_existing_positions,
+        sizing_mode: SizingMode::from_str(&row.sizing_mode).unwrap_or(SizingMode::Dollar),
+        webhook_url: row.webhook_url.clone(),
+        digest_interval_hours: row.digest_interval_hours,
+        sizing_base: SizingBase::from_str(&row.sizing_base).unwrap_or(SizingBase::Remaining),
+        max_runtime_secs: row.max_runtime_secs,
+        asset_allowlist: row
+            .asset_allowlist
+            .as_deref()
+            .and_then(|json| serde_json::from_str(json).ok()),
+        near_resolution_price: row.near_resolution_price,
+        gtc_price_ref: GtcPriceRef::from_str(&row.gtc_price_ref).unwrap_or(GtcPriceRef::Source),
+        gtc_price_offset_bps: row.gtc_price_offset_bps,
+        sell_behavior: SellBehavior::from_str(&row.sell_behavior)
+            .unwrap_or(SellBehavior::Proportional),
+        trail_activate_pct: row.trail_activate_pct,
+        trail_distance_pct: row.trail_distance_pct,
+        mark_stale_secs: row.mark_stale_secs,
+        signal_source: SignalSource::from_str(&row.signal_source).unwrap_or(SignalSource::Traders),
+        min_whale_usdc: row.min_whale_usdc,
+        slippage_fee_bps: row.slippage_fee_bps,
+        max_signal_age_secs: row.max_signal_age_secs,
+        top_n_metric: TopNMetric::from_str(&row.top_n_metric).unwrap_or(TopNMetric::Pnl),
+        min_hold_secs: row.min_hold_secs,
+        slippage_overrides: row
+            .slippage_overrides
+            .as_deref()
+            .and_then(|json| serde_json::from_str(json).ok()),
+        max_orders_per_minute: row.max_orders_per_minute,
+        size_rounding: SizeRounding::from_str(&row.size_rounding).unwrap_or(SizeRounding::Down),
+        take_profit_pct: row.take_profit_pct,
+        stop_loss_pct: row.stop_loss_pct,
+        mode: CopyMode::from_str(&row.mode).unwrap_or(CopyMode::Copy),
+        allowed_categories: row.allowed_categories.clone(),
+        blocked_categories: row.blocked_categories.clone(),
+        copy_uncategorized: row.copy_uncategorized,
+        copy_sides: CopySides::from_str(&row.copy_sides).unwrap_or(CopySides::Both),
+        fok_fallback_gtc: row.fok_fallback_gtc,
         status: SessionStatus::from_str(&row.status).unwrap_or(SessionStatus::Stopped),
         created_at: row.created_at.clone(),
         updated_at: row.updated_at.clone(),
+        approval: None,
     }
 }
 
@@ -938,5 +3862,29 @@ fn order_from_row(row: db::CopyTradeOrderRow) -> CopyTradeOrder {
         tx_hash: row.tx_hash,
         created_at: row.created_at,
         updated_at: row.updated_at,
+        origin: OrderOrigin::from_str(&row.origin).unwrap_or(OrderOrigin::Signal),
+        max_slippage_bps: row.max_slippage_bps,
+        fallback_for: row.fallback_for,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn force_simulate_skips_exchange_approvals() {
+        let req: CreateSessionRequest = serde_json::from_value(serde_json::json!({
+            "list_id": "list",
+            "copy_pct": 0.5,
+            "initial_capital": 100.0,
+            "simulate": false,
+            "auto_approve": true,
+        }))
+        .unwrap();
+        crate::api::engine::FORCE_SIMULATE_OVERRIDE.set(Some(false));
+        assert!(approves_on_create(&req));
+        crate::api::engine::FORCE_SIMULATE_OVERRIDE.set(Some(true));
+        assert!(!approves_on_create(&req));
     }
 }
@@ -38,6 +38,28 @@ pub fn encrypt_secret(
     Ok((ciphertext, nonce.to_vec()))
 }
 
+/// Why a stored secret could not be decrypted.
+#[derive(Debug)]
+pub enum DecryptError {
+    /// AEAD authentication failed: the blob was encrypted under a different
+    /// `WALLET_ENCRYPTION_KEY` (or owner), or the ciphertext/nonce is corrupted.
+    KeyMismatch,
+    /// The stored nonce isn't a valid AES-GCM nonce (wrong length).
+    Malformed(String),
+}
+
+impl std::fmt::Display for DecryptError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DecryptError::KeyMismatch => write!(
+                f,
+                "decryption failed: key mismatch or corrupted data (was WALLET_ENCRYPTION_KEY changed?)"
+            ),
+            DecryptError::Malformed(e) => write!(f, "decryption failed: {e}"),
+        }
+    }
+}
+
 /// Decrypts ciphertext with AES-256-GCM.
 /// `aad` must match the value used during encryption.
 pub fn decrypt_secret(
@@ -45,7 +67,15 @@ pub fn decrypt_secret(
     ciphertext: &[u8],
     nonce: &[u8],
     aad: &[u8],
-) -> Result<Vec<u8>, String> {
+) -> Result<Vec<u8>, DecryptError> {
+    // from_slice panics on a wrong-length nonce
+    if nonce.len() != 12 {
+        return Err(DecryptError::Malformed(format!(
+            "invalid nonce length {} (expected 12)",
+            nonce.len()
+        )));
+    }
+
     let cipher = Aes256Gcm::new(key.into());
 
     let nonce = aes_gcm::Nonce::from_slice(nonce);
@@ -55,7 +85,33 @@ pub fn decrypt_secret(
         aad,
     };
 
+    // aes-gcm's error is opaque; failure here can only be a tag mismatch
     cipher
         .decrypt(nonce, payload)
-        .map_err(|e| format!("decryption failed: {e}"))
+        .map_err(|_| DecryptError::KeyMismatch)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decrypt_distinguishes_key_mismatch_from_malformed_nonce() {
+        let key = derive_user_key(&[1; 32], "0xowner");
+        let (ciphertext, nonce) = encrypt_secret(&key, b"secret", b"0xowner").unwrap();
+        assert_eq!(
+            decrypt_secret(&key, &ciphertext, &nonce, b"0xowner").unwrap(),
+            b"secret"
+        );
+
+        let rotated = derive_user_key(&[2; 32], "0xowner");
+        assert!(matches!(
+            decrypt_secret(&rotated, &ciphertext, &nonce, b"0xowner"),
+            Err(DecryptError::KeyMismatch)
+        ));
+        assert!(matches!(
+            decrypt_secret(&key, &ciphertext, &nonce[..8], b"0xowner"),
+            Err(DecryptError::Malformed(_))
+        ));
+    }
 }
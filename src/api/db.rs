@@ -21,6 +21,21 @@ pub struct TradingWalletRow {
     pub status: String,
     pub created_at: String,
     pub updated_at: String,
+    /// "proxy" (orders signed for the Polymarket proxy wallet) or "eoa"
+    pub signature_type: String,
+}
+
+impl TradingWalletRow {
+    /// Address that holds the wallet's USDC.e: the proxy in proxy mode, the EOA otherwise.
+    pub fn funds_address(&self) -> &str {
+        match self.signature_type.as_str() {
+            "eoa" => &self.wallet_address,
+            _ => self
+                .proxy_address
+                .as_deref()
+                .unwrap_or(&self.wallet_address),
+        }
+    }
 }
 
 /// Opens (or creates) the SQLite user database and runs migrations.
@@ -115,13 +130,288 @@ pub fn init_user_db(path: &str) -> Connection {
             created_at      TEXT NOT NULL,
             updated_at      TEXT NOT NULL,
             FOREIGN KEY (session_id) REFERENCES copy_trade_sessions(id) ON DELETE CASCADE
-        )",
+        );
+
+        CREATE TABLE IF NOT EXISTS copy_trade_skips (
+            id              INTEGER PRIMARY KEY AUTOINCREMENT,
+            session_id      TEXT NOT NULL,
+            source_tx_hash  TEXT NOT NULL,
+            asset_id        TEXT NOT NULL,
+            reason          TEXT NOT NULL,
+            created_at      TEXT NOT NULL,
+            FOREIGN KEY (session_id) REFERENCES copy_trade_sessions(id) ON DELETE CASCADE
+        );
+        CREATE INDEX IF NOT EXISTS idx_copy_trade_skips_session
+            ON copy_trade_skips(session_id, created_at);
+
+        -- Change feed for the ClickHouse order mirror (keyset on updated_at, id)
+        CREATE INDEX IF NOT EXISTS idx_cto_updated_at
+            ON copy_trade_orders(updated_at, id);
+
+        -- Last trader set resolved for a session, the fallback while ClickHouse is down
+        CREATE TABLE IF NOT EXISTS session_resolved_traders (
+            session_id  TEXT PRIMARY KEY,
+            traders     TEXT NOT NULL,
+            resolved_at TEXT NOT NULL,
+            FOREIGN KEY (session_id) REFERENCES copy_trade_sessions(id) ON DELETE CASCADE
+        );
+
+        CREATE TABLE IF NOT EXISTS session_templates (
+            id          TEXT PRIMARY KEY,
+            owner       TEXT NOT NULL,
+            name        TEXT NOT NULL,
+            config      TEXT NOT NULL,
+            created_at  TEXT NOT NULL,
+            updated_at  TEXT NOT NULL,
+            UNIQUE(owner, name)
+        );
+
+        -- Append-only order lifecycle log (COPYTRADE_ORDER_AUDIT). No FK on purpose:
+        -- entries outlive the session and order rows they describe.
+        CREATE TABLE IF NOT EXISTS order_audit (
+            id          INTEGER PRIMARY KEY AUTOINCREMENT,
+            session_id  TEXT NOT NULL,
+            order_id    TEXT,
+            stage       TEXT NOT NULL,
+            context     TEXT NOT NULL,
+            created_at  TEXT NOT NULL
+        );
+        CREATE INDEX IF NOT EXISTS idx_order_audit_session
+            ON order_audit(session_id, created_at);
+        CREATE INDEX IF NOT EXISTS idx_order_audit_order
+            ON order_audit(order_id);
+        CREATE TRIGGER IF NOT EXISTS order_audit_no_update
+            BEFORE UPDATE ON order_audit
+            BEGIN SELECT RAISE(ABORT, 'order_audit is append-only'); END;
+        CREATE TRIGGER IF NOT EXISTS order_audit_no_delete
+            BEFORE DELETE ON order_audit
+            BEGIN SELECT RAISE(ABORT, 'order_audit is append-only'); END;",
     )
     .expect("failed to create tables");
+
+    // Column migrations for tables created by older versions
+    add_column_if_missing(
+        &conn,
+        "copy_trade_sessions",
+        "only_favorable",
+        "INTEGER NOT NULL DEFAULT 0",
+    );
+    add_column_if_missing(&conn, "copy_trade_sessions", "buy_order_type", "TEXT");
+    add_column_if_missing(&conn, "copy_trade_sessions", "sell_order_type", "TEXT");
+    add_column_if_missing(
+        &conn,
+        "copy_trade_sessions",
+        "mark_price_source",
+        "TEXT NOT NULL DEFAULT 'last_fill'",
+    );
+    add_column_if_missing(&conn, "copy_trade_sessions", "min_trader_pnl", "REAL");
+    add_column_if_missing(&conn, "copy_trade_sessions", "min_trader_winrate", "REAL");
+    add_column_if_missing(&conn, "copy_trade_sessions", "top_pct", "REAL");
+    add_column_if_missing(
+        &conn,
+        "copy_trade_sessions",
+        "slippage_pause_after",
+        "INTEGER",
+    );
+    add_column_if_missing(&conn, "copy_trade_sessions", "min_liquidity_usdc", "REAL");
+    add_column_if_missing(
+        &conn,
+        "trading_wallets",
+        "signature_type",
+        "TEXT NOT NULL DEFAULT 'proxy'",
+    );
+    add_column_if_missing(
+        &conn,
+        "copy_trade_sessions",
+        "close_on_trader_removal",
+        "INTEGER NOT NULL DEFAULT 0",
+    );
+    add_column_if_missing(&conn, "copy_trade_sessions", "max_market_usdc", "REAL");
+    add_column_if_missing(
+        &conn,
+        "copy_trade_sessions",
+        "seed_existing_positions",
+        "INTEGER NOT NULL DEFAULT 0",
+    );
+    add_column_if_missing(
+        &conn,
+        "copy_trade_sessions",
+        "sizing_mode",
+        "TEXT NOT NULL DEFAULT 'dollar'",
+    );
+    add_column_if_missing(&conn, "copy_trade_sessions", "webhook_url", "TEXT");
+    add_column_if_missing(
+        &conn,
+        "copy_trade_sessions",
+        "digest_interval_hours",
+        "INTEGER",
+    );
+    add_column_if_missing(&conn, "copy_trade_sessions", "last_digest_at", "TEXT");
+    add_column_if_missing(
+        &conn,
+        "copy_trade_sessions",
+        "sizing_base",
+        "TEXT NOT NULL DEFAULT 'remaining'",
+    );
+    add_column_if_missing(&conn, "copy_trade_sessions", "max_runtime_secs", "INTEGER");
+    add_column_if_missing(&conn, "copy_trade_sessions", "asset_allowlist", "TEXT");
+    add_column_if_missing(
+        &conn,
+        "copy_trade_sessions",
+        "near_resolution_price",
+        "REAL",
+    );
+    add_column_if_missing(
+        &conn,
+        "copy_trade_sessions",
+        "gtc_price_ref",
+        "TEXT NOT NULL DEFAULT 'source'",
+    );
+    add_column_if_missing(
+        &conn,
+        "copy_trade_sessions",
+        "gtc_price_offset_bps",
+        "INTEGER NOT NULL DEFAULT 0",
+    );
+    add_column_if_missing(
+        &conn,
+        "copy_trade_sessions",
+        "sell_behavior",
+        "TEXT NOT NULL DEFAULT 'proportional'",
+    );
+    add_column_if_missing(&conn, "copy_trade_sessions", "trail_activate_pct", "REAL");
+    add_column_if_missing(&conn, "copy_trade_sessions", "trail_distance_pct", "REAL");
+    add_column_if_missing(&conn, "copy_trade_sessions", "mark_stale_secs", "INTEGER");
+    add_column_if_missing(
+        &conn,
+        "copy_trade_sessions",
+        "signal_source",
+        "TEXT NOT NULL DEFAULT 'traders'",
+    );
+    add_column_if_missing(&conn, "copy_trade_sessions", "min_whale_usdc", "REAL");
+    add_column_if_missing(&conn, "copy_trade_sessions", "slippage_fee_bps", "INTEGER");
+    add_column_if_missing(
+        &conn,
+        "copy_trade_sessions",
+        "fees_paid",
+        "REAL NOT NULL DEFAULT 0",
+    );
+    add_column_if_missing(
+        &conn,
+        "copy_trade_sessions",
+        "max_signal_age_secs",
+        "INTEGER",
+    );
+    add_column_if_missing(
+        &conn,
+        "copy_trade_sessions",
+        "top_n_metric",
+        "TEXT NOT NULL DEFAULT 'pnl'",
+    );
+    add_column_if_missing(
+        &conn,
+        "copy_trade_sessions",
+        "min_hold_secs",
+        "INTEGER NOT NULL DEFAULT 0",
+    );
+    add_column_if_missing(&conn, "copy_trade_sessions", "slippage_overrides", "TEXT");
+    add_column_if_missing(
+        &conn,
+        "copy_trade_sessions",
+        "max_orders_per_minute",
+        "INTEGER",
+    );
+    add_column_if_missing(
+        &conn,
+        "copy_trade_sessions",
+        "size_rounding",
+        "TEXT NOT NULL DEFAULT 'down'",
+    );
+    add_column_if_missing(&conn, "copy_trade_sessions", "take_profit_pct", "REAL");
+    add_column_if_missing(&conn, "copy_trade_sessions", "stop_loss_pct", "REAL");
+    add_column_if_missing(
+        &conn,
+        "copy_trade_sessions",
+        "mode",
+        "TEXT NOT NULL DEFAULT 'copy'",
+    );
+    add_column_if_missing(&conn, "copy_trade_sessions", "allowed_categories", "TEXT");
+    add_column_if_missing(&conn, "copy_trade_sessions", "blocked_categories", "TEXT");
+    add_column_if_missing(
+        &conn,
+        "copy_trade_sessions",
+        "copy_uncategorized",
+        "INTEGER NOT NULL DEFAULT 1",
+    );
+    add_column_if_missing(
+        &conn,
+        "copy_trade_sessions",
+        "copy_sides",
+        "TEXT NOT NULL DEFAULT 'both'",
+    );
+    add_column_if_missing(
+        &conn,
+        "copy_trade_sessions",
+        "fok_fallback_gtc",
+        "INTEGER NOT NULL DEFAULT 0",
+    );
+    add_column_if_missing(
+        &conn,
+        "copy_trade_sessions",
+        "liquidity_window_mins",
+        "INTEGER",
+    );
+    add_column_if_missing(&conn, "copy_trade_orders", "max_slippage_bps", "INTEGER");
+    add_column_if_missing(&conn, "copy_trade_orders", "fallback_for", "TEXT");
+    add_column_if_missing(
+        &conn,
+        "trader_list_members",
+        "weight",
+        "REAL NOT NULL DEFAULT 1.0",
+    );
+    // Older rows only carry the origin in their sentinel source_tx_hash
+    if add_column_if_missing(
+        &conn,
+        "copy_trade_orders",
+        "origin",
+        "TEXT NOT NULL DEFAULT 'signal'",
+    ) {
+        conn.execute_batch(
+            "UPDATE copy_trade_orders SET origin = CASE source_tx_hash
+                WHEN 'manual' THEN 'manual'
+                WHEN 'close-position' THEN 'close'
+                WHEN 'redeem' THEN 'redeem'
+                WHEN 'trader-removed' THEN 'auto'
+                ELSE 'signal'
+             END",
+        )
+        .expect("failed to backfill copy_trade_orders.origin");
+    }
+
     tracing::info!("SQLite user DB initialized at {path}");
     conn
 }
 
+/// SQLite has no `ADD COLUMN IF NOT EXISTS`, so check `table_info` first.
+/// Panics on failure, like the rest of the startup migrations.
+/// Returns whether the column was added (i.e. this is the migration run).
+fn add_column_if_missing(conn: &Connection, table: &str, column: &str, decl: &str) -> bool {
+    let mut stmt = conn
+        .prepare(&format!("PRAGMA table_info({table})"))
+        .expect("failed to read table info");
+    let exists = stmt
+        .query_map([], |row| row.get::<_, String>(1))
+        .expect("failed to read table info")
+        .filter_map(|r| r.ok())
+        .any(|name| name == column);
+    drop(stmt);
+    if !exists {
+        conn.execute_batch(&format!("ALTER TABLE {table} ADD COLUMN {column} {decl}"))
+            .unwrap_or_else(|e| panic!("failed to add {table}.{column}: {e}"));
+    }
+    !exists
+}
+
 /// Returns `(nonce, issued_at)` for the given address, creating the user if needed.
 pub fn get_or_create_user(
     conn: &Connection,
@@ -205,7 +495,7 @@ impl From<rusqlite::Error> for ListError {
 }
 
 const MAX_LISTS_PER_USER: u32 = 20;
-const MAX_MEMBERS_PER_LIST: u32 = 100;
+pub const MAX_MEMBERS_PER_LIST: u32 = 100;
 
 pub fn create_trader_list(
     conn: &Connection,
@@ -284,7 +574,7 @@ pub fn get_trader_list(
         })?;
 
     let mut stmt = conn.prepare(
-        "SELECT address, label, added_at FROM trader_list_members WHERE list_id = ?1 ORDER BY added_at",
+        "SELECT address, label, added_at, weight FROM trader_list_members WHERE list_id = ?1 ORDER BY added_at",
     )?;
     let members = stmt
         .query_map(rusqlite::params![id], |row| {
@@ -292,6 +582,7 @@ pub fn get_trader_list(
                 address: row.get(0)?,
                 label: row.get(1)?,
                 added_at: row.get(2)?,
+                weight: row.get(3)?,
             })
         })?
         .collect::<Result<Vec<_>, _>>()?;
@@ -337,7 +628,7 @@ pub fn add_list_members(
     conn: &Connection,
     list_id: &str,
     owner: &str,
-    addresses: &[(String, Option<String>)],
+    addresses: &[(String, Option<String>, f64)],
 ) -> Result<(), ListError> {
     // Verify ownership
     let exists: bool = conn
@@ -351,24 +642,36 @@ pub fn add_list_members(
         return Err(ListError::NotFound);
     }
 
-    // Check member limit
+    // Check member limit (re-adding a member only updates its weight)
     let current: u32 = conn.query_row(
         "SELECT COUNT(*) FROM trader_list_members WHERE list_id = ?1",
         rusqlite::params![list_id],
         |row| row.get(0),
     )?;
-    if current + addresses.len() as u32 > MAX_MEMBERS_PER_LIST {
+    let added = addresses
+        .iter()
+        .filter(|(addr, _, _)| {
+            conn.query_row(
+                "SELECT 1 FROM trader_list_members WHERE list_id = ?1 AND address = ?2",
+                rusqlite::params![list_id, addr],
+                |_| Ok(()),
+            )
+            .is_err()
+        })
+        .count() as u32;
+    if current + added > MAX_MEMBERS_PER_LIST {
         return Err(ListError::LimitExceeded("Maximum 100 members per list"));
     }
 
     let now = chrono::Utc::now().to_rfc3339();
     let updated_at = now.clone();
 
-    for (addr, label) in addresses {
+    for (addr, label, weight) in addresses {
         conn.execute(
-            "INSERT OR IGNORE INTO trader_list_members (list_id, address, label, added_at)
-             VALUES (?1, ?2, ?3, ?4)",
-            rusqlite::params![list_id, addr, label, now],
+            "INSERT INTO trader_list_members (list_id, address, label, added_at, weight)
+             VALUES (?1, ?2, ?3, ?4, ?5)
+             ON CONFLICT(list_id, address) DO UPDATE SET weight = excluded.weight",
+            rusqlite::params![list_id, addr, label, now, weight],
         )?;
     }
 
@@ -435,6 +738,7 @@ pub fn create_trading_wallet(
     proxy_address: &str,
     encrypted_key: &[u8],
     key_nonce: &[u8],
+    signature_type: &str,
 ) -> Result<String, WalletError> {
     let count = count_trading_wallets(conn, owner)?;
     if count >= MAX_WALLETS_PER_USER {
@@ -445,9 +749,9 @@ pub fn create_trading_wallet(
     let now = chrono::Utc::now().to_rfc3339();
 
     conn.execute(
-        "INSERT INTO trading_wallets (id, owner, wallet_address, proxy_address, encrypted_key, key_nonce, status, created_at, updated_at)
-         VALUES (?1, ?2, ?3, ?4, ?5, ?6, 'created', ?7, ?7)",
-        rusqlite::params![id, owner, wallet_address, proxy_address, encrypted_key, key_nonce, now],
+        "INSERT INTO trading_wallets (id, owner, wallet_address, proxy_address, encrypted_key, key_nonce, status, created_at, updated_at, signature_type)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, 'created', ?7, ?7, ?8)",
+        rusqlite::params![id, owner, wallet_address, proxy_address, encrypted_key, key_nonce, now, signature_type],
     )?;
 
     Ok(id)
@@ -459,7 +763,8 @@ pub fn get_trading_wallets(
 ) -> Result<Vec<TradingWalletRow>, rusqlite::Error> {
     let mut stmt = conn.prepare(
         "SELECT id, owner, wallet_address, proxy_address, encrypted_key, key_nonce,
-                clob_api_key, clob_credentials, clob_nonce, status, created_at, updated_at
+                clob_api_key, clob_credentials, clob_nonce, status, created_at, updated_at,
+                signature_type
          FROM trading_wallets WHERE owner = ?1 ORDER BY created_at ASC",
     )?;
     let rows = stmt
@@ -477,6 +782,7 @@ pub fn get_trading_wallets(
                 status: row.get(9)?,
                 created_at: row.get(10)?,
                 updated_at: row.get(11)?,
+                signature_type: row.get(12)?,
             })
         })?
         .collect::<Result<Vec<_>, _>>()?;
@@ -490,7 +796,8 @@ pub fn get_trading_wallet_by_id(
 ) -> Result<Option<TradingWalletRow>, rusqlite::Error> {
     conn.query_row(
         "SELECT id, owner, wallet_address, proxy_address, encrypted_key, key_nonce,
-                clob_api_key, clob_credentials, clob_nonce, status, created_at, updated_at
+                clob_api_key, clob_credentials, clob_nonce, status, created_at, updated_at,
+                signature_type
          FROM trading_wallets WHERE owner = ?1 AND id = ?2",
         rusqlite::params![owner, id],
         |row| {
@@ -507,6 +814,7 @@ pub fn get_trading_wallet_by_id(
                 status: row.get(9)?,
                 created_at: row.get(10)?,
                 updated_at: row.get(11)?,
+                signature_type: row.get(12)?,
             })
         },
     )
@@ -534,7 +842,23 @@ pub fn update_wallet_credentials(
     Ok(())
 }
 
-#[allow(dead_code)]
+pub fn update_wallet_signature_type(
+    conn: &Connection,
+    owner: &str,
+    wallet_id: &str,
+    signature_type: &str,
+) -> Result<(), WalletError> {
+    let now = chrono::Utc::now().to_rfc3339();
+    let changed = conn.execute(
+        "UPDATE trading_wallets SET signature_type = ?1, updated_at = ?2 WHERE owner = ?3 AND id = ?4",
+        rusqlite::params![signature_type, now, owner, wallet_id],
+    )?;
+    if changed == 0 {
+        return Err(WalletError::NotFound);
+    }
+    Ok(())
+}
+
 pub fn update_wallet_status(
     conn: &Connection,
     owner: &str,
@@ -579,6 +903,105 @@ impl From<rusqlite::Error> for WalletError {
     }
 }
 
+// ---------------------------------------------------------------------------
+// Session Templates
+// ---------------------------------------------------------------------------
+
+const MAX_TEMPLATES_PER_USER: u32 = 20;
+
+pub struct SessionTemplateRow {
+    pub id: String,
+    pub name: String,
+    /// JSON object of `CreateSessionRequest` fields (no capital)
+    pub config: String,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+fn map_template_row(row: &rusqlite::Row) -> Result<SessionTemplateRow, rusqlite::Error> {
+    Ok(SessionTemplateRow {
+        id: row.get(0)?,
+        name: row.get(1)?,
+        config: row.get(2)?,
+        created_at: row.get(3)?,
+        updated_at: row.get(4)?,
+    })
+}
+
+/// Create the template, or overwrite the config of the owner's template with the same
+/// name. Returns None when a new template would exceed MAX_TEMPLATES_PER_USER.
+pub fn save_session_template(
+    conn: &Connection,
+    owner: &str,
+    name: &str,
+    config: &str,
+) -> Result<Option<SessionTemplateRow>, rusqlite::Error> {
+    let (count, exists): (u32, bool) = conn.query_row(
+        "SELECT COUNT(*), COALESCE(SUM(name = ?2), 0) > 0
+         FROM session_templates WHERE owner = ?1",
+        rusqlite::params![owner, name],
+        |row| Ok((row.get(0)?, row.get(1)?)),
+    )?;
+    if !exists && count >= MAX_TEMPLATES_PER_USER {
+        return Ok(None);
+    }
+
+    let now = chrono::Utc::now().to_rfc3339();
+    conn.execute(
+        "INSERT INTO session_templates (id, owner, name, config, created_at, updated_at)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?5)
+         ON CONFLICT(owner, name) DO UPDATE SET config = excluded.config, updated_at = excluded.updated_at",
+        rusqlite::params![uuid::Uuid::new_v4().to_string(), owner, name, config, now],
+    )?;
+    conn.query_row(
+        "SELECT id, name, config, created_at, updated_at
+         FROM session_templates WHERE owner = ?1 AND name = ?2",
+        rusqlite::params![owner, name],
+        map_template_row,
+    )
+    .map(Some)
+}
+
+pub fn get_session_templates(
+    conn: &Connection,
+    owner: &str,
+) -> Result<Vec<SessionTemplateRow>, rusqlite::Error> {
+    let mut stmt = conn.prepare(
+        "SELECT id, name, config, created_at, updated_at
+         FROM session_templates WHERE owner = ?1 ORDER BY name",
+    )?;
+    let rows = stmt
+        .query_map(rusqlite::params![owner], map_template_row)?
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(rows)
+}
+
+pub fn get_session_template(
+    conn: &Connection,
+    id: &str,
+    owner: &str,
+) -> Result<Option<SessionTemplateRow>, rusqlite::Error> {
+    conn.query_row(
+        "SELECT id, name, config, created_at, updated_at
+         FROM session_templates WHERE id = ?1 AND owner = ?2",
+        rusqlite::params![id, owner],
+        map_template_row,
+    )
+    .optional()
+}
+
+pub fn delete_session_template(
+    conn: &Connection,
+    id: &str,
+    owner: &str,
+) -> Result<bool, rusqlite::Error> {
+    let changed = conn.execute(
+        "DELETE FROM session_templates WHERE id = ?1 AND owner = ?2",
+        rusqlite::params![id, owner],
+    )?;
+    Ok(changed > 0)
+}
+
 // ---------------------------------------------------------------------------
 // Copy-Trade Sessions & Orders
 // ---------------------------------------------------------------------------
@@ -599,8 +1022,67 @@ pub struct CopyTradeSessionRow {
     pub status: String,
     pub created_at: String,
     pub updated_at: String,
+    pub only_favorable: bool,
+    pub buy_order_type: Option<String>,
+    pub sell_order_type: Option<String>,
+    pub mark_price_source: String,
+    pub min_trader_pnl: Option<f64>,
+    pub min_trader_winrate: Option<f64>,
+    pub top_pct: Option<f64>,
+    pub slippage_pause_after: Option<u32>,
+    pub min_liquidity_usdc: Option<f64>,
+    pub close_on_trader_removal: bool,
+    pub max_market_usdc: Option<f64>,
+    pub seed_existing_positions: bool,
+    pub sizing_mode: String,
+    pub webhook_url: Option<String>,
+    pub digest_interval_hours: Option<u32>,
+    pub sizing_base: String,
+    pub max_runtime_secs: Option<u32>,
+    /// JSON array of token ids; None copies every market
+    pub asset_allowlist: Option<String>,
+    pub near_resolution_price: Option<f64>,
+    pub gtc_price_ref: String,
+    pub gtc_price_offset_bps: i32,
+    pub sell_behavior: String,
+    pub trail_activate_pct: Option<f64>,
+    pub trail_distance_pct: Option<f64>,
+    pub mark_stale_secs: Option<u32>,
+    pub signal_source: String,
+    pub min_whale_usdc: Option<f64>,
+    pub slippage_fee_bps: Option<u32>,
+    pub fees_paid: f64,
+    pub max_signal_age_secs: Option<u32>,
+    pub top_n_metric: String,
+    pub min_hold_secs: u32,
+    pub slippage_overrides: Option<String>,
+    pub max_orders_per_minute: Option<u32>,
+    pub size_rounding: String,
+    pub take_profit_pct: Option<f64>,
+    pub stop_loss_pct: Option<f64>,
+    pub mode: String,
+    pub allowed_categories: Option<String>,
+    pub blocked_categories: Option<String>,
+    pub copy_uncategorized: bool,
+    pub copy_sides: String,
+    pub fok_fallback_gtc: bool,
+    pub liquidity_window_mins: Option<u32>,
 }
 
+/// Column list shared by every session SELECT — order must match `map_session_row`.
+const SESSION_COLUMNS: &str = "id, owner, list_id, top_n, copy_pct, max_position_usdc, \
+    max_slippage_bps, order_type, initial_capital, remaining_capital, simulate, max_loss_pct, \
+    status, created_at, updated_at, only_favorable, buy_order_type, sell_order_type, \
+    mark_price_source, min_trader_pnl, min_trader_winrate, top_pct, slippage_pause_after, \
+    min_liquidity_usdc, close_on_trader_removal, max_market_usdc, seed_existing_positions, \
+    sizing_mode, webhook_url, digest_interval_hours, sizing_base, max_runtime_secs, \
+    asset_allowlist, near_resolution_price, gtc_price_ref, gtc_price_offset_bps, \
+    sell_behavior, trail_activate_pct, trail_distance_pct, mark_stale_secs, signal_source, \
+    min_whale_usdc, slippage_fee_bps, fees_paid, max_signal_age_secs, top_n_metric, \
+    min_hold_secs, slippage_overrides, max_orders_per_minute, size_rounding, \
+    take_profit_pct, stop_loss_pct, mode, allowed_categories, blocked_categories, \
+    copy_uncategorized, copy_sides, fok_fallback_gtc, liquidity_window_mins";
+
 pub struct CopyTradeOrderRow {
     pub id: String,
     pub session_id: String,
@@ -620,6 +1102,13 @@ pub struct CopyTradeOrderRow {
     pub tx_hash: Option<String>,
     pub created_at: String,
     pub updated_at: String,
+    /// What placed the order: signal, manual, close, redeem or auto (see `OrderOrigin`)
+    pub origin: String,
+    /// Slippage bound the order was gated on: the session default or its per-asset
+    /// override. None for orders that skip the gate (manual, closes).
+    pub max_slippage_bps: Option<u32>,
+    /// For a GTC placed after a FOK found no fill: the FOK order's id
+    pub fallback_for: Option<String>,
 }
 
 pub fn create_copytrade_session(
@@ -630,8 +1119,22 @@ pub fn create_copytrade_session(
         "INSERT INTO copy_trade_sessions
             (id, owner, list_id, top_n, copy_pct, max_position_usdc, max_slippage_bps,
              order_type, initial_capital, remaining_capital, simulate, max_loss_pct, status,
-             created_at, updated_at)
-         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15)",
+             created_at, updated_at, only_favorable, buy_order_type, sell_order_type,
+             mark_price_source, min_trader_pnl, min_trader_winrate, top_pct,
+             slippage_pause_after, min_liquidity_usdc, close_on_trader_removal,
+             max_market_usdc, seed_existing_positions, sizing_mode, webhook_url,
+             digest_interval_hours, sizing_base, max_runtime_secs, asset_allowlist,
+             near_resolution_price, gtc_price_ref, gtc_price_offset_bps, sell_behavior,
+             trail_activate_pct, trail_distance_pct, mark_stale_secs, signal_source,
+             min_whale_usdc, slippage_fee_bps, fees_paid, max_signal_age_secs,
+             top_n_metric, min_hold_secs, slippage_overrides, max_orders_per_minute,
+             size_rounding, take_profit_pct, stop_loss_pct, mode, allowed_categories,
+             blocked_categories, copy_uncategorized, copy_sides, fok_fallback_gtc,
+             liquidity_window_mins)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16, ?17, ?18,
+                 ?19, ?20, ?21, ?22, ?23, ?24, ?25, ?26, ?27, ?28, ?29, ?30, ?31, ?32, ?33, ?34,
+                 ?35, ?36, ?37, ?38, ?39, ?40, ?41, ?42, ?43, ?44, ?45, ?46, ?47, ?48, ?49, ?50,
+                 ?51, ?52, ?53, ?54, ?55, ?56, ?57, ?58, ?59)",
         rusqlite::params![
             row.id,
             row.owner,
@@ -648,6 +1151,50 @@ pub fn create_copytrade_session(
             row.status,
             row.created_at,
             row.updated_at,
+            row.only_favorable as i32,
+            row.buy_order_type,
+            row.sell_order_type,
+            row.mark_price_source,
+            row.min_trader_pnl,
+            row.min_trader_winrate,
+            row.top_pct,
+            row.slippage_pause_after,
+            row.min_liquidity_usdc,
+            row.close_on_trader_removal as i32,
+            row.max_market_usdc,
+            row.seed_existing_positions as i32,
+            row.sizing_mode,
+            row.webhook_url,
+            row.digest_interval_hours,
+            row.sizing_base,
+            row.max_runtime_secs,
+            row.asset_allowlist,
+            row.near_resolution_price,
+            row.gtc_price_ref,
+            row.gtc_price_offset_bps,
+            row.sell_behavior,
+            row.trail_activate_pct,
+            row.trail_distance_pct,
+            row.mark_stale_secs,
+            row.signal_source,
+            row.min_whale_usdc,
+            row.slippage_fee_bps,
+            row.fees_paid,
+            row.max_signal_age_secs,
+            row.top_n_metric,
+            row.min_hold_secs,
+            row.slippage_overrides,
+            row.max_orders_per_minute,
+            row.size_rounding,
+            row.take_profit_pct,
+            row.stop_loss_pct,
+            row.mode,
+            row.allowed_categories,
+            row.blocked_categories,
+            row.copy_uncategorized,
+            row.copy_sides,
+            row.fok_fallback_gtc,
+            row.liquidity_window_mins,
         ],
     )?;
     Ok(())
@@ -657,12 +1204,9 @@ pub fn get_copytrade_sessions(
     conn: &Connection,
     owner: &str,
 ) -> Result<Vec<CopyTradeSessionRow>, rusqlite::Error> {
-    let mut stmt = conn.prepare(
-        "SELECT id, owner, list_id, top_n, copy_pct, max_position_usdc, max_slippage_bps,
-                order_type, initial_capital, remaining_capital, simulate, max_loss_pct,
-                status, created_at, updated_at
-         FROM copy_trade_sessions WHERE owner = ?1 ORDER BY created_at DESC",
-    )?;
+    let mut stmt = conn.prepare(&format!(
+        "SELECT {SESSION_COLUMNS} FROM copy_trade_sessions WHERE owner = ?1 ORDER BY created_at DESC"
+    ))?;
     let rows = stmt
         .query_map(rusqlite::params![owner], map_session_row)?
         .collect::<Result<Vec<_>, _>>()?;
@@ -675,10 +1219,7 @@ pub fn get_copytrade_session(
     owner: &str,
 ) -> Result<Option<CopyTradeSessionRow>, rusqlite::Error> {
     conn.query_row(
-        "SELECT id, owner, list_id, top_n, copy_pct, max_position_usdc, max_slippage_bps,
-                order_type, initial_capital, remaining_capital, simulate, max_loss_pct,
-                status, created_at, updated_at
-         FROM copy_trade_sessions WHERE id = ?1 AND owner = ?2",
+        &format!("SELECT {SESSION_COLUMNS} FROM copy_trade_sessions WHERE id = ?1 AND owner = ?2"),
         rusqlite::params![id, owner],
         map_session_row,
     )
@@ -698,15 +1239,55 @@ pub fn update_session_status(
     Ok(changed > 0)
 }
 
+/// Replaces a session's category filters (comma-separated, None for no filter).
+pub fn update_session_categories(
+    conn: &Connection,
+    id: &str,
+    allowed_categories: Option<&str>,
+    blocked_categories: Option<&str>,
+    copy_uncategorized: bool,
+) -> Result<bool, rusqlite::Error> {
+    let now = chrono::Utc::now().to_rfc3339();
+    let changed = conn.execute(
+        "UPDATE copy_trade_sessions SET allowed_categories = ?1, blocked_categories = ?2,
+                copy_uncategorized = ?3, updated_at = ?4 WHERE id = ?5",
+        rusqlite::params![
+            allowed_categories,
+            blocked_categories,
+            copy_uncategorized,
+            now,
+            id
+        ],
+    )?;
+    Ok(changed > 0)
+}
+
 pub fn update_session_capital(
     conn: &Connection,
     id: &str,
     remaining: f64,
+    fees_paid: f64,
+) -> Result<(), rusqlite::Error> {
+    let now = chrono::Utc::now().to_rfc3339();
+    conn.execute(
+        "UPDATE copy_trade_sessions SET remaining_capital = ?1, fees_paid = ?2, updated_at = ?3
+         WHERE id = ?4",
+        rusqlite::params![remaining, fees_paid, now, id],
+    )?;
+    Ok(())
+}
+
+/// Adds `amount` back to a session's remaining capital. Used when the engine
+/// has no in-memory copy of the session to refund against.
+pub fn refund_session_capital(
+    conn: &Connection,
+    id: &str,
+    amount: f64,
 ) -> Result<(), rusqlite::Error> {
     let now = chrono::Utc::now().to_rfc3339();
     conn.execute(
-        "UPDATE copy_trade_sessions SET remaining_capital = ?1, updated_at = ?2 WHERE id = ?3",
-        rusqlite::params![remaining, now, id],
+        "UPDATE copy_trade_sessions SET remaining_capital = remaining_capital + ?1, updated_at = ?2 WHERE id = ?3",
+        rusqlite::params![amount, now, id],
     )?;
     Ok(())
 }
@@ -735,17 +1316,104 @@ pub fn has_active_copytrade_session(
     Ok(count > 0)
 }
 
+pub fn count_active_copytrade_sessions(
+    conn: &Connection,
+    owner: &str,
+) -> Result<u32, rusqlite::Error> {
+    conn.query_row(
+        "SELECT COUNT(*) FROM copy_trade_sessions WHERE owner = ?1 AND status IN ('running', 'paused')",
+        rusqlite::params![owner],
+        |row| row.get(0),
+    )
+}
+
 pub fn get_running_sessions(
     conn: &Connection,
 ) -> Result<Vec<CopyTradeSessionRow>, rusqlite::Error> {
+    let mut stmt = conn.prepare(&format!(
+        "SELECT {SESSION_COLUMNS} FROM copy_trade_sessions WHERE status = 'running'"
+    ))?;
+    let rows = stmt
+        .query_map([], map_session_row)?
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(rows)
+}
+
+/// Running or paused sessions with a digest webhook, paired with when their last
+/// digest went out (None if never). Due-ness is decided by the caller.
+pub fn get_digest_sessions(
+    conn: &Connection,
+) -> Result<Vec<(CopyTradeSessionRow, Option<String>)>, rusqlite::Error> {
+    let mut stmt = conn.prepare(&format!(
+        "SELECT {SESSION_COLUMNS}, last_digest_at FROM copy_trade_sessions
+         WHERE status IN ('running', 'paused')
+           AND webhook_url IS NOT NULL AND digest_interval_hours IS NOT NULL"
+    ))?;
+    let rows = stmt
+        .query_map([], |row| Ok((map_session_row(row)?, row.get(58)?)))?
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(rows)
+}
+
+pub fn set_session_last_digest(
+    conn: &Connection,
+    id: &str,
+    sent_at: &str,
+) -> Result<(), rusqlite::Error> {
+    conn.execute(
+        "UPDATE copy_trade_sessions SET last_digest_at = ?2 WHERE id = ?1",
+        rusqlite::params![id, sent_at],
+    )?;
+    Ok(())
+}
+
+/// Stores a session's freshly resolved trader set (JSON array), replacing the previous one.
+pub fn save_resolved_traders(
+    conn: &Connection,
+    session_id: &str,
+    traders: &[&str],
+) -> Result<(), rusqlite::Error> {
+    conn.execute(
+        "INSERT INTO session_resolved_traders (session_id, traders, resolved_at)
+         VALUES (?1, ?2, ?3)
+         ON CONFLICT(session_id) DO UPDATE SET traders = ?2, resolved_at = ?3",
+        rusqlite::params![
+            session_id,
+            serde_json::to_string(traders).unwrap_or_default(),
+            chrono::Utc::now().to_rfc3339(),
+        ],
+    )?;
+    Ok(())
+}
+
+/// The last trader set saved for a session and when it was resolved.
+pub fn get_resolved_traders(
+    conn: &Connection,
+    session_id: &str,
+) -> Result<Option<(Vec<String>, String)>, rusqlite::Error> {
+    let row: Option<(String, String)> = conn
+        .query_row(
+            "SELECT traders, resolved_at FROM session_resolved_traders WHERE session_id = ?1",
+            rusqlite::params![session_id],
+            |row| Ok((row.get(0)?, row.get(1)?)),
+        )
+        .optional()?;
+    Ok(row
+        .map(|(json, resolved_at)| (serde_json::from_str(&json).unwrap_or_default(), resolved_at)))
+}
+
+/// IDs of the owner's running or paused sessions that copy the given list.
+pub fn get_list_session_ids(
+    conn: &Connection,
+    list_id: &str,
+    owner: &str,
+) -> Result<Vec<String>, rusqlite::Error> {
     let mut stmt = conn.prepare(
-        "SELECT id, owner, list_id, top_n, copy_pct, max_position_usdc, max_slippage_bps,
-                order_type, initial_capital, remaining_capital, simulate, max_loss_pct,
-                status, created_at, updated_at
-         FROM copy_trade_sessions WHERE status = 'running'",
+        "SELECT id FROM copy_trade_sessions
+         WHERE list_id = ?1 AND owner = ?2 AND status IN ('running', 'paused')",
     )?;
     let rows = stmt
-        .query_map([], map_session_row)?
+        .query_map(rusqlite::params![list_id, owner], |row| row.get(0))?
         .collect::<Result<Vec<_>, _>>()?;
     Ok(rows)
 }
@@ -758,8 +1426,10 @@ pub fn insert_copytrade_order(
         "INSERT INTO copy_trade_orders
             (id, session_id, source_tx_hash, source_trader, clob_order_id, asset_id, side,
              price, source_price, size_usdc, size_shares, status, error_message,
-             fill_price, slippage_bps, tx_hash, created_at, updated_at)
-         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16, ?17, ?18)",
+             fill_price, slippage_bps, tx_hash, created_at, updated_at, origin,
+             max_slippage_bps, fallback_for)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16, ?17, ?18,
+                 ?19, ?20, ?21)",
         rusqlite::params![
             row.id,
             row.session_id,
@@ -779,11 +1449,134 @@ pub fn insert_copytrade_order(
             row.tx_hash,
             row.created_at,
             row.updated_at,
+            row.origin,
+            row.max_slippage_bps,
+            row.fallback_for,
         ],
     )?;
     Ok(())
 }
 
+/// Records a source trade the engine decided not to copy (see `OrderSkipped`).
+pub fn insert_order_skip(
+    conn: &Connection,
+    session_id: &str,
+    source_tx_hash: &str,
+    asset_id: &str,
+    reason: &str,
+) -> Result<(), rusqlite::Error> {
+    let now = chrono::Utc::now().to_rfc3339();
+    conn.execute(
+        "INSERT INTO copy_trade_skips (session_id, source_tx_hash, asset_id, reason, created_at)
+         VALUES (?1, ?2, ?3, ?4, ?5)",
+        rusqlite::params![session_id, source_tx_hash, asset_id, reason, now],
+    )?;
+    Ok(())
+}
+
+pub fn append_order_audit(
+    conn: &Connection,
+    session_id: &str,
+    order_id: Option<&str>,
+    stage: &str,
+    context: &str,
+) -> Result<(), rusqlite::Error> {
+    let now = chrono::Utc::now().to_rfc3339();
+    conn.execute(
+        "INSERT INTO order_audit (session_id, order_id, stage, context, created_at)
+         VALUES (?1, ?2, ?3, ?4, ?5)",
+        rusqlite::params![session_id, order_id, stage, context, now],
+    )?;
+    Ok(())
+}
+
+pub struct OrderAuditRow {
+    pub id: i64,
+    pub session_id: String,
+    pub order_id: Option<String>,
+    pub stage: String,
+    /// JSON object as written by the engine
+    pub context: String,
+    pub created_at: String,
+}
+
+/// Audit entries matching the given filters (RFC 3339 bounds, inclusive), oldest first.
+pub fn get_order_audit(
+    conn: &Connection,
+    session_id: Option<&str>,
+    order_id: Option<&str>,
+    from: Option<&str>,
+    to: Option<&str>,
+    limit: u32,
+) -> Result<Vec<OrderAuditRow>, rusqlite::Error> {
+    let mut stmt = conn.prepare(
+        "SELECT id, session_id, order_id, stage, context, created_at
+         FROM order_audit
+         WHERE (?1 IS NULL OR session_id = ?1)
+           AND (?2 IS NULL OR order_id = ?2)
+           AND (?3 IS NULL OR created_at >= ?3)
+           AND (?4 IS NULL OR created_at <= ?4)
+         ORDER BY id ASC
+         LIMIT ?5",
+    )?;
+    let rows = stmt.query_map(
+        rusqlite::params![session_id, order_id, from, to, limit],
+        |row| {
+            Ok(OrderAuditRow {
+                id: row.get(0)?,
+                session_id: row.get(1)?,
+                order_id: row.get(2)?,
+                stage: row.get(3)?,
+                context: row.get(4)?,
+                created_at: row.get(5)?,
+            })
+        },
+    )?;
+    rows.collect()
+}
+
+pub struct SessionThroughputRaw {
+    pub orders: u32,
+    pub failed_orders: u32,
+    pub order_usdc: f64,
+    /// Skip reason → count
+    pub skips: Vec<(String, u32)>,
+}
+
+/// Order and skip counts for a session since `since` (RFC 3339).
+pub fn get_session_throughput(
+    conn: &Connection,
+    session_id: &str,
+    since: &str,
+) -> Result<SessionThroughputRaw, rusqlite::Error> {
+    let (orders, failed_orders, order_usdc) = conn.query_row(
+        "SELECT COUNT(*),
+                COALESCE(SUM(CASE WHEN status = 'failed' THEN 1 ELSE 0 END), 0),
+                COALESCE(SUM(CASE WHEN status != 'failed' THEN size_usdc ELSE 0 END), 0)
+         FROM copy_trade_orders WHERE session_id = ?1 AND created_at >= ?2",
+        rusqlite::params![session_id, since],
+        |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)),
+    )?;
+
+    let mut stmt = conn.prepare(
+        "SELECT reason, COUNT(*) FROM copy_trade_skips
+         WHERE session_id = ?1 AND created_at >= ?2
+         GROUP BY reason ORDER BY COUNT(*) DESC",
+    )?;
+    let skips = stmt
+        .query_map(rusqlite::params![session_id, since], |row| {
+            Ok((row.get(0)?, row.get(1)?))
+        })?
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(SessionThroughputRaw {
+        orders,
+        failed_orders,
+        order_usdc,
+        skips,
+    })
+}
+
 pub fn update_copytrade_order(
     conn: &Connection,
     id: &str,
@@ -810,6 +1603,51 @@ pub fn update_copytrade_order(
     Ok(())
 }
 
+/// Records the size a resting GTC order actually matched: `partial` while it still
+/// rests on the book, `filled` once it leaves it (fully matched, canceled or expired).
+pub fn record_matched_fill(
+    conn: &Connection,
+    id: &str,
+    status: &str,
+    size_usdc: f64,
+    size_shares: f64,
+    fill_price: f64,
+    slippage_bps: Option<f64>,
+) -> Result<(), rusqlite::Error> {
+    let now = chrono::Utc::now().to_rfc3339();
+    conn.execute(
+        "UPDATE copy_trade_orders SET status = ?1, size_usdc = ?2, size_shares = ?3,
+                fill_price = ?4, slippage_bps = ?5, updated_at = ?6 WHERE id = ?7",
+        rusqlite::params![
+            status,
+            size_usdc,
+            size_shares,
+            fill_price,
+            slippage_bps,
+            now,
+            id
+        ],
+    )?;
+    Ok(())
+}
+
+pub fn get_copytrade_order(
+    conn: &Connection,
+    session_id: &str,
+    order_id: &str,
+) -> Result<Option<CopyTradeOrderRow>, rusqlite::Error> {
+    conn.query_row(
+        "SELECT id, session_id, source_tx_hash, source_trader, clob_order_id, asset_id, side,
+                price, source_price, size_usdc, size_shares, status, error_message,
+                fill_price, slippage_bps, tx_hash, created_at, updated_at, origin,
+                max_slippage_bps, fallback_for
+         FROM copy_trade_orders WHERE id = ?1 AND session_id = ?2",
+        rusqlite::params![order_id, session_id],
+        map_order_row,
+    )
+    .optional()
+}
+
 pub fn get_session_orders(
     conn: &Connection,
     session_id: &str,
@@ -819,7 +1657,8 @@ pub fn get_session_orders(
     let mut stmt = conn.prepare(
         "SELECT id, session_id, source_tx_hash, source_trader, clob_order_id, asset_id, side,
                 price, source_price, size_usdc, size_shares, status, error_message,
-                fill_price, slippage_bps, tx_hash, created_at, updated_at
+                fill_price, slippage_bps, tx_hash, created_at, updated_at, origin,
+                max_slippage_bps, fallback_for
          FROM copy_trade_orders WHERE session_id = ?1
          ORDER BY created_at DESC LIMIT ?2 OFFSET ?3",
     )?;
@@ -829,6 +1668,136 @@ pub fn get_session_orders(
     Ok(rows)
 }
 
+/// A session's resting orders (submitted or partially matched, not yet filled, canceled or
+/// expired), oldest first.
+pub fn get_session_open_orders(
+    conn: &Connection,
+    session_id: &str,
+) -> Result<Vec<CopyTradeOrderRow>, rusqlite::Error> {
+    let mut stmt = conn.prepare(
+        "SELECT id, session_id, source_tx_hash, source_trader, clob_order_id, asset_id, side,
+                price, source_price, size_usdc, size_shares, status, error_message,
+                fill_price, slippage_bps, tx_hash, created_at, updated_at, origin,
+                max_slippage_bps, fallback_for
+         FROM copy_trade_orders
+         WHERE session_id = ?1 AND status IN ('submitted', 'partial')
+         ORDER BY created_at ASC, id ASC",
+    )?;
+    let rows = stmt
+        .query_map(rusqlite::params![session_id], map_order_row)?
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(rows)
+}
+
+/// A session's filled (or simulated, or partially matched) orders, oldest first — the input
+/// to FIFO lot matching.
+pub fn get_session_fills(
+    conn: &Connection,
+    session_id: &str,
+) -> Result<Vec<CopyTradeOrderRow>, rusqlite::Error> {
+    let mut stmt = conn.prepare(
+        "SELECT id, session_id, source_tx_hash, source_trader, clob_order_id, asset_id, side,
+                price, source_price, size_usdc, size_shares, status, error_message,
+                fill_price, slippage_bps, tx_hash, created_at, updated_at, origin,
+                max_slippage_bps, fallback_for
+         FROM copy_trade_orders
+         WHERE session_id = ?1 AND status IN ('filled', 'partial', 'simulated')
+         ORDER BY created_at ASC, id ASC",
+    )?;
+    let rows = stmt
+        .query_map(rusqlite::params![session_id], map_order_row)?
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(rows)
+}
+
+/// An owner's orders across all their sessions, newest first. `before` is the
+/// (created_at, id) of the last order on the previous page (keyset pagination).
+pub fn get_owner_orders(
+    conn: &Connection,
+    owner: &str,
+    before: Option<(&str, &str)>,
+    limit: u32,
+) -> Result<Vec<CopyTradeOrderRow>, rusqlite::Error> {
+    let (before_ts, before_id) = before.unzip();
+    let mut stmt = conn.prepare(
+        "SELECT o.id, o.session_id, o.source_tx_hash, o.source_trader, o.clob_order_id,
+                o.asset_id, o.side, o.price, o.source_price, o.size_usdc, o.size_shares,
+                o.status, o.error_message, o.fill_price, o.slippage_bps, o.tx_hash,
+                o.created_at, o.updated_at, o.origin, o.max_slippage_bps, o.fallback_for
+         FROM copy_trade_orders o
+         JOIN copy_trade_sessions s ON s.id = o.session_id
+         WHERE s.owner = ?1
+           AND (?2 IS NULL OR o.created_at < ?2 OR (o.created_at = ?2 AND o.id < ?3))
+         ORDER BY o.created_at DESC, o.id DESC LIMIT ?4",
+    )?;
+    let rows = stmt
+        .query_map(
+            rusqlite::params![owner, before_ts, before_id, limit],
+            map_order_row,
+        )?
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(rows)
+}
+
+/// A single order by id, if it belongs to one of the owner's sessions, with the
+/// session's simulate flag.
+pub fn get_owner_order(
+    conn: &Connection,
+    owner: &str,
+    order_id: &str,
+) -> Result<Option<(CopyTradeOrderRow, bool)>, rusqlite::Error> {
+    conn.query_row(
+        "SELECT o.id, o.session_id, o.source_tx_hash, o.source_trader, o.clob_order_id,
+                o.asset_id, o.side, o.price, o.source_price, o.size_usdc, o.size_shares,
+                o.status, o.error_message, o.fill_price, o.slippage_bps, o.tx_hash,
+                o.created_at, o.updated_at, o.origin, o.max_slippage_bps, o.fallback_for,
+                s.simulate
+         FROM copy_trade_orders o
+         JOIN copy_trade_sessions s ON s.id = o.session_id
+         WHERE o.id = ?1 AND s.owner = ?2",
+        rusqlite::params![order_id, owner],
+        |row| Ok((map_order_row(row)?, row.get::<_, i32>(21)? != 0)),
+    )
+    .optional()
+}
+
+/// An order plus the session fields the ClickHouse mirror denormalizes onto it.
+pub struct OrderChange {
+    pub order: CopyTradeOrderRow,
+    pub owner: String,
+    pub simulate: bool,
+}
+
+/// Orders inserted or updated after the `(updated_at, id)` keyset cursor, oldest change
+/// first. The id breaks ties, so a batch cut off inside one timestamp resumes there.
+pub fn get_order_changes_since(
+    conn: &Connection,
+    cursor: (&str, &str),
+    limit: u32,
+) -> Result<Vec<OrderChange>, rusqlite::Error> {
+    let mut stmt = conn.prepare(
+        "SELECT o.id, o.session_id, o.source_tx_hash, o.source_trader, o.clob_order_id,
+                o.asset_id, o.side, o.price, o.source_price, o.size_usdc, o.size_shares,
+                o.status, o.error_message, o.fill_price, o.slippage_bps, o.tx_hash,
+                o.created_at, o.updated_at, o.origin, o.max_slippage_bps, o.fallback_for,
+                s.owner, s.simulate
+         FROM copy_trade_orders o
+         JOIN copy_trade_sessions s ON s.id = o.session_id
+         WHERE (o.updated_at, o.id) > (?1, ?2)
+         ORDER BY o.updated_at ASC, o.id ASC LIMIT ?3",
+    )?;
+    let rows = stmt
+        .query_map(rusqlite::params![cursor.0, cursor.1, limit], |row| {
+            Ok(OrderChange {
+                order: map_order_row(row)?,
+                owner: row.get(21)?,
+                simulate: row.get::<_, i32>(22)? != 0,
+            })
+        })?
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(rows)
+}
+
 pub fn get_net_shares(
     conn: &Connection,
     session_id: &str,
@@ -836,8 +1805,8 @@ pub fn get_net_shares(
 ) -> Result<f64, rusqlite::Error> {
     conn.query_row(
         "SELECT COALESCE(
-            SUM(CASE WHEN side = 'buy' AND status IN ('filled', 'simulated') THEN size_shares ELSE 0 END) -
-            SUM(CASE WHEN side = 'sell' AND status IN ('filled', 'simulated') THEN size_shares ELSE 0 END),
+            SUM(CASE WHEN side = 'buy' AND status IN ('filled', 'partial', 'simulated') THEN size_shares ELSE 0 END) -
+            SUM(CASE WHEN side = 'sell' AND status IN ('filled', 'partial', 'simulated') THEN size_shares ELSE 0 END),
             0.0
         ) FROM copy_trade_orders WHERE session_id = ?1 AND asset_id = ?2",
         rusqlite::params![session_id, asset_id],
@@ -856,12 +1825,12 @@ pub fn get_session_positions_value(
     let mut stmt = conn.prepare(
         "SELECT
             o.asset_id,
-            SUM(CASE WHEN o.side = 'buy' AND o.status IN ('filled', 'simulated') THEN o.size_shares ELSE 0 END) -
-            SUM(CASE WHEN o.side = 'sell' AND o.status IN ('filled', 'simulated') THEN o.size_shares ELSE 0 END) AS net_shares,
+            SUM(CASE WHEN o.side = 'buy' AND o.status IN ('filled', 'partial', 'simulated') THEN o.size_shares ELSE 0 END) -
+            SUM(CASE WHEN o.side = 'sell' AND o.status IN ('filled', 'partial', 'simulated') THEN o.size_shares ELSE 0 END) AS net_shares,
             -- Last fill price for this asset (most recent order with a fill)
             (SELECT fill_price FROM copy_trade_orders
              WHERE session_id = ?1 AND asset_id = o.asset_id
-               AND fill_price IS NOT NULL AND status IN ('filled', 'simulated')
+               AND fill_price IS NOT NULL AND status IN ('filled', 'partial', 'simulated')
              ORDER BY created_at DESC LIMIT 1) AS last_price
          FROM copy_trade_orders o
          WHERE o.session_id = ?1
@@ -878,20 +1847,21 @@ pub fn get_session_positions_value(
     Ok(values?.into_iter().sum())
 }
 
-/// Returns all open positions for a session: asset_id → (net_shares, last_fill_price).
+/// Returns all open positions for a session: asset_id → (net_shares, last_fill_price,
+/// peak_price), the peak seeded with the last fill.
 /// Used to restore in-memory positions on engine restart.
 pub fn get_session_positions(
     conn: &Connection,
     session_id: &str,
-) -> Result<std::collections::HashMap<String, (f64, f64)>, rusqlite::Error> {
+) -> Result<std::collections::HashMap<String, (f64, f64, f64)>, rusqlite::Error> {
     let mut stmt = conn.prepare(
         "SELECT
             o.asset_id,
-            SUM(CASE WHEN o.side = 'buy' AND o.status IN ('filled', 'simulated') THEN o.size_shares ELSE 0 END) -
-            SUM(CASE WHEN o.side = 'sell' AND o.status IN ('filled', 'simulated') THEN o.size_shares ELSE 0 END) AS net_shares,
+            SUM(CASE WHEN o.side = 'buy' AND o.status IN ('filled', 'partial', 'simulated') THEN o.size_shares ELSE 0 END) -
+            SUM(CASE WHEN o.side = 'sell' AND o.status IN ('filled', 'partial', 'simulated') THEN o.size_shares ELSE 0 END) AS net_shares,
             (SELECT fill_price FROM copy_trade_orders
              WHERE session_id = ?1 AND asset_id = o.asset_id
-               AND fill_price IS NOT NULL AND status IN ('filled', 'simulated')
+               AND fill_price IS NOT NULL AND status IN ('filled', 'partial', 'simulated')
              ORDER BY created_at DESC LIMIT 1) AS last_price
          FROM copy_trade_orders o
          WHERE o.session_id = ?1
@@ -903,12 +1873,99 @@ pub fn get_session_positions(
             let asset_id: String = row.get(0)?;
             let net_shares: f64 = row.get(1)?;
             let last_price: f64 = row.get::<_, Option<f64>>(2)?.unwrap_or(0.0);
-            Ok((asset_id, (net_shares, last_price)))
+            Ok((asset_id, (net_shares, last_price, last_price)))
         })?
         .collect();
     Ok(rows?.into_iter().collect())
 }
 
+/// Time of the most recent fill per asset in a session: asset_id → created_at (RFC 3339).
+pub fn get_last_fill_times(
+    conn: &Connection,
+    session_id: &str,
+) -> Result<std::collections::HashMap<String, String>, rusqlite::Error> {
+    let mut stmt = conn.prepare(
+        "SELECT asset_id, MAX(created_at)
+         FROM copy_trade_orders
+         WHERE session_id = ?1 AND fill_price IS NOT NULL AND status IN ('filled', 'partial', 'simulated')
+         GROUP BY asset_id",
+    )?;
+    let rows = stmt.query_map(rusqlite::params![session_id], |row| {
+        Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?))
+    })?;
+    rows.collect()
+}
+
+/// Latest signal-copy order per "asset_id:side" created at or after `since`, as
+/// key → created_at. Seeds the engine's in-memory dedup map on restart.
+pub fn get_recent_dedup_keys(
+    conn: &Connection,
+    session_id: &str,
+    since: &str,
+) -> Result<Vec<(String, String)>, rusqlite::Error> {
+    let mut stmt = conn.prepare(
+        "SELECT asset_id || ':' || side, MAX(created_at)
+         FROM copy_trade_orders
+         WHERE session_id = ?1 AND created_at >= ?2
+           AND origin = 'signal' AND status != 'failed'
+         GROUP BY asset_id, side",
+    )?;
+    let rows = stmt
+        .query_map(rusqlite::params![session_id, since], |row| {
+            Ok((row.get(0)?, row.get(1)?))
+        })?
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(rows)
+}
+
+/// When each asset's position opened, as asset_id → created_at of the oldest fill
+/// since its last sell. Seeds the engine's min_hold_secs clock on restart; after a
+/// partial sell it errs late (longer hold), never early.
+pub fn get_position_open_times(
+    conn: &Connection,
+    session_id: &str,
+) -> Result<Vec<(String, String)>, rusqlite::Error> {
+    let mut stmt = conn.prepare(
+        "SELECT o.asset_id, MIN(o.created_at)
+         FROM copy_trade_orders o
+         WHERE o.session_id = ?1 AND o.side = 'buy' AND o.status IN ('filled', 'partial', 'simulated')
+           AND o.created_at > COALESCE((
+               SELECT MAX(s.created_at) FROM copy_trade_orders s
+               WHERE s.session_id = ?1 AND s.asset_id = o.asset_id AND s.side = 'sell'
+                 AND s.status IN ('filled', 'partial', 'simulated')
+           ), '')
+         GROUP BY o.asset_id",
+    )?;
+    let rows = stmt
+        .query_map(rusqlite::params![session_id], |row| {
+            Ok((row.get(0)?, row.get(1)?))
+        })?
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(rows)
+}
+
+/// Returns, per asset, the distinct source traders whose copied buys opened the position.
+pub fn get_position_buy_traders(
+    conn: &Connection,
+    session_id: &str,
+) -> Result<std::collections::HashMap<String, std::collections::HashSet<String>>, rusqlite::Error> {
+    let mut stmt = conn.prepare(
+        "SELECT DISTINCT asset_id, lower(source_trader) FROM copy_trade_orders
+         WHERE session_id = ?1 AND side = 'buy' AND status IN ('filled', 'partial', 'simulated')",
+    )?;
+    let rows = stmt
+        .query_map(rusqlite::params![session_id], |row| {
+            Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?))
+        })?
+        .collect::<Result<Vec<_>, _>>()?;
+    let mut map: std::collections::HashMap<String, std::collections::HashSet<String>> =
+        std::collections::HashMap::new();
+    for (asset_id, trader) in rows {
+        map.entry(asset_id).or_default().insert(trader);
+    }
+    Ok(map)
+}
+
 /// Returns the last fill price for a specific asset in a session, if any.
 pub fn get_last_fill_price(
     conn: &Connection,
@@ -918,7 +1975,7 @@ pub fn get_last_fill_price(
     conn.query_row(
         "SELECT fill_price FROM copy_trade_orders
          WHERE session_id = ?1 AND asset_id = ?2
-           AND fill_price IS NOT NULL AND status IN ('filled', 'simulated')
+           AND fill_price IS NOT NULL AND status IN ('filled', 'partial', 'simulated')
          ORDER BY created_at DESC LIMIT 1",
         rusqlite::params![session_id, asset_id],
         |row| row.get(0),
@@ -951,14 +2008,14 @@ pub fn get_session_order_stats(
     conn.query_row(
         "SELECT
             COUNT(*) AS total_orders,
-            SUM(CASE WHEN status IN ('filled','simulated') THEN 1 ELSE 0 END) AS filled_orders,
+            SUM(CASE WHEN status IN ('filled','partial','simulated') THEN 1 ELSE 0 END) AS filled_orders,
             SUM(CASE WHEN status = 'failed' THEN 1 ELSE 0 END) AS failed_orders,
             SUM(CASE WHEN status IN ('pending','submitted') THEN 1 ELSE 0 END) AS pending_orders,
             SUM(CASE WHEN status = 'canceled' THEN 1 ELSE 0 END) AS canceled_orders,
-            COALESCE(SUM(CASE WHEN side='buy' AND status IN ('filled','simulated') THEN size_usdc ELSE 0.0 END), 0.0) AS total_invested,
-            COALESCE(SUM(CASE WHEN side='sell' AND status IN ('filled','simulated') THEN size_usdc ELSE 0.0 END), 0.0) AS total_returned,
-            COALESCE(AVG(CASE WHEN slippage_bps IS NOT NULL AND status IN ('filled','simulated') THEN slippage_bps END), 0.0) AS avg_slippage,
-            COALESCE(MAX(CASE WHEN slippage_bps IS NOT NULL AND status IN ('filled','simulated') THEN slippage_bps END), 0.0) AS max_slippage
+            COALESCE(SUM(CASE WHEN side='buy' AND status IN ('filled','partial','simulated') THEN size_usdc ELSE 0.0 END), 0.0) AS total_invested,
+            COALESCE(SUM(CASE WHEN side='sell' AND status IN ('filled','partial','simulated') THEN size_usdc ELSE 0.0 END), 0.0) AS total_returned,
+            COALESCE(AVG(CASE WHEN slippage_bps IS NOT NULL AND status IN ('filled','partial','simulated') THEN slippage_bps END), 0.0) AS avg_slippage,
+            COALESCE(MAX(CASE WHEN slippage_bps IS NOT NULL AND status IN ('filled','partial','simulated') THEN slippage_bps END), 0.0) AS max_slippage
          FROM copy_trade_orders WHERE session_id = ?1",
         rusqlite::params![session_id],
         |row| {
@@ -977,6 +2034,28 @@ pub fn get_session_order_stats(
     )
 }
 
+/// Filled sells per (asset, origin): (asset_id, origin, shares sold, USDC received).
+/// The handler prices them against the asset's average entry cost.
+pub fn get_sell_totals_by_origin(
+    conn: &Connection,
+    session_id: &str,
+) -> Result<Vec<(String, String, f64, f64)>, rusqlite::Error> {
+    let mut stmt = conn.prepare(
+        "SELECT asset_id, origin,
+            COALESCE(SUM(COALESCE(size_shares, 0.0)), 0.0),
+            COALESCE(SUM(size_usdc), 0.0)
+         FROM copy_trade_orders
+         WHERE session_id = ?1 AND side = 'sell' AND status IN ('filled','partial','simulated')
+         GROUP BY asset_id, origin",
+    )?;
+    let rows = stmt
+        .query_map(rusqlite::params![session_id], |row| {
+            Ok((row.get(0)?, row.get(1)?, row.get(2)?, row.get(3)?))
+        })?
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(rows)
+}
+
 /// Raw per-asset position aggregation from copy_trade_orders.
 pub struct PositionRaw {
     pub asset_id: String,
@@ -987,6 +2066,7 @@ pub struct PositionRaw {
     pub sell_proceeds: f64,
     pub order_count: u32,
     pub source_traders: String,
+    pub first_order_at: String,
     pub last_order_at: String,
     pub last_fill_price: f64,
 }
@@ -998,18 +2078,19 @@ pub fn get_positions_raw(
     let mut stmt = conn.prepare(
         "SELECT
             o.asset_id,
-            SUM(CASE WHEN o.side='buy'  AND o.status IN ('filled','simulated') THEN COALESCE(o.size_shares, 0.0) ELSE 0.0 END) AS buy_shares,
-            SUM(CASE WHEN o.side='sell' AND o.status IN ('filled','simulated') THEN COALESCE(o.size_shares, 0.0) ELSE 0.0 END) AS sell_shares,
-            SUM(CASE WHEN o.side='buy'  AND o.status IN ('filled','simulated') THEN COALESCE(o.size_shares, 0.0) ELSE 0.0 END) -
-            SUM(CASE WHEN o.side='sell' AND o.status IN ('filled','simulated') THEN COALESCE(o.size_shares, 0.0) ELSE 0.0 END) AS net_shares,
-            COALESCE(SUM(CASE WHEN o.side='buy'  AND o.status IN ('filled','simulated') THEN o.size_usdc ELSE 0.0 END), 0.0) AS cost_basis,
-            COALESCE(SUM(CASE WHEN o.side='sell' AND o.status IN ('filled','simulated') THEN o.size_usdc ELSE 0.0 END), 0.0) AS sell_proceeds,
+            SUM(CASE WHEN o.side='buy'  AND o.status IN ('filled','partial','simulated') THEN COALESCE(o.size_shares, 0.0) ELSE 0.0 END) AS buy_shares,
+            SUM(CASE WHEN o.side='sell' AND o.status IN ('filled','partial','simulated') THEN COALESCE(o.size_shares, 0.0) ELSE 0.0 END) AS sell_shares,
+            SUM(CASE WHEN o.side='buy'  AND o.status IN ('filled','partial','simulated') THEN COALESCE(o.size_shares, 0.0) ELSE 0.0 END) -
+            SUM(CASE WHEN o.side='sell' AND o.status IN ('filled','partial','simulated') THEN COALESCE(o.size_shares, 0.0) ELSE 0.0 END) AS net_shares,
+            COALESCE(SUM(CASE WHEN o.side='buy'  AND o.status IN ('filled','partial','simulated') THEN o.size_usdc ELSE 0.0 END), 0.0) AS cost_basis,
+            COALESCE(SUM(CASE WHEN o.side='sell' AND o.status IN ('filled','partial','simulated') THEN o.size_usdc ELSE 0.0 END), 0.0) AS sell_proceeds,
             COUNT(*) AS order_count,
             GROUP_CONCAT(DISTINCT o.source_trader) AS source_traders,
+            MIN(o.created_at) AS first_order_at,
             MAX(o.created_at) AS last_order_at,
             (SELECT fill_price FROM copy_trade_orders
              WHERE session_id = ?1 AND asset_id = o.asset_id
-               AND fill_price IS NOT NULL AND status IN ('filled', 'simulated')
+               AND fill_price IS NOT NULL AND status IN ('filled', 'partial', 'simulated')
              ORDER BY created_at DESC LIMIT 1) AS last_fill_price
          FROM copy_trade_orders o
          WHERE o.session_id = ?1
@@ -1027,8 +2108,9 @@ pub fn get_positions_raw(
                 sell_proceeds: row.get(5)?,
                 order_count: row.get(6)?,
                 source_traders: row.get::<_, Option<String>>(7)?.unwrap_or_default(),
-                last_order_at: row.get::<_, Option<String>>(8)?.unwrap_or_default(),
-                last_fill_price: row.get::<_, Option<f64>>(9)?.unwrap_or(0.0),
+                first_order_at: row.get::<_, Option<String>>(8)?.unwrap_or_default(),
+                last_order_at: row.get::<_, Option<String>>(9)?.unwrap_or_default(),
+                last_fill_price: row.get::<_, Option<f64>>(10)?.unwrap_or(0.0),
             })
         })?
         .collect();
@@ -1041,12 +2123,58 @@ pub fn get_total_order_count(conn: &Connection, owner: &str) -> Result<u32, rusq
         "SELECT COUNT(o.id)
          FROM copy_trade_orders o
          JOIN copy_trade_sessions s ON o.session_id = s.id
-         WHERE s.owner = ?1 AND o.status IN ('filled', 'simulated')",
+         WHERE s.owner = ?1 AND o.status IN ('filled', 'partial', 'simulated')",
         rusqlite::params![owner],
         |row| row.get(0),
     )
 }
 
+pub struct CopyTradeOverviewRaw {
+    pub owners: u32,
+    pub running_sessions: u32,
+    pub paused_sessions: u32,
+    pub stopped_sessions: u32,
+    pub orders: u32,
+    pub failed_orders: u32,
+    pub skipped: u32,
+}
+
+/// Cross-owner session counts, plus orders and skips created since `since` (RFC 3339).
+pub fn get_copytrade_overview(
+    conn: &Connection,
+    since: &str,
+) -> Result<CopyTradeOverviewRaw, rusqlite::Error> {
+    let (owners, running_sessions, paused_sessions, stopped_sessions) = conn.query_row(
+        "SELECT COUNT(DISTINCT owner),
+                COALESCE(SUM(CASE WHEN status = 'running' THEN 1 ELSE 0 END), 0),
+                COALESCE(SUM(CASE WHEN status = 'paused' THEN 1 ELSE 0 END), 0),
+                COALESCE(SUM(CASE WHEN status = 'stopped' THEN 1 ELSE 0 END), 0)
+         FROM copy_trade_sessions",
+        [],
+        |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?, row.get(3)?)),
+    )?;
+    let (orders, failed_orders) = conn.query_row(
+        "SELECT COUNT(*), COALESCE(SUM(CASE WHEN status = 'failed' THEN 1 ELSE 0 END), 0)
+         FROM copy_trade_orders WHERE created_at >= ?1",
+        rusqlite::params![since],
+        |row| Ok((row.get(0)?, row.get(1)?)),
+    )?;
+    let skipped = conn.query_row(
+        "SELECT COUNT(*) FROM copy_trade_skips WHERE created_at >= ?1",
+        rusqlite::params![since],
+        |row| row.get(0),
+    )?;
+    Ok(CopyTradeOverviewRaw {
+        owners,
+        running_sessions,
+        paused_sessions,
+        stopped_sessions,
+        orders,
+        failed_orders,
+        skipped,
+    })
+}
+
 fn map_session_row(row: &rusqlite::Row) -> Result<CopyTradeSessionRow, rusqlite::Error> {
     Ok(CopyTradeSessionRow {
         id: row.get(0)?,
@@ -1064,6 +2192,50 @@ fn map_session_row(row: &rusqlite::Row) -> Result<CopyTradeSessionRow, rusqlite:
         status: row.get(12)?,
         created_at: row.get(13)?,
         updated_at: row.get(14)?,
+        only_favorable: row.get::<_, i32>(15)? != 0,
+        buy_order_type: row.get(16)?,
+        sell_order_type: row.get(17)?,
+        mark_price_source: row.get(18)?,
+        min_trader_pnl: row.get(19)?,
+        min_trader_winrate: row.get(20)?,
+        top_pct: row.get(21)?,
+        slippage_pause_after: row.get(22)?,
+        min_liquidity_usdc: row.get(23)?,
+        close_on_trader_removal: row.get::<_, i32>(24)? != 0,
+        max_market_usdc: row.get(25)?,
+        seed_existing_positions: row.get::<_, i32>(26)? != 0,
+        sizing_mode: row.get(27)?,
+        webhook_url: row.get(28)?,
+        digest_interval_hours: row.get(29)?,
+        sizing_base: row.get(30)?,
+        max_runtime_secs: row.get(31)?,
+        asset_allowlist: row.get(32)?,
+        near_resolution_price: row.get(33)?,
+        gtc_price_ref: row.get(34)?,
+        gtc_price_offset_bps: row.get(35)?,
+        sell_behavior: row.get(36)?,
+        trail_activate_pct: row.get(37)?,
+        trail_distance_pct: row.get(38)?,
+        mark_stale_secs: row.get(39)?,
+        signal_source: row.get(40)?,
+        min_whale_usdc: row.get(41)?,
+        slippage_fee_bps: row.get(42)?,
+        fees_paid: row.get(43)?,
+        max_signal_age_secs: row.get(44)?,
+        top_n_metric: row.get(45)?,
+        min_hold_secs: row.get(46)?,
+        slippage_overrides: row.get(47)?,
+        max_orders_per_minute: row.get(48)?,
+        size_rounding: row.get(49)?,
+        take_profit_pct: row.get(50)?,
+        stop_loss_pct: row.get(51)?,
+        mode: row.get(52)?,
+        allowed_categories: row.get(53)?,
+        blocked_categories: row.get(54)?,
+        copy_uncategorized: row.get(55)?,
+        copy_sides: row.get(56)?,
+        fok_fallback_gtc: row.get(57)?,
+        liquidity_window_mins: row.get(58)?,
     })
 }
 
@@ -1087,6 +2259,9 @@ fn map_order_row(row: &rusqlite::Row) -> Result<CopyTradeOrderRow, rusqlite::Err
         tx_hash: row.get(15)?,
         created_at: row.get(16)?,
         updated_at: row.get(17)?,
+        origin: row.get(18)?,
+        max_slippage_bps: row.get(19)?,
+        fallback_for: row.get(20)?,
     })
 }
 
@@ -1114,3 +2289,95 @@ pub fn get_list_member_addresses(
 
     Ok(addrs)
 }
+
+/// Copy weight per lowercase member address of a list. Verifies ownership like
+/// `get_list_member_addresses`.
+pub fn get_list_member_weights(
+    conn: &Connection,
+    list_id: &str,
+    owner: &str,
+) -> Result<std::collections::HashMap<String, f64>, ListError> {
+    let exists: bool = conn
+        .query_row(
+            "SELECT 1 FROM trader_lists WHERE id = ?1 AND owner = ?2",
+            rusqlite::params![list_id, owner],
+            |_| Ok(true),
+        )
+        .unwrap_or(false);
+    if !exists {
+        return Err(ListError::NotFound);
+    }
+
+    let mut stmt =
+        conn.prepare("SELECT address, weight FROM trader_list_members WHERE list_id = ?1")?;
+    let weights = stmt
+        .query_map(rusqlite::params![list_id], |row| {
+            Ok((row.get::<_, String>(0)?.to_lowercase(), row.get(1)?))
+        })?
+        .collect::<Result<std::collections::HashMap<String, f64>, _>>()?;
+
+    Ok(weights)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn order_changes_page_through_a_shared_timestamp() {
+        let conn = init_user_db(":memory:");
+        conn.execute_batch(
+            "INSERT INTO copy_trade_sessions
+                (id, owner, copy_pct, initial_capital, remaining_capital, created_at, updated_at)
+             VALUES ('s', '0xowner', 0.5, 100, 100, 't', 't')",
+        )
+        .unwrap();
+        let at = "2026-01-01T00:00:00+00:00";
+        for id in ["a", "b", "c"] {
+            conn.execute(
+                "INSERT INTO copy_trade_orders
+                    (id, session_id, source_tx_hash, source_trader, asset_id, side, price,
+                     source_price, size_usdc, created_at, updated_at)
+                 VALUES (?1, 's', '0x', '0xtrader', 'asset', 'buy', 0.5, 0.5, 10, ?2, ?2)",
+                rusqlite::params![id, at],
+            )
+            .unwrap();
+        }
+
+        let first = get_order_changes_since(&conn, ("", ""), 2).unwrap();
+        let ids: Vec<_> = first.iter().map(|c| c.order.id.as_str()).collect();
+        assert_eq!(ids, ["a", "b"]);
+
+        // The batch ended inside one timestamp: the next page picks up the rest
+        let rest = get_order_changes_since(&conn, (at, "b"), 2).unwrap();
+        let ids: Vec<_> = rest.iter().map(|c| c.order.id.as_str()).collect();
+        assert_eq!(ids, ["c"]);
+        assert!(
+            get_order_changes_since(&conn, (at, "c"), 2)
+                .unwrap()
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn re_adding_a_list_member_updates_its_weight() {
+        let conn = init_user_db(":memory:");
+        let list = create_trader_list(&conn, "0xowner", "whales").ok().unwrap();
+        let member = |weight| vec![("0xtrader".to_string(), Some("t".to_string()), weight)];
+
+        add_list_members(&conn, &list.id, "0xowner", &member(1.0))
+            .ok()
+            .unwrap();
+        add_list_members(&conn, &list.id, "0xowner", &member(2.5))
+            .ok()
+            .unwrap();
+
+        let weights = get_list_member_weights(&conn, &list.id, "0xowner")
+            .ok()
+            .unwrap();
+        assert_eq!(
+            weights,
+            std::collections::HashMap::from([("0xtrader".to_string(), 2.5)])
+        );
+    }
+}
@@ -0,0 +1,133 @@
+//! Turns the poll-only bridge deposit status into a push notification.
+//!
+//! A wallet is watched once its owner opens the deposit flow (deposit address or
+//! status requested). While watched, the bridge status API is polled and a
+//! `DepositConfirmed` update is broadcast when a deposit transitions to completed.
+//! Polling stops once the wallet's pending deposits have all settled, or after
+//! IDLE_TIMEOUT if none ever showed up.
+
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+use tokio::sync::{broadcast, mpsc};
+
+use super::server::WalletBalances;
+use super::types::{CopyTradeUpdate, PendingDeposit};
+
+const POLL_INTERVAL: Duration = Duration::from_secs(20);
+const IDLE_TIMEOUT: Duration = Duration::from_secs(1800);
+
+pub struct WatchRequest {
+    pub wallet_id: String,
+    pub owner: String,
+    /// Address deposits are bridged to (proxy or EOA, see `funds_address`)
+    pub address: String,
+}
+
+struct WatchedWallet {
+    owner: String,
+    address: String,
+    watching_since: Instant,
+    /// Deposit key → last seen bridge status
+    seen: HashMap<String, String>,
+    polled_once: bool,
+    had_pending: bool,
+}
+
+fn deposit_key(d: &PendingDeposit) -> String {
+    match &d.tx_hash {
+        Some(hash) => hash.clone(),
+        None => format!("{}:{}:{}", d.from_chain, d.token, d.amount),
+    }
+}
+
+fn is_completed(status: &str) -> bool {
+    status.eq_ignore_ascii_case("COMPLETED")
+}
+
+fn is_failed(status: &str) -> bool {
+    status.eq_ignore_ascii_case("FAILED")
+}
+
+pub async fn run(
+    http: reqwest::Client,
+    mut rx: mpsc::Receiver<WatchRequest>,
+    update_tx: broadcast::Sender<CopyTradeUpdate>,
+    wallet_balances: WalletBalances,
+) {
+    let mut watched: HashMap<String, WatchedWallet> = HashMap::new();
+    let mut interval = tokio::time::interval(POLL_INTERVAL);
+    interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+
+    loop {
+        tokio::select! {
+            req = rx.recv() => {
+                let Some(req) = req else { return };
+                // Re-requests (user polling the UI) extend the watch window
+                let entry = watched.entry(req.wallet_id).or_insert_with(|| WatchedWallet {
+                    owner: req.owner,
+                    address: req.address.clone(),
+                    watching_since: Instant::now(),
+                    seen: HashMap::new(),
+                    polled_once: false,
+                    had_pending: false,
+                });
+                entry.address = req.address;
+                entry.watching_since = Instant::now();
+            }
+            _ = interval.tick(), if !watched.is_empty() => {
+                let mut done: Vec<String> = Vec::new();
+                for (wallet_id, wallet) in watched.iter_mut() {
+                    let deposits = match super::wallet::fetch_bridge_deposits(&http, &wallet.address).await {
+                        Ok(d) => d,
+                        Err(e) => {
+                            tracing::warn!("Deposit watch: {e}");
+                            continue;
+                        }
+                    };
+
+                    let mut pending = false;
+                    for d in &deposits {
+                        let prev = wallet.seen.insert(deposit_key(d), d.status.clone());
+                        if is_completed(&d.status) {
+                            // Announce transitions only; completed history seen on the first
+                            // poll predates the watch
+                            let transitioned = match &prev {
+                                Some(p) => !is_completed(p),
+                                None => wallet.polled_once,
+                            };
+                            if transitioned {
+                                tracing::info!(
+                                    "Deposit confirmed for wallet {wallet_id}: {} ({})",
+                                    d.amount,
+                                    d.token
+                                );
+                                wallet_balances.write().await.remove(wallet_id);
+                                let _ = update_tx.send(CopyTradeUpdate::DepositConfirmed {
+                                    wallet_id: wallet_id.clone(),
+                                    amount: d.amount.clone(),
+                                    token: d.token.clone(),
+                                    tx_hash: d.tx_hash.clone(),
+                                    owner: wallet.owner.clone(),
+                                });
+                            }
+                        } else if !is_failed(&d.status) {
+                            pending = true;
+                        }
+                    }
+                    wallet.polled_once = true;
+
+                    if pending {
+                        wallet.had_pending = true;
+                    } else if wallet.had_pending || wallet.watching_since.elapsed() >= IDLE_TIMEOUT {
+                        done.push(wallet_id.clone());
+                    }
+                }
+                for wallet_id in done {
+                    tracing::debug!("Deposit watch: no pending deposits for {wallet_id}, stopping");
+                    watched.remove(&wallet_id);
+                }
+            }
+        }
+    }
+}
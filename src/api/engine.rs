@@ -1,24 +1,29 @@
 use std::collections::{HashMap, HashSet, VecDeque};
 use std::str::FromStr;
 use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::time::{Duration, Instant};
 
-use rust_decimal::Decimal;
+use rust_decimal::{Decimal, RoundingStrategy};
 use std::sync::Mutex;
-use tokio::sync::{RwLock, broadcast, mpsc};
+use tokio::sync::{RwLock, broadcast, mpsc, oneshot};
 
 use alloy::signers::Signer as _;
 use polymarket_client_sdk::auth::state::Authenticated;
 use polymarket_client_sdk::auth::{Credentials, Normal};
-use polymarket_client_sdk::clob::types::request::PriceRequest;
+use polymarket_client_sdk::clob::types::request::{PriceRequest, TradesRequest};
 use polymarket_client_sdk::clob::types::{Amount, OrderStatusType, OrderType, Side, SignatureType};
 use polymarket_client_sdk::clob::{Client, Config};
 use polymarket_client_sdk::types::U256;
 
-use super::alerts::LiveTrade;
+use super::alerts::{Alert, LiveTrade};
 use super::db::{self, CopyTradeOrderRow, CopyTradeSessionRow};
+use super::markets::{self, MarketCache};
 use super::types::{
-    CopyOrderType, CopyTradeOrderSummary, CopyTradeUpdate, OrderStatus, SessionStatus,
+    CopyMode, CopyOrderType, CopySides, CopyTradeOrderSummary, CopyTradeUpdate,
+    EngineMetricsSnapshot, EngineOpenOrder, EngineSessionState, EngineStateSnapshot, GtcPriceRef,
+    MarkPriceSource, OrderOrigin, OrderStatus, SellBehavior, SessionStatus, SignalSource,
+    SizeRounding, SizingBase, SizingMode, TopNMetric,
 };
 
 // ---------------------------------------------------------------------------
@@ -26,10 +31,95 @@ use super::types::{
 // ---------------------------------------------------------------------------
 
 pub enum CopyTradeCommand {
-    Start { session_id: String, owner: String },
-    Pause { session_id: String },
-    Resume { session_id: String },
-    Stop { session_id: String },
+    Start {
+        session_id: String,
+        owner: String,
+    },
+    Pause {
+        session_id: String,
+    },
+    Resume {
+        session_id: String,
+        owner: String,
+    },
+    Stop {
+        session_id: String,
+    },
+    /// Re-resolve a session's traders after its list was edited.
+    RefreshTraders {
+        session_id: String,
+    },
+    /// Cancel a single resting GTC order and refund its reserved capital.
+    CancelOrder {
+        session_id: String,
+        order_id: String,
+        clob_order_id: String,
+        refund_usdc: f64,
+        reply: oneshot::Sender<Result<(), String>>,
+    },
+    /// Snapshot of the engine's in-memory state across all owners (admin overview).
+    Inspect {
+        reply: oneshot::Sender<EngineStateSnapshot>,
+    },
+    /// A session's tracked resting GTC orders; `None` when the session isn't loaded.
+    OpenOrders {
+        session_id: String,
+        reply: oneshot::Sender<Option<Vec<EngineOpenOrder>>>,
+    },
+    /// Apply a session's edited category filters.
+    UpdateCategories {
+        session_id: String,
+        allowed_categories: Option<String>,
+        blocked_categories: Option<String>,
+        copy_uncategorized: bool,
+    },
+    /// A session's in-memory state for its diagnosis; `None` when the session isn't loaded.
+    Diagnose {
+        session_id: String,
+        reply: oneshot::Sender<Option<EngineSessionState>>,
+    },
+    /// Trades lost upstream of the engine (the confirmation gate lagged on the live feed).
+    SignalsDropped {
+        count: u64,
+    },
+}
+
+/// Cumulative engine throughput counters, shared with AppState for /api/admin.
+#[derive(Default)]
+pub struct EngineMetrics {
+    trades_received: AtomicU64,
+    trades_dropped: AtomicU64,
+    trades_processed: AtomicU64,
+    process_time_us: AtomicU64,
+    token_swap_fills: AtomicU64,
+    alerts_dropped: AtomicU64,
+}
+
+impl EngineMetrics {
+    /// Counts an OrderFilled event whose maker and taker assets are both outcome
+    /// tokens. The exchange emits these when it settles complementary orders by
+    /// minting or merging a full YES/NO set: the fill has no USDC leg, so there is
+    /// no side or price to copy and both feeds drop it.
+    pub fn record_token_swap_fill(&self) {
+        self.token_swap_fills.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub fn snapshot(&self) -> EngineMetricsSnapshot {
+        let processed = self.trades_processed.load(Ordering::Relaxed);
+        let total_us = self.process_time_us.load(Ordering::Relaxed);
+        EngineMetricsSnapshot {
+            trades_received: self.trades_received.load(Ordering::Relaxed),
+            trades_dropped: self.trades_dropped.load(Ordering::Relaxed),
+            trades_processed: processed,
+            token_swap_fills_skipped: self.token_swap_fills.load(Ordering::Relaxed),
+            whale_alerts_dropped: self.alerts_dropped.load(Ordering::Relaxed),
+            avg_process_ms: if processed > 0 {
+                total_us as f64 / processed as f64 / 1000.0
+            } else {
+                0.0
+            },
+        }
+    }
 }
 
 pub struct ClobClientState {
@@ -43,35 +133,304 @@ pub struct ClobClientState {
 
 struct ActiveSession {
     config: CopyTradeSessionRow,
-    traders: HashSet<String>,
+    /// Watched traders → normalised copy weight (sums to 1.0)
+    traders: HashMap<String, f64>,
     trader_count: usize,
     recent_orders: HashMap<String, Instant>, // "asset_id:side" → last order time (dedup)
+    /// This session's submissions in the last minute (max_orders_per_minute)
+    order_timestamps: VecDeque<Instant>,
     consecutive_failures: u32,
+    /// Orders rejected by the slippage gate since the last fill
+    consecutive_slippage_rejections: u32,
+    /// When capital first dropped below MIN_ORDER_USDC (pending auto-pause)
+    low_balance_since: Option<Instant>,
     cooldown_until: Option<Instant>,
     remaining_capital: f64,
-    // Position tracking: asset_id → (net_shares, last_fill_price)
-    positions: HashMap<String, (f64, f64)>,
-    open_gtc_orders: HashMap<String, (String, Instant, f64)>, // clob_order_id → (our_id, placed_at, usdc)
+    /// Fill costs beyond the sized order (fees, rounding) absorbed by clamping capital at zero
+    fees_paid: f64,
+    /// Parsed `asset_allowlist`; None copies every market
+    asset_allowlist: Option<HashSet<String>>,
+    // Position tracking: asset_id → (net_shares, last_fill_price, peak_price). The peak is
+    // the trailing-stop high-water mark: best fill or health-check mark since the position
+    // opened (restarts from the last fill after a reload).
+    positions: HashMap<String, (f64, f64, f64)>,
+    /// When each held position opened (first buy after being flat), for min_hold_secs
+    position_opened: HashMap<String, Instant>,
+    open_gtc_orders: HashMap<String, OpenGtcOrder>, // clob_order_id → order
+}
+
+/// A resting GTC order, tracked until it fills, is canceled or times out.
+struct OpenGtcOrder {
+    order_id: String,
+    placed_at: Instant,
+    /// Reserved from capital at placement (buys) or expected on fill (sells)
+    usdc: f64,
+    asset_id: String,
+    side: Side,
+    price: f64,
+    source_price: f64,
+    /// Shares matched so far, as of the last reconciliation
+    matched_shares: f64,
+    /// USDC those shares actually traded for, from the CLOB's trades
+    matched_usdc: f64,
+}
+
+impl OpenGtcOrder {
+    /// Capital still reserved for the unmatched part; resting sells reserve none.
+    fn unmatched_reserve(&self) -> f64 {
+        match self.side {
+            Side::Buy => (self.usdc - self.matched_usdc).max(0.0),
+            _ => 0.0,
+        }
+    }
+
+    /// Average price of what has matched so far; the limit price before any fill
+    fn fill_price(&self) -> f64 {
+        if self.matched_shares > 0.0 && self.matched_usdc > 0.0 {
+            self.matched_usdc / self.matched_shares
+        } else {
+            self.price
+        }
+    }
+
+    fn slippage_bps(&self) -> f64 {
+        ((self.fill_price() - self.source_price) / self.source_price * 10000.0).abs()
+    }
+}
+
+/// Malformed JSON is treated as no allowlist (the API validates on create)
+pub(crate) fn parse_asset_allowlist(row: &CopyTradeSessionRow) -> Option<HashSet<String>> {
+    let json = row.asset_allowlist.as_deref()?;
+    match serde_json::from_str::<Vec<String>>(json) {
+        Ok(ids) => Some(ids.into_iter().collect()),
+        Err(e) => {
+            tracing::warn!(
+                "Session {}: ignoring malformed asset_allowlist: {e}",
+                row.id
+            );
+            None
+        }
+    }
 }
 
-// Rate limit: global sliding window across all sessions (shared CLOB account)
-const MAX_ORDERS_PER_MINUTE: usize = 10;
+// Rate limit: global sliding window across all sessions (shared CLOB account), a hard
+// ceiling on top of each session's own max_orders_per_minute. Twice the per-session
+// maximum, so one session running flat out can't starve the others.
+const MAX_ORDERS_PER_MINUTE: usize = 2 * MAX_SESSION_ORDERS_PER_MINUTE as usize;
+/// Largest max_orders_per_minute a session may ask for
+pub(crate) const MAX_SESSION_ORDERS_PER_MINUTE: u32 = 60;
+/// Per-session order rate for sessions without max_orders_per_minute
+const DEFAULT_SESSION_ORDERS_PER_MINUTE: u32 = 10;
 const DEDUP_WINDOW: Duration = Duration::from_secs(30);
+/// Copy window for sessions without max_signal_age_secs
+const DEFAULT_MAX_SIGNAL_AGE_SECS: u32 = 300;
 const COOLDOWN_DURATION: Duration = Duration::from_secs(60);
 const MAX_CONSECUTIVE_FAILURES: u32 = 3;
-const MIN_ORDER_USDC: f64 = 1.0;
+/// Log a warning every N consecutive slippage rejections (sessions without slippage_pause_after)
+const SLIPPAGE_REJECTION_WARN_EVERY: u32 = 10;
+pub(crate) const MIN_ORDER_USDC: f64 = 1.0;
+/// CLOB price bounds (finest tick). Outside them orders are rejected, and near zero
+/// `usdc / price` turns into absurd share counts.
+const MIN_OUTCOME_PRICE: f64 = 0.001;
+const MAX_OUTCOME_PRICE: f64 = 0.999;
+/// Polymarket's default minimum size (shares) for limit orders
+const MIN_GTC_SHARES: f64 = 5.0;
+/// CLOB lot size: share quantities carry at most 2 decimals
+const LOT_SIZE_DECIMALS: u32 = 2;
+/// Market buys are sized in whole cents of USDC
+const MARKET_BUY_USDC_DECIMALS: u32 = 2;
 const GTC_TIMEOUT: Duration = Duration::from_secs(3600);
 const HEALTH_INTERVAL: Duration = Duration::from_secs(60);
+/// Absolute ceiling on a single live order (COPYTRADE_MAX_ORDER_USDC), independent of
+/// session config: a backstop against sizing/accounting bugs becoming real orders
+const DEFAULT_MAX_ORDER_USDC: f64 = 10_000.0;
+/// Default grace before auto-pausing on empty balance (COPYTRADE_EMPTY_BALANCE_GRACE_SECS)
+const DEFAULT_EMPTY_BALANCE_GRACE: Duration = Duration::from_secs(120);
+/// Warn when more than this share of trades was dropped within one health window
+const DROP_RATE_WARN_PCT: f64 = 1.0;
+/// Trader confidence filter: lookback for "recent" performance, and how long a result is reused
+const TRADER_PERF_WINDOW_DAYS: u32 = 7;
+const TRADER_PERF_TTL: Duration = Duration::from_secs(600);
+
+/// Recent performance of a source trader (positions traded within TRADER_PERF_WINDOW_DAYS)
+#[derive(Clone, Copy)]
+struct TraderPerf {
+    pnl: f64,
+    /// None when the trader has no recent positions
+    win_rate: Option<f64>,
+}
+
+type TraderPerfCache = HashMap<String, (TraderPerf, Instant)>;
+
+/// Liquidity filter: volume lookback for sessions without liquidity_window_mins, the
+/// longest lookback a session may ask for, and how long a result is reused
+const DEFAULT_LIQUIDITY_WINDOW_MINS: u32 = 60;
+pub(crate) const MAX_LIQUIDITY_WINDOW_MINS: u32 = 1440;
+const LIQUIDITY_TTL: Duration = Duration::from_secs(60);
+/// An expired volume keeps gating buys while its refresh runs, up to this age
+const LIQUIDITY_STALE_LIMIT: Duration = Duration::from_secs(600);
+
+struct AssetVolume {
+    /// None when the last query failed
+    volume: Option<f64>,
+    /// None until the first query for this key returns
+    fetched: Option<Instant>,
+    refreshing: bool,
+}
+
+/// (asset_id, window mins) → last known USDC volume. Shared with the spawned refresh
+/// tasks so a ClickHouse round-trip never sits on the trade path.
+type AssetVolumeCache = Arc<Mutex<HashMap<(String, u32), AssetVolume>>>;
+
+/// How long a live CLOB midpoint is reused for breaker marks and trailing stops, so
+/// sessions holding the same asset share one fetch per health cycle
+const MARK_TTL: Duration = Duration::from_secs(30);
+
+/// asset_id → (live midpoint, fetched at)
+type MarkCache = HashMap<String, (f64, Instant)>;
+
+/// Portfolio sizing: how long a source trader's book value is reused
+const TRADER_PORTFOLIO_TTL: Duration = Duration::from_secs(300);
+
+/// trader → (open positions marked to market in USDC, fetched at)
+pub(crate) type TraderPortfolioCache = HashMap<String, (f64, Instant)>;
 
 // ---------------------------------------------------------------------------
 // CLOB client initialization
 // ---------------------------------------------------------------------------
 
-pub async fn init_clob_client(
+/// Flags a wallet whose secrets no longer decrypt (server key changed or data
+/// corrupted) so the UI can surface it, and returns an actionable init error.
+fn wallet_decrypt_failed(
+    user_db: &Mutex<rusqlite::Connection>,
+    owner: &str,
+    wallet_id: &str,
+    e: super::crypto::DecryptError,
+) -> String {
+    if let super::crypto::DecryptError::KeyMismatch = e {
+        let conn = user_db.lock().unwrap_or_else(|p| p.into_inner());
+        let _ = db::update_wallet_status(&conn, owner, wallet_id, "decrypt_error");
+        return format!(
+            "wallet {wallet_id} cannot be decrypted with the current WALLET_ENCRYPTION_KEY \
+             (key changed or data corrupted); restore the original key or re-import the wallet"
+        );
+    }
+    e.to_string()
+}
+
+/// Why `init_clob_client` failed. Only the CLOB auth round-trip is worth retrying;
+/// a missing wallet or credentials that don't decrypt won't fix themselves.
+pub enum ClobInitError {
+    Permanent(String),
+    Transient(String),
+}
+
+impl std::fmt::Display for ClobInitError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Permanent(e) | Self::Transient(e) => f.write_str(e),
+        }
+    }
+}
+
+const DEFAULT_CLOB_INIT_ATTEMPTS: u32 = 3;
+const CLOB_INIT_BACKOFF_BASE: Duration = Duration::from_secs(1);
+const CLOB_INIT_BACKOFF_MAX: Duration = Duration::from_secs(8);
+
+/// Attempts at CLOB client init before a starting session is stopped
+/// (COPYTRADE_CLOB_INIT_ATTEMPTS, 1 disables retries)
+fn clob_init_attempts() -> u32 {
+    static ATTEMPTS: std::sync::OnceLock<u32> = std::sync::OnceLock::new();
+    *ATTEMPTS.get_or_init(|| match std::env::var("COPYTRADE_CLOB_INIT_ATTEMPTS") {
+        Ok(v) => match v.trim().parse::<u32>() {
+            Ok(n) if (1..=10).contains(&n) => n,
+            _ => {
+                tracing::warn!(
+                    "Invalid COPYTRADE_CLOB_INIT_ATTEMPTS={v}, using default {DEFAULT_CLOB_INIT_ATTEMPTS}"
+                );
+                DEFAULT_CLOB_INIT_ATTEMPTS
+            }
+        },
+        Err(_) => DEFAULT_CLOB_INIT_ATTEMPTS,
+    })
+}
+
+/// `init_clob_client` with exponential backoff on transient failures. Permanent
+/// failures return at once. Runs inline in the engine loop, so the backoff is capped.
+async fn init_clob_client_with_retry(
     user_db: &Arc<Mutex<rusqlite::Connection>>,
     encryption_key: &[u8; 32],
     owner: &str,
 ) -> Result<ClobClientState, String> {
+    retry_clob_init(clob_init_attempts(), owner, || {
+        init_clob_client(user_db, encryption_key, owner)
+    })
+    .await
+}
+
+async fn retry_clob_init<T, F, Fut>(attempts: u32, owner: &str, mut init: F) -> Result<T, String>
+where
+    F: FnMut() -> Fut,
+    Fut: std::future::Future<Output = Result<T, ClobInitError>>,
+{
+    let mut backoff = CLOB_INIT_BACKOFF_BASE;
+    let mut attempt = 1;
+    loop {
+        match init().await {
+            Ok(cs) => return Ok(cs),
+            Err(ClobInitError::Permanent(e)) => return Err(e),
+            Err(ClobInitError::Transient(e)) if attempt >= attempts => {
+                return Err(format!("{e} (after {attempt} attempts)"));
+            }
+            Err(ClobInitError::Transient(e)) => {
+                tracing::warn!(
+                    "CLOB init attempt {attempt}/{attempts} for owner {owner} failed: {e}; retrying in {}s",
+                    backoff.as_secs()
+                );
+                tokio::time::sleep(backoff).await;
+                backoff = (backoff * 2).min(CLOB_INIT_BACKOFF_MAX);
+                attempt += 1;
+            }
+        }
+    }
+}
+
+pub async fn init_clob_client(
+    user_db: &Arc<Mutex<rusqlite::Connection>>,
+    encryption_key: &[u8; 32],
+    owner: &str,
+) -> Result<ClobClientState, ClobInitError> {
+    let (signer, credentials, signature_type) =
+        clob_auth_inputs(user_db, encryption_key, owner).map_err(ClobInitError::Permanent)?;
+
+    // Build authenticated client
+    let config = Config::builder().use_server_time(true).build();
+    let client = Client::new("https://clob.polymarket.com", config)
+        .map_err(|e| ClobInitError::Permanent(format!("CLOB client error: {e}")))?
+        .authentication_builder(&signer)
+        .credentials(credentials)
+        .signature_type(signature_type)
+        .authenticate()
+        .await
+        .map_err(|e| ClobInitError::Transient(format!("CLOB auth error: {e}")))?;
+
+    Ok(ClobClientState { client, signer })
+}
+
+/// Signer, API credentials and signature type from the owner's first credentialed
+/// wallet. Everything here is local (SQLite + decryption), so failures are permanent.
+fn clob_auth_inputs(
+    user_db: &Arc<Mutex<rusqlite::Connection>>,
+    encryption_key: &[u8; 32],
+    owner: &str,
+) -> Result<
+    (
+        alloy::signers::local::LocalSigner<k256::ecdsa::SigningKey>,
+        Credentials,
+        SignatureType,
+    ),
+    String,
+> {
     // Load the first credentialed wallet for this owner
     let row = {
         let conn = user_db.lock().unwrap_or_else(|p| p.into_inner());
@@ -90,14 +449,16 @@ pub async fn init_clob_client(
         &row.encrypted_key,
         &row.key_nonce,
         owner.as_bytes(),
-    )?;
+    )
+    .map_err(|e| wallet_decrypt_failed(user_db, owner, &row.id, e))?;
     let pk_hex = format!("0x{}", hex::encode(&pk_bytes));
 
     // Decrypt CLOB credentials
     let cred_blob = row.clob_credentials.ok_or("Missing CLOB credentials")?;
     let cred_nonce = row.clob_nonce.ok_or("Missing CLOB nonce")?;
     let cred_json_bytes =
-        super::crypto::decrypt_secret(&user_key, &cred_blob, &cred_nonce, owner.as_bytes())?;
+        super::crypto::decrypt_secret(&user_key, &cred_blob, &cred_nonce, owner.as_bytes())
+            .map_err(|e| wallet_decrypt_failed(user_db, owner, &row.id, e))?;
     let cred_json: serde_json::Value =
         serde_json::from_slice(&cred_json_bytes).map_err(|e| format!("Invalid cred JSON: {e}"))?;
 
@@ -115,71 +476,264 @@ pub async fn init_clob_client(
 
     let credentials = Credentials::new(api_key_uuid, secret, passphrase);
 
+    let signature_type = match row.signature_type.as_str() {
+        "eoa" => SignatureType::Eoa,
+        _ => {
+            // Orders are signed for the proxy — refuse to trade against a mismatched one
+            super::wallet::verify_proxy_address(&row.wallet_address, row.proxy_address.as_deref())?;
+            SignatureType::Proxy
+        }
+    };
+
     // Create signer
     let signer = alloy::signers::local::LocalSigner::from_str(&pk_hex)
         .map_err(|e| format!("Signer creation failed: {e}"))?
         .with_chain_id(Some(polymarket_client_sdk::POLYGON));
 
-    // Build authenticated client
-    let config = Config::builder().use_server_time(true).build();
-    let client = Client::new("https://clob.polymarket.com", config)
-        .map_err(|e| format!("CLOB client error: {e}"))?
-        .authentication_builder(&signer)
-        .credentials(credentials)
-        .signature_type(SignatureType::Proxy)
-        .authenticate()
-        .await
-        .map_err(|e| format!("CLOB auth error: {e}"))?;
-
-    Ok(ClobClientState { client, signer })
+    Ok((signer, credentials, signature_type))
 }
 
 // ---------------------------------------------------------------------------
 // Trader resolution
 // ---------------------------------------------------------------------------
 
+/// Why a trader set couldn't be resolved from its source.
+enum ResolveError {
+    /// ClickHouse failed; a previously resolved set can stand in
+    Unavailable(String),
+    Invalid(String),
+}
+
+/// Resolves a session's trader set with each trader's normalised copy weight (weights
+/// sum to 1.0). List sessions weigh traders by their list member `weight`; every other
+/// trader weighs 1.0, so an unweighted set splits the budget evenly.
 pub async fn resolve_session_traders(
     user_db: &Arc<Mutex<rusqlite::Connection>>,
     ch_db: &clickhouse::Client,
     session: &CopyTradeSessionRow,
+) -> Result<HashMap<String, f64>, String> {
+    let traders = resolve_trader_set(user_db, ch_db, session).await?;
+    let list_weights = match session.list_id {
+        Some(ref list_id) => {
+            let conn = user_db.lock().unwrap_or_else(|p| p.into_inner());
+            db::get_list_member_weights(&conn, list_id, &session.owner).unwrap_or_default()
+        }
+        None => HashMap::new(),
+    };
+    let weight_of = |addr: &str| list_weights.get(addr).copied().unwrap_or(1.0);
+    let total: f64 = traders.iter().map(|a| weight_of(a)).sum();
+    Ok(traders
+        .into_iter()
+        .map(|addr| {
+            let weight = weight_of(&addr) / total;
+            (addr, weight)
+        })
+        .collect())
+}
+
+/// Resolves a session's trader set and saves it. When ClickHouse is unavailable the
+/// last saved set is used instead, so loaded sessions ride out an outage; a session
+/// that was never resolved (a fresh start) fails with "data source unavailable".
+async fn resolve_trader_set(
+    user_db: &Arc<Mutex<rusqlite::Connection>>,
+    ch_db: &clickhouse::Client,
+    session: &CopyTradeSessionRow,
 ) -> Result<HashSet<String>, String> {
-    if let Some(ref list_id) = session.list_id {
-        let conn = user_db.lock().unwrap_or_else(|p| p.into_inner());
-        let addrs = db::get_list_member_addresses(&conn, list_id, &session.owner)
-            .map_err(|_| "List not found".to_string())?;
-        Ok(addrs.into_iter().map(|a| a.to_lowercase()).collect())
+    match resolve_from_source(user_db, ch_db, session).await {
+        Ok(traders) => {
+            if !is_whale_follow(session) {
+                let addrs: Vec<&str> = traders.iter().map(|a| a.as_str()).collect();
+                let conn = user_db.lock().unwrap_or_else(|p| p.into_inner());
+                if let Err(e) = db::save_resolved_traders(&conn, &session.id, &addrs) {
+                    tracing::warn!(
+                        "Session {}: failed to save resolved traders: {e}",
+                        session.id
+                    );
+                }
+            }
+            Ok(traders)
+        }
+        Err(ResolveError::Invalid(e)) => Err(e),
+        Err(ResolveError::Unavailable(e)) => {
+            let cached = {
+                let conn = user_db.lock().unwrap_or_else(|p| p.into_inner());
+                db::get_resolved_traders(&conn, &session.id).ok().flatten()
+            };
+            match cached {
+                Some((traders, resolved_at)) => {
+                    tracing::warn!(
+                        "Session {}: trader resolution failed ({e}), using {} trader(s) resolved at {resolved_at}",
+                        session.id,
+                        traders.len()
+                    );
+                    Ok(traders.into_iter().collect())
+                }
+                None => Err(format!("Trader data source unavailable: {e}")),
+            }
+        }
+    }
+}
+
+async fn resolve_from_source(
+    user_db: &Arc<Mutex<rusqlite::Connection>>,
+    ch_db: &clickhouse::Client,
+    session: &CopyTradeSessionRow,
+) -> Result<HashSet<String>, ResolveError> {
+    if is_whale_follow(session) {
+        // Signals come from the whale alert feed, not a tracked address set
+        Ok(HashSet::new())
+    } else if let Some(ref list_id) = session.list_id {
+        let addrs: Vec<String> = {
+            let conn = user_db.lock().unwrap_or_else(|p| p.into_inner());
+            db::get_list_member_addresses(&conn, list_id, &session.owner)
+                .map_err(|_| ResolveError::Invalid("List not found".into()))?
+                .into_iter()
+                .map(|a| a.to_lowercase())
+                .collect()
+        };
+        match session.top_n {
+            // Hybrid: the list's best N members by the session's ranking metric
+            Some(top_n) if !addrs.is_empty() => top_traders(
+                ch_db,
+                top_n_metric(session),
+                top_n.clamp(1, 50),
+                Some(addrs),
+            )
+            .await
+            .map_err(ResolveError::Unavailable),
+            _ => Ok(addrs.into_iter().collect()),
+        }
     } else if let Some(top_n) = session.top_n {
-        let top_n = top_n.clamp(1, 50);
+        top_traders(ch_db, top_n_metric(session), top_n.clamp(1, 50), None)
+            .await
+            .map_err(ResolveError::Unavailable)
+    } else if let Some(top_pct) = session.top_pct {
+        // Scale with the trader universe, capped at what the WS subscriber can track
         let exclude = super::routes::exclude_clause();
-        let query = format!(
-            "WITH resolved AS (
-                SELECT asset_id, toNullable(toFloat64(resolved_price)) AS resolved_price
-                FROM poly_dearboard.resolved_prices FINAL
-            )
-            SELECT toString(p.trader) AS address
-            FROM poly_dearboard.trader_positions p
-            LEFT JOIN (SELECT asset_id, latest_price FROM poly_dearboard.asset_latest_price FINAL) AS lp ON p.asset_id = lp.asset_id
-            LEFT JOIN resolved rp ON p.asset_id = rp.asset_id
-            WHERE p.trader NOT IN ({exclude})
-            GROUP BY p.trader
-            ORDER BY sum((p.sell_usdc - p.buy_usdc) + (p.buy_amount - p.sell_amount) * coalesce(rp.resolved_price, toFloat64(lp.latest_price))) DESC
-            LIMIT {top_n}"
-        );
+        let total: u64 = ch_db
+            .query(&format!(
+                "SELECT uniqExact(trader) FROM trader_positions WHERE trader NOT IN ({exclude})"
+            ))
+            .fetch_one()
+            .await
+            .map_err(|e| ResolveError::Unavailable(format!("ClickHouse error: {e}")))?;
+        let cap = super::ws_subscriber::MAX_TRACKED_ADDRESSES_WARN as u32;
+        let n = ((total as f64 * top_pct / 100.0).ceil() as u32).clamp(1, cap);
+        top_traders(ch_db, top_n_metric(session), n, None)
+            .await
+            .map_err(ResolveError::Unavailable)
+    } else {
+        Err(ResolveError::Invalid(
+            "Session has none of list_id, top_n or top_pct".into(),
+        ))
+    }
+}
 
-        #[derive(clickhouse::Row, serde::Deserialize)]
-        struct Addr {
-            address: String,
-        }
+fn is_whale_follow(config: &CopyTradeSessionRow) -> bool {
+    SignalSource::from_str(&config.signal_source) == Some(SignalSource::WhaleFollow)
+}
 
-        let rows: Vec<Addr> = ch_db
-            .query(&query)
-            .fetch_all::<Addr>()
-            .await
-            .map_err(|e| format!("ClickHouse error: {e}"))?;
-        Ok(rows.into_iter().map(|r| r.address).collect())
+/// Tells every running session fed by the lagged channel (trader feed, or whale
+/// alerts when `whale`) that `count` signals were lost. Which of them the session
+/// would have copied is unknowable, so each one gets the full count.
+fn notify_signals_dropped(
+    sessions: &HashMap<String, ActiveSession>,
+    whale: bool,
+    count: u64,
+    update_tx: &broadcast::Sender<CopyTradeUpdate>,
+) {
+    let at = chrono::Utc::now().to_rfc3339();
+    for session in sessions.values().filter(|s| {
+        SessionStatus::from_str(&s.config.status) == Some(SessionStatus::Running)
+            && is_whale_follow(&s.config) == whale
+    }) {
+        let _ = update_tx.send(CopyTradeUpdate::SignalsDropped {
+            session_id: session.config.id.clone(),
+            count,
+            at: at.clone(),
+            owner: session.config.owner.clone(),
+        });
+    }
+}
+
+fn top_n_metric(config: &CopyTradeSessionRow) -> TopNMetric {
+    TopNMetric::from_str(&config.top_n_metric).unwrap_or(TopNMetric::Pnl)
+}
+
+/// Per-position P&L: realized cash flow plus held shares at the resolved or latest price
+/// (the leaderboard's realized_pnl, before the per-trader sum)
+const POSITION_PNL_EXPR: &str = "(p.sell_usdc - p.buy_usdc) + (p.buy_amount - p.sell_amount) * coalesce(rp.resolved_price, toFloat64(lp.latest_price))";
+/// ROI ranking only considers traders who moved at least this much USDC, so a lucky
+/// handful of small trades can't top the list
+const ROI_MIN_VOLUME_USDC: f64 = 10_000.0;
+/// Consistency ranking only considers traders with at least this many non-flat positions
+const CONSISTENCY_MIN_POSITIONS: u32 = 10;
+
+/// ClickHouse ORDER BY expression and HAVING filter for a ranking metric
+fn top_n_ranking(metric: TopNMetric) -> (String, String) {
+    let pnl = POSITION_PNL_EXPR;
+    match metric {
+        TopNMetric::Pnl => (format!("sum({pnl})"), String::new()),
+        TopNMetric::Roi => (
+            format!("sum({pnl}) / sum(p.total_volume)"),
+            format!("HAVING sum(p.total_volume) >= {ROI_MIN_VOLUME_USDC}"),
+        ),
+        TopNMetric::Volume => ("sum(p.total_volume)".to_string(), String::new()),
+        TopNMetric::Consistency => (
+            // Ties (common with few positions) fall back to P&L; the caller appends DESC
+            format!("countIf({pnl} > 0) / countIf({pnl} != 0) DESC, sum({pnl})"),
+            format!("HAVING countIf({pnl} != 0) >= {CONSISTENCY_MIN_POSITIONS}"),
+        ),
+    }
+}
+
+/// Top `limit` traders by the session's ranking metric over all-time positions (P&L
+/// by default, the leaderboard's default sort), optionally ranked only `among` the
+/// given lowercase addresses. Addresses without any positions can't be ranked and are
+/// left out, as are traders below the ROI / consistency floors.
+async fn top_traders(
+    ch_db: &clickhouse::Client,
+    metric: TopNMetric,
+    limit: u32,
+    among: Option<Vec<String>>,
+) -> Result<HashSet<String>, String> {
+    let exclude = super::routes::exclude_clause();
+    let (order_by, having) = top_n_ranking(metric);
+    let among_filter = if among.is_some() {
+        "AND has(?, lower(toString(p.trader)))"
     } else {
-        Err("Session has neither list_id nor top_n".into())
+        ""
+    };
+    let query = format!(
+        "WITH resolved AS (
+            SELECT asset_id, toNullable(toFloat64(resolved_price)) AS resolved_price
+            FROM resolved_prices FINAL
+        )
+        SELECT toString(p.trader) AS address
+        FROM trader_positions p
+        LEFT JOIN (SELECT asset_id, latest_price FROM asset_latest_price FINAL) AS lp ON p.asset_id = lp.asset_id
+        LEFT JOIN resolved rp ON p.asset_id = rp.asset_id
+        WHERE p.trader NOT IN ({exclude}) {among_filter}
+        GROUP BY p.trader
+        {having}
+        ORDER BY {order_by} DESC
+        LIMIT {limit}"
+    );
+
+    #[derive(clickhouse::Row, serde::Deserialize)]
+    struct Addr {
+        address: String,
     }
+
+    let mut q = ch_db.query(&query);
+    if let Some(among) = among {
+        q = q.bind(among);
+    }
+    let rows: Vec<Addr> = q
+        .fetch_all::<Addr>()
+        .await
+        .map_err(|e| format!("ClickHouse error: {e}"))?;
+    Ok(rows.into_iter().map(|r| r.address).collect())
 }
 
 // ---------------------------------------------------------------------------
@@ -189,6 +743,7 @@ pub async fn resolve_session_traders(
 #[allow(clippy::too_many_arguments)]
 pub async fn copytrade_engine_loop(
     mut trade_rx: broadcast::Receiver<LiveTrade>,
+    mut alert_rx: broadcast::Receiver<Alert>,
     mut cmd_rx: mpsc::Receiver<CopyTradeCommand>,
     update_tx: broadcast::Sender<CopyTradeUpdate>,
     clob_client: Arc<RwLock<Option<ClobClientState>>>,
@@ -196,11 +751,26 @@ pub async fn copytrade_engine_loop(
     encryption_key: Arc<[u8; 32]>,
     ch_db: clickhouse::Client,
     trader_watch_tx: tokio::sync::watch::Sender<std::collections::HashSet<String>>,
+    http: reqwest::Client,
+    metrics: Arc<EngineMetrics>,
+    market_cache: MarketCache,
 ) {
     let mut sessions: HashMap<String, ActiveSession> = HashMap::new();
+    let mut last_window = metrics.snapshot();
     let mut health_interval = tokio::time::interval(HEALTH_INTERVAL);
     health_interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
     let mut order_timestamps: VecDeque<Instant> = VecDeque::new();
+    let mut perf_cache: TraderPerfCache = HashMap::new();
+    let volume_cache: AssetVolumeCache = Arc::new(Mutex::new(HashMap::new()));
+    let mut mark_cache: MarkCache = HashMap::new();
+    let mut portfolio_cache: TraderPortfolioCache = HashMap::new();
+    let empty_balance_grace = empty_balance_grace_from_env();
+    let mut alerts_open = true;
+    if force_simulate() {
+        tracing::warn!(
+            "FORCE_SIMULATE is set: every copy-trade session runs simulated, no orders will be placed"
+        );
+    }
 
     // On startup: reload running sessions
     {
@@ -225,17 +795,25 @@ pub async fn copytrade_engine_loop(
                             session_row.id
                         );
                     }
+                    let recent_orders = restore_recent_orders(&user_db, &session_row.id);
+                    let position_opened = restore_position_opened(&user_db, &session_row.id);
                     sessions.insert(
                         session_row.id.clone(),
                         ActiveSession {
                             remaining_capital: session_row.remaining_capital,
+                            fees_paid: session_row.fees_paid,
+                            asset_allowlist: parse_asset_allowlist(&session_row),
                             config: session_row,
                             traders,
                             trader_count,
-                            recent_orders: HashMap::new(),
+                            recent_orders,
+                            order_timestamps: VecDeque::new(),
                             consecutive_failures: 0,
+                            consecutive_slippage_rejections: 0,
+                            low_balance_since: None,
                             cooldown_until: None,
                             positions,
+                            position_opened,
                             open_gtc_orders: HashMap::new(),
                         },
                     );
@@ -256,22 +834,38 @@ pub async fn copytrade_engine_loop(
             result = trade_rx.recv() => {
                 match result {
                     Ok(trade) => {
+                        metrics.trades_received.fetch_add(1, Ordering::Relaxed);
+                        let started = Instant::now();
                         for session in sessions.values_mut().filter(|s| {
                             SessionStatus::from_str(&s.config.status) == Some(SessionStatus::Running)
+                                && !is_whale_follow(&s.config)
                         }) {
                             process_trade(
                                 &trade,
                                 session,
                                 &clob_client,
+                                &http,
+                                &ch_db,
                                 &user_db,
                                 &update_tx,
                                 &mut order_timestamps,
+                                &mut perf_cache,
+                                &volume_cache,
+                                &mut portfolio_cache,
+                                &market_cache,
+                                empty_balance_grace,
                             )
                             .await;
                         }
+                        metrics.trades_processed.fetch_add(1, Ordering::Relaxed);
+                        metrics
+                            .process_time_us
+                            .fetch_add(started.elapsed().as_micros() as u64, Ordering::Relaxed);
                     }
                     Err(broadcast::error::RecvError::Lagged(n)) => {
-                        tracing::warn!("Copytrade engine lagged, dropped {n} trades");
+                        metrics.trades_dropped.fetch_add(n, Ordering::Relaxed);
+                        tracing::warn!("Copytrade engine lagged, dropped {n} trades (consider raising COPYTRADE_LIVE_CAPACITY)");
+                        notify_signals_dropped(&sessions, false, n, &update_tx);
                     }
                     Err(_) => {
                         tracing::error!("copytrade_live_tx channel closed, engine shutting down");
@@ -280,6 +874,50 @@ pub async fn copytrade_engine_loop(
                 }
             }
 
+            result = alert_rx.recv(), if alerts_open => {
+                match result {
+                    Ok(alert) => {
+                        // whale_follow sessions: any trader's whale fill at or above the
+                        // session threshold runs through the regular pipeline
+                        if let Some(trade) = LiveTrade::from_whale_alert(&alert) {
+                            let usdc = trade.usdc_amount.parse::<f64>().unwrap_or(0.0);
+                            for session in sessions.values_mut().filter(|s| {
+                                SessionStatus::from_str(&s.config.status)
+                                    == Some(SessionStatus::Running)
+                                    && is_whale_follow(&s.config)
+                                    && s.config.min_whale_usdc.is_some_and(|min| usdc >= min)
+                            }) {
+                                process_trade(
+                                    &trade,
+                                    session,
+                                    &clob_client,
+                                    &http,
+                                    &ch_db,
+                                    &user_db,
+                                    &update_tx,
+                                    &mut order_timestamps,
+                                    &mut perf_cache,
+                                    &volume_cache,
+                                    &mut portfolio_cache,
+                                    &market_cache,
+                                    empty_balance_grace,
+                                )
+                                .await;
+                            }
+                        }
+                    }
+                    Err(broadcast::error::RecvError::Lagged(n)) => {
+                        metrics.alerts_dropped.fetch_add(n, Ordering::Relaxed);
+                        tracing::warn!("Copytrade engine lagged on whale alerts, dropped {n} alert(s)");
+                        notify_signals_dropped(&sessions, true, n, &update_tx);
+                    }
+                    Err(_) => {
+                        tracing::error!("alert_tx channel closed, whale_follow sessions get no signals");
+                        alerts_open = false;
+                    }
+                }
+            }
+
             Some(cmd) = cmd_rx.recv() => {
                 match cmd {
                     CopyTradeCommand::Start { session_id, owner } => {
@@ -287,6 +925,15 @@ pub async fn copytrade_engine_loop(
                             &session_id, &owner, &mut sessions, &clob_client,
                             &user_db, &encryption_key, &ch_db, &update_tx,
                         ).await;
+                        if let Some(session) = sessions.get_mut(&session_id)
+                            && session.config.seed_existing_positions
+                        {
+                            seed_existing_positions(
+                                session, &clob_client, &http, &ch_db, &user_db, &update_tx,
+                                &mut order_timestamps, &mut perf_cache, &volume_cache,
+                                &mut portfolio_cache, &market_cache, empty_balance_grace,
+                            ).await;
+                        }
                         publish_tracked_addresses(&sessions, &trader_watch_tx);
                     }
                     CopyTradeCommand::Pause { session_id } => {
@@ -294,13 +941,29 @@ pub async fn copytrade_engine_loop(
                             session.config.status = "paused".to_string();
                             let _ = update_tx.send(CopyTradeUpdate::SessionPaused {
                                 session_id,
+                                reason: Some("user".to_string()),
                                 owner: session.config.owner.clone(),
                             });
                             publish_tracked_addresses(&sessions, &trader_watch_tx);
                         }
                     }
-                    CopyTradeCommand::Resume { session_id } => {
-                        if let Some(session) = sessions.get_mut(&session_id) {
+                    CopyTradeCommand::Resume { session_id, owner } => {
+                        if !sessions.contains_key(&session_id) {
+                            // Not loaded (paused sessions aren't reloaded after a restart):
+                            // load it like a fresh start. handle_start only requires a CLOB
+                            // client for live sessions, so simulate sessions resume without one.
+                            handle_start(
+                                &session_id, &owner, &mut sessions, &clob_client,
+                                &user_db, &encryption_key, &ch_db, &update_tx,
+                            ).await;
+                            if sessions.contains_key(&session_id) {
+                                let _ = update_tx.send(CopyTradeUpdate::SessionResumed {
+                                    session_id,
+                                    owner,
+                                });
+                            }
+                            publish_tracked_addresses(&sessions, &trader_watch_tx);
+                        } else if let Some(session) = sessions.get_mut(&session_id) {
                             // Refresh trader set on resume
                             if let Ok(traders) = resolve_session_traders(&user_db, &ch_db, &session.config).await {
                                 session.trader_count = traders.len();
@@ -337,11 +1000,59 @@ pub async fn copytrade_engine_loop(
                             publish_tracked_addresses(&sessions, &trader_watch_tx);
                         }
                     }
+                    CopyTradeCommand::RefreshTraders { session_id } => {
+                        if let Some(session) = sessions.get_mut(&session_id) {
+                            handle_refresh_traders(
+                                session, &clob_client, &http, &user_db, &ch_db, &update_tx,
+                                &mut order_timestamps,
+                            ).await;
+                            publish_tracked_addresses(&sessions, &trader_watch_tx);
+                        }
+                    }
+                    CopyTradeCommand::CancelOrder { session_id, order_id, clob_order_id, refund_usdc, reply } => {
+                        let result = handle_cancel_order(
+                            &session_id, &order_id, &clob_order_id, refund_usdc,
+                            &mut sessions, &clob_client, &user_db,
+                        ).await;
+                        let _ = reply.send(result);
+                    }
+                    CopyTradeCommand::Inspect { reply } => {
+                        let _ = reply.send(
+                            inspect_engine(&sessions, &clob_client, &mut order_timestamps).await,
+                        );
+                    }
+                    CopyTradeCommand::OpenOrders { session_id, reply } => {
+                        let _ = reply.send(session_open_orders(&sessions, &session_id));
+                    }
+                    CopyTradeCommand::UpdateCategories {
+                        session_id,
+                        allowed_categories,
+                        blocked_categories,
+                        copy_uncategorized,
+                    } => {
+                        if let Some(session) = sessions.get_mut(&session_id) {
+                            session.config.allowed_categories = allowed_categories;
+                            session.config.blocked_categories = blocked_categories;
+                            session.config.copy_uncategorized = copy_uncategorized;
+                        }
+                    }
+                    CopyTradeCommand::Diagnose { session_id, reply } => {
+                        let _ = reply.send(session_state(&sessions, &session_id));
+                    }
+                    CopyTradeCommand::SignalsDropped { count } => {
+                        metrics.trades_dropped.fetch_add(count, Ordering::Relaxed);
+                        notify_signals_dropped(&sessions, false, count, &update_tx);
+                    }
                 }
             }
 
             _ = health_interval.tick() => {
-                health_check(&mut sessions, &clob_client, &user_db, &update_tx, &trader_watch_tx).await;
+                last_window = log_metrics_window(&metrics, last_window);
+                health_check(
+                    &mut sessions, &clob_client, &http, &user_db, &update_tx,
+                    &trader_watch_tx, &mut order_timestamps, &mut mark_cache,
+                    empty_balance_grace,
+                ).await;
             }
         }
     }
@@ -379,10 +1090,10 @@ async fn handle_start(
     };
 
     // Initialize CLOB client if not yet done (skip for simulation-only)
-    if !session_row.simulate {
+    if !runs_simulated(&session_row) {
         let needs_init = clob_client.read().await.is_none();
         if needs_init {
-            match init_clob_client(user_db, encryption_key, owner).await {
+            match init_clob_client_with_retry(user_db, encryption_key, owner).await {
                 Ok(cs) => {
                     *clob_client.write().await = Some(cs);
                     tracing::info!("CLOB client initialized for owner {owner}");
@@ -410,19 +1121,30 @@ async fn handle_start(
             tracing::info!(
                 "Session {session_id} started: {} traders, simulate={}",
                 trader_count,
-                session_row.simulate
+                runs_simulated(&session_row)
             );
+            // Restore positions (non-empty when resuming a session that was unloaded)
+            let positions = {
+                let conn = user_db.lock().unwrap_or_else(|p| p.into_inner());
+                db::get_session_positions(&conn, session_id).unwrap_or_default()
+            };
             sessions.insert(
                 session_id.to_string(),
                 ActiveSession {
                     remaining_capital: session_row.remaining_capital,
+                    fees_paid: session_row.fees_paid,
+                    asset_allowlist: parse_asset_allowlist(&session_row),
                     config: session_row,
                     traders,
                     trader_count,
-                    recent_orders: HashMap::new(),
+                    recent_orders: restore_recent_orders(user_db, session_id),
+                    order_timestamps: VecDeque::new(),
                     consecutive_failures: 0,
+                    consecutive_slippage_rejections: 0,
+                    low_balance_since: None,
                     cooldown_until: None,
-                    positions: HashMap::new(),
+                    positions,
+                    position_opened: restore_position_opened(user_db, session_id),
                     open_gtc_orders: HashMap::new(),
                 },
             );
@@ -441,131 +1163,1419 @@ async fn handle_start(
 }
 
 // ---------------------------------------------------------------------------
-// Trade processing (the 11-step pipeline)
+// Start: seed existing positions
 // ---------------------------------------------------------------------------
 
-async fn process_trade(
-    trade: &LiveTrade,
+/// Mirrors the tracked traders' current open positions (largest first) by feeding each
+/// one through `process_trade` as a synthetic buy at the latest price, so seeding obeys
+/// the same filters, sizing, capital, rate limit and slippage checks as live copies.
+#[allow(clippy::too_many_arguments)]
+async fn seed_existing_positions(
     session: &mut ActiveSession,
     clob_client: &Arc<RwLock<Option<ClobClientState>>>,
+    http: &reqwest::Client,
+    ch_db: &clickhouse::Client,
     user_db: &Arc<Mutex<rusqlite::Connection>>,
     update_tx: &broadcast::Sender<CopyTradeUpdate>,
     order_timestamps: &mut VecDeque<Instant>,
+    perf_cache: &mut TraderPerfCache,
+    volume_cache: &AssetVolumeCache,
+    portfolio_cache: &mut TraderPortfolioCache,
+    market_cache: &MarketCache,
+    empty_balance_grace: Duration,
 ) {
-    let sid = &session.config.id;
+    let sid = session.config.id.clone();
+    let traders: Vec<String> = session.traders.keys().cloned().collect();
+    // Most trader positions mirrored: the session's order rate limit would drop anything
+    // past one minute's budget anyway
+    let max_seed_positions = session_orders_per_minute(&session.config);
+    let query = format!(
+        "SELECT
+            lower(toString(p.trader)) AS trader,
+            toString(p.asset_id) AS asset_id,
+            toFloat64(p.buy_amount - p.sell_amount) AS net_shares,
+            toFloat64(lp.latest_price) AS price
+        FROM trader_positions p
+        INNER JOIN (SELECT asset_id, latest_price FROM asset_latest_price FINAL) AS lp ON p.asset_id = lp.asset_id
+        WHERE has(?, lower(toString(p.trader)))
+          AND p.buy_amount > p.sell_amount
+          AND p.asset_id NOT IN (SELECT asset_id FROM resolved_prices FINAL)
+          AND toFloat64(lp.latest_price) BETWEEN 0.005 AND 0.995
+        ORDER BY net_shares * price DESC
+        LIMIT {max_seed_positions}"
+    );
 
-    // 1. FILTER — is trader in watched set?
-    if !session.traders.contains(&trade.trader.to_lowercase()) {
-        return;
+    #[derive(clickhouse::Row, serde::Deserialize)]
+    struct OpenPosition {
+        trader: String,
+        asset_id: String,
+        net_shares: f64,
+        price: f64,
     }
 
-    // 2. COOLDOWN
-    if let Some(until) = session.cooldown_until {
-        if Instant::now() < until {
-            tracing::debug!("Session {sid} in cooldown, skipping trade");
+    let positions = match ch_db
+        .query(&query)
+        .bind(traders)
+        .fetch_all::<OpenPosition>()
+        .await
+    {
+        Ok(p) => p,
+        Err(e) => {
+            tracing::warn!("Session {sid}: failed to load positions to seed: {e}");
             return;
         }
-        session.cooldown_until = None;
-        session.consecutive_failures = 0;
-    }
+    };
+    tracing::info!(
+        "Session {sid}: seeding from {} open trader position(s)",
+        positions.len()
+    );
 
-    // 3. DEDUP — same asset_id + side within 30s?
-    let dedup_key = format!("{}:{}", trade.asset_id, trade.side);
-    if let Some(last) = session.recent_orders.get(&dedup_key) {
-        if last.elapsed() < DEDUP_WINDOW {
-            tracing::debug!("Dedup: already ordered {dedup_key} within 30s");
-            return;
-        }
+    let now = chrono::Utc::now().to_rfc3339();
+    for p in positions {
+        let trade = LiveTrade {
+            tx_hash: "seed".to_string(),
+            block_timestamp: now.clone(),
+            trader: p.trader,
+            side: "buy".to_string(),
+            amount: p.net_shares.to_string(),
+            price: p.price.to_string(),
+            usdc_amount: (p.net_shares * p.price).to_string(),
+            cache_key: markets::cache_key(&p.asset_id),
+            asset_id: p.asset_id,
+            question: String::new(),
+            outcome: String::new(),
+            category: String::new(),
+            block_number: 0,
+        };
+        process_trade(
+            &trade,
+            session,
+            clob_client,
+            http,
+            ch_db,
+            user_db,
+            update_tx,
+            order_timestamps,
+            perf_cache,
+            volume_cache,
+            portfolio_cache,
+            market_cache,
+            empty_balance_grace,
+        )
+        .await;
     }
+}
 
-    // Parse amounts
-    let source_price = match trade.price.parse::<f64>() {
-        Ok(p) if p > 0.0 => p,
-        _ => return,
-    };
-    let trade_usdc = match trade.usdc_amount.parse::<f64>() {
-        Ok(u) if u > 0.0 => u,
-        _ => return,
-    };
-
-    // Parse side early — needed for sizing logic
-    let side = match trade.side.to_lowercase().as_str() {
-        "buy" => Side::Buy,
-        "sell" => Side::Sell,
-        _ => return,
-    };
+// ---------------------------------------------------------------------------
+// Command: Inspect
+// ---------------------------------------------------------------------------
 
-    // 4. SIZING (direction-aware)
-    let copy_pct = session.config.copy_pct;
-    let order_usdc = match side {
-        Side::Buy => {
-            let per_trader_budget = if session.trader_count > 0 {
-                session.remaining_capital * copy_pct / session.trader_count as f64
+async fn inspect_engine(
+    sessions: &HashMap<String, ActiveSession>,
+    clob_client: &Arc<RwLock<Option<ClobClientState>>>,
+    order_timestamps: &mut VecDeque<Instant>,
+) -> EngineStateSnapshot {
+    let now = Instant::now();
+    order_timestamps.retain(|t| now.duration_since(*t) < Duration::from_secs(60));
+    let with_status = |status: SessionStatus| {
+        sessions
+            .values()
+            .filter(|s| SessionStatus::from_str(&s.config.status) == Some(status))
+            .count()
+    };
+    EngineStateSnapshot {
+        loaded_sessions: sessions.len(),
+        running_sessions: with_status(SessionStatus::Running),
+        paused_sessions: with_status(SessionStatus::Paused),
+        sessions_in_cooldown: sessions
+            .values()
+            .filter(|s| s.cooldown_until.is_some_and(|t| now < t))
+            .count(),
+        sessions_low_balance: sessions
+            .values()
+            .filter(|s| s.low_balance_since.is_some())
+            .count(),
+        // One shared client serves every live session
+        clob_clients: clob_client.read().await.is_some() as usize,
+        orders_last_minute: order_timestamps.len(),
+        max_orders_per_minute: MAX_ORDERS_PER_MINUTE,
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Command: OpenOrders
+// ---------------------------------------------------------------------------
+
+fn session_open_orders(
+    sessions: &HashMap<String, ActiveSession>,
+    session_id: &str,
+) -> Option<Vec<EngineOpenOrder>> {
+    let session = sessions.get(session_id)?;
+    Some(
+        session
+            .open_gtc_orders
+            .values()
+            .map(|order| EngineOpenOrder {
+                order_id: order.order_id.clone(),
+                age_secs: order.placed_at.elapsed().as_secs(),
+                // What still rests on the book once partial fills are taken out
+                usdc: (order.usdc - order.matched_usdc).max(0.0),
+            })
+            .collect(),
+    )
+}
+
+// ---------------------------------------------------------------------------
+// Command: Diagnose
+// ---------------------------------------------------------------------------
+
+fn session_state(
+    sessions: &HashMap<String, ActiveSession>,
+    session_id: &str,
+) -> Option<EngineSessionState> {
+    let session = sessions.get(session_id)?;
+    let now = Instant::now();
+    Some(EngineSessionState {
+        status: session.config.status.clone(),
+        traders: session.traders.keys().cloned().collect(),
+        remaining_capital: session.remaining_capital,
+        consecutive_failures: session.consecutive_failures,
+        consecutive_slippage_rejections: session.consecutive_slippage_rejections,
+        cooldown_remaining_secs: session
+            .cooldown_until
+            .filter(|until| *until > now)
+            .map(|until| (until - now).as_secs()),
+        low_balance_secs: session
+            .low_balance_since
+            .map(|since| now.duration_since(since).as_secs()),
+        orders_last_minute: session
+            .order_timestamps
+            .iter()
+            .filter(|t| now.duration_since(**t) < Duration::from_secs(60))
+            .count(),
+        max_orders_per_minute: session_orders_per_minute(&session.config),
+        open_positions: session.positions.len(),
+        open_gtc_orders: session.open_gtc_orders.len(),
+    })
+}
+
+// ---------------------------------------------------------------------------
+// Command: CancelOrder
+// ---------------------------------------------------------------------------
+
+async fn handle_cancel_order(
+    session_id: &str,
+    order_id: &str,
+    clob_order_id: &str,
+    refund_usdc: f64,
+    sessions: &mut HashMap<String, ActiveSession>,
+    clob_client: &Arc<RwLock<Option<ClobClientState>>>,
+    user_db: &Arc<Mutex<rusqlite::Connection>>,
+) -> Result<(), String> {
+    // Cancel on the CLOB first, then drop the async lock before touching SQLite
+    let resp = {
+        let clob = clob_client.read().await;
+        let cs = clob.as_ref().ok_or("CLOB client not initialized")?;
+        cs.client
+            .cancel_orders(&[clob_order_id])
+            .await
+            .map_err(|e| format!("CLOB cancel failed: {e}"))?
+    };
+    if !resp.canceled.iter().any(|id| id == clob_order_id) {
+        return Err(format!("CLOB did not cancel order {clob_order_id}"));
+    }
+
+    let conn = user_db.lock().unwrap_or_else(|p| p.into_inner());
+    // Keep in-memory state authoritative when the session is loaded; otherwise
+    // (e.g. after a restart) refund straight into SQLite.
+    let mut refund_usdc = refund_usdc;
+    let mut tracked = None;
+    if let Some(session) = sessions.get_mut(session_id) {
+        // A tracked order knows what already matched; only the rest is refunded
+        if let Some(order) = session.open_gtc_orders.remove(clob_order_id) {
+            refund_usdc = order.unmatched_reserve();
+            tracked = Some(order);
+        }
+        session.remaining_capital += refund_usdc;
+        let _ = db::update_session_capital(
+            &conn,
+            session_id,
+            session.remaining_capital,
+            session.fees_paid,
+        );
+    } else {
+        let _ = db::refund_session_capital(&conn, session_id, refund_usdc);
+    }
+    record_gtc_close(&conn, order_id, clob_order_id, tracked.as_ref())
+        .map_err(|e| format!("DB error updating order: {e}"))?;
+    drop(conn);
+
+    tracing::info!(
+        "Session {session_id}: canceled resting order {clob_order_id} (refund {refund_usdc:.2} USDC)"
+    );
+    audit_order(user_db, session_id, Some(order_id), "canceled", || {
+        serde_json::json!({
+            "clob_order_id": clob_order_id,
+            "refund_usdc": refund_usdc,
+            "by": "owner",
+        })
+    });
+    Ok(())
+}
+
+/// Records a GTC order that left the book before filling completely: `filled` with
+/// the matched size when part of it matched, `canceled` otherwise.
+fn record_gtc_close(
+    conn: &rusqlite::Connection,
+    order_id: &str,
+    clob_order_id: &str,
+    order: Option<&OpenGtcOrder>,
+) -> Result<(), rusqlite::Error> {
+    match order.filter(|o| o.matched_shares > 0.0) {
+        Some(o) => db::record_matched_fill(
+            conn,
+            order_id,
+            OrderStatus::Filled.as_str(),
+            o.matched_usdc,
+            o.matched_shares,
+            o.fill_price(),
+            Some(o.slippage_bps()),
+        ),
+        None => db::update_copytrade_order(
+            conn,
+            order_id,
+            OrderStatus::Canceled.as_str(),
+            None,
+            None,
+            None,
+            Some(clob_order_id),
+        ),
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Command: RefreshTraders
+// ---------------------------------------------------------------------------
+
+async fn handle_refresh_traders(
+    session: &mut ActiveSession,
+    clob_client: &Arc<RwLock<Option<ClobClientState>>>,
+    http: &reqwest::Client,
+    user_db: &Arc<Mutex<rusqlite::Connection>>,
+    ch_db: &clickhouse::Client,
+    update_tx: &broadcast::Sender<CopyTradeUpdate>,
+    order_timestamps: &mut VecDeque<Instant>,
+) {
+    let sid = session.config.id.clone();
+    let traders = match resolve_session_traders(user_db, ch_db, &session.config).await {
+        Ok(t) => t,
+        Err(e) => {
+            tracing::warn!("Session {sid}: trader refresh failed: {e}");
+            return;
+        }
+    };
+    let removed: HashSet<String> = session
+        .traders
+        .keys()
+        .filter(|addr| !traders.contains_key(*addr))
+        .cloned()
+        .collect();
+    tracing::info!(
+        "Session {sid}: traders refreshed ({} → {}, {} removed)",
+        session.trader_count,
+        traders.len(),
+        removed.len()
+    );
+    session.trader_count = traders.len();
+    session.traders = traders;
+
+    let running = SessionStatus::from_str(&session.config.status) == Some(SessionStatus::Running);
+    if removed.is_empty() || !session.config.close_on_trader_removal || !running {
+        return;
+    }
+
+    // Only positions every buy of which came from a removed trader; shared ones stay open
+    let buy_traders = {
+        let conn = user_db.lock().unwrap_or_else(|p| p.into_inner());
+        match db::get_position_buy_traders(&conn, &sid) {
+            Ok(m) => m,
+            Err(e) => {
+                tracing::error!("Session {sid}: failed to load position attribution: {e}");
+                return;
+            }
+        }
+    };
+    let to_close: Vec<(String, f64, String)> = session
+        .positions
+        .iter()
+        .filter_map(|(asset_id, (shares, _, _))| {
+            let sources = buy_traders.get(asset_id)?;
+            if sources.iter().all(|t| removed.contains(t)) {
+                let trader = sources.iter().min().cloned().unwrap_or_default();
+                Some((asset_id.clone(), *shares, trader))
             } else {
-                0.0
+                None
+            }
+        })
+        .collect();
+
+    for (asset_id, shares, trader) in to_close {
+        close_position_at_market(
+            session,
+            &asset_id,
+            shares,
+            &trader,
+            "trader_removed",
+            clob_client,
+            http,
+            user_db,
+            update_tx,
+            order_timestamps,
+        )
+        .await;
+    }
+}
+
+/// Sells a whole position at the current price through the regular execution path.
+/// The current price doubles as the source price, so the slippage gate only guards
+/// against the book moving between the two price reads. `reason` is reported in
+/// `PositionClosed` and, dashed, stands in for the source tx hash.
+#[allow(clippy::too_many_arguments)]
+async fn close_position_at_market(
+    session: &mut ActiveSession,
+    asset_id: &str,
+    shares: f64,
+    trader: &str,
+    reason: &str,
+    clob_client: &Arc<RwLock<Option<ClobClientState>>>,
+    http: &reqwest::Client,
+    user_db: &Arc<Mutex<rusqlite::Connection>>,
+    update_tx: &broadcast::Sender<CopyTradeUpdate>,
+    order_timestamps: &mut VecDeque<Instant>,
+) {
+    let sid = session.config.id.clone();
+    let price = if runs_simulated(&session.config) {
+        super::copytrade::fetch_one_price(http, asset_id, "SELL").await
+    } else {
+        fetch_clob_price(clob_client, asset_id, Side::Sell).await
+    };
+    let Some(price) = price.filter(|p| *p > 0.0) else {
+        tracing::warn!("Session {sid}: no price for {asset_id}, position left open");
+        return;
+    };
+    let order_usdc = shares * price;
+    if order_usdc < MIN_ORDER_USDC {
+        tracing::debug!("Session {sid}: position {asset_id} below minimum order, left open");
+        return;
+    }
+
+    let now = Instant::now();
+    if let Some(limit) = rate_limit_exceeded(
+        &session.config,
+        &mut session.order_timestamps,
+        order_timestamps,
+        now,
+    ) {
+        tracing::warn!("Session {sid}: rate limit: {limit} exceeded, {asset_id} left open");
+        return;
+    }
+
+    let trade = LiveTrade {
+        tx_hash: reason.replace('_', "-"),
+        block_timestamp: String::new(),
+        trader: trader.to_string(),
+        side: "sell".to_string(),
+        asset_id: asset_id.to_string(),
+        amount: shares.to_string(),
+        price: price.to_string(),
+        usdc_amount: order_usdc.to_string(),
+        question: String::new(),
+        outcome: String::new(),
+        category: String::new(),
+        block_number: 0,
+        cache_key: String::new(),
+    };
+    let order_id = uuid::Uuid::new_v4().to_string();
+    let created_at = chrono::Utc::now().to_rfc3339();
+
+    let submitted = if runs_simulated(&session.config) {
+        execute_simulated(
+            &trade,
+            session,
+            order_usdc,
+            price,
+            Side::Sell,
+            &order_id,
+            &created_at,
+            http,
+            user_db,
+            update_tx,
+        )
+        .await
+    } else {
+        execute_live(
+            &trade,
+            session,
+            order_usdc,
+            price,
+            Side::Sell,
+            CopyOrderType::FOK,
+            &order_id,
+            &created_at,
+            clob_client,
+            user_db,
+            update_tx,
+            order_timestamps,
+        )
+        .await
+    };
+
+    if !submitted {
+        return;
+    }
+    session.order_timestamps.push_back(now);
+    order_timestamps.push_back(now);
+
+    // A submitted FOK can still come back unmatched: only a fill closes the position
+    let status = {
+        let conn = user_db.lock().unwrap_or_else(|p| p.into_inner());
+        db::get_copytrade_order(&conn, &sid, &order_id)
+            .ok()
+            .flatten()
+            .map(|o| o.status)
+    };
+    if !status.as_deref().is_some_and(close_filled) {
+        let status = status.unwrap_or_else(|| "unknown".to_string());
+        tracing::warn!(
+            "Session {sid}: {reason} close of {asset_id} not filled ({status}), position left open"
+        );
+        audit_order(
+            user_db,
+            &sid,
+            Some(&order_id),
+            "close_failed",
+            || serde_json::json!({ "reason": reason, "asset_id": asset_id, "status": status }),
+        );
+        let _ = update_tx.send(CopyTradeUpdate::OrderFailed {
+            session_id: sid,
+            order_id,
+            error: format!("{reason} close not filled ({status})"),
+            owner: session.config.owner.clone(),
+        });
+        return;
+    }
+    tracing::info!("Session {sid}: closed {shares:.4} shares of {asset_id} ({reason})");
+    let _ = update_tx.send(CopyTradeUpdate::PositionClosed {
+        session_id: sid,
+        order_id,
+        asset_id: asset_id.to_string(),
+        shares,
+        reason: reason.to_string(),
+        owner: session.config.owner.clone(),
+    });
+}
+
+/// Whether a closing order's recorded status means the shares actually sold.
+fn close_filled(status: &str) -> bool {
+    status == OrderStatus::Filled.as_str() || status == OrderStatus::Simulated.as_str()
+}
+
+/// Live midpoints for `ids`, fetching only the assets not already in the cache. Assets
+/// the CLOB had no price for are absent from the result.
+async fn live_marks(
+    http: &reqwest::Client,
+    cache: &mut MarkCache,
+    ids: &[String],
+) -> HashMap<String, f64> {
+    let missing: Vec<String> = ids
+        .iter()
+        .filter(|id| !cache.contains_key(*id))
+        .cloned()
+        .collect();
+    if !missing.is_empty() {
+        let now = Instant::now();
+        for (id, price) in super::copytrade::fetch_clob_midpoints(http, &missing).await {
+            cache.insert(id, (price, now));
+        }
+    }
+    ids.iter()
+        .filter_map(|id| cache.get(id).map(|(price, _)| (id.clone(), *price)))
+        .collect()
+}
+
+/// Positions whose last fill is older than `stale_secs` (or unknown).
+fn stale_position_ids(
+    user_db: &Mutex<rusqlite::Connection>,
+    session: &ActiveSession,
+    stale_secs: u32,
+) -> Vec<String> {
+    let last_fills = {
+        let conn = user_db.lock().unwrap_or_else(|p| p.into_inner());
+        match db::get_last_fill_times(&conn, &session.config.id) {
+            Ok(t) => t,
+            Err(e) => {
+                tracing::warn!(
+                    "Session {}: last fill times unreadable, keeping last-fill marks: {e}",
+                    session.config.id
+                );
+                return Vec::new();
+            }
+        }
+    };
+    let cutoff = chrono::Utc::now() - chrono::Duration::seconds(stale_secs as i64);
+    session
+        .positions
+        .keys()
+        .filter(|asset_id| {
+            last_fills
+                .get(*asset_id)
+                .and_then(|t| chrono::DateTime::parse_from_rfc3339(t).ok())
+                .is_none_or(|t| t < cutoff)
+        })
+        .cloned()
+        .collect()
+}
+
+/// Trailing stop: armed once the peak cleared the entry by `activate_pct`, fires when
+/// the mark has given back `distance_pct` of the peak.
+fn trailing_stop_hit(
+    entry: f64,
+    peak: f64,
+    mark: f64,
+    activate_pct: f64,
+    distance_pct: f64,
+) -> bool {
+    entry > 0.0
+        && peak >= entry * (1.0 + activate_pct / 100.0)
+        && mark <= peak * (1.0 - distance_pct / 100.0)
+}
+
+/// Which per-position exit, if any, a mark triggers: stop_loss_pct / take_profit_pct
+/// against the average entry, then the trailing stop.
+fn position_exit(
+    config: &CopyTradeSessionRow,
+    entry: f64,
+    peak: f64,
+    mark: f64,
+) -> Option<&'static str> {
+    if entry <= 0.0 {
+        return None;
+    }
+    let return_pct = (mark / entry - 1.0) * 100.0;
+    if config.stop_loss_pct.is_some_and(|pct| return_pct <= -pct) {
+        return Some("stop_loss");
+    }
+    if config.take_profit_pct.is_some_and(|pct| return_pct >= pct) {
+        return Some("take_profit");
+    }
+    match (config.trail_activate_pct, config.trail_distance_pct) {
+        (Some(activate_pct), Some(distance_pct))
+            if trailing_stop_hit(entry, peak, mark, activate_pct, distance_pct) =>
+        {
+            Some("trailing_stop")
+        }
+        _ => None,
+    }
+}
+
+fn has_position_exits(config: &CopyTradeSessionRow) -> bool {
+    config.take_profit_pct.is_some()
+        || config.stop_loss_pct.is_some()
+        || (config.trail_activate_pct.is_some() && config.trail_distance_pct.is_some())
+}
+
+/// Ratchets each position's peak up to the live midpoint, then sells out the ones whose
+/// stop loss, take profit or trailing stop fired. Entry is the average buy price from
+/// the order history. Simulated sessions go through the same close path.
+#[allow(clippy::too_many_arguments)]
+async fn check_position_exits(
+    session: &mut ActiveSession,
+    mark_cache: &mut MarkCache,
+    clob_client: &Arc<RwLock<Option<ClobClientState>>>,
+    http: &reqwest::Client,
+    user_db: &Arc<Mutex<rusqlite::Connection>>,
+    update_tx: &broadcast::Sender<CopyTradeUpdate>,
+    order_timestamps: &mut VecDeque<Instant>,
+) {
+    let sid = session.config.id.clone();
+    let ids: Vec<String> = session.positions.keys().cloned().collect();
+    let marks = live_marks(http, mark_cache, &ids).await;
+    let entries: HashMap<String, (f64, String)> = {
+        let conn = user_db.lock().unwrap_or_else(|p| p.into_inner());
+        match db::get_positions_raw(&conn, &sid) {
+            Ok(rows) => rows
+                .into_iter()
+                .map(|p| {
+                    let trader = p.source_traders.split(',').min().unwrap_or_default();
+                    let entry = (p.cost_basis / p.buy_shares, trader.to_string());
+                    (p.asset_id, entry)
+                })
+                .collect(),
+            Err(e) => {
+                tracing::warn!("Session {sid}: position exits skipped, positions unreadable: {e}");
+                return;
+            }
+        }
+    };
+
+    // Checked again next health tick, once the hold has elapsed
+    let held_too_briefly: HashSet<String> = {
+        let session: &ActiveSession = session;
+        session
+            .positions
+            .keys()
+            .filter(|id| within_min_hold(session, id))
+            .cloned()
+            .collect()
+    };
+    let mut to_close: Vec<(String, f64, String, &'static str)> = Vec::new();
+    for (asset_id, (shares, _, peak)) in session.positions.iter_mut() {
+        let Some(&mark) = marks.get(asset_id) else {
+            continue;
+        };
+        *peak = peak.max(mark);
+        let Some((entry, trader)) = entries.get(asset_id) else {
+            continue;
+        };
+        if let Some(reason) = position_exit(&session.config, *entry, *peak, mark) {
+            if held_too_briefly.contains(asset_id) {
+                tracing::debug!(
+                    "Session {sid}: {reason} on {asset_id} deferred until min_hold_secs"
+                );
+                continue;
+            }
+            tracing::info!(
+                "Session {sid}: {reason} on {asset_id}: mark {mark:.4}, entry {entry:.4}, peak {:.4}",
+                *peak
+            );
+            to_close.push((asset_id.clone(), *shares, trader.clone(), reason));
+        }
+    }
+
+    for (asset_id, shares, trader, reason) in to_close {
+        close_position_at_market(
+            session,
+            &asset_id,
+            shares,
+            &trader,
+            reason,
+            clob_client,
+            http,
+            user_db,
+            update_tx,
+            order_timestamps,
+        )
+        .await;
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Order decision (pure — shared by the pipeline and the what-if endpoint)
+// ---------------------------------------------------------------------------
+
+/// Why a source trade would not be copied.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) enum SkipReason {
+    /// Sell from the source, but we hold none of the asset
+    NoPosition,
+    /// The asset's market already holds max_market_usdc of exposure
+    MarketCap,
+    /// Sized order is below MIN_ORDER_USDC
+    BelowMinimum,
+    InsufficientCapital {
+        needed: f64,
+    },
+    /// Price moved more than max_slippage_bps against us
+    Slippage,
+    /// only_favorable session and the price moved against us at all
+    UnfavorableSlippage,
+    /// Source price outside [MIN_OUTCOME_PRICE, MAX_OUTCOME_PRICE]
+    PriceOutOfRange,
+    /// The CLOB has no book (or no resting orders) on our side of the market
+    NoLiquidity,
+    /// The source trade is older than the session's max_signal_age_secs
+    StaleSignal,
+    /// Sell of a position opened less than min_hold_secs ago
+    MinHold,
+}
+
+impl SkipReason {
+    pub(crate) fn as_str(&self) -> &'static str {
+        match self {
+            Self::NoPosition => "no_position",
+            Self::MarketCap => "market_cap",
+            Self::BelowMinimum => "below_minimum",
+            Self::InsufficientCapital { .. } => "insufficient_capital",
+            Self::Slippage => "slippage",
+            Self::UnfavorableSlippage => "unfavorable_slippage",
+            Self::PriceOutOfRange => "price_out_of_range",
+            Self::NoLiquidity => "no_liquidity",
+            Self::StaleSignal => "stale_signal",
+            Self::MinHold => "min_hold",
+        }
+    }
+}
+
+/// Age of a source trade from its `block_timestamp`: unix seconds (decimal or 0x hex,
+/// as rindexer sends it) or RFC 3339 (seeded trades). None when it can't be parsed,
+/// e.g. the empty timestamp of engine-generated exits.
+fn signal_age_secs(block_timestamp: &str) -> Option<i64> {
+    let ts = block_timestamp.trim();
+    let secs = if let Some(hex) = ts.strip_prefix("0x") {
+        i64::from_str_radix(hex, 16).ok()?
+    } else if let Ok(secs) = ts.parse::<i64>() {
+        secs
+    } else {
+        chrono::DateTime::parse_from_rfc3339(ts).ok()?.timestamp()
+    };
+    Some(chrono::Utc::now().timestamp() - secs)
+}
+
+fn session_orders_per_minute(config: &CopyTradeSessionRow) -> u32 {
+    config
+        .max_orders_per_minute
+        .unwrap_or(DEFAULT_SESSION_ORDERS_PER_MINUTE)
+}
+
+/// One-minute sliding-window rate limits: the session's own max_orders_per_minute, then
+/// the global ceiling shared by every session. Returns the limit that is hit, if any.
+/// Callers record a submission in both windows.
+fn rate_limit_exceeded(
+    config: &CopyTradeSessionRow,
+    session_timestamps: &mut VecDeque<Instant>,
+    global_timestamps: &mut VecDeque<Instant>,
+    now: Instant,
+) -> Option<String> {
+    let window = Duration::from_secs(60);
+    session_timestamps.retain(|t| now.duration_since(*t) < window);
+    global_timestamps.retain(|t| now.duration_since(*t) < window);
+    let session_max = session_orders_per_minute(config);
+    if session_timestamps.len() >= session_max as usize {
+        Some(format!("{session_max} orders/min for this session"))
+    } else if global_timestamps.len() >= MAX_ORDERS_PER_MINUTE {
+        Some(format!(
+            "{MAX_ORDERS_PER_MINUTE} orders/min across sessions"
+        ))
+    } else {
+        None
+    }
+}
+
+/// The copy window: a signal older than the session's max_signal_age_secs is no longer
+/// worth acting on. Trades without a readable timestamp pass.
+pub(crate) fn check_signal_age(
+    config: &CopyTradeSessionRow,
+    block_timestamp: &str,
+) -> Result<(), SkipReason> {
+    let max_age = config
+        .max_signal_age_secs
+        .unwrap_or(DEFAULT_MAX_SIGNAL_AGE_SECS);
+    match signal_age_secs(block_timestamp) {
+        Some(age) if age > max_age as i64 => Err(SkipReason::StaleSignal),
+        _ => Ok(()),
+    }
+}
+
+/// Session state the sizing step depends on.
+pub(crate) struct SizingInputs<'a> {
+    pub config: &'a CopyTradeSessionRow,
+    /// The source trader's normalised share of the copy_pct budget
+    pub trader_weight: f64,
+    pub remaining_capital: f64,
+    /// Shares we currently hold in the traded asset
+    pub held_shares: f64,
+    /// How long that position has been open; None skips the min_hold_secs check
+    pub held_secs: Option<u64>,
+    /// Combined exposure in the asset's market; None skips the per-market cap
+    pub market_exposure: Option<f64>,
+    /// Source trader's book in USDC for portfolio sizing; None falls back to dollar sizing
+    pub source_portfolio: Option<f64>,
+}
+
+/// Steps 4–5 of the pipeline: direction-aware sizing, the per-market cap, the minimum
+/// order size and the capital check. Returns the order size in USDC.
+pub(crate) fn size_order(
+    inputs: &SizingInputs,
+    side: Side,
+    source_price: f64,
+    trade_usdc: f64,
+) -> Result<f64, SkipReason> {
+    if !valid_outcome_price(source_price) {
+        return Err(SkipReason::PriceOutOfRange);
+    }
+    let config = inputs.config;
+    let copy_pct = config.copy_pct;
+    let mut order_usdc = match side {
+        Side::Buy => {
+            let base = match SizingBase::from_str(&config.sizing_base) {
+                Some(SizingBase::Initial) => config.initial_capital,
+                _ => inputs.remaining_capital,
+            };
+            let per_trader_budget = base * copy_pct * inputs.trader_weight;
+            let target = match (
+                SizingMode::from_str(&config.sizing_mode),
+                inputs.source_portfolio,
+            ) {
+                (Some(SizingMode::Portfolio), Some(book)) if book > 0.0 => {
+                    // The book can lag the fill being copied; never count it below the trade
+                    let fraction = trade_usdc / book.max(trade_usdc);
+                    config.initial_capital * fraction * copy_pct
+                }
+                _ => trade_usdc * copy_pct,
             };
-            (trade_usdc * copy_pct)
-                .min(per_trader_budget)
-                .min(session.config.max_position_usdc)
+            target.min(per_trader_budget).min(config.max_position_usdc)
         }
-        Side::Sell => {
+        _ => {
             // For sells, size based on our position, not capital
-            let (cur_shares, _) = session
-                .positions
-                .get(&trade.asset_id)
+            if inputs.held_shares <= 0.0 {
+                return Err(SkipReason::NoPosition);
+            }
+            if inputs
+                .held_secs
+                .is_some_and(|secs| secs < config.min_hold_secs as u64)
+            {
+                return Err(SkipReason::MinHold);
+            }
+            let our_sell_shares = match SellBehavior::from_str(&config.sell_behavior) {
+                // Any sell by the source closes our whole position
+                Some(SellBehavior::FullExit) => inputs.held_shares,
+                // Mirror the source trader's sell proportion, capped by our holdings
+                _ => {
+                    let source_shares = trade_usdc / source_price;
+                    (source_shares * copy_pct).min(inputs.held_shares)
+                }
+            };
+            our_sell_shares * source_price // Convert to USDC equivalent for the order
+        }
+    };
+
+    // Combined exposure across all outcome tokens of one market (NegRisk events),
+    // on top of the per-order max_position_usdc
+    if let (Side::Buy, Some(cap), Some(exposure)) =
+        (side, config.max_market_usdc, inputs.market_exposure)
+    {
+        let headroom = cap - exposure;
+        if headroom < MIN_ORDER_USDC {
+            return Err(SkipReason::MarketCap);
+        }
+        order_usdc = order_usdc.min(headroom);
+    }
+
+    if order_usdc < MIN_ORDER_USDC {
+        return Err(SkipReason::BelowMinimum);
+    }
+    // Only buys spend capital — sells add it
+    if matches!(side, Side::Buy) && inputs.remaining_capital < order_usdc {
+        return Err(SkipReason::InsufficientCapital { needed: order_usdc });
+    }
+    Ok(order_usdc)
+}
+
+pub(crate) fn valid_outcome_price(price: f64) -> bool {
+    price.is_finite() && (MIN_OUTCOME_PRICE..=MAX_OUTCOME_PRICE).contains(&price)
+}
+
+/// Rounds an order quantity to `scale` decimals per the session's size_rounding.
+/// `Nearest` keeps a rounded-up value only while it costs at most `max_usdc` at
+/// `unit_usdc` per unit; past that it rounds down like `Down`.
+fn round_order_size(
+    value: f64,
+    scale: u32,
+    rounding: SizeRounding,
+    unit_usdc: f64,
+    max_usdc: f64,
+) -> Option<Decimal> {
+    let exact = Decimal::from_f64_retain(value)?;
+    let down = exact.trunc_with_scale(scale);
+    if rounding == SizeRounding::Down {
+        return Some(down);
+    }
+    let nearest = exact.round_dp_with_strategy(scale, RoundingStrategy::MidpointAwayFromZero);
+    if nearest > down && nearest.to_f64()? * unit_usdc > max_usdc {
+        Some(down)
+    } else {
+        Some(nearest)
+    }
+}
+
+/// What a matched FOK/FAK order moved, from the CLOB's making/taking amounts
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct MatchedFill {
+    /// USDC per share; the quoted price when either amount is zero
+    price: f64,
+    shares: f64,
+    /// USDC spent (buy) or received (sell)
+    usdc: f64,
+    /// Part of the requested USDC left unmatched (a FAK's canceled remainder)
+    remaining_usdc: f64,
+}
+
+/// Buy: making = USDC sent, taking = shares received. Sell: making = shares sent,
+/// taking = USDC received.
+fn matched_fill(
+    side: Side,
+    making: Decimal,
+    taking: Decimal,
+    order_usdc: f64,
+    quoted_price: f64,
+) -> MatchedFill {
+    let making = making.to_f64().unwrap_or(0.0);
+    let taking = taking.to_f64().unwrap_or(0.0);
+    let (usdc, shares) = match side {
+        Side::Buy => (making, taking),
+        _ => (taking, making),
+    };
+    MatchedFill {
+        price: if usdc > 0.0 && shares > 0.0 {
+            usdc / shares
+        } else {
+            quoted_price
+        },
+        shares,
+        usdc,
+        remaining_usdc: (order_usdc - usdc).max(0.0),
+    }
+}
+
+/// Amount of a FOK/FAK market order at the CLOB's precision, rounded per
+/// `round_order_size`: USDC cents for buys, shares at the lot size for sells (the
+/// CLOB takes market sells in shares). None when it isn't finite.
+fn market_order_size(
+    side: Side,
+    order_usdc: f64,
+    price: f64,
+    rounding: SizeRounding,
+    max_usdc: f64,
+) -> Option<Decimal> {
+    match side {
+        Side::Buy => round_order_size(
+            order_usdc,
+            MARKET_BUY_USDC_DECIMALS,
+            rounding,
+            1.0,
+            max_usdc,
+        ),
+        _ => {
+            let shares = order_usdc / price;
+            if !shares.is_finite() {
+                return None;
+            }
+            round_order_size(shares, LOT_SIZE_DECIMALS, rounding, price, max_usdc)
+        }
+    }
+}
+
+/// Share size of a GTC limit order at the CLOB's lot size, rounded per
+/// `round_order_size`. None when it isn't finite or falls below MIN_GTC_SHARES.
+fn gtc_share_size(
+    order_usdc: f64,
+    price: f64,
+    rounding: SizeRounding,
+    max_usdc: f64,
+) -> Option<Decimal> {
+    let shares = order_usdc / price;
+    if !shares.is_finite() {
+        return None;
+    }
+    let size = round_order_size(shares, LOT_SIZE_DECIMALS, rounding, price, max_usdc)?;
+    (size.to_f64()? >= MIN_GTC_SHARES).then_some(size)
+}
+
+/// GTC limit price: `reference` moved by `gtc_price_offset_bps` toward filling (up
+/// for buys, down for sells), then capped at the session's slippage bound around
+/// the source price (zero with `only_favorable`, net of `slippage_fee_bps`) and kept
+/// inside the valid range.
+fn gtc_limit_price(
+    config: &CopyTradeSessionRow,
+    max_slippage_bps: u32,
+    side: Side,
+    source_price: f64,
+    reference: f64,
+) -> f64 {
+    let offset = config.gtc_price_offset_bps as f64 / 10000.0;
+    let bound = if config.only_favorable {
+        0.0
+    } else {
+        max_slippage_bps as f64 / 10000.0
+    };
+    let fee = fee_factor(config, side);
+    let limit = match side {
+        Side::Buy => (reference * (1.0 + offset)).min(source_price * (1.0 + bound) / fee),
+        _ => (reference * (1.0 - offset)).max(source_price * (1.0 - bound) / fee),
+    };
+    limit.clamp(MIN_OUTCOME_PRICE, MAX_OUTCOME_PRICE)
+}
+
+/// Slippage of `current_price` vs the source fill in bps; positive means worse for us.
+pub(crate) fn signed_slippage_bps(side: Side, source_price: f64, current_price: f64) -> f64 {
+    match side {
+        Side::Buy => (current_price - source_price) / source_price * 10000.0,
+        _ => (source_price - current_price) / source_price * 10000.0,
+    }
+}
+
+/// Price used in the slippage gate: with `slippage_fee_bps` set, the all-in cost of
+/// filling at `price` (a buy pays the fee on top, a sell receives less).
+pub(crate) fn fee_adjusted_price(config: &CopyTradeSessionRow, side: Side, price: f64) -> f64 {
+    price * fee_factor(config, side)
+}
+
+fn fee_factor(config: &CopyTradeSessionRow, side: Side) -> f64 {
+    let fee = config.slippage_fee_bps.unwrap_or(0) as f64 / 10000.0;
+    match side {
+        Side::Buy => 1.0 + fee,
+        _ => 1.0 - fee,
+    }
+}
+
+/// Slippage bound for `asset_id`: its entry in `slippage_overrides`, else the
+/// session's max_slippage_bps. Malformed JSON is ignored (the API validates on create).
+pub(crate) fn max_slippage_bps_for(config: &CopyTradeSessionRow, asset_id: &str) -> u32 {
+    config
+        .slippage_overrides
+        .as_deref()
+        .and_then(|json| serde_json::from_str::<HashMap<String, u32>>(json).ok())
+        .and_then(|overrides| overrides.get(asset_id).copied())
+        .unwrap_or(config.max_slippage_bps)
+}
+
+/// Step 7: the session's slippage gate, against the asset's bound from
+/// `max_slippage_bps_for`.
+pub(crate) fn check_slippage(
+    config: &CopyTradeSessionRow,
+    max_slippage_bps: u32,
+    slippage_bps: f64,
+) -> Result<(), SkipReason> {
+    if slippage_bps > max_slippage_bps as f64 {
+        return Err(SkipReason::Slippage);
+    }
+    // Conservative mode: never pay up relative to the source trader
+    if config.only_favorable && slippage_bps > 0.0 {
+        return Err(SkipReason::UnfavorableSlippage);
+    }
+    Ok(())
+}
+
+// ---------------------------------------------------------------------------
+// Trade processing (the 11-step pipeline)
+// ---------------------------------------------------------------------------
+
+#[allow(clippy::too_many_arguments)]
+async fn process_trade(
+    trade: &LiveTrade,
+    session: &mut ActiveSession,
+    clob_client: &Arc<RwLock<Option<ClobClientState>>>,
+    http: &reqwest::Client,
+    ch_db: &clickhouse::Client,
+    user_db: &Arc<Mutex<rusqlite::Connection>>,
+    update_tx: &broadcast::Sender<CopyTradeUpdate>,
+    order_timestamps: &mut VecDeque<Instant>,
+    perf_cache: &mut TraderPerfCache,
+    volume_cache: &AssetVolumeCache,
+    portfolio_cache: &mut TraderPortfolioCache,
+    market_cache: &MarketCache,
+    empty_balance_grace: Duration,
+) {
+    let sid = &session.config.id;
+
+    // 1. FILTER — is trader in watched set? whale_follow sessions copy any trader,
+    // but are only fed trades from the whale alert feed.
+    let whale_follow = is_whale_follow(&session.config);
+    if !whale_follow && !session.traders.contains_key(&trade.trader.to_lowercase()) {
+        return;
+    }
+
+    let source_side = match trade.side.to_lowercase().as_str() {
+        "buy" => Side::Buy,
+        "sell" => Side::Sell,
+        _ => return,
+    };
+
+    // SIGNAL FILTERS — category, asset allowlist, copy window, fade and copy_sides
+    let side = match filter_signal(
+        &session.config,
+        session.asset_allowlist.as_ref(),
+        &trade.category,
+        &trade.asset_id,
+        &trade.block_timestamp,
+        source_side,
+    ) {
+        Ok(side) => side,
+        Err(reason) => {
+            if reason == SkipReason::StaleSignal.as_str() {
+                tracing::info!(
+                    "Session {sid}: skipping stale trade {} (block time {}, max {}s)",
+                    trade.tx_hash,
+                    trade.block_timestamp,
+                    session
+                        .config
+                        .max_signal_age_secs
+                        .unwrap_or(DEFAULT_MAX_SIGNAL_AGE_SECS)
+                );
+            } else {
+                tracing::debug!(
+                    "Session {sid}: skipping {} {} of {}: {reason}",
+                    trade.side,
+                    trade.tx_hash,
+                    trade.asset_id
+                );
+            }
+            skip_trade(session, trade, reason.to_string(), user_db, update_tx);
+            return;
+        }
+    };
+
+    // FADE — the flipped trade is what gets sized, deduped and recorded, so a
+    // fade-sell goes through position-based sizing (and is skipped without a
+    // position) and a fade-buy through the capital budget.
+    let faded;
+    let trade = if side == source_side {
+        trade
+    } else {
+        faded = LiveTrade {
+            side: match side {
+                Side::Buy => "buy",
+                _ => "sell",
+            }
+            .to_string(),
+            ..trade.clone()
+        };
+        &faded
+    };
+
+    // 2. COOLDOWN
+    if let Some(until) = session.cooldown_until {
+        if Instant::now() < until {
+            tracing::debug!("Session {sid} in cooldown, skipping trade");
+            return;
+        }
+        session.cooldown_until = None;
+        session.consecutive_failures = 0;
+    }
+
+    // 3. DEDUP — same asset_id + side within 30s?
+    let dedup_key = format!("{}:{}", trade.asset_id, trade.side);
+    if let Some(last) = session.recent_orders.get(&dedup_key) {
+        if last.elapsed() < DEDUP_WINDOW {
+            tracing::debug!("Dedup: already ordered {dedup_key} within 30s");
+            return;
+        }
+    }
+
+    // Parse amounts
+    let source_price = match trade.price.parse::<f64>() {
+        Ok(p) if p > 0.0 => p,
+        _ => return,
+    };
+    let trade_usdc = match trade.usdc_amount.parse::<f64>() {
+        Ok(u) if u > 0.0 => u,
+        _ => return,
+    };
+
+    // NEAR RESOLUTION — no new positions in closed markets or at near-certain prices,
+    // where a late entry has no edge. Market state comes from the (periodically
+    // refreshed) market cache; unknown markets are only checked by price.
+    if let (Side::Buy, Some(threshold)) = (side, session.config.near_resolution_price) {
+        let closed = market_cache
+            .read()
+            .await
+            .get(&trade.cache_key)
+            .is_some_and(|m| !m.active);
+        if closed || source_price >= threshold || source_price <= 1.0 - threshold {
+            tracing::info!(
+                "Session {sid}: skipping buy of {} at {source_price}: near resolution (closed={closed})",
+                trade.asset_id
+            );
+            skip_trade(
+                session,
+                trade,
+                "near_resolution".to_string(),
+                user_db,
+                update_tx,
+            );
+            return;
+        }
+    }
+
+    // CONFIDENCE — skip buys from traders whose recent performance fell below the
+    // session thresholds. Sells are always mirrored so open positions can still exit.
+    if matches!(side, Side::Buy)
+        && (session.config.min_trader_pnl.is_some() || session.config.min_trader_winrate.is_some())
+        && let Some(perf) = trader_recent_perf(ch_db, perf_cache, &trade.trader).await
+        && let Some(reason) = confidence_skip_reason(&session.config, perf)
+    {
+        tracing::info!(
+            "Session {sid}: skipping trade from {}: {reason}",
+            trade.trader
+        );
+        skip_trade(session, trade, reason, user_db, update_tx);
+        return;
+    }
+
+    // LIQUIDITY — skip buys into assets that barely traded recently (dead book).
+    // Sells are always mirrored so open positions can still exit.
+    let window_mins = session
+        .config
+        .liquidity_window_mins
+        .unwrap_or(DEFAULT_LIQUIDITY_WINDOW_MINS);
+    if let (Side::Buy, Some(min_volume)) = (side, session.config.min_liquidity_usdc)
+        && let Some(volume) = asset_recent_volume(ch_db, volume_cache, &trade.asset_id, window_mins)
+        && volume < min_volume
+    {
+        tracing::info!(
+            "Session {sid}: skipping {}: {volume:.2} USDC traded in {window_mins}m (min {min_volume:.2})",
+            trade.asset_id
+        );
+        skip_trade(session, trade, "illiquid".to_string(), user_db, update_tx);
+        return;
+    }
+
+    // 4. SIZING (direction-aware, per-market cap) + 5. BALANCE
+    let source_portfolio = match (side, SizingMode::from_str(&session.config.sizing_mode)) {
+        (Side::Buy, Some(SizingMode::Portfolio)) => {
+            trader_portfolio_value(ch_db, portfolio_cache, &trade.trader).await
+        }
+        _ => None,
+    };
+    let market_exposure = match (side, session.config.max_market_usdc) {
+        (Side::Buy, Some(_)) => {
+            market_exposure(market_cache, &session.positions, &trade.asset_id).await
+        }
+        _ => None,
+    };
+    let inputs = SizingInputs {
+        config: &session.config,
+        // whale_follow has no trader set; the whole copy_pct budget is one slot
+        trader_weight: if whale_follow {
+            1.0
+        } else {
+            session
+                .traders
+                .get(&trade.trader.to_lowercase())
                 .copied()
-                .unwrap_or((0.0, 0.0));
-            if cur_shares <= 0.0 {
-                return; // No position to sell
+                .unwrap_or(0.0)
+        },
+        remaining_capital: session.remaining_capital,
+        held_shares: session
+            .positions
+            .get(&trade.asset_id)
+            .map(|(shares, _, _)| *shares)
+            .unwrap_or(0.0),
+        held_secs: session
+            .position_opened
+            .get(&trade.asset_id)
+            .map(|opened| opened.elapsed().as_secs()),
+        market_exposure,
+        source_portfolio,
+    };
+    let order_usdc = match size_order(&inputs, side, source_price, trade_usdc) {
+        Ok(usdc) => usdc,
+        Err(SkipReason::MarketCap) => {
+            tracing::info!(
+                "Session {sid}: skipping {}: market exposure at cap {:.2} USDC",
+                trade.asset_id,
+                session.config.max_market_usdc.unwrap_or_default()
+            );
+            let reason = SkipReason::MarketCap.as_str().to_string();
+            skip_trade(session, trade, reason, user_db, update_tx);
+            return;
+        }
+        Err(SkipReason::MinHold) => {
+            tracing::info!(
+                "Session {sid}: deferring sell of {}: held under min_hold_secs ({}s)",
+                trade.asset_id,
+                session.config.min_hold_secs
+            );
+            let reason = SkipReason::MinHold.as_str().to_string();
+            skip_trade(session, trade, reason, user_db, update_tx);
+            return;
+        }
+        Err(SkipReason::PriceOutOfRange) => {
+            tracing::warn!(
+                "Session {sid}: skipping {}: source price {source_price} outside [{MIN_OUTCOME_PRICE}, {MAX_OUTCOME_PRICE}]",
+                trade.asset_id
+            );
+            let reason = SkipReason::PriceOutOfRange.as_str().to_string();
+            skip_trade(session, trade, reason, user_db, update_tx);
+            return;
+        }
+        Err(SkipReason::InsufficientCapital { needed }) => {
+            tracing::warn!(
+                "Session {sid}: insufficient capital ({:.2} < {needed:.2})",
+                session.remaining_capital
+            );
+            if session.remaining_capital < MIN_ORDER_USDC {
+                // Auto-pause on empty balance — after a grace window (enforced in health_check),
+                // since a GTC refund or a sell may replenish capital shortly
+                if empty_balance_grace.is_zero() {
+                    pause_for_empty_balance(session, user_db, update_tx);
+                } else if session.low_balance_since.is_none() {
+                    tracing::info!(
+                        "Session {sid}: capital below {MIN_ORDER_USDC} USDC, auto-pause in {}s unless replenished",
+                        empty_balance_grace.as_secs()
+                    );
+                    session.low_balance_since = Some(Instant::now());
+                }
             }
-            // Mirror the source trader's sell proportion, capped by our holdings
-            let source_shares = trade_usdc / source_price;
-            let our_sell_shares = (source_shares * copy_pct).min(cur_shares);
-            our_sell_shares * source_price // Convert to USDC equivalent for the order
+            return;
         }
-        _ => return,
+        Err(_) => return,
     };
 
-    if order_usdc < MIN_ORDER_USDC {
-        return;
-    }
-
-    // 5. BALANCE (only check for buys — sells add capital)
-    if matches!(side, Side::Buy) && session.remaining_capital < order_usdc {
-        tracing::warn!(
-            "Session {sid}: insufficient capital ({:.2} < {:.2})",
-            session.remaining_capital,
-            order_usdc
-        );
-        if session.remaining_capital < MIN_ORDER_USDC {
-            // Auto-pause on empty balance
-            session.config.status = "paused".to_string();
-            let conn = user_db.lock().unwrap_or_else(|p| p.into_inner());
-            let _ = db::update_session_status(&conn, &session.config.id, "paused");
-            let _ = update_tx.send(CopyTradeUpdate::SessionPaused {
-                session_id: sid.clone(),
-                owner: session.config.owner.clone(),
-            });
-        }
-        return;
-    }
-
-    // 6. RATE LIMIT (global)
+    // 6. RATE LIMIT (per session, then the global ceiling)
     let now = Instant::now();
-    order_timestamps.retain(|t| now.duration_since(*t) < Duration::from_secs(60));
-    if order_timestamps.len() >= MAX_ORDERS_PER_MINUTE {
-        tracing::warn!("Rate limit: {MAX_ORDERS_PER_MINUTE} orders/min exceeded");
+    if let Some(limit) = rate_limit_exceeded(
+        &session.config,
+        &mut session.order_timestamps,
+        order_timestamps,
+        now,
+    ) {
+        tracing::warn!("Session {sid}: rate limit: {limit} exceeded");
+        skip_trade(
+            session,
+            trade,
+            "rate_limited".to_string(),
+            user_db,
+            update_tx,
+        );
         return;
     }
 
-    let order_type =
-        CopyOrderType::from_str(&session.config.order_type).unwrap_or(CopyOrderType::FOK);
+    // Per-side override, falling back to the session-wide order type
+    let side_order_type = match side {
+        Side::Buy => session.config.buy_order_type.as_deref(),
+        _ => session.config.sell_order_type.as_deref(),
+    };
+    let order_type = side_order_type
+        .and_then(CopyOrderType::from_str)
+        .or_else(|| CopyOrderType::from_str(&session.config.order_type))
+        .unwrap_or(CopyOrderType::FOK);
 
     // 7. SLIPPAGE CHECK + 8. EXECUTE
     let order_id = uuid::Uuid::new_v4().to_string();
     let created_at = chrono::Utc::now().to_rfc3339();
+    audit_order(user_db, sid, Some(&order_id), "decision", || {
+        serde_json::json!({
+            "trade": trade,
+            "mode": session.config.mode,
+            "simulate": session.config.simulate,
+            "order_type": order_type.as_str(),
+            "source_price": source_price,
+            "source_usdc": trade_usdc,
+            "order_usdc": order_usdc,
+            "sizing": {
+                "mode": session.config.sizing_mode,
+                "copy_pct": session.config.copy_pct,
+                "trader_weight": inputs.trader_weight,
+                "remaining_capital": inputs.remaining_capital,
+                "held_shares": inputs.held_shares,
+                "market_exposure": inputs.market_exposure,
+                "source_portfolio": inputs.source_portfolio,
+            },
+        })
+    });
 
-    let submitted = if session.config.simulate {
+    let submitted = if runs_simulated(&session.config) {
         execute_simulated(
             trade,
             session,
@@ -574,7 +2584,7 @@ async fn process_trade(
             side,
             &order_id,
             &created_at,
-            clob_client,
+            http,
             user_db,
             update_tx,
         )
@@ -592,21 +2602,630 @@ async fn process_trade(
             clob_client,
             user_db,
             update_tx,
+            order_timestamps,
         )
         .await
     };
 
+    let sid = &session.config.id;
+    audit_order(user_db, sid, Some(&order_id), "result", || {
+        let order = {
+            let conn = user_db.lock().unwrap_or_else(|p| p.into_inner());
+            db::get_copytrade_order(&conn, sid, &order_id)
+                .ok()
+                .flatten()
+        };
+        serde_json::json!({
+            "submitted": submitted,
+            "order": order.as_ref().map(order_audit_context),
+        })
+    });
+
     // Only record dedup + rate limit on actual submission
     if submitted {
         session.recent_orders.insert(dedup_key, now);
+        session.order_timestamps.push_back(now);
         order_timestamps.push_back(now);
+        // A sell brings capital back: cancel any pending empty-balance pause
+        if matches!(side, Side::Sell) && session.low_balance_since.take().is_some() {
+            tracing::info!(
+                "Session {}: sell placed, pending auto-pause canceled",
+                session.config.id
+            );
+        }
+    }
+}
+
+fn empty_balance_grace_from_env() -> Duration {
+    match std::env::var("COPYTRADE_EMPTY_BALANCE_GRACE_SECS") {
+        Ok(v) => match v.trim().parse::<u64>() {
+            Ok(secs) => Duration::from_secs(secs),
+            Err(_) => {
+                tracing::warn!(
+                    "Invalid COPYTRADE_EMPTY_BALANCE_GRACE_SECS={v}, using default {}s",
+                    DEFAULT_EMPTY_BALANCE_GRACE.as_secs()
+                );
+                DEFAULT_EMPTY_BALANCE_GRACE
+            }
+        },
+        Err(_) => DEFAULT_EMPTY_BALANCE_GRACE,
+    }
+}
+
+/// Rebuilds the dedup map from orders copied within DEDUP_WINDOW, so a trade replayed
+/// right after a restart (or a resume) isn't copied twice.
+fn restore_recent_orders(
+    user_db: &Mutex<rusqlite::Connection>,
+    session_id: &str,
+) -> HashMap<String, Instant> {
+    let now = chrono::Utc::now();
+    let window = chrono::Duration::from_std(DEDUP_WINDOW).unwrap_or_default();
+    let since = (now - window).to_rfc3339();
+    let keys = {
+        let conn = user_db.lock().unwrap_or_else(|p| p.into_inner());
+        db::get_recent_dedup_keys(&conn, session_id, &since).unwrap_or_default()
+    };
+    keys.into_iter()
+        .filter_map(|(key, created_at)| {
+            let created = chrono::DateTime::parse_from_rfc3339(&created_at).ok()?;
+            let age = (now - created.with_timezone(&chrono::Utc))
+                .to_std()
+                .unwrap_or_default();
+            Some((key, Instant::now().checked_sub(age)?))
+        })
+        .collect()
+}
+
+fn restore_position_opened(
+    user_db: &Mutex<rusqlite::Connection>,
+    session_id: &str,
+) -> HashMap<String, Instant> {
+    let now = chrono::Utc::now();
+    let opened = {
+        let conn = user_db.lock().unwrap_or_else(|p| p.into_inner());
+        db::get_position_open_times(&conn, session_id).unwrap_or_default()
+    };
+    opened
+        .into_iter()
+        .filter_map(|(asset_id, created_at)| {
+            let created = chrono::DateTime::parse_from_rfc3339(&created_at).ok()?;
+            let age = (now - created.with_timezone(&chrono::Utc))
+                .to_std()
+                .unwrap_or_default();
+            Some((asset_id, Instant::now().checked_sub(age)?))
+        })
+        .collect()
+}
+
+/// Starts the min-hold clock when a fill opens a position and drops it once flat.
+fn sync_position_opened(
+    positions: &HashMap<String, (f64, f64, f64)>,
+    position_opened: &mut HashMap<String, Instant>,
+    asset_id: &str,
+) {
+    if positions.contains_key(asset_id) {
+        position_opened
+            .entry(asset_id.to_string())
+            .or_insert_with(Instant::now);
+    } else {
+        position_opened.remove(asset_id);
+    }
+}
+
+/// Whether a held position is still inside the session's min_hold_secs window.
+fn within_min_hold(session: &ActiveSession, asset_id: &str) -> bool {
+    let min_hold = Duration::from_secs(session.config.min_hold_secs as u64);
+    !min_hold.is_zero()
+        && session
+            .position_opened
+            .get(asset_id)
+            .is_some_and(|opened| opened.elapsed() < min_hold)
+}
+
+/// The hard per-order cap, read from the environment once.
+fn max_order_usdc() -> f64 {
+    static CAP: std::sync::OnceLock<f64> = std::sync::OnceLock::new();
+    *CAP.get_or_init(|| match std::env::var("COPYTRADE_MAX_ORDER_USDC") {
+        Ok(v) => match v.trim().parse::<f64>() {
+            Ok(cap) if cap.is_finite() && cap > 0.0 => cap,
+            _ => {
+                tracing::warn!(
+                    "Invalid COPYTRADE_MAX_ORDER_USDC={v}, using default {DEFAULT_MAX_ORDER_USDC}"
+                );
+                DEFAULT_MAX_ORDER_USDC
+            }
+        },
+        Err(_) => DEFAULT_MAX_ORDER_USDC,
+    })
+}
+
+/// Whether a live order's size is sane: finite, positive and at most `cap`.
+fn within_order_cap(order_usdc: f64, cap: f64) -> bool {
+    order_usdc.is_finite() && order_usdc > 0.0 && order_usdc <= cap
+}
+
+/// What happens when a fill leaves `remaining_capital` below zero
+/// (COPYTRADE_NEGATIVE_CAPITAL)
+#[derive(Clone, Copy, PartialEq)]
+enum NegativeCapitalPolicy {
+    /// Clamp to zero and book the shortfall as fees (default)
+    Clamp,
+    /// Keep the negative balance (log only)
+    Allow,
+}
+
+fn negative_capital_policy() -> NegativeCapitalPolicy {
+    static POLICY: std::sync::OnceLock<NegativeCapitalPolicy> = std::sync::OnceLock::new();
+    *POLICY.get_or_init(|| match std::env::var("COPYTRADE_NEGATIVE_CAPITAL") {
+        Ok(v) => match v.trim().to_lowercase().as_str() {
+            "clamp" => NegativeCapitalPolicy::Clamp,
+            "allow" => NegativeCapitalPolicy::Allow,
+            _ => {
+                tracing::warn!("Invalid COPYTRADE_NEGATIVE_CAPITAL={v}, using default clamp");
+                NegativeCapitalPolicy::Clamp
+            }
+        },
+        Err(_) => NegativeCapitalPolicy::Clamp,
+    })
+}
+
+/// Settles a buy that spent more than the capital left (fee rounding, a fill
+/// slightly above the sized amount). Under the clamp policy the shortfall moves
+/// into `fees_paid` so capital never reports below zero.
+fn settle_negative_capital(sid: &str, remaining_capital: &mut f64, fees_paid: &mut f64) {
+    if *remaining_capital >= 0.0 {
+        return;
+    }
+    let shortfall = -*remaining_capital;
+    match negative_capital_policy() {
+        NegativeCapitalPolicy::Clamp => {
+            tracing::warn!(
+                "Session {sid}: remaining capital went {shortfall:.4} USDC negative, clamping to zero and booking it as fees"
+            );
+            *fees_paid += shortfall;
+            *remaining_capital = 0.0;
+        }
+        NegativeCapitalPolicy::Allow => {
+            tracing::warn!("Session {sid}: remaining capital is {shortfall:.4} USDC negative");
+        }
+    }
+}
+
+/// Truthy values accepted by on/off environment flags.
+fn env_flag(v: &str) -> bool {
+    matches!(v.trim().to_lowercase().as_str(), "1" | "true" | "yes")
+}
+
+/// FORCE_SIMULATE=true: every session trades simulated whatever its row says, so no
+/// real order reaches the CLOB. A safety switch for staging deployments that share
+/// a database with production.
+pub(crate) fn force_simulate() -> bool {
+    #[cfg(test)]
+    if let Some(forced) = FORCE_SIMULATE_OVERRIDE.get() {
+        return forced;
+    }
+    static FORCED: std::sync::OnceLock<bool> = std::sync::OnceLock::new();
+    *FORCED.get_or_init(|| {
+        std::env::var("FORCE_SIMULATE")
+            .map(|v| env_flag(&v))
+            .unwrap_or(false)
+    })
+}
+
+#[cfg(test)]
+thread_local! {
+    /// Turns FORCE_SIMULATE on or off for the current test thread only
+    pub(crate) static FORCE_SIMULATE_OVERRIDE: std::cell::Cell<Option<bool>> =
+        const { std::cell::Cell::new(None) };
+}
+
+/// Whether orders for this session are simulated (its own flag or FORCE_SIMULATE)
+pub(crate) fn runs_simulated(config: &CopyTradeSessionRow) -> bool {
+    config.simulate || force_simulate()
+}
+
+/// Whether the order audit log is on (`COPYTRADE_ORDER_AUDIT=true`). Off by default.
+fn order_audit_enabled() -> bool {
+    static ENABLED: std::sync::OnceLock<bool> = std::sync::OnceLock::new();
+    *ENABLED.get_or_init(|| {
+        std::env::var("COPYTRADE_ORDER_AUDIT")
+            .map(|v| env_flag(&v))
+            .unwrap_or(false)
+    })
+}
+
+/// Appends one lifecycle stage to `order_audit`. The context is only built when the
+/// audit log is enabled; a failed write is logged and never blocks the trade.
+fn audit_order(
+    user_db: &Mutex<rusqlite::Connection>,
+    session_id: &str,
+    order_id: Option<&str>,
+    stage: &str,
+    context: impl FnOnce() -> serde_json::Value,
+) {
+    if !order_audit_enabled() {
+        return;
+    }
+    let context = context().to_string();
+    let conn = user_db.lock().unwrap_or_else(|p| p.into_inner());
+    if let Err(e) = db::append_order_audit(&conn, session_id, order_id, stage, &context) {
+        tracing::warn!("Session {session_id}: failed to append order audit ({stage}): {e}");
+    }
+}
+
+/// Audit context for an order as recorded in `copy_trade_orders`.
+fn order_audit_context(order: &CopyTradeOrderRow) -> serde_json::Value {
+    serde_json::json!({
+        "status": order.status,
+        "clob_order_id": order.clob_order_id,
+        "side": order.side,
+        "price": order.price,
+        "source_price": order.source_price,
+        "size_usdc": order.size_usdc,
+        "size_shares": order.size_shares,
+        "fill_price": order.fill_price,
+        "slippage_bps": order.slippage_bps,
+        "tx_hash": order.tx_hash,
+        "error_message": order.error_message,
+    })
+}
+
+/// Persists a skipped source trade (for the throughput endpoint) and broadcasts it.
+fn skip_trade(
+    session: &ActiveSession,
+    trade: &LiveTrade,
+    reason: String,
+    user_db: &Arc<Mutex<rusqlite::Connection>>,
+    update_tx: &broadcast::Sender<CopyTradeUpdate>,
+) {
+    {
+        let conn = user_db.lock().unwrap_or_else(|p| p.into_inner());
+        if let Err(e) = db::insert_order_skip(
+            &conn,
+            &session.config.id,
+            &trade.tx_hash,
+            &trade.asset_id,
+            &reason,
+        ) {
+            tracing::warn!("Session {}: failed to record skip: {e}", session.config.id);
+        }
+    }
+    audit_order(
+        user_db,
+        &session.config.id,
+        None,
+        "skipped",
+        || serde_json::json!({ "trade": trade, "reason": reason }),
+    );
+    let _ = update_tx.send(CopyTradeUpdate::OrderSkipped {
+        session_id: session.config.id.clone(),
+        source_tx_hash: trade.tx_hash.clone(),
+        source_trader: trade.trader.clone(),
+        asset_id: trade.asset_id.clone(),
+        reason,
+        owner: session.config.owner.clone(),
+    });
+}
+
+fn pause_for_empty_balance(
+    session: &mut ActiveSession,
+    user_db: &Arc<Mutex<rusqlite::Connection>>,
+    update_tx: &broadcast::Sender<CopyTradeUpdate>,
+) {
+    session.config.status = "paused".to_string();
+    session.low_balance_since = None;
+    let conn = user_db.lock().unwrap_or_else(|p| p.into_inner());
+    let _ = db::update_session_status(&conn, &session.config.id, "paused");
+    let _ = update_tx.send(CopyTradeUpdate::SessionPaused {
+        session_id: session.config.id.clone(),
+        reason: Some("insufficient_capital".to_string()),
+        owner: session.config.owner.clone(),
+    });
+}
+
+/// Recent P&L and win rate for a source trader, cached for TRADER_PERF_TTL so the
+/// confidence filter doesn't hit ClickHouse on every trade. Returns None on query
+/// failure (the filter then lets the trade through).
+async fn trader_recent_perf(
+    ch_db: &clickhouse::Client,
+    cache: &mut TraderPerfCache,
+    trader: &str,
+) -> Option<TraderPerf> {
+    let trader = trader.to_lowercase();
+    if let Some((perf, fetched)) = cache.get(&trader)
+        && fetched.elapsed() < TRADER_PERF_TTL
+    {
+        return Some(*perf);
+    }
+
+    #[derive(clickhouse::Row, serde::Deserialize)]
+    struct PerfRow {
+        pnl: f64,
+        positions: u64,
+        wins: u64,
+    }
+
+    // trader_positions is an AggregatingMergeTree: a position can still span several
+    // unmerged rows, so fold them per asset before counting positions and wins
+    let query = format!(
+        "WITH resolved AS (
+            SELECT asset_id, toNullable(toFloat64(resolved_price)) AS resolved_price
+            FROM resolved_prices FINAL
+        ),
+        positions AS (
+            SELECT tp.asset_id AS asset_id,
+                sum(tp.buy_amount) AS buy_amount, sum(tp.sell_amount) AS sell_amount,
+                sum(tp.buy_usdc) AS buy_usdc, sum(tp.sell_usdc) AS sell_usdc,
+                max(tp.last_ts) AS last_ts
+            FROM trader_positions tp
+            WHERE lower(tp.trader) = ?
+            GROUP BY tp.asset_id
+            HAVING last_ts >= now() - INTERVAL {TRADER_PERF_WINDOW_DAYS} DAY
+        )
+        SELECT
+            toFloat64(sum({POSITION_PNL_EXPR})) AS pnl,
+            count() AS positions,
+            countIf({POSITION_PNL_EXPR} > 0) AS wins
+        FROM positions p
+        LEFT JOIN (SELECT asset_id, latest_price FROM asset_latest_price FINAL) AS lp ON p.asset_id = lp.asset_id
+        LEFT JOIN resolved rp ON p.asset_id = rp.asset_id"
+    );
+
+    let result = tokio::time::timeout(
+        Duration::from_secs(2),
+        ch_db.query(&query).bind(&trader).fetch_one::<PerfRow>(),
+    )
+    .await;
+    let row = match result {
+        Ok(Ok(row)) => row,
+        Ok(Err(e)) => {
+            tracing::warn!("Trader perf query failed for {trader}: {e}");
+            return None;
+        }
+        Err(_) => {
+            tracing::warn!("Trader perf query timed out for {trader}");
+            return None;
+        }
+    };
+
+    let perf = TraderPerf {
+        pnl: row.pnl,
+        win_rate: (row.positions > 0).then(|| row.wins as f64 / row.positions as f64 * 100.0),
+    };
+    cache.insert(trader, (perf, Instant::now()));
+    Some(perf)
+}
+
+/// Last known USDC volume traded on an asset over `window_mins`. Never waits on
+/// ClickHouse: a missing or expired entry spawns a refresh, and the trade is judged on
+/// the previous result while it is younger than LIQUIDITY_STALE_LIMIT. Returns None
+/// when there is no usable result yet (the filter then lets the trade through).
+fn asset_recent_volume(
+    ch_db: &clickhouse::Client,
+    cache: &AssetVolumeCache,
+    asset_id: &str,
+    window_mins: u32,
+) -> Option<f64> {
+    let key = (asset_id.to_string(), window_mins);
+    let mut entries = cache.lock().unwrap();
+    let entry = entries.entry(key.clone()).or_insert(AssetVolume {
+        volume: None,
+        fetched: None,
+        refreshing: false,
+    });
+    let age = entry.fetched.map(|at| at.elapsed());
+    if !entry.refreshing && age.is_none_or(|age| age >= LIQUIDITY_TTL) {
+        entry.refreshing = true;
+        tokio::spawn(refresh_asset_volume(ch_db.clone(), Arc::clone(cache), key));
+    }
+    age.filter(|age| *age < LIQUIDITY_STALE_LIMIT)
+        .and(entry.volume)
+}
+
+async fn refresh_asset_volume(
+    ch_db: clickhouse::Client,
+    cache: AssetVolumeCache,
+    key: (String, u32),
+) {
+    let (asset_id, window_mins) = (&key.0, key.1);
+    let query = format!(
+        "SELECT toFloat64(sum(usdc_amount))
+        FROM trades
+        WHERE asset_id = ? AND block_timestamp >= now() - INTERVAL {window_mins} MINUTE"
+    );
+    let result = tokio::time::timeout(
+        Duration::from_secs(2),
+        ch_db.query(&query).bind(asset_id).fetch_one::<f64>(),
+    )
+    .await;
+    let volume = match result {
+        Ok(Ok(v)) => Some(v),
+        Ok(Err(e)) => {
+            tracing::warn!("Asset volume query failed for {asset_id}: {e}");
+            None
+        }
+        Err(_) => {
+            tracing::warn!("Asset volume query timed out for {asset_id}");
+            None
+        }
+    };
+
+    let mut entries = cache.lock().unwrap();
+    // Bound the cache: drop entries too old to gate a trade once it grows
+    if entries.len() > 10_000 {
+        entries.retain(|_, e| {
+            e.refreshing
+                || e.fetched
+                    .is_some_and(|at| at.elapsed() < LIQUIDITY_STALE_LIMIT)
+        });
+    }
+    entries.insert(
+        key,
+        AssetVolume {
+            volume,
+            fetched: Some(Instant::now()),
+            refreshing: false,
+        },
+    );
+}
+
+/// Mark-to-market value of a trader's open positions (latest price, resolved price
+/// once settled), cached for TRADER_PORTFOLIO_TTL. Returns None on query failure;
+/// portfolio sizing then falls back to dollar sizing.
+pub(crate) async fn trader_portfolio_value(
+    ch_db: &clickhouse::Client,
+    cache: &mut TraderPortfolioCache,
+    trader: &str,
+) -> Option<f64> {
+    let trader = trader.to_lowercase();
+    if let Some((value, fetched)) = cache.get(&trader)
+        && fetched.elapsed() < TRADER_PORTFOLIO_TTL
+    {
+        return Some(*value);
+    }
+
+    let query = "WITH resolved AS (
+            SELECT asset_id, toNullable(toFloat64(resolved_price)) AS resolved_price
+            FROM resolved_prices FINAL
+        )
+        SELECT toFloat64(sumIf(
+            (p.buy_amount - p.sell_amount) * coalesce(rp.resolved_price, toFloat64(lp.latest_price)),
+            p.buy_amount > p.sell_amount
+        ))
+        FROM trader_positions p
+        LEFT JOIN (SELECT asset_id, latest_price FROM asset_latest_price FINAL) AS lp ON p.asset_id = lp.asset_id
+        LEFT JOIN resolved rp ON p.asset_id = rp.asset_id
+        WHERE lower(p.trader) = ?";
+    let result = tokio::time::timeout(
+        Duration::from_secs(2),
+        ch_db.query(query).bind(&trader).fetch_one::<f64>(),
+    )
+    .await;
+    let value = match result {
+        Ok(Ok(v)) => v,
+        Ok(Err(e)) => {
+            tracing::warn!("Trader portfolio query failed for {trader}: {e}");
+            return None;
+        }
+        Err(_) => {
+            tracing::warn!("Trader portfolio query timed out for {trader}");
+            return None;
+        }
+    };
+
+    if cache.len() > 10_000 {
+        cache.retain(|_, (_, fetched)| fetched.elapsed() < TRADER_PORTFOLIO_TTL);
+    }
+    cache.insert(trader, (value, Instant::now()));
+    Some(value)
+}
+
+/// Session exposure (shares × last fill price) across every position in the same market
+/// as `asset_id`, grouped by the cached condition_id. None when the market is unknown.
+pub(crate) async fn market_exposure(
+    market_cache: &MarketCache,
+    positions: &HashMap<String, (f64, f64, f64)>,
+    asset_id: &str,
+) -> Option<f64> {
+    let cache = market_cache.read().await;
+    let condition_of = |id: &str| {
+        cache
+            .get(&markets::cache_key(id))
+            .and_then(|m| m.condition_id.clone())
+    };
+    let condition_id = condition_of(asset_id)?;
+    Some(
+        positions
+            .iter()
+            .filter(|(id, _)| condition_of(id).as_deref() == Some(condition_id.as_str()))
+            .map(|(_, (shares, price, _))| shares * price)
+            .sum(),
+    )
+}
+
+/// The session's signal filters, shared by `process_trade` and the what-if endpoint:
+/// market category, asset allowlist and copy window, then fade mode (which flips the
+/// source side) and copy_sides on the side that would be traded. Ok is that side,
+/// Err the skip reason.
+pub(crate) fn filter_signal(
+    config: &CopyTradeSessionRow,
+    asset_allowlist: Option<&HashSet<String>>,
+    category: &str,
+    asset_id: &str,
+    block_timestamp: &str,
+    source_side: Side,
+) -> Result<Side, &'static str> {
+    if let Some(reason) = category_skip_reason(config, category) {
+        return Err(reason);
+    }
+    if asset_allowlist.is_some_and(|list| !list.contains(asset_id)) {
+        return Err("not_in_allowlist");
+    }
+    check_signal_age(config, block_timestamp).map_err(|r| r.as_str())?;
+
+    let side = match (CopyMode::from_str(&config.mode), source_side) {
+        (Some(CopyMode::Fade), Side::Buy) => Side::Sell,
+        (Some(CopyMode::Fade), _) => Side::Buy,
+        _ => source_side,
+    };
+    let copied = match CopySides::from_str(&config.copy_sides) {
+        Some(CopySides::Buy) => matches!(side, Side::Buy),
+        Some(CopySides::Sell) => matches!(side, Side::Sell),
+        _ => true,
+    };
+    if !copied {
+        return Err("side_not_copied");
+    }
+    Ok(side)
+}
+
+/// Why the session's category filters reject a trade in `category`, if they do.
+/// Blocked categories win over the allowlist; matching ignores case. An empty
+/// category (the market cache missed) passes only with `copy_uncategorized`.
+fn category_skip_reason(config: &CopyTradeSessionRow, category: &str) -> Option<&'static str> {
+    let category = category.trim();
+    if category.is_empty() {
+        return (!config.copy_uncategorized).then_some("uncategorized");
+    }
+    let listed = |list: &Option<String>| {
+        list.as_deref().is_some_and(|list| {
+            list.split(',')
+                .any(|c| c.trim().eq_ignore_ascii_case(category))
+        })
+    };
+    if listed(&config.blocked_categories) {
+        return Some("category_blocked");
+    }
+    if config.allowed_categories.is_some() && !listed(&config.allowed_categories) {
+        return Some("category_not_allowed");
+    }
+    None
+}
+
+fn confidence_skip_reason(config: &CopyTradeSessionRow, perf: TraderPerf) -> Option<String> {
+    if let Some(min_pnl) = config.min_trader_pnl
+        && perf.pnl < min_pnl
+    {
+        return Some(format!(
+            "trader recent P&L {:.2} below minimum {min_pnl:.2}",
+            perf.pnl
+        ));
+    }
+    if let (Some(min_wr), Some(wr)) = (config.min_trader_winrate, perf.win_rate)
+        && wr < min_wr
+    {
+        return Some(format!(
+            "trader recent win rate {wr:.1}% below minimum {min_wr:.1}%"
+        ));
     }
+    None
 }
 
 // ---------------------------------------------------------------------------
 // Simulation execution (paper trading with real prices)
 // ---------------------------------------------------------------------------
 
+#[allow(clippy::too_many_arguments)]
 async fn execute_simulated(
     trade: &LiveTrade,
     session: &mut ActiveSession,
@@ -615,16 +3234,21 @@ async fn execute_simulated(
     side: Side,
     order_id: &str,
     created_at: &str,
-    clob_client: &Arc<RwLock<Option<ClobClientState>>>,
+    http: &reqwest::Client,
     user_db: &Arc<Mutex<rusqlite::Connection>>,
     update_tx: &broadcast::Sender<CopyTradeUpdate>,
 ) -> bool {
     let sid = &session.config.id;
 
-    // Try to fetch real CLOB price for realistic simulation
-    let current_price = fetch_clob_price(clob_client, &trade.asset_id, side).await;
+    // Real price from the public (no-auth) CLOB endpoint, so paper trading is
+    // realistic for owners that never created a wallet.
+    let side_str = match side {
+        Side::Buy => "BUY",
+        _ => "SELL",
+    };
+    let current_price = super::copytrade::fetch_one_price(http, &trade.asset_id, side_str).await;
 
-    // Simulate fill: use real price if available, otherwise source price + random slippage
+    // Simulate fill: use real price if available, else (last resort) source price + noise
     let fill_price = if let Some(cp) = current_price {
         cp
     } else {
@@ -632,19 +3256,39 @@ async fn execute_simulated(
         let slippage_factor = 1.0 + (rand::random::<f64>() - 0.5) * 0.01; // ±0.5%
         source_price * slippage_factor
     };
+    if !valid_outcome_price(fill_price) {
+        tracing::warn!(
+            "Session {sid}: simulated fill price {fill_price} for {} out of range, not filling",
+            trade.asset_id
+        );
+        return false;
+    }
 
-    // Check slippage
-    let slippage_bps = match side {
-        Side::Buy => (fill_price - source_price) / source_price * 10000.0,
-        Side::Sell => (source_price - fill_price) / source_price * 10000.0,
-        _ => return false,
-    };
-
-    if slippage_bps > session.config.max_slippage_bps as f64 {
+    // Check slippage (gated on the all-in price; the order records the raw fill slippage)
+    let slippage_bps = signed_slippage_bps(side, source_price, fill_price);
+    let effective_price = fee_adjusted_price(&session.config, side, fill_price);
+    let gate_bps = signed_slippage_bps(side, source_price, effective_price);
+    let max_slippage_bps = max_slippage_bps_for(&session.config, &trade.asset_id);
+    let slippage = check_slippage(&session.config, max_slippage_bps, gate_bps);
+    audit_order(user_db, sid, Some(order_id), "slippage", || {
+        serde_json::json!({
+            "source_price": source_price,
+            "current_price": current_price,
+            "fill_price": fill_price,
+            "effective_price": effective_price,
+            "fee_bps": session.config.slippage_fee_bps,
+            "slippage_bps": slippage_bps,
+            "gate_slippage_bps": gate_bps,
+            "max_slippage_bps": max_slippage_bps,
+            "rejected": slippage.err().map(|r| r.as_str()),
+        })
+    });
+    if let Err(reason) = slippage {
         tracing::info!(
-            "Session {sid}: slippage {slippage_bps:.0}bps exceeds max {}bps (simulated)",
-            session.config.max_slippage_bps
+            "Session {sid}: {} {gate_bps:.0}bps (max {max_slippage_bps}bps, simulated)",
+            reason.as_str(),
         );
+        note_slippage_rejection(session, user_db, update_tx);
         return false;
     }
 
@@ -659,23 +3303,25 @@ async fn execute_simulated(
             actual_usdc = order_usdc;
             actual_shares = size_shares;
             session.remaining_capital -= actual_usdc;
-            let (cur_shares, _) = session
+            settle_negative_capital(sid, &mut session.remaining_capital, &mut session.fees_paid);
+            let (cur_shares, _, peak) = session
                 .positions
                 .get(&trade.asset_id)
                 .copied()
-                .unwrap_or((0.0, 0.0));
+                .unwrap_or((0.0, 0.0, 0.0));
             let new_shares = cur_shares + actual_shares;
-            session
-                .positions
-                .insert(trade.asset_id.clone(), (new_shares, fill_price));
+            session.positions.insert(
+                trade.asset_id.clone(),
+                (new_shares, fill_price, peak.max(fill_price)),
+            );
         }
         Side::Sell => {
             // Sell: only if we hold shares in this asset
-            let (cur_shares, _) = session
+            let (cur_shares, _, peak) = session
                 .positions
                 .get(&trade.asset_id)
                 .copied()
-                .unwrap_or((0.0, 0.0));
+                .unwrap_or((0.0, 0.0, 0.0));
             if cur_shares <= 0.0 {
                 tracing::debug!("SIM {sid}: no position to sell for {}", trade.asset_id);
                 return false;
@@ -690,11 +3336,16 @@ async fn execute_simulated(
             } else {
                 session
                     .positions
-                    .insert(trade.asset_id.clone(), (new_shares, fill_price));
+                    .insert(trade.asset_id.clone(), (new_shares, fill_price, peak));
             }
         }
         _ => return false,
     }
+    sync_position_opened(
+        &session.positions,
+        &mut session.position_opened,
+        &trade.asset_id,
+    );
 
     // Record order
     let order_row = CopyTradeOrderRow {
@@ -716,6 +3367,11 @@ async fn execute_simulated(
         tx_hash: None,
         created_at: created_at.to_string(),
         updated_at: created_at.to_string(),
+        origin: OrderOrigin::from_source_tx_hash(&trade.tx_hash)
+            .as_str()
+            .to_string(),
+        max_slippage_bps: Some(max_slippage_bps),
+        fallback_for: None,
     };
 
     {
@@ -760,6 +3416,7 @@ async fn execute_simulated(
     });
 
     session.consecutive_failures = 0;
+    session.consecutive_slippage_rejections = 0;
     true
 }
 
@@ -780,42 +3437,114 @@ async fn execute_live(
     clob_client: &Arc<RwLock<Option<ClobClientState>>>,
     user_db: &Arc<Mutex<rusqlite::Connection>>,
     update_tx: &broadcast::Sender<CopyTradeUpdate>,
+    order_timestamps: &mut VecDeque<Instant>,
 ) -> bool {
     let sid = session.config.id.clone();
 
     // 7. SLIPPAGE CHECK — fetch current CLOB price
-    let current_price = match fetch_clob_price(clob_client, &trade.asset_id, side).await {
-        Some(p) => p,
-        None => {
+    let current_price = match fetch_clob_price_retrying(clob_client, &trade.asset_id, side).await {
+        Ok(p) => p,
+        Err(PriceUnavailable::NoLiquidity) => {
+            // A thin market, not a failure: record it like any other skip
+            tracing::info!(
+                "Session {sid}: no CLOB liquidity for {}, skipping",
+                trade.asset_id
+            );
+            let reason = SkipReason::NoLiquidity.as_str().to_string();
+            skip_trade(session, trade, reason, user_db, update_tx);
+            return false;
+        }
+        Err(PriceUnavailable::Transient(e) | PriceUnavailable::Failed(e)) => {
             tracing::warn!(
-                "Session {sid}: couldn't fetch CLOB price for {}, skipping",
+                "Session {sid}: couldn't fetch CLOB price for {}, skipping: {e}",
                 trade.asset_id
             );
             return false;
         }
     };
 
-    let slippage_bps = match side {
-        Side::Buy => (current_price - source_price) / source_price * 10000.0,
-        Side::Sell => (source_price - current_price) / source_price * 10000.0,
-        _ => return false,
+    let effective_price = fee_adjusted_price(&session.config, side, current_price);
+    let slippage_bps = signed_slippage_bps(side, source_price, effective_price);
+    let max_slippage_bps = max_slippage_bps_for(&session.config, &trade.asset_id);
+    let slippage = check_slippage(&session.config, max_slippage_bps, slippage_bps);
+    audit_order(user_db, &sid, Some(order_id), "slippage", || {
+        serde_json::json!({
+            "source_price": source_price,
+            "current_price": current_price,
+            "effective_price": effective_price,
+            "fee_bps": session.config.slippage_fee_bps,
+            "slippage_bps": slippage_bps,
+            "max_slippage_bps": max_slippage_bps,
+            "rejected": slippage.err().map(|r| r.as_str()),
+        })
+    });
+    if let Err(reason) = slippage {
+        tracing::info!(
+            "Session {sid}: {} {slippage_bps:.0}bps (max {max_slippage_bps}bps), skipping",
+            reason.as_str(),
+        );
+        note_slippage_rejection(session, user_db, update_tx);
+        return false;
+    }
+
+    // Parse token_id
+    let token_id = match U256::from_str(&trade.asset_id) {
+        Ok(id) => id,
+        Err(e) => {
+            tracing::error!("Session {sid}: invalid asset_id '{}': {e}", trade.asset_id);
+            return false;
+        }
     };
 
-    if slippage_bps > session.config.max_slippage_bps as f64 {
-        tracing::info!(
-            "Session {sid}: slippage {slippage_bps:.0}bps exceeds max {}bps",
-            session.config.max_slippage_bps
+    // Sanity cap: whatever the session config says, never send an absurd order
+    let cap = max_order_usdc();
+    if !within_order_cap(order_usdc, cap) {
+        tracing::error!(
+            "Session {sid}: REFUSING order of {order_usdc} USDC on {} (hard cap {cap} USDC). \
+             This points at a sizing or accounting bug",
+            trade.asset_id
         );
+        record_failed_order(
+            order_id,
+            &sid,
+            trade,
+            source_price,
+            order_usdc,
+            created_at,
+            &format!("Order size {order_usdc} USDC exceeds the {cap} USDC hard cap"),
+            session,
+            user_db,
+            update_tx,
+        )
+        .await;
         return false;
     }
 
-    // Parse token_id
-    let token_id = match U256::from_str(&trade.asset_id) {
-        Ok(id) => id,
-        Err(e) => {
-            tracing::error!("Session {sid}: invalid asset_id '{}': {e}", trade.asset_id);
-            return false;
-        }
+    // GTC limit: the source fill by default, or the live midpoint when the session
+    // expects signals to arrive after the book has moved
+    let gtc_price = if order_type == CopyOrderType::GTC {
+        let reference = match GtcPriceRef::from_str(&session.config.gtc_price_ref) {
+            Some(GtcPriceRef::Current) => {
+                let opposite = match side {
+                    Side::Buy => Side::Sell,
+                    _ => Side::Buy,
+                };
+                match fetch_clob_price(clob_client, &trade.asset_id, opposite).await {
+                    Some(p) => (current_price + p) / 2.0,
+                    None => current_price,
+                }
+            }
+            _ => source_price,
+        };
+        gtc_limit_price(
+            &session.config,
+            max_slippage_bps,
+            side,
+            source_price,
+            reference,
+        )
+    } else {
+        source_price
     };
 
     // Broadcast OrderPlaced
@@ -855,12 +3584,39 @@ async fn execute_live(
         }
     };
 
+    let rounding =
+        SizeRounding::from_str(&session.config.size_rounding).unwrap_or(SizeRounding::Down);
+    // Most a rounded-up order may cost: the position cap and capital for buys, our
+    // holdings for sells, and the hard cap either way
+    let held_shares = session
+        .positions
+        .get(&trade.asset_id)
+        .map(|(shares, _, _)| *shares)
+        .unwrap_or(0.0);
+    let buy_cap = session
+        .config
+        .max_position_usdc
+        .min(session.remaining_capital)
+        .min(cap);
+    let rounding_cap = move |price: f64| match side {
+        Side::Buy => buy_cap,
+        _ => (held_shares * price).min(cap),
+    };
     let result = match order_type {
-        CopyOrderType::FOK => {
-            let usdc_dec = Decimal::from_f64_retain(order_usdc)
-                .unwrap_or(Decimal::ZERO)
-                .trunc_with_scale(6);
-            let amount = match Amount::usdc(usdc_dec) {
+        CopyOrderType::FOK | CopyOrderType::FAK => {
+            let size_dec = market_order_size(
+                side,
+                order_usdc,
+                source_price,
+                rounding,
+                rounding_cap(source_price),
+            )
+            .unwrap_or(Decimal::ZERO);
+            let amount = match side {
+                Side::Buy => Amount::usdc(size_dec),
+                _ => Amount::shares(size_dec),
+            };
+            let amount = match amount {
                 Ok(a) => a,
                 Err(e) => {
                     record_failed_order(
@@ -886,7 +3642,11 @@ async fn execute_live(
                 .token_id(token_id)
                 .side(side)
                 .amount(amount)
-                .order_type(OrderType::FOK)
+                .order_type(if order_type == CopyOrderType::FAK {
+                    OrderType::FAK
+                } else {
+                    OrderType::FOK
+                })
                 .build()
                 .await;
 
@@ -899,13 +3659,30 @@ async fn execute_live(
             }
         }
         CopyOrderType::GTC => {
-            let price_dec = Decimal::from_f64_retain(source_price)
+            let price_dec = Decimal::from_f64_retain(gtc_price)
                 .unwrap_or(Decimal::ZERO)
                 .trunc_with_scale(4);
-            let shares = order_usdc / source_price;
-            let size_dec = Decimal::from_f64_retain(shares)
-                .unwrap_or(Decimal::ZERO)
-                .trunc_with_scale(2);
+            let Some(size_dec) =
+                gtc_share_size(order_usdc, gtc_price, rounding, rounding_cap(gtc_price))
+            else {
+                record_failed_order(
+                    order_id,
+                    &sid,
+                    trade,
+                    source_price,
+                    order_usdc,
+                    created_at,
+                    &format!(
+                        "Order size {:.2} shares at {gtc_price} is below the {MIN_GTC_SHARES} share minimum or invalid",
+                        order_usdc / gtc_price
+                    ),
+                    session,
+                    user_db,
+                    update_tx,
+                )
+                .await;
+                return false;
+            };
 
             let signable = cs
                 .client
@@ -938,76 +3715,79 @@ async fn execute_live(
             let status_str;
             let size_shares;
             let actual_slippage;
+            // What the order actually moved: the full size unless a FAK filled partially
+            let mut recorded_usdc = order_usdc;
 
             match resp.status {
                 OrderStatusType::Matched => {
-                    // FOK filled — compute price per share (USDC/share)
-                    fill_price_val = if resp.taking_amount > Decimal::ZERO
-                        && resp.making_amount > Decimal::ZERO
-                    {
-                        let fp = match side {
-                            // Buy: making=USDC sent, taking=shares received
-                            Side::Buy => {
-                                resp.making_amount.to_f64().unwrap_or(0.0)
-                                    / resp.taking_amount.to_f64().unwrap_or(1.0)
-                            }
-                            // Sell: taking=USDC received, making=shares sent
-                            _ => {
-                                resp.taking_amount.to_f64().unwrap_or(0.0)
-                                    / resp.making_amount.to_f64().unwrap_or(1.0)
-                            }
-                        };
-                        Some(fp)
-                    } else {
-                        Some(current_price)
-                    };
-                    let shares_filled = match side {
-                        Side::Buy => resp.taking_amount.to_f64().unwrap_or(0.0),
-                        _ => resp.making_amount.to_f64().unwrap_or(0.0),
-                    };
+                    // FOK/FAK filled. A FAK's unfilled remainder is already canceled,
+                    // so only the matched portion counts.
+                    let fill = matched_fill(
+                        side,
+                        resp.making_amount,
+                        resp.taking_amount,
+                        order_usdc,
+                        current_price,
+                    );
+                    let fp = fill.price;
+                    let shares_filled = fill.shares;
+                    fill_price_val = Some(fp);
                     size_shares = Some(shares_filled);
-                    actual_slippage = fill_price_val
-                        .map(|fp| ((fp - source_price) / source_price * 10000.0).abs());
+                    actual_slippage = Some(((fp - source_price) / source_price * 10000.0).abs());
                     status_str = OrderStatus::Filled.as_str();
-                    let fp = fill_price_val.unwrap_or(current_price);
+                    recorded_usdc = fill.usdc;
+                    if order_type == CopyOrderType::FAK && fill.remaining_usdc > order_usdc * 0.01 {
+                        tracing::info!(
+                            "Session {sid}: FAK order {} partially filled ({recorded_usdc:.2} of {order_usdc:.2} USDC), remainder canceled",
+                            resp.order_id
+                        );
+                    }
                     // Position-aware capital tracking
                     match side {
                         Side::Buy => {
-                            let usdc_spent = resp.making_amount.to_f64().unwrap_or(order_usdc);
-                            session.remaining_capital -= usdc_spent;
-                            let (cur_shares, _) = session
+                            session.remaining_capital -= recorded_usdc;
+                            settle_negative_capital(
+                                &sid,
+                                &mut session.remaining_capital,
+                                &mut session.fees_paid,
+                            );
+                            let (cur_shares, _, peak) = session
                                 .positions
                                 .get(&trade.asset_id)
                                 .copied()
-                                .unwrap_or((0.0, 0.0));
+                                .unwrap_or((0.0, 0.0, 0.0));
                             let new_shares = cur_shares + shares_filled;
                             session
                                 .positions
-                                .insert(trade.asset_id.clone(), (new_shares, fp));
+                                .insert(trade.asset_id.clone(), (new_shares, fp, peak.max(fp)));
                         }
                         _ => {
-                            let usdc_received = resp.taking_amount.to_f64().unwrap_or(order_usdc);
-                            session.remaining_capital += usdc_received;
-                            let (cur_shares, _) = session
+                            session.remaining_capital += recorded_usdc;
+                            let (cur_shares, _, peak) = session
                                 .positions
                                 .get(&trade.asset_id)
                                 .copied()
-                                .unwrap_or((0.0, 0.0));
+                                .unwrap_or((0.0, 0.0, 0.0));
                             let new_shares = cur_shares - shares_filled;
                             if new_shares < 0.001 {
                                 session.positions.remove(&trade.asset_id);
                             } else {
                                 session
                                     .positions
-                                    .insert(trade.asset_id.clone(), (new_shares, fp));
+                                    .insert(trade.asset_id.clone(), (new_shares, fp, peak));
                             }
                         }
                     }
+                    sync_position_opened(
+                        &session.positions,
+                        &mut session.position_opened,
+                        &trade.asset_id,
+                    );
                 }
                 OrderStatusType::Live => {
                     // GTC resting
                     fill_price_val = None;
-                    size_shares = Some(order_usdc / source_price);
+                    size_shares = Some(order_usdc / gtc_price);
                     actual_slippage = None;
                     status_str = OrderStatus::Submitted.as_str();
                     // Only deduct capital for buys (sells receive capital on fill)
@@ -1016,7 +3796,17 @@ async fn execute_live(
                     }
                     session.open_gtc_orders.insert(
                         resp.order_id.clone(),
-                        (order_id.to_string(), Instant::now(), order_usdc),
+                        OpenGtcOrder {
+                            order_id: order_id.to_string(),
+                            placed_at: Instant::now(),
+                            usdc: order_usdc,
+                            asset_id: trade.asset_id.clone(),
+                            side,
+                            price: gtc_price,
+                            source_price,
+                            matched_shares: 0.0,
+                            matched_usdc: 0.0,
+                        },
                     );
                 }
                 OrderStatusType::Canceled | OrderStatusType::Unmatched => {
@@ -1046,7 +3836,7 @@ async fn execute_live(
                 side: trade.side.clone(),
                 price: current_price,
                 source_price,
-                size_usdc: order_usdc,
+                size_usdc: recorded_usdc,
                 size_shares,
                 status: status_str.to_string(),
                 error_message: None,
@@ -1055,6 +3845,11 @@ async fn execute_live(
                 tx_hash: resp.transaction_hashes.first().map(|h| h.to_string()),
                 created_at: created_at.to_string(),
                 updated_at: created_at.to_string(),
+                origin: OrderOrigin::from_source_tx_hash(&trade.tx_hash)
+                    .as_str()
+                    .to_string(),
+                max_slippage_bps: Some(max_slippage_bps),
+                fallback_for: None,
             };
 
             {
@@ -1065,11 +3860,32 @@ async fn execute_live(
             tracing::info!(
                 "Session {sid}: {status_str} {} {:.2} USDC on {} (CLOB order {})",
                 trade.side,
-                order_usdc,
+                recorded_usdc,
                 trade.asset_id,
                 resp.order_id
             );
 
+            if status_str == OrderStatus::Canceled.as_str()
+                && falls_back_to_gtc(&session.config, order_type, &trade.tx_hash)
+            {
+                place_fallback_gtc(
+                    trade,
+                    session,
+                    side,
+                    token_id,
+                    order_usdc,
+                    source_price,
+                    rounding,
+                    rounding_cap(source_price),
+                    order_id,
+                    clob_client,
+                    user_db,
+                    update_tx,
+                    order_timestamps,
+                )
+                .await;
+            }
+
             if status_str == OrderStatus::Filled.as_str() {
                 let _ = update_tx.send(CopyTradeUpdate::OrderFilled {
                     session_id: sid.clone(),
@@ -1078,6 +3894,7 @@ async fn execute_live(
                     slippage_bps: actual_slippage.unwrap_or(0.0),
                     owner: session.config.owner.clone(),
                 });
+                session.consecutive_slippage_rejections = 0;
             }
 
             session.consecutive_failures = 0;
@@ -1125,20 +3942,316 @@ async fn execute_live(
 // Helpers
 // ---------------------------------------------------------------------------
 
+/// Whether an unmatched order rests as a GTC instead: FOK copies of a source trade in
+/// sessions with `fok_fallback_gtc`. Engine exits never leave a resting order behind.
+fn falls_back_to_gtc(
+    config: &CopyTradeSessionRow,
+    order_type: CopyOrderType,
+    source_tx_hash: &str,
+) -> bool {
+    config.fok_fallback_gtc
+        && order_type == CopyOrderType::FOK
+        && matches!(
+            OrderOrigin::from_source_tx_hash(source_tx_hash),
+            OrderOrigin::Signal
+        )
+}
+
+/// `rate_limit_exceeded` for a GTC fallback. The FOK it replaces is recorded only once
+/// execute_live returns, so its slot is counted here first.
+fn fallback_rate_limited(
+    config: &CopyTradeSessionRow,
+    session_timestamps: &VecDeque<Instant>,
+    global_timestamps: &VecDeque<Instant>,
+    now: Instant,
+) -> Option<String> {
+    let mut session_window = session_timestamps.clone();
+    let mut global_window = global_timestamps.clone();
+    session_window.push_back(now);
+    global_window.push_back(now);
+    rate_limit_exceeded(config, &mut session_window, &mut global_window, now)
+}
+
+/// `fok_fallback_gtc`: after a FOK found no fill, rests a GTC limit for the same
+/// size at the source price. It's accounted like any resting GTC (buys reserve
+/// capital now, sells get theirs on fill) and tracked in open_gtc_orders, so even
+/// an immediate match is applied by the next reconciliation. The order row points
+/// back at the FOK through `fallback_for`.
+#[allow(clippy::too_many_arguments)]
+async fn place_fallback_gtc(
+    trade: &LiveTrade,
+    session: &mut ActiveSession,
+    side: Side,
+    token_id: U256,
+    order_usdc: f64,
+    source_price: f64,
+    rounding: SizeRounding,
+    max_usdc: f64,
+    fok_order_id: &str,
+    clob_client: &Arc<RwLock<Option<ClobClientState>>>,
+    user_db: &Arc<Mutex<rusqlite::Connection>>,
+    update_tx: &broadcast::Sender<CopyTradeUpdate>,
+    order_timestamps: &mut VecDeque<Instant>,
+) {
+    let sid = session.config.id.clone();
+    let placed_at = Instant::now();
+    if let Some(limit) = fallback_rate_limited(
+        &session.config,
+        &session.order_timestamps,
+        order_timestamps,
+        placed_at,
+    ) {
+        tracing::info!(
+            "Session {sid}: GTC fallback for FOK {fok_order_id} skipped, rate limit hit ({limit})"
+        );
+        return;
+    }
+    if matches!(side, Side::Buy) && session.remaining_capital < order_usdc {
+        tracing::info!(
+            "Session {sid}: no capital left to rest {order_usdc:.2} USDC after FOK {fok_order_id}"
+        );
+        return;
+    }
+    let Some(size_dec) = gtc_share_size(order_usdc, source_price, rounding, max_usdc) else {
+        tracing::info!(
+            "Session {sid}: GTC fallback for FOK {fok_order_id} is below the {MIN_GTC_SHARES} share minimum"
+        );
+        return;
+    };
+    let price_dec = Decimal::from_f64_retain(source_price)
+        .unwrap_or(Decimal::ZERO)
+        .trunc_with_scale(4);
+
+    let result = {
+        let clob = clob_client.read().await;
+        let Some(cs) = clob.as_ref() else {
+            return;
+        };
+        let signable = cs
+            .client
+            .limit_order()
+            .token_id(token_id)
+            .side(side)
+            .price(price_dec)
+            .size(size_dec)
+            .order_type(OrderType::GTC)
+            .build()
+            .await;
+        match signable {
+            Ok(order) => match cs.client.sign(&cs.signer, order).await {
+                Ok(signed) => cs.client.post_order(signed).await,
+                Err(e) => Err(e),
+            },
+            Err(e) => Err(e),
+        }
+    }; // clob read guard dropped here
+
+    let resp = match result {
+        Ok(resp)
+            if resp.success
+                && !matches!(
+                    resp.status,
+                    OrderStatusType::Canceled | OrderStatusType::Unmatched
+                ) =>
+        {
+            resp
+        }
+        Ok(resp) => {
+            tracing::warn!(
+                "Session {sid}: GTC fallback for FOK {fok_order_id} not placed: {}",
+                resp.error_msg
+                    .unwrap_or_else(|| "Unknown CLOB error".into())
+            );
+            return;
+        }
+        Err(e) => {
+            tracing::warn!("Session {sid}: GTC fallback for FOK {fok_order_id} failed: {e}");
+            return;
+        }
+    };
+
+    // Same accounting as a GTC that rests on placement
+    session.order_timestamps.push_back(placed_at);
+    order_timestamps.push_back(placed_at);
+    if matches!(side, Side::Buy) {
+        session.remaining_capital -= order_usdc;
+    }
+    let order_id = uuid::Uuid::new_v4().to_string();
+    session.open_gtc_orders.insert(
+        resp.order_id.clone(),
+        OpenGtcOrder {
+            order_id: order_id.clone(),
+            placed_at: Instant::now(),
+            usdc: order_usdc,
+            asset_id: trade.asset_id.clone(),
+            side,
+            price: source_price,
+            source_price,
+            matched_shares: 0.0,
+            matched_usdc: 0.0,
+        },
+    );
+
+    let now = chrono::Utc::now().to_rfc3339();
+    let order_row = CopyTradeOrderRow {
+        id: order_id.clone(),
+        session_id: sid.clone(),
+        source_tx_hash: trade.tx_hash.clone(),
+        source_trader: trade.trader.clone(),
+        clob_order_id: Some(resp.order_id.clone()),
+        asset_id: trade.asset_id.clone(),
+        side: trade.side.clone(),
+        price: source_price,
+        source_price,
+        size_usdc: order_usdc,
+        // What was actually sent, after lot rounding
+        size_shares: size_dec.to_f64(),
+        status: OrderStatus::Submitted.as_str().to_string(),
+        error_message: None,
+        fill_price: None,
+        slippage_bps: None,
+        tx_hash: None,
+        created_at: now.clone(),
+        updated_at: now,
+        origin: OrderOrigin::from_source_tx_hash(&trade.tx_hash)
+            .as_str()
+            .to_string(),
+        max_slippage_bps: Some(max_slippage_bps_for(&session.config, &trade.asset_id)),
+        fallback_for: Some(fok_order_id.to_string()),
+    };
+    {
+        let conn = user_db.lock().unwrap_or_else(|p| p.into_inner());
+        let _ = db::insert_copytrade_order(&conn, &order_row);
+    }
+    audit_order(user_db, &sid, Some(&order_id), "fok_fallback", || {
+        serde_json::json!({
+            "fallback_for": fok_order_id,
+            "clob_order_id": resp.order_id,
+            "price": source_price,
+            "size_usdc": order_usdc,
+        })
+    });
+    tracing::info!(
+        "Session {sid}: FOK {fok_order_id} unmatched, resting GTC {} {order_usdc:.2} USDC at {source_price:.4} (CLOB order {})",
+        trade.side,
+        resp.order_id
+    );
+    let _ = update_tx.send(CopyTradeUpdate::OrderPlaced {
+        session_id: sid,
+        order: CopyTradeOrderSummary {
+            id: order_id,
+            asset_id: trade.asset_id.clone(),
+            side: trade.side.clone(),
+            size_usdc: order_usdc,
+            price: source_price,
+            source_trader: trade.trader.clone(),
+            simulate: false,
+        },
+        owner: session.config.owner.clone(),
+    });
+}
+
 async fn fetch_clob_price(
     clob_client: &Arc<RwLock<Option<ClobClientState>>>,
     asset_id: &str,
     side: Side,
 ) -> Option<f64> {
-    let token_id = U256::from_str(asset_id).ok()?;
+    fetch_clob_price_checked(clob_client, asset_id, side)
+        .await
+        .ok()
+}
+
+/// Why a CLOB price read produced no price.
+enum PriceUnavailable {
+    /// The market has no orderbook, or nothing resting on the requested side
+    NoLiquidity,
+    /// A transport or API error (timeouts, 5xx), worth retrying
+    Transient(String),
+    /// Client missing, bad token id or an unparseable price
+    Failed(String),
+}
+
+/// Waits before each retry of a failed price read
+const PRICE_RETRY_BACKOFF: [Duration; 3] = [
+    Duration::from_millis(200),
+    Duration::from_millis(400),
+    Duration::from_millis(800),
+];
+/// Longest one price read may take; a read that runs over counts as transient
+const PRICE_ATTEMPT_TIMEOUT: Duration = Duration::from_secs(1);
+
+/// `fetch_clob_price_checked`, retried with backoff on transient errors only. Every
+/// read is cut off at PRICE_ATTEMPT_TIMEOUT, so the copy path waits at most 5.4s for
+/// a price (4 reads plus 1.4s of backoff): a late copy fills further from the source.
+async fn fetch_clob_price_retrying(
+    clob_client: &Arc<RwLock<Option<ClobClientState>>>,
+    asset_id: &str,
+    side: Side,
+) -> Result<f64, PriceUnavailable> {
+    let attempt = || async {
+        tokio::time::timeout(
+            PRICE_ATTEMPT_TIMEOUT,
+            fetch_clob_price_checked(clob_client, asset_id, side),
+        )
+        .await
+        .unwrap_or_else(|_| {
+            Err(PriceUnavailable::Transient(format!(
+                "timed out after {}ms",
+                PRICE_ATTEMPT_TIMEOUT.as_millis()
+            )))
+        })
+    };
+    let mut result = attempt().await;
+    for (retry, delay) in PRICE_RETRY_BACKOFF.iter().enumerate() {
+        match &result {
+            Err(PriceUnavailable::Transient(e)) => tracing::warn!(
+                "CLOB price for {asset_id} failed ({e}), retry {}/{} in {}ms",
+                retry + 1,
+                PRICE_RETRY_BACKOFF.len(),
+                delay.as_millis()
+            ),
+            _ => break,
+        }
+        tokio::time::sleep(*delay).await;
+        result = attempt().await;
+    }
+    result
+}
+
+/// Like `fetch_clob_price`, but tells a market without liquidity apart from a failed
+/// request. The CLOB answers a missing book with a "No orderbook exists" error, and an
+/// empty side with a zero price.
+async fn fetch_clob_price_checked(
+    clob_client: &Arc<RwLock<Option<ClobClientState>>>,
+    asset_id: &str,
+    side: Side,
+) -> Result<f64, PriceUnavailable> {
+    let token_id = U256::from_str(asset_id)
+        .map_err(|e| PriceUnavailable::Failed(format!("invalid token id: {e}")))?;
     let clob = clob_client.read().await;
-    let cs = clob.as_ref()?;
+    let cs = clob
+        .as_ref()
+        .ok_or_else(|| PriceUnavailable::Failed("CLOB client not initialized".into()))?;
     let req = PriceRequest::builder()
         .token_id(token_id)
         .side(side)
         .build();
-    let resp = cs.client.price(&req).await.ok()?;
-    resp.price.to_f64()
+    let resp = cs.client.price(&req).await.map_err(|e| {
+        let msg = e.to_string();
+        if msg.to_lowercase().contains("no orderbook") {
+            PriceUnavailable::NoLiquidity
+        } else {
+            PriceUnavailable::Transient(msg)
+        }
+    })?;
+    match resp.price.to_f64() {
+        Some(p) if p > 0.0 => Ok(p),
+        Some(_) => Err(PriceUnavailable::NoLiquidity),
+        None => Err(PriceUnavailable::Failed(format!(
+            "unparseable price {}",
+            resp.price
+        ))),
+    }
 }
 
 use rust_decimal::prelude::ToPrimitive;
@@ -1177,6 +4290,11 @@ async fn record_failed_order(
         tx_hash: None,
         created_at: created_at.to_string(),
         updated_at: created_at.to_string(),
+        origin: OrderOrigin::from_source_tx_hash(&trade.tx_hash)
+            .as_str()
+            .to_string(),
+        max_slippage_bps: Some(max_slippage_bps_for(&session.config, &trade.asset_id)),
+        fallback_for: None,
     };
 
     {
@@ -1203,65 +4321,414 @@ async fn record_failed_order(
     }
 }
 
+/// Count a slippage-gate rejection. These aren't failures (no cooldown), so a
+/// fast market can starve a session silently; warn periodically, and auto-pause
+/// once the session's `slippage_pause_after` threshold is reached.
+fn note_slippage_rejection(
+    session: &mut ActiveSession,
+    user_db: &Arc<Mutex<rusqlite::Connection>>,
+    update_tx: &broadcast::Sender<CopyTradeUpdate>,
+) {
+    session.consecutive_slippage_rejections += 1;
+    let count = session.consecutive_slippage_rejections;
+    let sid = &session.config.id;
+
+    match session.config.slippage_pause_after {
+        Some(limit) if count >= limit => {
+            tracing::warn!("Session {sid}: {count} consecutive slippage rejections, auto-pausing");
+            session.config.status = "paused".to_string();
+            session.consecutive_slippage_rejections = 0;
+            let conn = user_db.lock().unwrap_or_else(|p| p.into_inner());
+            let _ = db::update_session_status(&conn, sid, "paused");
+            let _ = update_tx.send(CopyTradeUpdate::SessionPaused {
+                session_id: sid.clone(),
+                reason: Some("persistent_slippage".to_string()),
+                owner: session.config.owner.clone(),
+            });
+        }
+        _ if count.is_multiple_of(SLIPPAGE_REJECTION_WARN_EVERY) => {
+            tracing::warn!(
+                "Session {sid}: {count} consecutive slippage rejections without a fill \
+                 (max_slippage_bps={})",
+                session.config.max_slippage_bps
+            );
+        }
+        _ => {}
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Publish tracked addresses to ws_subscriber via watch channel
 // ---------------------------------------------------------------------------
 
-fn publish_tracked_addresses(
-    sessions: &HashMap<String, ActiveSession>,
-    trader_watch_tx: &tokio::sync::watch::Sender<std::collections::HashSet<String>>,
-) {
-    let union: std::collections::HashSet<String> = sessions
-        .values()
-        .filter(|s| SessionStatus::from_str(&s.config.status) == Some(SessionStatus::Running))
-        .flat_map(|s| s.traders.iter().cloned())
-        .map(|addr| addr.to_lowercase())
-        .collect();
+fn publish_tracked_addresses(
+    sessions: &HashMap<String, ActiveSession>,
+    trader_watch_tx: &tokio::sync::watch::Sender<std::collections::HashSet<String>>,
+) {
+    let union: std::collections::HashSet<String> = sessions
+        .values()
+        .filter(|s| SessionStatus::from_str(&s.config.status) == Some(SessionStatus::Running))
+        .flat_map(|s| s.traders.keys().cloned())
+        .map(|addr| addr.to_lowercase())
+        .collect();
+
+    tracing::info!(
+        "Publishing {} tracked address(es) to ws_subscriber",
+        union.len()
+    );
+    let _ = trader_watch_tx.send(union);
+}
+
+// ---------------------------------------------------------------------------
+// Health check (60s interval)
+// ---------------------------------------------------------------------------
+
+/// Logs throughput for the window since `prev` and returns the new baseline.
+fn log_metrics_window(
+    metrics: &EngineMetrics,
+    prev: EngineMetricsSnapshot,
+) -> EngineMetricsSnapshot {
+    let now = metrics.snapshot();
+    let received = now.trades_received - prev.trades_received;
+    let dropped = now.trades_dropped - prev.trades_dropped;
+    let swaps = now.token_swap_fills_skipped - prev.token_swap_fills_skipped;
+    if swaps > 0 {
+        tracing::info!("Skipped {swaps} token-for-token (mint/merge) fills in the last window");
+    }
+    if received + dropped > 0 {
+        let drop_pct = dropped as f64 / (received + dropped) as f64 * 100.0;
+        if drop_pct > DROP_RATE_WARN_PCT {
+            tracing::warn!(
+                "Copytrade engine dropped {dropped}/{} trades ({drop_pct:.1}%) in the last window — copies were missed",
+                received + dropped
+            );
+        } else {
+            tracing::debug!(
+                "Copytrade engine: {received} trades, {dropped} dropped, avg {:.1}ms",
+                now.avg_process_ms
+            );
+        }
+    }
+    now
+}
+
+/// One reconciliation step of a resting GTC order, already applied to the session.
+struct GtcFill {
+    order_id: String,
+    /// Cumulative matched size and the USDC it traded for
+    matched_shares: f64,
+    matched_usdc: f64,
+    fill_price: f64,
+    slippage_bps: f64,
+    /// Fully matched: the order left the book and is no longer tracked
+    complete: bool,
+}
+
+/// Polls the CLOB for each resting GTC order and applies fills the engine hasn't
+/// seen, priced from the order's trades rather than its limit. Partial fills are
+/// persisted as `partial` so they count toward positions while the rest of the
+/// order keeps resting; a fully matched order is marked filled and dropped.
+async fn reconcile_gtc_orders(
+    session: &mut ActiveSession,
+    clob_client: &Arc<RwLock<Option<ClobClientState>>>,
+    user_db: &Arc<Mutex<rusqlite::Connection>>,
+    update_tx: &broadcast::Sender<CopyTradeUpdate>,
+) {
+    if session.open_gtc_orders.is_empty() {
+        return;
+    }
+    let sid = session.config.id.clone();
+
+    // (clob_order_id, original_size, size_matched, matched_usdc), fetched before
+    // touching state. Trades are only read for orders that matched more shares.
+    let statuses: Vec<(String, f64, f64, f64)> = {
+        let clob = clob_client.read().await;
+        let Some(ref cs) = *clob else {
+            return;
+        };
+        let mut statuses = Vec::with_capacity(session.open_gtc_orders.len());
+        for (clob_order_id, order) in &session.open_gtc_orders {
+            let resp = match cs.client.order(clob_order_id).await {
+                Ok(resp) => resp,
+                Err(e) => {
+                    tracing::warn!(
+                        "Session {sid}: GTC status lookup failed for {clob_order_id}: {e}"
+                    );
+                    continue;
+                }
+            };
+            let size_matched = resp.size_matched.to_f64().unwrap_or(0.0);
+            if size_matched <= order.matched_shares {
+                continue;
+            }
+            match clob_matched_usdc(&cs.client, clob_order_id, &resp.associate_trades).await {
+                Ok(usdc) if usdc > 0.0 => statuses.push((
+                    clob_order_id.clone(),
+                    resp.original_size.to_f64().unwrap_or(0.0),
+                    size_matched,
+                    usdc,
+                )),
+                // Retried next health check rather than guessing a price
+                Ok(_) => tracing::debug!(
+                    "Session {sid}: GTC order {clob_order_id} matched but its trades aren't listed yet"
+                ),
+                Err(e) => tracing::warn!(
+                    "Session {sid}: GTC trade lookup failed for {clob_order_id}: {e}"
+                ),
+            }
+        }
+        statuses
+    }; // clob read guard dropped here
+
+    for (clob_order_id, original_size, size_matched, matched_usdc) in statuses {
+        let Some(fill) = apply_gtc_fill(
+            session,
+            &clob_order_id,
+            original_size,
+            size_matched,
+            matched_usdc,
+        ) else {
+            continue;
+        };
+        let status = if fill.complete {
+            OrderStatus::Filled
+        } else {
+            OrderStatus::Partial
+        };
+        {
+            let conn = user_db.lock().unwrap_or_else(|p| p.into_inner());
+            let _ = db::record_matched_fill(
+                &conn,
+                &fill.order_id,
+                status.as_str(),
+                fill.matched_usdc,
+                fill.matched_shares,
+                fill.fill_price,
+                Some(fill.slippage_bps),
+            );
+        }
+
+        if !fill.complete {
+            tracing::info!(
+                "Session {sid}: GTC order {clob_order_id} partially filled ({size_matched:.2} of {original_size:.2} shares)"
+            );
+            audit_order(user_db, &sid, Some(&fill.order_id), "partial_fill", || {
+                serde_json::json!({
+                    "clob_order_id": clob_order_id,
+                    "matched_shares": fill.matched_shares,
+                    "matched_usdc": fill.matched_usdc,
+                    "original_shares": original_size,
+                })
+            });
+            continue;
+        }
+
+        audit_order(user_db, &sid, Some(&fill.order_id), "filled", || {
+            serde_json::json!({
+                "clob_order_id": clob_order_id,
+                "matched_shares": fill.matched_shares,
+                "matched_usdc": fill.matched_usdc,
+                "by": "reconcile",
+            })
+        });
+        tracing::info!(
+            "Session {sid}: GTC order {clob_order_id} filled ({:.2} shares @ {:.4})",
+            fill.matched_shares,
+            fill.fill_price
+        );
+        let _ = update_tx.send(CopyTradeUpdate::OrderFilled {
+            session_id: sid.clone(),
+            order_id: fill.order_id,
+            fill_price: fill.fill_price,
+            slippage_bps: fill.slippage_bps,
+            owner: session.config.owner.clone(),
+        });
+    }
+}
+
+/// USDC the CLOB matched for `clob_order_id` across its trades: the trade price when
+/// we were the taker, each of our maker orders' own price otherwise.
+async fn clob_matched_usdc(
+    client: &Client<Authenticated<Normal>>,
+    clob_order_id: &str,
+    trade_ids: &[String],
+) -> Result<f64, String> {
+    let mut usdc = 0.0;
+    for trade_id in trade_ids {
+        let request = TradesRequest::builder().id(trade_id.as_str()).build();
+        let page = client
+            .trades(&request, None)
+            .await
+            .map_err(|e| e.to_string())?;
+        for trade in page.data {
+            if trade.taker_order_id == clob_order_id {
+                usdc += (trade.size * trade.price).to_f64().unwrap_or(0.0);
+                continue;
+            }
+            usdc += trade
+                .maker_orders
+                .iter()
+                .filter(|m| m.order_id == clob_order_id)
+                .map(|m| (m.matched_amount * m.price).to_f64().unwrap_or(0.0))
+                .sum::<f64>();
+        }
+    }
+    Ok(usdc)
+}
+
+/// Applies the shares and USDC a resting GTC order matched since the last check:
+/// buys add to the position (their capital was reserved at placement), sells come
+/// out of it and return their proceeds. A fully matched order stops being tracked
+/// and gives back whatever of a buy's reservation went unspent.
+fn apply_gtc_fill(
+    session: &mut ActiveSession,
+    clob_order_id: &str,
+    original_size: f64,
+    size_matched: f64,
+    matched_usdc: f64,
+) -> Option<GtcFill> {
+    let order = session.open_gtc_orders.get_mut(clob_order_id)?;
+    let new_shares = size_matched - order.matched_shares;
+    if new_shares <= 0.0 {
+        return None;
+    }
+    let new_usdc = (matched_usdc - order.matched_usdc).max(0.0);
+    order.matched_shares = size_matched;
+    order.matched_usdc = matched_usdc.max(order.matched_usdc);
+    let price = if new_usdc > 0.0 {
+        new_usdc / new_shares
+    } else {
+        order.fill_price()
+    };
+    let (asset_id, side) = (order.asset_id.clone(), order.side);
+    let fill = GtcFill {
+        order_id: order.order_id.clone(),
+        matched_shares: order.matched_shares,
+        matched_usdc: order.matched_usdc,
+        fill_price: order.fill_price(),
+        slippage_bps: order.slippage_bps(),
+        complete: original_size > 0.0 && size_matched >= original_size * 0.999,
+    };
+
+    let (cur_shares, _, peak) = session
+        .positions
+        .get(&asset_id)
+        .copied()
+        .unwrap_or((0.0, 0.0, 0.0));
+    match side {
+        Side::Buy => {
+            session.positions.insert(
+                asset_id.clone(),
+                (cur_shares + new_shares, price, peak.max(price)),
+            );
+        }
+        _ => {
+            session.remaining_capital += new_usdc;
+            let left = cur_shares - new_shares;
+            if left < 0.001 {
+                session.positions.remove(&asset_id);
+            } else {
+                session
+                    .positions
+                    .insert(asset_id.clone(), (left, price, peak));
+            }
+        }
+    }
+    sync_position_opened(&session.positions, &mut session.position_opened, &asset_id);
 
-    tracing::info!(
-        "Publishing {} tracked address(es) to ws_subscriber",
-        union.len()
-    );
-    let _ = trader_watch_tx.send(union);
+    if fill.complete
+        && let Some(order) = session.open_gtc_orders.remove(clob_order_id)
+    {
+        // Price improvement and share rounding leave part of a buy's reservation
+        session.remaining_capital += order.unmatched_reserve();
+    }
+    Some(fill)
 }
 
-// ---------------------------------------------------------------------------
-// Health check (60s interval)
-// ---------------------------------------------------------------------------
-
+#[allow(clippy::too_many_arguments)]
 async fn health_check(
     sessions: &mut HashMap<String, ActiveSession>,
     clob_client: &Arc<RwLock<Option<ClobClientState>>>,
+    http: &reqwest::Client,
     user_db: &Arc<Mutex<rusqlite::Connection>>,
     update_tx: &broadcast::Sender<CopyTradeUpdate>,
     trader_watch_tx: &tokio::sync::watch::Sender<std::collections::HashSet<String>>,
+    order_timestamps: &mut VecDeque<Instant>,
+    mark_cache: &mut MarkCache,
+    empty_balance_grace: Duration,
 ) {
     let mut to_stop: Vec<(String, String, String)> = Vec::new(); // (id, owner, reason)
+    let mut had_pauses = false;
+    mark_cache.retain(|_, (_, fetched)| fetched.elapsed() < MARK_TTL);
 
     for (sid, session) in sessions.iter_mut() {
+        // Resting GTC orders may have filled on the book since the last check
+        reconcile_gtc_orders(session, clob_client, user_db, update_tx).await;
+
         // Sync remaining_capital to SQLite
         {
             let conn = user_db.lock().unwrap_or_else(|p| p.into_inner());
-            let _ = db::update_session_capital(&conn, sid, session.remaining_capital);
+            let _ = db::update_session_capital(
+                &conn,
+                sid,
+                session.remaining_capital,
+                session.fees_paid,
+            );
+        }
+
+        // Time-boxed sessions: age counts from created_at, so it survives restarts
+        if let Some(max_runtime) = session.config.max_runtime_secs {
+            let age = chrono::DateTime::parse_from_rfc3339(&session.config.created_at)
+                .map(|created| {
+                    (chrono::Utc::now() - created.with_timezone(&chrono::Utc)).num_seconds()
+                })
+                .unwrap_or(0);
+            if age >= max_runtime as i64 {
+                tracing::info!("Session {sid} auto-stopped: ran {age}s (max {max_runtime}s)");
+                to_stop.push((
+                    sid.clone(),
+                    session.config.owner.clone(),
+                    "max_runtime".to_string(),
+                ));
+                continue;
+            }
         }
 
         // Circuit breaker — account for unrealized value in open positions
         if let Some(max_loss_pct) = session.config.max_loss_pct {
-            // Unrealized value = sum(shares * last_fill_price)
-            // Uses the most recent fill price per asset as best available estimate
+            // Unrealized value = sum(shares * mark). Mark is the most recent fill price
+            // unless the session opted into live CLOB midpoints, for every position or
+            // just those whose last fill is older than mark_stale_secs (falls back per asset).
+            let live_ids: Vec<String> =
+                match MarkPriceSource::from_str(&session.config.mark_price_source) {
+                    _ if session.positions.is_empty() => Vec::new(),
+                    Some(MarkPriceSource::Live) => session.positions.keys().cloned().collect(),
+                    _ => match session.config.mark_stale_secs {
+                        Some(stale_secs) => stale_position_ids(user_db, session, stale_secs),
+                        None => Vec::new(),
+                    },
+                };
+            let live_prices = if live_ids.is_empty() {
+                HashMap::new()
+            } else {
+                live_marks(http, mark_cache, &live_ids).await
+            };
             let unrealized_value: f64 = session
                 .positions
-                .values()
-                .map(|(shares, last_price)| shares * last_price)
+                .iter()
+                .map(|(asset_id, (shares, last_price, _))| {
+                    shares * live_prices.get(asset_id).copied().unwrap_or(*last_price)
+                })
                 .sum();
             let total_value = session.remaining_capital + unrealized_value;
             let pnl = total_value - session.config.initial_capital;
             let loss_pct = -pnl / session.config.initial_capital * 100.0;
             if loss_pct > max_loss_pct {
                 tracing::error!(
-                    "Session {sid} auto-stopped: loss {loss_pct:.1}% exceeds max {max_loss_pct:.1}% (cash={:.2}, positions={:.2})",
+                    "Session {sid} auto-stopped: loss {loss_pct:.1}% exceeds max {max_loss_pct:.1}% (cash={:.2}, positions={:.2}, mark={})",
                     session.remaining_capital,
-                    unrealized_value
+                    unrealized_value,
+                    session.config.mark_price_source
                 );
                 to_stop.push((
                     sid.clone(),
@@ -1272,11 +4739,29 @@ async fn health_check(
             }
         }
 
+        // Stop loss / take profit / trailing stop (running sessions only)
+        if has_position_exits(&session.config) {
+            let running =
+                SessionStatus::from_str(&session.config.status) == Some(SessionStatus::Running);
+            if running && !session.positions.is_empty() {
+                check_position_exits(
+                    session,
+                    mark_cache,
+                    clob_client,
+                    http,
+                    user_db,
+                    update_tx,
+                    order_timestamps,
+                )
+                .await;
+            }
+        }
+
         // Cancel GTC orders older than 1 hour
         let expired: Vec<String> = session
             .open_gtc_orders
             .iter()
-            .filter(|(_, (_, placed_at, _))| placed_at.elapsed() > GTC_TIMEOUT)
+            .filter(|(_, order)| order.placed_at.elapsed() > GTC_TIMEOUT)
             .map(|(clob_id, _)| clob_id.clone())
             .collect();
 
@@ -1294,12 +4779,23 @@ async fn health_check(
 
             if let Some(Ok(resp)) = cancel_result {
                 for canceled_id in &resp.canceled {
-                    if let Some((our_id, _, usdc)) = session.open_gtc_orders.remove(canceled_id) {
-                        session.remaining_capital += usdc; // Refund capital
-                        let conn = user_db.lock().unwrap_or_else(|p| p.into_inner());
-                        let _ = db::update_copytrade_order(
-                            &conn, &our_id, "canceled", None, None, None, None,
-                        );
+                    if let Some(order) = session.open_gtc_orders.remove(canceled_id) {
+                        // Refund what a buy still reserves; matched shares are kept
+                        let refund_usdc = order.unmatched_reserve();
+                        session.remaining_capital += refund_usdc;
+                        {
+                            let conn = user_db.lock().unwrap_or_else(|p| p.into_inner());
+                            let _ =
+                                record_gtc_close(&conn, &order.order_id, canceled_id, Some(&order));
+                        }
+                        audit_order(user_db, sid, Some(&order.order_id), "canceled", || {
+                            serde_json::json!({
+                                "clob_order_id": canceled_id,
+                                "refund_usdc": refund_usdc,
+                                "matched_shares": order.matched_shares,
+                                "by": "gtc_timeout",
+                            })
+                        });
                     }
                 }
                 tracing::info!(
@@ -1310,6 +4806,23 @@ async fn health_check(
                 tracing::warn!("Failed to cancel expired GTC orders: {e}");
             }
         }
+
+        // Empty-balance grace: pause only if capital stayed low for the whole window
+        if let Some(since) = session.low_balance_since {
+            if session.remaining_capital >= MIN_ORDER_USDC {
+                tracing::info!("Session {sid}: capital replenished, pending auto-pause canceled");
+                session.low_balance_since = None;
+            } else if since.elapsed() >= empty_balance_grace
+                && SessionStatus::from_str(&session.config.status) == Some(SessionStatus::Running)
+            {
+                tracing::warn!(
+                    "Session {sid}: capital below {MIN_ORDER_USDC} USDC for {}s, auto-pausing",
+                    since.elapsed().as_secs()
+                );
+                pause_for_empty_balance(session, user_db, update_tx);
+                had_pauses = true;
+            }
+        }
     }
 
     // Process stops outside the mutable borrow
@@ -1335,7 +4848,967 @@ async fn health_check(
         }
     }
 
-    if had_stops {
+    if had_stops || had_pauses {
         publish_tracked_addresses(sessions, trader_watch_tx);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A running live session with every optional filter and exit off.
+    fn session_config() -> CopyTradeSessionRow {
+        CopyTradeSessionRow {
+            id: "session".into(),
+            owner: "0xowner".into(),
+            list_id: None,
+            top_n: Some(10),
+            copy_pct: 0.5,
+            max_position_usdc: 100.0,
+            max_slippage_bps: 200,
+            order_type: "FOK".into(),
+            initial_capital: 1000.0,
+            remaining_capital: 1000.0,
+            simulate: false,
+            max_loss_pct: None,
+            status: "running".into(),
+            created_at: String::new(),
+            updated_at: String::new(),
+            only_favorable: false,
+            buy_order_type: None,
+            sell_order_type: None,
+            mark_price_source: "last_fill".into(),
+            min_trader_pnl: None,
+            min_trader_winrate: None,
+            top_pct: None,
+            slippage_pause_after: None,
+            min_liquidity_usdc: None,
+            close_on_trader_removal: false,
+            max_market_usdc: None,
+            seed_existing_positions: false,
+            sizing_mode: "dollar".into(),
+            webhook_url: None,
+            digest_interval_hours: None,
+            sizing_base: "remaining".into(),
+            max_runtime_secs: None,
+            asset_allowlist: None,
+            near_resolution_price: None,
+            gtc_price_ref: "source".into(),
+            gtc_price_offset_bps: 0,
+            sell_behavior: "proportional".into(),
+            trail_activate_pct: None,
+            trail_distance_pct: None,
+            mark_stale_secs: None,
+            signal_source: "traders".into(),
+            min_whale_usdc: None,
+            slippage_fee_bps: None,
+            fees_paid: 0.0,
+            max_signal_age_secs: None,
+            top_n_metric: "pnl".into(),
+            min_hold_secs: 0,
+            slippage_overrides: None,
+            max_orders_per_minute: None,
+            size_rounding: "down".into(),
+            take_profit_pct: None,
+            stop_loss_pct: None,
+            mode: "copy".into(),
+            allowed_categories: None,
+            blocked_categories: None,
+            copy_uncategorized: true,
+            copy_sides: "both".into(),
+            fok_fallback_gtc: false,
+            liquidity_window_mins: None,
+        }
+    }
+
+    fn active_session(config: CopyTradeSessionRow) -> ActiveSession {
+        ActiveSession {
+            remaining_capital: config.remaining_capital,
+            fees_paid: config.fees_paid,
+            asset_allowlist: parse_asset_allowlist(&config),
+            config,
+            traders: HashMap::from([("0xtrader".to_string(), 1.0)]),
+            trader_count: 1,
+            recent_orders: HashMap::new(),
+            order_timestamps: VecDeque::new(),
+            consecutive_failures: 0,
+            consecutive_slippage_rejections: 0,
+            low_balance_since: None,
+            cooldown_until: None,
+            positions: HashMap::new(),
+            position_opened: HashMap::new(),
+            open_gtc_orders: HashMap::new(),
+        }
+    }
+
+    fn resting_order(side: Side, usdc: f64, price: f64) -> OpenGtcOrder {
+        OpenGtcOrder {
+            order_id: "order".into(),
+            placed_at: Instant::now(),
+            usdc,
+            asset_id: "asset".into(),
+            side,
+            price,
+            source_price: price,
+            matched_shares: 0.0,
+            matched_usdc: 0.0,
+        }
+    }
+
+    #[test]
+    fn close_counts_only_filled_orders() {
+        assert!(close_filled(OrderStatus::Filled.as_str()));
+        assert!(close_filled(OrderStatus::Simulated.as_str()));
+        assert!(!close_filled(OrderStatus::Canceled.as_str()));
+        assert!(!close_filled(OrderStatus::Submitted.as_str()));
+        assert!(!close_filled(OrderStatus::Failed.as_str()));
+    }
+
+    #[test]
+    fn engine_exits_are_auto_origin() {
+        for reason in [
+            "stop_loss",
+            "take_profit",
+            "trailing_stop",
+            "trader_removed",
+        ] {
+            let origin = OrderOrigin::from_source_tx_hash(&reason.replace('_', "-"));
+            assert!(matches!(origin, OrderOrigin::Auto), "{reason}");
+        }
+    }
+
+    #[test]
+    fn session_rate_limit_and_global_ceiling() {
+        let mut config = session_config();
+        let now = Instant::now();
+        let mut global = VecDeque::new();
+
+        config.max_orders_per_minute = Some(2);
+        let mut session = VecDeque::from([now, now]);
+        let hit = rate_limit_exceeded(&config, &mut session, &mut global, now);
+        assert_eq!(hit.as_deref(), Some("2 orders/min for this session"));
+
+        // Stale submissions slide out of the one-minute window
+        let mut session = VecDeque::from([now - Duration::from_secs(61); 2]);
+        assert!(rate_limit_exceeded(&config, &mut session, &mut global, now).is_none());
+        assert!(session.is_empty());
+
+        // Unset falls back to the default
+        config.max_orders_per_minute = None;
+        let mut session = VecDeque::from(vec![now; 10]);
+        let hit = rate_limit_exceeded(&config, &mut session, &mut global, now);
+        assert_eq!(hit.as_deref(), Some("10 orders/min for this session"));
+
+        // The global ceiling still caps every session together
+        let mut session = VecDeque::new();
+        let mut global = VecDeque::from(vec![now; MAX_ORDERS_PER_MINUTE]);
+        let hit = rate_limit_exceeded(&config, &mut session, &mut global, now);
+        assert_eq!(hit.as_deref(), Some("120 orders/min across sessions"));
+    }
+
+    #[test]
+    fn busy_session_does_not_starve_another() {
+        let now = Instant::now();
+        let mut global = VecDeque::new();
+        let mut aggressive = session_config();
+        aggressive.max_orders_per_minute = Some(MAX_SESSION_ORDERS_PER_MINUTE);
+        let mut a = VecDeque::new();
+        // Session A places as many orders as it is allowed to
+        while rate_limit_exceeded(&aggressive, &mut a, &mut global, now).is_none() {
+            a.push_back(now);
+            global.push_back(now);
+        }
+        assert_eq!(a.len(), MAX_SESSION_ORDERS_PER_MINUTE as usize);
+
+        // Session B still has its whole budget
+        let slow = session_config();
+        let mut b = VecDeque::new();
+        for _ in 0..DEFAULT_SESSION_ORDERS_PER_MINUTE {
+            assert!(rate_limit_exceeded(&slow, &mut b, &mut global, now).is_none());
+            b.push_back(now);
+            global.push_back(now);
+        }
+    }
+
+    #[test]
+    fn gtc_buy_fills_at_matched_price_and_refunds_the_rest() {
+        let mut session = active_session(session_config());
+        // 50 USDC reserved for 100 shares at 0.50
+        session.remaining_capital = 950.0;
+        session
+            .open_gtc_orders
+            .insert("clob".into(), resting_order(Side::Buy, 50.0, 0.5));
+
+        // 40 shares crossed at 0.45: a partial fill, still resting
+        let fill = apply_gtc_fill(&mut session, "clob", 100.0, 40.0, 18.0).unwrap();
+        assert!(!fill.complete);
+        assert!((fill.fill_price - 0.45).abs() < 1e-9);
+        assert!((fill.slippage_bps - 1000.0).abs() < 1e-6);
+        assert_eq!(session.positions["asset"].0, 40.0);
+        assert_eq!(session.remaining_capital, 950.0);
+        assert!(session.open_gtc_orders.contains_key("clob"));
+        assert_eq!(session.open_gtc_orders["clob"].unmatched_reserve(), 32.0);
+
+        // Nothing new matched: no-op
+        assert!(apply_gtc_fill(&mut session, "clob", 100.0, 40.0, 18.0).is_none());
+
+        // The other 60 match at the limit: done, 2 USDC of the reservation unspent
+        let fill = apply_gtc_fill(&mut session, "clob", 100.0, 100.0, 48.0).unwrap();
+        assert!(fill.complete);
+        assert_eq!(fill.matched_usdc, 48.0);
+        assert!((fill.fill_price - 0.48).abs() < 1e-9);
+        assert_eq!(session.positions["asset"].0, 100.0);
+        assert!((session.remaining_capital - 952.0).abs() < 1e-9);
+        assert!(session.open_gtc_orders.is_empty());
+    }
+
+    #[test]
+    fn gtc_sell_fill_returns_matched_proceeds() {
+        let mut session = active_session(session_config());
+        session.positions.insert("asset".into(), (100.0, 0.5, 0.5));
+        session
+            .open_gtc_orders
+            .insert("clob".into(), resting_order(Side::Sell, 60.0, 0.6));
+
+        let fill = apply_gtc_fill(&mut session, "clob", 100.0, 25.0, 15.5).unwrap();
+        assert!(!fill.complete);
+        assert_eq!(session.positions["asset"].0, 75.0);
+        assert!((session.remaining_capital - 1015.5).abs() < 1e-9);
+
+        let fill = apply_gtc_fill(&mut session, "clob", 100.0, 100.0, 60.5).unwrap();
+        assert!(fill.complete);
+        assert!(!session.positions.contains_key("asset"));
+        assert!((session.remaining_capital - 1060.5).abs() < 1e-9);
+    }
+
+    fn dec(s: &str) -> Decimal {
+        Decimal::from_str(s).unwrap()
+    }
+
+    #[test]
+    fn market_orders_round_to_clob_precision() {
+        use SizeRounding::{Down, Nearest};
+        // Buys in USDC cents: a minimum-size order stays at the minimum
+        let size = market_order_size(Side::Buy, MIN_ORDER_USDC, 0.5, Down, 100.0);
+        assert_eq!(size, Some(dec("1")));
+        assert_eq!(
+            market_order_size(Side::Buy, 1.0049, 0.5, Down, 100.0),
+            Some(dec("1"))
+        );
+        assert_eq!(
+            market_order_size(Side::Buy, 1.0051, 0.5, Nearest, 100.0),
+            Some(dec("1.01"))
+        );
+        // Rounding up past the cap falls back to rounding down
+        assert_eq!(
+            market_order_size(Side::Buy, 1.0051, 0.5, Nearest, 1.005),
+            Some(dec("1"))
+        );
+
+        // Sells in shares at the lot size: 10 USDC at 0.3 is 33.333... shares
+        assert_eq!(
+            market_order_size(Side::Sell, 10.0, 0.3, Down, 100.0),
+            Some(dec("33.33"))
+        );
+        // 20.006 USDC at 0.4 is 50.015 shares; holding only 50.01 keeps it at 50.01
+        assert_eq!(
+            market_order_size(Side::Sell, 20.006, 0.4, Nearest, 50.01 * 0.4),
+            Some(dec("50.01"))
+        );
+        assert_eq!(
+            market_order_size(Side::Sell, 20.006, 0.4, Nearest, 100.0),
+            Some(dec("50.02"))
+        );
+        assert_eq!(market_order_size(Side::Sell, 1.0, 0.0, Down, 100.0), None);
+    }
+
+    #[test]
+    fn gtc_size_respects_share_minimum() {
+        use SizeRounding::{Down, Nearest};
+        // 2.499 USDC at 0.5 is 4.998 shares: below the minimum unless rounded up
+        assert_eq!(gtc_share_size(2.499, 0.5, Down, 100.0), None);
+        assert_eq!(gtc_share_size(2.499, 0.5, Nearest, 100.0), Some(dec("5")));
+        assert_eq!(gtc_share_size(2.499, 0.5, Nearest, 2.49), None);
+        assert_eq!(gtc_share_size(2.5, 0.5, Down, 100.0), Some(dec("5")));
+    }
+
+    fn filter(
+        config: &CopyTradeSessionRow,
+        category: &str,
+        side: Side,
+    ) -> Result<Side, &'static str> {
+        filter_signal(
+            config,
+            parse_asset_allowlist(config).as_ref(),
+            category,
+            "asset",
+            "",
+            side,
+        )
+    }
+
+    #[test]
+    fn fade_flips_the_side_before_copy_sides() {
+        let mut config = session_config();
+        config.mode = "fade".into();
+        assert_eq!(filter(&config, "", Side::Buy), Ok(Side::Sell));
+        assert_eq!(filter(&config, "", Side::Sell), Ok(Side::Buy));
+
+        // A buys-only fade session enters against source sells and ignores source buys
+        config.copy_sides = "buy".into();
+        assert_eq!(filter(&config, "", Side::Sell), Ok(Side::Buy));
+        assert_eq!(filter(&config, "", Side::Buy), Err("side_not_copied"));
+    }
+
+    #[test]
+    fn category_and_allowlist_filters() {
+        let mut config = session_config();
+        config.allowed_categories = Some("Politics,Sports".into());
+        config.blocked_categories = Some("sports".into());
+        assert_eq!(filter(&config, "politics", Side::Buy), Ok(Side::Buy));
+        assert_eq!(
+            filter(&config, "Sports", Side::Buy),
+            Err("category_blocked")
+        );
+        assert_eq!(
+            filter(&config, "Crypto", Side::Buy),
+            Err("category_not_allowed")
+        );
+        assert_eq!(filter(&config, "", Side::Buy), Ok(Side::Buy));
+        config.copy_uncategorized = false;
+        assert_eq!(filter(&config, " ", Side::Buy), Err("uncategorized"));
+
+        let mut config = session_config();
+        config.asset_allowlist = Some(r#"["other"]"#.into());
+        assert_eq!(filter(&config, "", Side::Buy), Err("not_in_allowlist"));
+        config.asset_allowlist = Some(r#"["asset"]"#.into());
+        assert_eq!(filter(&config, "", Side::Buy), Ok(Side::Buy));
+    }
+
+    #[test]
+    fn stale_signals_are_filtered() {
+        let mut config = session_config();
+        config.max_signal_age_secs = Some(60);
+        let old = (chrono::Utc::now().timestamp() - 600).to_string();
+        let fresh = (chrono::Utc::now().timestamp() - 5).to_string();
+        let run = |ts: &str| filter_signal(&config, None, "", "asset", ts, Side::Buy);
+        assert_eq!(run(&old), Err("stale_signal"));
+        assert_eq!(run(&fresh), Ok(Side::Buy));
+        // Unreadable timestamps pass
+        assert_eq!(run(""), Ok(Side::Buy));
+    }
+
+    #[test]
+    fn position_exit_branches() {
+        let mut config = session_config();
+        assert!(!has_position_exits(&config));
+        assert_eq!(position_exit(&config, 0.5, 0.9, 0.1), None);
+
+        config.stop_loss_pct = Some(20.0);
+        config.take_profit_pct = Some(50.0);
+        config.trail_activate_pct = Some(10.0);
+        config.trail_distance_pct = Some(5.0);
+        assert!(has_position_exits(&config));
+
+        // Entry 0.50: stop below 0.40 (-20%), take profit above 0.75 (+50%)
+        assert_eq!(position_exit(&config, 0.5, 0.5, 0.39), Some("stop_loss"));
+        assert_eq!(position_exit(&config, 0.5, 0.8, 0.76), Some("take_profit"));
+        // Peak 0.60 armed the trail (+20% > 10%); 0.56 gave back more than 5%
+        assert_eq!(
+            position_exit(&config, 0.5, 0.6, 0.56),
+            Some("trailing_stop")
+        );
+        assert_eq!(position_exit(&config, 0.5, 0.6, 0.58), None);
+        // Peak 0.54 never armed the trail
+        assert_eq!(position_exit(&config, 0.5, 0.54, 0.45), None);
+        // Stop loss wins when a trailing stop would also fire
+        assert_eq!(position_exit(&config, 0.5, 0.6, 0.38), Some("stop_loss"));
+        // No entry price: nothing to measure against
+        assert_eq!(position_exit(&config, 0.0, 0.6, 0.1), None);
+
+        // A trail needs both of its settings
+        config.trail_distance_pct = None;
+        config.stop_loss_pct = None;
+        config.take_profit_pct = None;
+        assert!(!has_position_exits(&config));
+        assert_eq!(position_exit(&config, 0.5, 0.6, 0.5), None);
+    }
+
+    fn sizing_inputs(config: &CopyTradeSessionRow, held_shares: f64) -> SizingInputs<'_> {
+        SizingInputs {
+            config,
+            trader_weight: 1.0,
+            remaining_capital: config.remaining_capital,
+            held_shares,
+            held_secs: None,
+            market_exposure: None,
+            source_portfolio: None,
+        }
+    }
+
+    #[test]
+    fn full_exit_sells_the_whole_position() {
+        let mut config = session_config();
+        // Source sells 10 USDC at 0.5 (20 shares); copy_pct 0.5 mirrors 10 of our 100
+        let inputs = sizing_inputs(&config, 100.0);
+        let proportional = size_order(&inputs, Side::Sell, 0.5, 10.0).unwrap();
+        assert!((proportional - 5.0).abs() < 1e-9);
+
+        config.sell_behavior = "full_exit".into();
+        let inputs = sizing_inputs(&config, 100.0);
+        let full = size_order(&inputs, Side::Sell, 0.5, 10.0).unwrap();
+        assert!((full - 50.0).abs() < 1e-9);
+
+        // Buys are untouched
+        let buy = size_order(&inputs, Side::Buy, 0.5, 10.0).unwrap();
+        assert!((buy - 5.0).abs() < 1e-9);
+        // Nothing held, nothing to exit
+        let flat = sizing_inputs(&config, 0.0);
+        assert!(matches!(
+            size_order(&flat, Side::Sell, 0.5, 10.0),
+            Err(SkipReason::NoPosition)
+        ));
+    }
+
+    #[test]
+    fn min_hold_defers_early_sells() {
+        let mut config = session_config();
+        config.min_hold_secs = 300;
+        let mut inputs = sizing_inputs(&config, 100.0);
+
+        inputs.held_secs = Some(299);
+        assert!(matches!(
+            size_order(&inputs, Side::Sell, 0.5, 10.0),
+            Err(SkipReason::MinHold)
+        ));
+        inputs.held_secs = Some(300);
+        assert!(size_order(&inputs, Side::Sell, 0.5, 10.0).is_ok());
+        // Unknown open time: no hold to enforce
+        inputs.held_secs = None;
+        assert!(size_order(&inputs, Side::Sell, 0.5, 10.0).is_ok());
+        // Adding to a fresh position is still allowed
+        inputs.held_secs = Some(1);
+        assert!(size_order(&inputs, Side::Buy, 0.5, 10.0).is_ok());
+    }
+
+    #[test]
+    fn min_hold_clock_follows_the_position() {
+        let mut config = session_config();
+        config.min_hold_secs = 300;
+        let mut session = active_session(config);
+        assert!(!within_min_hold(&session, "asset"));
+
+        session.positions.insert("asset".into(), (10.0, 0.5, 0.5));
+        sync_position_opened(&session.positions, &mut session.position_opened, "asset");
+        assert!(within_min_hold(&session, "asset"));
+
+        // Going flat stops the clock; the next buy starts a new one
+        session.positions.remove("asset");
+        sync_position_opened(&session.positions, &mut session.position_opened, "asset");
+        assert!(!within_min_hold(&session, "asset"));
+
+        session.config.min_hold_secs = 0;
+        session.positions.insert("asset".into(), (10.0, 0.5, 0.5));
+        sync_position_opened(&session.positions, &mut session.position_opened, "asset");
+        assert!(!within_min_hold(&session, "asset"));
+    }
+
+    #[test]
+    fn negative_capital_is_booked_as_fees() {
+        // Default policy (COPYTRADE_NEGATIVE_CAPITAL unset) clamps
+        let (mut capital, mut fees) = (-0.25, 1.0);
+        settle_negative_capital("session", &mut capital, &mut fees);
+        assert_eq!(capital, 0.0);
+        assert!((fees - 1.25).abs() < 1e-9);
+
+        let (mut capital, mut fees) = (3.0, 1.0);
+        settle_negative_capital("session", &mut capital, &mut fees);
+        assert_eq!((capital, fees), (3.0, 1.0));
+    }
+
+    #[test]
+    fn fok_fallback_only_for_copy_entries() {
+        let mut config = session_config();
+        let source_tx = "0xabc";
+        assert!(!falls_back_to_gtc(&config, CopyOrderType::FOK, source_tx));
+
+        config.fok_fallback_gtc = true;
+        assert!(falls_back_to_gtc(&config, CopyOrderType::FOK, source_tx));
+        assert!(!falls_back_to_gtc(&config, CopyOrderType::FAK, source_tx));
+        for exit in [
+            "stop-loss",
+            "take-profit",
+            "trailing-stop",
+            "trader-removed",
+        ] {
+            assert!(
+                !falls_back_to_gtc(&config, CopyOrderType::FOK, exit),
+                "{exit}"
+            );
+        }
+    }
+
+    #[test]
+    fn only_favorable_rejects_any_adverse_move() {
+        let mut config = session_config();
+        assert_eq!(check_slippage(&config, 200, 150.0), Ok(()));
+        assert_eq!(
+            check_slippage(&config, 200, 250.0),
+            Err(SkipReason::Slippage)
+        );
+
+        config.only_favorable = true;
+        assert_eq!(check_slippage(&config, 200, -50.0), Ok(()));
+        assert_eq!(check_slippage(&config, 200, 0.0), Ok(()));
+        assert_eq!(
+            check_slippage(&config, 200, 1.0),
+            Err(SkipReason::UnfavorableSlippage)
+        );
+        // A resting GTC never bids above the source price
+        let limit = gtc_limit_price(&config, 200, Side::Buy, 0.5, 0.6);
+        assert!(limit <= 0.5);
+    }
+
+    #[test]
+    fn undecryptable_wallet_is_flagged() {
+        use crate::api::crypto::DecryptError;
+
+        let user_db = Mutex::new(db::init_user_db(":memory:"));
+        let wallet_id = {
+            let conn = user_db.lock().unwrap();
+            db::create_trading_wallet(
+                &conn, "0xowner", "0xwallet", "0xproxy", b"key", b"nonce", "proxy",
+            )
+            .ok()
+            .expect("wallet created")
+        };
+        let status = |user_db: &Mutex<rusqlite::Connection>| {
+            let conn = user_db.lock().unwrap();
+            db::get_trading_wallet_by_id(&conn, "0xowner", &wallet_id)
+                .unwrap()
+                .unwrap()
+                .status
+        };
+
+        let malformed = DecryptError::Malformed("bad nonce".into());
+        wallet_decrypt_failed(&user_db, "0xowner", &wallet_id, malformed);
+        assert_ne!(status(&user_db), "decrypt_error");
+
+        let err = wallet_decrypt_failed(&user_db, "0xowner", &wallet_id, DecryptError::KeyMismatch);
+        assert!(err.contains("WALLET_ENCRYPTION_KEY"));
+        assert_eq!(status(&user_db), "decrypt_error");
+    }
+
+    #[test]
+    fn hard_order_cap_refuses_absurd_sizes() {
+        let cap = DEFAULT_MAX_ORDER_USDC;
+        assert!(within_order_cap(25.0, cap));
+        assert!(within_order_cap(cap, cap));
+        assert!(!within_order_cap(cap + 0.01, cap));
+        assert!(!within_order_cap(0.0, cap));
+        assert!(!within_order_cap(-5.0, cap));
+        assert!(!within_order_cap(f64::NAN, cap));
+        assert!(!within_order_cap(f64::INFINITY, cap));
+    }
+
+    #[test]
+    fn dedup_map_is_seeded_from_recent_signal_orders() {
+        let user_db = Mutex::new(db::init_user_db(":memory:"));
+        let now = chrono::Utc::now();
+        let order = |id: &str, asset_id: &str, status: &str, origin: &str, age_secs: i64| {
+            let created_at = (now - chrono::Duration::seconds(age_secs)).to_rfc3339();
+            CopyTradeOrderRow {
+                id: id.into(),
+                session_id: "session".into(),
+                source_tx_hash: "0xsource".into(),
+                source_trader: "0xtrader".into(),
+                clob_order_id: None,
+                asset_id: asset_id.into(),
+                side: "buy".into(),
+                price: 0.5,
+                source_price: 0.5,
+                size_usdc: 10.0,
+                size_shares: Some(20.0),
+                status: status.into(),
+                error_message: None,
+                fill_price: None,
+                slippage_bps: None,
+                tx_hash: None,
+                created_at: created_at.clone(),
+                updated_at: created_at,
+                origin: origin.into(),
+                max_slippage_bps: None,
+                fallback_for: None,
+            }
+        };
+        {
+            let conn = user_db.lock().unwrap();
+            db::create_copytrade_session(&conn, &session_config()).unwrap();
+            for row in [
+                order("recent", "copied", "filled", "signal", 5),
+                order("failed", "failed", "failed", "signal", 5),
+                order("expired", "expired", "filled", "signal", 120),
+                order("manual", "manual", "filled", "manual", 5),
+            ] {
+                db::insert_copytrade_order(&conn, &row).unwrap();
+            }
+        }
+
+        let recent = restore_recent_orders(&user_db, "session");
+        assert_eq!(recent.len(), 1);
+        // Backdated by the order's age, so the window ends where it would have
+        let elapsed = recent["copied:buy"].elapsed();
+        assert!(elapsed >= Duration::from_secs(4) && elapsed < DEDUP_WINDOW);
+    }
+
+    #[tokio::test]
+    async fn clob_init_retries_only_transient_errors() {
+        let calls = std::cell::Cell::new(0);
+        let flaky = retry_clob_init(3, "0xowner", || {
+            calls.set(calls.get() + 1);
+            let n = calls.get();
+            async move {
+                if n == 1 {
+                    Err(ClobInitError::Transient("CLOB auth error: timeout".into()))
+                } else {
+                    Ok(n)
+                }
+            }
+        })
+        .await;
+        assert_eq!(flaky, Ok(2));
+
+        calls.set(0);
+        let permanent: Result<(), _> = retry_clob_init(3, "0xowner", || {
+            calls.set(calls.get() + 1);
+            async { Err(ClobInitError::Permanent("no credentialed wallet".into())) }
+        })
+        .await;
+        assert_eq!(permanent, Err("no credentialed wallet".to_string()));
+        assert_eq!(calls.get(), 1);
+
+        let exhausted: Result<(), _> = retry_clob_init(1, "0xowner", || async {
+            Err(ClobInitError::Transient("CLOB auth error: 503".into()))
+        })
+        .await;
+        assert_eq!(
+            exhausted,
+            Err("CLOB auth error: 503 (after 1 attempts)".to_string())
+        );
+    }
+
+    #[test]
+    fn dropped_signals_reach_running_sessions_on_that_feed() {
+        let session = |id: &str, status: &str, signal_source: &str| {
+            let mut config = session_config();
+            config.id = id.into();
+            config.status = status.into();
+            config.signal_source = signal_source.into();
+            (id.to_string(), active_session(config))
+        };
+        let sessions = HashMap::from([
+            session("traders", "running", "traders"),
+            session("paused", "paused", "traders"),
+            session("whales", "running", "whale_follow"),
+        ]);
+        let (update_tx, mut update_rx) = broadcast::channel(8);
+
+        notify_signals_dropped(&sessions, false, 7, &update_tx);
+        match update_rx.try_recv() {
+            Ok(CopyTradeUpdate::SignalsDropped {
+                session_id, count, ..
+            }) => assert_eq!((session_id.as_str(), count), ("traders", 7)),
+            _ => panic!("expected SignalsDropped for the trader session"),
+        }
+        assert!(update_rx.try_recv().is_err());
+
+        notify_signals_dropped(&sessions, true, 2, &update_tx);
+        match update_rx.try_recv() {
+            Ok(CopyTradeUpdate::SignalsDropped { session_id, .. }) => {
+                assert_eq!(session_id, "whales")
+            }
+            _ => panic!("expected SignalsDropped for the whale session"),
+        }
+        assert!(update_rx.try_recv().is_err());
+    }
+
+    #[test]
+    fn env_flags_accept_truthy_values() {
+        for on in ["1", "true", " TRUE ", "yes"] {
+            assert!(env_flag(on), "{on}");
+        }
+        for off in ["", "0", "false", "no", "on"] {
+            assert!(!env_flag(off), "{off}");
+        }
+    }
+
+    /// Everything `process_trade` needs besides the trade and session. The user DB is
+    /// in memory and HTTP goes to a closed local port, so price lookups fail fast and
+    /// simulated fills fall back to the source price.
+    struct Pipeline {
+        clob_client: Arc<RwLock<Option<ClobClientState>>>,
+        http: reqwest::Client,
+        ch_db: clickhouse::Client,
+        user_db: Arc<Mutex<rusqlite::Connection>>,
+        update_tx: broadcast::Sender<CopyTradeUpdate>,
+        order_timestamps: VecDeque<Instant>,
+        perf_cache: TraderPerfCache,
+        volume_cache: AssetVolumeCache,
+        portfolio_cache: TraderPortfolioCache,
+        market_cache: MarketCache,
+    }
+
+    impl Pipeline {
+        fn new(config: &CopyTradeSessionRow) -> Self {
+            let conn = db::init_user_db(":memory:");
+            db::create_copytrade_session(&conn, config).unwrap();
+            Self {
+                clob_client: Arc::new(RwLock::new(None)),
+                http: reqwest::Client::builder()
+                    .proxy(reqwest::Proxy::all("http://127.0.0.1:9").unwrap())
+                    .build()
+                    .unwrap(),
+                ch_db: clickhouse::Client::default(),
+                user_db: Arc::new(Mutex::new(conn)),
+                update_tx: broadcast::channel(64).0,
+                order_timestamps: VecDeque::new(),
+                perf_cache: HashMap::new(),
+                volume_cache: Arc::new(Mutex::new(HashMap::new())),
+                portfolio_cache: HashMap::new(),
+                market_cache: Arc::new(RwLock::new(HashMap::new())),
+            }
+        }
+
+        async fn run(&mut self, session: &mut ActiveSession, trade: &LiveTrade) {
+            process_trade(
+                trade,
+                session,
+                &self.clob_client,
+                &self.http,
+                &self.ch_db,
+                &self.user_db,
+                &self.update_tx,
+                &mut self.order_timestamps,
+                &mut self.perf_cache,
+                &self.volume_cache,
+                &mut self.portfolio_cache,
+                &self.market_cache,
+                Duration::ZERO,
+            )
+            .await;
+        }
+
+        fn orders(&self, session_id: &str) -> Vec<CopyTradeOrderRow> {
+            let conn = self.user_db.lock().unwrap();
+            db::get_session_orders(&conn, session_id, 100, 0).unwrap()
+        }
+    }
+
+    fn source_trade(side: &str, tx_hash: &str) -> LiveTrade {
+        LiveTrade {
+            tx_hash: tx_hash.into(),
+            block_timestamp: String::new(),
+            trader: "0xtrader".into(),
+            side: side.into(),
+            asset_id: "asset".into(),
+            amount: "100.000000".into(),
+            price: "0.500000".into(),
+            usdc_amount: "50.000000".into(),
+            question: String::new(),
+            outcome: String::new(),
+            category: String::new(),
+            block_number: 1,
+            cache_key: String::new(),
+        }
+    }
+
+    #[tokio::test]
+    async fn force_simulate_keeps_live_sessions_off_the_clob() {
+        FORCE_SIMULATE_OVERRIDE.set(Some(true));
+        let config = session_config();
+        assert!(!config.simulate);
+        let mut pipeline = Pipeline::new(&config);
+        let mut session = active_session(config);
+
+        pipeline
+            .run(&mut session, &source_trade("buy", "0xbuy"))
+            .await;
+
+        // The live path would have failed the order: there is no CLOB client
+        let orders = pipeline.orders("session");
+        assert_eq!(orders.len(), 1);
+        assert_eq!(orders[0].status, OrderStatus::Simulated.as_str());
+        assert!(session.positions.contains_key("asset"));
+    }
+
+    #[tokio::test]
+    async fn sells_only_session_ignores_buys() {
+        let mut config = session_config();
+        config.copy_sides = "sell".into();
+        config.simulate = true;
+        let mut pipeline = Pipeline::new(&config);
+        let mut session = active_session(config);
+        session.positions.insert("asset".into(), (100.0, 0.5, 0.5));
+
+        pipeline
+            .run(&mut session, &source_trade("buy", "0xbuy"))
+            .await;
+        assert!(pipeline.orders("session").is_empty());
+        assert_eq!(session.positions["asset"].0, 100.0);
+
+        pipeline
+            .run(&mut session, &source_trade("sell", "0xsell"))
+            .await;
+        let orders = pipeline.orders("session");
+        assert_eq!(orders.len(), 1);
+        assert_eq!(orders[0].side, "sell");
+        assert!(session.positions.get("asset").is_none_or(|p| p.0 < 100.0));
+    }
+
+    #[tokio::test]
+    async fn illiquid_buys_are_judged_on_the_cached_volume() {
+        let mut config = session_config();
+        config.simulate = true;
+        config.min_liquidity_usdc = Some(1_000.0);
+        let mut pipeline = Pipeline::new(&config);
+        pipeline.volume_cache.lock().unwrap().insert(
+            ("asset".into(), DEFAULT_LIQUIDITY_WINDOW_MINS),
+            AssetVolume {
+                volume: Some(10.0),
+                fetched: Some(Instant::now()),
+                refreshing: false,
+            },
+        );
+        let mut session = active_session(config);
+
+        pipeline
+            .run(&mut session, &source_trade("buy", "0xdead"))
+            .await;
+        assert!(pipeline.orders("session").is_empty());
+
+        // No volume yet for a 15-minute window: the buy goes through while the
+        // lookup runs in the background
+        session.config.liquidity_window_mins = Some(15);
+        pipeline
+            .run(&mut session, &source_trade("buy", "0xfresh"))
+            .await;
+        assert_eq!(pipeline.orders("session").len(), 1);
+        assert!(
+            pipeline
+                .volume_cache
+                .lock()
+                .unwrap()
+                .contains_key(&("asset".to_string(), 15))
+        );
+    }
+
+    #[tokio::test]
+    async fn lagged_feeds_are_reported_to_running_sessions() {
+        let mut config = session_config();
+        config.status = "running".into();
+        let conn = db::init_user_db(":memory:");
+        db::create_copytrade_session(&conn, &config).unwrap();
+        db::save_resolved_traders(&conn, "session", &["0xtrader"]).unwrap();
+
+        // Overflow a one-slot feed before the engine starts reading it
+        let (trade_tx, trade_rx) = broadcast::channel(1);
+        for tx_hash in ["0x1", "0x2", "0x3"] {
+            let mut trade = source_trade("buy", tx_hash);
+            trade.trader = "0xuntracked".into();
+            trade_tx.send(trade).unwrap();
+        }
+        let (_alert_tx, alert_rx) = broadcast::channel(1);
+        let (cmd_tx, cmd_rx) = mpsc::channel(8);
+        let (update_tx, mut update_rx) = broadcast::channel(64);
+        let metrics = Arc::new(EngineMetrics::default());
+        tokio::spawn(copytrade_engine_loop(
+            trade_rx,
+            alert_rx,
+            cmd_rx,
+            update_tx,
+            Arc::new(RwLock::new(None)),
+            Arc::new(Mutex::new(conn)),
+            Arc::new([0; 32]),
+            clickhouse::Client::default(),
+            tokio::sync::watch::channel(HashSet::new()).0,
+            reqwest::Client::new(),
+            Arc::clone(&metrics),
+            Arc::new(RwLock::new(HashMap::new())),
+        ));
+
+        let mut next_drop = async || loop {
+            match tokio::time::timeout(Duration::from_secs(5), update_rx.recv()).await {
+                Ok(Ok(CopyTradeUpdate::SignalsDropped {
+                    session_id, count, ..
+                })) => return (session_id, count),
+                Ok(Ok(_)) => continue,
+                _ => panic!("expected SignalsDropped"),
+            }
+        };
+        assert_eq!(next_drop().await, ("session".to_string(), 2));
+
+        // Drops reported by the confirmation gate take the same path
+        cmd_tx
+            .send(CopyTradeCommand::SignalsDropped { count: 5 })
+            .await
+            .unwrap();
+        assert_eq!(next_drop().await, ("session".to_string(), 5));
+        assert_eq!(metrics.snapshot().trades_dropped, 7);
+    }
+
+    #[test]
+    fn matched_fill_reads_the_filled_portion() {
+        // Buy filled in full: 50 USDC for 100 shares
+        let full = matched_fill(Side::Buy, dec("50"), dec("100"), 50.0, 0.52);
+        assert_eq!(
+            full,
+            MatchedFill {
+                price: 0.5,
+                shares: 100.0,
+                usdc: 50.0,
+                remaining_usdc: 0.0,
+            }
+        );
+
+        // FAK buy matched 20 of 50 USDC at 0.4
+        let partial = matched_fill(Side::Buy, dec("20"), dec("50"), 50.0, 0.52);
+        assert_eq!((partial.price, partial.shares), (0.4, 50.0));
+        assert_eq!((partial.usdc, partial.remaining_usdc), (20.0, 30.0));
+
+        // Sell: making is shares sent, taking the USDC received
+        let sell = matched_fill(Side::Sell, dec("100"), dec("45"), 50.0, 0.52);
+        assert_eq!((sell.price, sell.shares, sell.usdc), (0.45, 100.0, 45.0));
+        assert_eq!(sell.remaining_usdc, 5.0);
+
+        // Nothing matched: the quoted price stands in and the whole size remains
+        for (making, taking) in [("0", "0"), ("0", "10"), ("10", "0")] {
+            let none = matched_fill(Side::Buy, dec(making), dec(taking), 50.0, 0.52);
+            assert_eq!(none.price, 0.52, "{making}/{taking}");
+        }
+        let zero = matched_fill(Side::Buy, dec("0"), dec("0"), 50.0, 0.52);
+        assert_eq!(
+            (zero.shares, zero.usdc, zero.remaining_usdc),
+            (0.0, 0.0, 50.0)
+        );
+    }
+
+    #[test]
+    fn gtc_fallback_counts_the_fok_it_replaces() {
+        let mut config = session_config();
+        config.max_orders_per_minute = Some(3);
+        let now = Instant::now();
+        let one = VecDeque::from([now]);
+        let two = VecDeque::from([now, now]);
+
+        // One earlier order + the FOK + the fallback fits in 3/min; two earlier do not
+        assert_eq!(fallback_rate_limited(&config, &one, &one, now), None);
+        assert_eq!(
+            fallback_rate_limited(&config, &two, &two, now),
+            Some("3 orders/min for this session".into())
+        );
+
+        let global = VecDeque::from(vec![now; MAX_ORDERS_PER_MINUTE - 1]);
+        assert!(fallback_rate_limited(&config, &one, &global, now).is_some());
+    }
+}
@@ -1,9 +1,25 @@
 use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 use tokio::sync::RwLock;
 
 const PREFIX_LEN: usize = 15;
 
+/// Attempts per Gamma request (transport errors, 429 and 5xx are retried)
+const GAMMA_MAX_ATTEMPTS: u32 = 3;
+/// Backoff before the first retry, doubled for each further attempt
+#[cfg(not(test))]
+const GAMMA_RETRY_BASE: Duration = Duration::from_millis(500);
+#[cfg(test)]
+const GAMMA_RETRY_BASE: Duration = Duration::from_millis(1);
+/// Consecutive failed requests (after retries) that open the circuit breaker
+const GAMMA_BREAKER_THRESHOLD: u32 = 5;
+/// While open, Gamma calls fail fast instead of hammering a degraded API
+#[cfg(not(test))]
+const GAMMA_BREAKER_COOLDOWN: Duration = Duration::from_secs(30);
+#[cfg(test)]
+const GAMMA_BREAKER_COOLDOWN: Duration = Duration::from_millis(100);
+
 #[derive(Clone, Debug)]
 pub struct MarketInfo {
     pub question: String,
@@ -68,13 +84,127 @@ pub(crate) fn cache_key(token_id: &str) -> String {
     }
 }
 
+// ---------------------------------------------------------------------------
+// Gamma API access (retry + circuit breaker)
+// ---------------------------------------------------------------------------
+
+struct GammaBreaker {
+    consecutive_failures: u32,
+    open_until: Option<Instant>,
+}
+
+impl GammaBreaker {
+    fn is_open(&self) -> bool {
+        self.open_until.is_some_and(|t| Instant::now() < t)
+    }
+
+    fn record(&mut self, ok: bool) {
+        if ok {
+            if self.open_until.take().is_some() {
+                tracing::info!("Gamma circuit breaker closed: API responding again");
+            }
+            self.consecutive_failures = 0;
+            return;
+        }
+        self.consecutive_failures += 1;
+        // Re-opens after one failed probe once the cooldown has elapsed
+        if self.consecutive_failures >= GAMMA_BREAKER_THRESHOLD && !self.is_open() {
+            tracing::warn!(
+                "Gamma circuit breaker open for {}s after {} consecutive failures",
+                GAMMA_BREAKER_COOLDOWN.as_secs(),
+                self.consecutive_failures
+            );
+            self.open_until = Some(Instant::now() + GAMMA_BREAKER_COOLDOWN);
+        }
+    }
+}
+
+/// Shared by every Gamma caller: the breaker tracks the health of one upstream API.
+static GAMMA_BREAKER: std::sync::Mutex<GammaBreaker> = std::sync::Mutex::new(GammaBreaker {
+    consecutive_failures: 0,
+    open_until: None,
+});
+
+fn gamma_base_url() -> String {
+    std::env::var("GAMMA_API_URL")
+        .map(|u| u.trim_end_matches('/').to_string())
+        .unwrap_or_else(|_| "https://gamma-api.polymarket.com".into())
+}
+
+/// Statuses worth another attempt: rate limiting and server-side errors.
+fn gamma_retryable(status: reqwest::StatusCode) -> bool {
+    status.is_server_error() || status == reqwest::StatusCode::TOO_MANY_REQUESTS
+}
+
+/// GETs `path` (e.g. "/markets?...") from the Gamma API and decodes the JSON body.
+/// Transient failures are retried with exponential backoff; while the circuit
+/// breaker is open the call fails immediately without a request.
+pub(crate) async fn gamma_get<T: serde::de::DeserializeOwned>(
+    http: &reqwest::Client,
+    path: &str,
+    timeout: Duration,
+) -> Result<T, String> {
+    gamma_get_from(&GAMMA_BREAKER, &gamma_base_url(), http, path, timeout).await
+}
+
+/// `gamma_get` against an explicit breaker and base URL. Only an unreachable or
+/// overloaded API (transport errors, 429, 5xx after retries) counts against the
+/// breaker; any other response, including a 4xx or an undecodable body, proves the
+/// API is up.
+async fn gamma_get_from<T: serde::de::DeserializeOwned>(
+    breaker: &std::sync::Mutex<GammaBreaker>,
+    base_url: &str,
+    http: &reqwest::Client,
+    path: &str,
+    timeout: Duration,
+) -> Result<T, String> {
+    let lock = || breaker.lock().unwrap_or_else(|p| p.into_inner());
+    if lock().is_open() {
+        return Err("Gamma circuit breaker open".into());
+    }
+
+    let url = format!("{base_url}{path}");
+    let mut delay = GAMMA_RETRY_BASE;
+    let mut attempt = 1;
+    let (result, api_up) = loop {
+        let retryable_err = match http.get(&url).timeout(timeout).send().await {
+            Ok(resp) if resp.status().is_success() => {
+                let body = resp
+                    .json::<T>()
+                    .await
+                    .map_err(|e| format!("Gamma parse error: {e}"));
+                break (body, true);
+            }
+            Ok(resp) => {
+                let status = resp.status();
+                let err = format!("Gamma returned {status}");
+                if !gamma_retryable(status) {
+                    break (Err(err), true);
+                }
+                err
+            }
+            Err(e) => format!("Gamma request failed: {e}"),
+        };
+        if attempt >= GAMMA_MAX_ATTEMPTS {
+            break (Err(retryable_err), false);
+        }
+        tracing::debug!("{retryable_err} (attempt {attempt}/{GAMMA_MAX_ATTEMPTS}), retrying");
+        tokio::time::sleep(delay).await;
+        delay *= 2;
+        attempt += 1;
+    };
+
+    lock().record(api_up);
+    result
+}
+
 /// Pre-warm the cache by fetching Gamma events targeted to tokens in ClickHouse.
 /// Queries ClickHouse for all distinct asset_ids, then paginates Gamma events
 /// until every ClickHouse token has a full-precision match (or pagination exhausted).
 pub async fn warm_cache(http: &reqwest::Client, db: &clickhouse::Client, cache: &MarketCache) {
     // 1. Get all distinct token prefixes from ClickHouse
     let target_prefixes: HashSet<String> = match db
-        .query("SELECT DISTINCT asset_id FROM poly_dearboard.trades")
+        .query("SELECT DISTINCT asset_id FROM trades")
         .fetch_all::<AssetIdRow>()
         .await
     {
@@ -100,27 +230,13 @@ pub async fn warm_cache(http: &reqwest::Client, db: &clickhouse::Client, cache:
     let max_offset = 100_000u32;
 
     loop {
-        let url = format!(
-            "https://gamma-api.polymarket.com/events?limit={batch}&offset={offset}&order=volume24hr&ascending=false"
-        );
-
-        let resp = match http
-            .get(&url)
-            .timeout(std::time::Duration::from_secs(15))
-            .send()
-            .await
-        {
-            Ok(r) => r,
-            Err(e) => {
-                tracing::warn!("Market cache warm failed at offset {offset}: {e}");
-                break;
-            }
-        };
+        let path =
+            format!("/events?limit={batch}&offset={offset}&order=volume24hr&ascending=false");
 
-        let events: Vec<GammaEvent> = match resp.json().await {
+        let events: Vec<GammaEvent> = match gamma_get(http, &path, Duration::from_secs(15)).await {
             Ok(e) => e,
             Err(e) => {
-                tracing::warn!("Market cache parse failed at offset {offset}: {e}");
+                tracing::warn!("Market cache warm failed at offset {offset}: {e}");
                 break;
             }
         };
@@ -194,15 +310,19 @@ struct AssetIdRow {
 
 /// Cross-reference the warm cache with on-chain ConditionResolution events,
 /// compute exact resolved prices, and write them to the resolved_prices table.
-pub async fn populate_resolved_prices(db: &clickhouse::Client, cache: &MarketCache) {
+pub async fn populate_resolved_prices(
+    db: &clickhouse::Client,
+    database: &str,
+    cache: &MarketCache,
+) {
     use super::types::{ConditionResolutionRow, ResolvedPriceRow};
 
     // 1. Query all condition resolutions from ClickHouse
     let resolutions: Vec<ConditionResolutionRow> = match db
-        .query(
+        .query(&format!(
             "SELECT condition_id, payout_numerators, block_number
-             FROM poly_dearboard_conditional_tokens.condition_resolution",
-        )
+             FROM {database}_conditional_tokens.condition_resolution"
+        ))
         .fetch_all()
         .await
     {
@@ -240,7 +360,7 @@ pub async fn populate_resolved_prices(db: &clickhouse::Client, cache: &MarketCac
 
     // 3. Query distinct ClickHouse asset_ids
     let ch_assets: Vec<AssetIdRow> = match db
-        .query("SELECT DISTINCT asset_id FROM poly_dearboard.trades")
+        .query("SELECT DISTINCT asset_id FROM trades")
         .fetch_all()
         .await
     {
@@ -297,14 +417,14 @@ pub async fn populate_resolved_prices(db: &clickhouse::Client, cache: &MarketCac
 
     // 5. Truncate + batch INSERT
     if let Err(e) = db
-        .query("TRUNCATE TABLE IF EXISTS poly_dearboard.resolved_prices")
+        .query("TRUNCATE TABLE IF EXISTS resolved_prices")
         .execute()
         .await
     {
         tracing::warn!("Failed to truncate resolved_prices: {e}");
     }
 
-    let mut inserter = match db.insert("poly_dearboard.resolved_prices") {
+    let mut inserter = match db.insert("resolved_prices") {
         Ok(i) => i,
         Err(e) => {
             tracing::warn!("Failed to create inserter for resolved_prices: {e}");
@@ -340,7 +460,7 @@ pub async fn persist_cache_to_clickhouse(db: &clickhouse::Client, cache: &Market
 
     let now = chrono::Utc::now().timestamp() as u32;
 
-    let mut inserter = match db.insert("poly_dearboard.market_metadata") {
+    let mut inserter = match db.insert("market_metadata") {
         Ok(i) => i,
         Err(e) => {
             tracing::warn!("Failed to create inserter for market_metadata: {e}");
@@ -417,7 +537,7 @@ pub async fn resolve_markets(
         let query = format!(
             "SELECT asset_id, question, outcome, category, condition_id, gamma_token_id, \
                     outcome_index, active, all_token_ids, outcomes \
-             FROM poly_dearboard.market_metadata FINAL \
+             FROM market_metadata FINAL \
              WHERE asset_id IN ({in_clause})"
         );
 
@@ -503,19 +623,8 @@ async fn fetch_market_info(http: &reqwest::Client, token_id: &str) -> Option<Mar
     // For legacy scientific notation IDs, convert to integer form for Gamma lookup.
     let lookup_id = to_integer_id(token_id);
 
-    let url = format!(
-        "https://gamma-api.polymarket.com/markets?clob_token_ids={}",
-        lookup_id
-    );
-
-    let resp = http
-        .get(&url)
-        .timeout(std::time::Duration::from_secs(5))
-        .send()
-        .await
-        .ok()?;
-
-    let markets: Vec<GammaMarket> = resp.json().await.ok()?;
+    let path = format!("/markets?clob_token_ids={lookup_id}");
+    let markets: Vec<GammaMarket> = gamma_get(http, &path, Duration::from_secs(5)).await.ok()?;
     let market = markets.into_iter().next()?;
 
     let ids = market.parsed_token_ids();
@@ -605,3 +714,161 @@ impl GammaMarket {
             .unwrap_or_default()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn gamma_breaker_opens_after_threshold_and_closes_on_success() {
+        let mut breaker = GammaBreaker {
+            consecutive_failures: 0,
+            open_until: None,
+        };
+        for _ in 1..GAMMA_BREAKER_THRESHOLD {
+            breaker.record(false);
+        }
+        assert!(!breaker.is_open());
+        breaker.record(false);
+        assert!(breaker.is_open());
+
+        breaker.record(true);
+        assert!(!breaker.is_open());
+        assert_eq!(breaker.consecutive_failures, 0);
+    }
+
+    #[test]
+    fn gamma_retries_only_transient_statuses() {
+        use reqwest::StatusCode;
+        assert!(gamma_retryable(StatusCode::TOO_MANY_REQUESTS));
+        assert!(gamma_retryable(StatusCode::BAD_GATEWAY));
+        assert!(gamma_retryable(StatusCode::SERVICE_UNAVAILABLE));
+        assert!(!gamma_retryable(StatusCode::NOT_FOUND));
+        assert!(!gamma_retryable(StatusCode::BAD_REQUEST));
+    }
+
+    /// Minimal HTTP/1.1 server answering each request from `script`, then with `fallback`
+    struct MockGamma {
+        base_url: String,
+        hits: Arc<std::sync::atomic::AtomicUsize>,
+        script: Arc<std::sync::Mutex<std::collections::VecDeque<(u16, &'static str)>>>,
+    }
+
+    impl MockGamma {
+        async fn start(fallback: (u16, &'static str)) -> Self {
+            use std::sync::atomic::Ordering;
+            use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+            let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+            let base_url = format!("http://{}", listener.local_addr().unwrap());
+            let hits = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+            let script = Arc::new(std::sync::Mutex::new(std::collections::VecDeque::new()));
+            let (server_hits, server_script) = (hits.clone(), script.clone());
+            tokio::spawn(async move {
+                loop {
+                    let Ok((mut socket, _)) = listener.accept().await else {
+                        return;
+                    };
+                    let mut buf = [0u8; 4096];
+                    let _ = socket.read(&mut buf).await;
+                    server_hits.fetch_add(1, Ordering::SeqCst);
+                    let (status, body) = server_script
+                        .lock()
+                        .unwrap()
+                        .pop_front()
+                        .unwrap_or(fallback);
+                    let response = format!(
+                        "HTTP/1.1 {status} X\r\ncontent-type: application/json\r\n\
+                         content-length: {}\r\nconnection: close\r\n\r\n{body}",
+                        body.len()
+                    );
+                    let _ = socket.write_all(response.as_bytes()).await;
+                }
+            });
+            Self {
+                base_url,
+                hits,
+                script,
+            }
+        }
+
+        fn hits(&self) -> usize {
+            self.hits.load(std::sync::atomic::Ordering::SeqCst)
+        }
+
+        async fn get(&self, breaker: &std::sync::Mutex<GammaBreaker>) -> Result<Vec<u32>, String> {
+            let http = reqwest::Client::builder().no_proxy().build().unwrap();
+            gamma_get_from(
+                breaker,
+                &self.base_url,
+                &http,
+                "/markets",
+                Duration::from_secs(2),
+            )
+            .await
+        }
+    }
+
+    fn closed_breaker() -> std::sync::Mutex<GammaBreaker> {
+        std::sync::Mutex::new(GammaBreaker {
+            consecutive_failures: 0,
+            open_until: None,
+        })
+    }
+
+    #[tokio::test]
+    async fn gamma_outage_opens_the_breaker_until_a_probe_succeeds() {
+        let breaker = closed_breaker();
+        let gamma = MockGamma::start((503, "")).await;
+
+        // A transient 503 is retried away
+        gamma.script.lock().unwrap().push_back((503, ""));
+        gamma.script.lock().unwrap().push_back((200, "[1]"));
+        assert_eq!(gamma.get(&breaker).await, Ok(vec![1]));
+        assert_eq!(gamma.hits(), 2);
+
+        // A sustained outage: every call exhausts its attempts, then the breaker opens
+        for _ in 0..GAMMA_BREAKER_THRESHOLD {
+            assert!(gamma.get(&breaker).await.is_err());
+        }
+        let hits = gamma.hits();
+        assert_eq!(
+            hits,
+            2 + (GAMMA_BREAKER_THRESHOLD * GAMMA_MAX_ATTEMPTS) as usize
+        );
+        assert_eq!(
+            gamma.get(&breaker).await,
+            Err("Gamma circuit breaker open".into())
+        );
+        assert_eq!(gamma.hits(), hits);
+
+        // After the cooldown one probe goes through; its success closes the breaker
+        tokio::time::sleep(GAMMA_BREAKER_COOLDOWN).await;
+        gamma.script.lock().unwrap().push_back((200, "[2]"));
+        assert_eq!(gamma.get(&breaker).await, Ok(vec![2]));
+        assert!(!breaker.lock().unwrap().is_open());
+        assert_eq!(breaker.lock().unwrap().consecutive_failures, 0);
+    }
+
+    #[tokio::test]
+    async fn gamma_client_errors_do_not_trip_the_breaker() {
+        let breaker = closed_breaker();
+        let gamma = MockGamma::start((404, "")).await;
+        for _ in 0..GAMMA_BREAKER_THRESHOLD {
+            assert_eq!(
+                gamma.get(&breaker).await,
+                Err("Gamma returned 404 Not Found".into())
+            );
+        }
+        // Not retried either
+        assert_eq!(gamma.hits(), GAMMA_BREAKER_THRESHOLD as usize);
+
+        for _ in 0..GAMMA_BREAKER_THRESHOLD {
+            gamma.script.lock().unwrap().push_back((200, "not json"));
+            let err = gamma.get(&breaker).await.unwrap_err();
+            assert!(err.starts_with("Gamma parse error"), "{err}");
+        }
+        assert!(!breaker.lock().unwrap().is_open());
+        assert_eq!(breaker.lock().unwrap().consecutive_failures, 0);
+    }
+}
@@ -43,3 +43,21 @@ impl FromRequestParts<AppState> for AuthUser {
         Ok(AuthUser(address))
     }
 }
+
+/// An `AuthUser` whose address is listed in `ADMIN_ADDRESSES`.
+pub struct AdminUser(#[allow(dead_code)] pub String);
+
+impl FromRequestParts<AppState> for AdminUser {
+    type Rejection = StatusCode;
+
+    async fn from_request_parts(
+        parts: &mut Parts,
+        state: &AppState,
+    ) -> Result<Self, Self::Rejection> {
+        let AuthUser(address) = AuthUser::from_request_parts(parts, state).await?;
+        if !state.admin_addresses.contains(&address) {
+            return Err(StatusCode::FORBIDDEN);
+        }
+        Ok(AdminUser(address))
+    }
+}
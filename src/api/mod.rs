@@ -1,15 +1,21 @@
 pub mod alerts;
 pub mod auth;
+pub mod confirmations;
 pub mod contracts;
 pub mod copytrade;
 pub mod crypto;
 pub mod db;
+pub mod deposit_watch;
+pub mod digest;
 pub mod engine;
 pub mod markets;
 pub mod middleware;
+pub mod order_mirror;
+pub mod precision;
 pub mod routes;
 pub mod scanner;
 pub mod server;
 pub mod types;
+pub mod update_replay;
 pub mod wallet;
 pub mod ws_subscriber;
@@ -0,0 +1,174 @@
+//! Optional analytics sink: mirrors `copy_trade_orders` (SQLite, per-user source of
+//! truth) into ClickHouse so operators can aggregate across users and sessions.
+//!
+//! Rather than hooking every engine write site, the mirror tails SQLite by
+//! `(updated_at, id)`, which picks up inserts and later status changes (GTC fills,
+//! cancels) alike. ReplacingMergeTree keyed on `version` keeps the latest state.
+
+use std::sync::{Arc, Mutex};
+
+use super::db;
+
+const TABLE_DDL: &str = "CREATE TABLE IF NOT EXISTS copytrade_orders (
+    order_id       String,
+    session_id     String,
+    owner          String,
+    simulate       UInt8,
+    source_trader  String,
+    source_tx_hash String,
+    asset_id       String,
+    side           LowCardinality(String),
+    status         LowCardinality(String),
+    price          Float64,
+    source_price   Float64,
+    size_usdc      Float64,
+    fill_price     Nullable(Float64),
+    slippage_bps   Nullable(Float64),
+    created_at     DateTime('UTC'),
+    updated_at     DateTime('UTC'),
+    version        UInt64
+) ENGINE = ReplacingMergeTree(version)
+ORDER BY (session_id, order_id)";
+
+const POLL_INTERVAL_SECS: u64 = 5;
+const BATCH_SIZE: u32 = 500;
+
+/// Whether the mirror is enabled (`COPYTRADE_CH_MIRROR=true`). Off by default.
+pub fn enabled_from_env() -> bool {
+    std::env::var("COPYTRADE_CH_MIRROR")
+        .map(|v| matches!(v.trim().to_lowercase().as_str(), "1" | "true" | "yes"))
+        .unwrap_or(false)
+}
+
+#[derive(clickhouse::Row, serde::Serialize)]
+struct MirrorRow {
+    order_id: String,
+    session_id: String,
+    owner: String,
+    simulate: u8,
+    source_trader: String,
+    source_tx_hash: String,
+    asset_id: String,
+    side: String,
+    status: String,
+    price: f64,
+    source_price: f64,
+    size_usdc: f64,
+    fill_price: Option<f64>,
+    slippage_bps: Option<f64>,
+    created_at: u32,
+    updated_at: u32,
+    version: u64,
+}
+
+fn parse_ts(s: &str) -> Option<chrono::DateTime<chrono::Utc>> {
+    chrono::DateTime::parse_from_rfc3339(s)
+        .ok()
+        .map(|t| t.with_timezone(&chrono::Utc))
+}
+
+impl MirrorRow {
+    fn from_change(change: db::OrderChange) -> Self {
+        let o = change.order;
+        let created = parse_ts(&o.created_at);
+        let updated = parse_ts(&o.updated_at);
+        MirrorRow {
+            order_id: o.id,
+            session_id: o.session_id,
+            owner: change.owner,
+            simulate: change.simulate as u8,
+            source_trader: o.source_trader.to_lowercase(),
+            source_tx_hash: o.source_tx_hash,
+            asset_id: o.asset_id,
+            side: o.side.to_lowercase(),
+            status: o.status,
+            price: o.price,
+            source_price: o.source_price,
+            size_usdc: o.size_usdc,
+            fill_price: o.fill_price,
+            slippage_bps: o.slippage_bps,
+            created_at: created.map(|t| t.timestamp() as u32).unwrap_or(0),
+            updated_at: updated.map(|t| t.timestamp() as u32).unwrap_or(0),
+            version: updated
+                .map(|t| t.timestamp_micros().max(0) as u64)
+                .unwrap_or(0),
+        }
+    }
+}
+
+pub async fn run(db: clickhouse::Client, user_db: Arc<Mutex<rusqlite::Connection>>) {
+    if let Err(e) = db.query(TABLE_DDL).execute().await {
+        tracing::error!("Order mirror: failed to create copytrade_orders table: {e}");
+        return;
+    }
+
+    // Resume from the newest mirrored change; an empty table backfills all history.
+    // Rows at exactly that timestamp are sent again, which the version dedup absorbs.
+    let mut cursor_at = match db
+        .query("SELECT max(version) FROM copytrade_orders")
+        .fetch_one::<u64>()
+        .await
+    {
+        Ok(v) => chrono::DateTime::from_timestamp_micros(v as i64)
+            .unwrap_or_default()
+            .to_rfc3339(),
+        Err(e) => {
+            tracing::error!("Order mirror: failed to read cursor: {e}");
+            return;
+        }
+    };
+    let mut cursor_id = String::new();
+    tracing::info!("Order mirror starting from {cursor_at}");
+
+    let mut interval = tokio::time::interval(std::time::Duration::from_secs(POLL_INTERVAL_SECS));
+    loop {
+        interval.tick().await;
+
+        // Drain everything changed since the cursor, one batch at a time
+        loop {
+            let changes = {
+                let conn = user_db.lock().unwrap_or_else(|p| p.into_inner());
+                match db::get_order_changes_since(&conn, (&cursor_at, &cursor_id), BATCH_SIZE) {
+                    Ok(c) => c,
+                    Err(e) => {
+                        tracing::warn!("Order mirror: SQLite read failed: {e}");
+                        break;
+                    }
+                }
+            };
+            if changes.is_empty() {
+                break;
+            }
+            let full_batch = changes.len() as u32 >= BATCH_SIZE;
+            let next_cursor = changes
+                .last()
+                .map(|c| (c.order.updated_at.clone(), c.order.id.clone()));
+
+            if let Err(e) = flush_batch(&db, changes).await {
+                // Keep the cursor; the batch is retried next tick
+                tracing::warn!("Order mirror: batch flush failed: {e}");
+                break;
+            }
+            if let Some((at, id)) = next_cursor {
+                cursor_at = at;
+                cursor_id = id;
+            }
+            if !full_batch {
+                break;
+            }
+        }
+    }
+}
+
+async fn flush_batch(db: &clickhouse::Client, changes: Vec<db::OrderChange>) -> Result<(), String> {
+    let mut inserter = db
+        .insert::<MirrorRow>("copytrade_orders")
+        .map_err(|e| e.to_string())?;
+    for change in changes {
+        inserter
+            .write(&MirrorRow::from_change(change))
+            .await
+            .map_err(|e| e.to_string())?;
+    }
+    inserter.end().await.map_err(|e| e.to_string())
+}
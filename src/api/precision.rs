@@ -0,0 +1,82 @@
+//! Serialization-time rounding for reported amounts and prices, so responses don't
+//! carry float noise like `12.340000000001`. Used via `#[serde(serialize_with)]`;
+//! values are only rounded on the way out, never in the aggregates they feed.
+//!
+//! USDC amounts, P&L and percentages use API_USDC_DECIMALS (default 2), outcome
+//! prices API_PRICE_DECIMALS (default 4). Slippage in basis points keeps
+//! BPS_DECIMALS whatever the USDC setting.
+
+use std::collections::BTreeMap;
+use std::sync::OnceLock;
+
+use serde::Serializer;
+
+const DEFAULT_USDC_DECIMALS: u32 = 2;
+const DEFAULT_PRICE_DECIMALS: u32 = 4;
+const MAX_DECIMALS: u32 = 10;
+/// A tenth of a basis point is finer than any fill can resolve
+const BPS_DECIMALS: u32 = 1;
+
+fn decimals_from_env(var: &str, default: u32) -> u32 {
+    match std::env::var(var) {
+        Ok(v) => match v.trim().parse::<u32>() {
+            Ok(n) if n <= MAX_DECIMALS => n,
+            _ => {
+                tracing::warn!("Invalid {var}={v}, using default {default}");
+                default
+            }
+        },
+        Err(_) => default,
+    }
+}
+
+fn usdc_decimals() -> u32 {
+    static DECIMALS: OnceLock<u32> = OnceLock::new();
+    *DECIMALS.get_or_init(|| decimals_from_env("API_USDC_DECIMALS", DEFAULT_USDC_DECIMALS))
+}
+
+fn price_decimals() -> u32 {
+    static DECIMALS: OnceLock<u32> = OnceLock::new();
+    *DECIMALS.get_or_init(|| decimals_from_env("API_PRICE_DECIMALS", DEFAULT_PRICE_DECIMALS))
+}
+
+fn round_to(value: f64, decimals: u32) -> f64 {
+    let factor = 10f64.powi(decimals as i32);
+    let rounded = (value * factor).round() / factor;
+    // Keep non-finite values (and magnitudes where scaling overflows) untouched
+    if rounded.is_finite() { rounded } else { value }
+}
+
+pub fn usdc<S: Serializer>(value: &f64, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_f64(round_to(*value, usdc_decimals()))
+}
+
+pub fn usdc_map<S: Serializer>(
+    value: &BTreeMap<String, f64>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    let decimals = usdc_decimals();
+    serializer.collect_map(value.iter().map(|(k, v)| (k, round_to(*v, decimals))))
+}
+
+pub fn bps<S: Serializer>(value: &f64, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_f64(round_to(*value, BPS_DECIMALS))
+}
+
+pub fn bps_opt<S: Serializer>(value: &Option<f64>, serializer: S) -> Result<S::Ok, S::Error> {
+    match value {
+        Some(v) => bps(v, serializer),
+        None => serializer.serialize_none(),
+    }
+}
+
+pub fn price<S: Serializer>(value: &f64, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_f64(round_to(*value, price_decimals()))
+}
+
+pub fn price_opt<S: Serializer>(value: &Option<f64>, serializer: S) -> Result<S::Ok, S::Error> {
+    match value {
+        Some(v) => price(v, serializer),
+        None => serializer.serialize_none(),
+    }
+}
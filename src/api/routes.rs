@@ -24,8 +24,34 @@ const EXCHANGE_CONTRACTS: &[&str] = &[
     "0x02A86f51aA7B8b1c17c30364748d5Ae4a0727E23", // Polymarket Relayer
 ];
 
+/// Addresses kept out of trader rankings. `EXCLUDED_TRADER_ADDRESSES` (comma-separated)
+/// replaces the built-in EXCHANGE_CONTRACTS so new relayers can be filtered without a
+/// redeploy. Entries that aren't 0x addresses are dropped, since they end up in SQL.
+pub(crate) fn excluded_traders() -> &'static [String] {
+    static EXCLUDED: std::sync::OnceLock<Vec<String>> = std::sync::OnceLock::new();
+    EXCLUDED.get_or_init(|| {
+        let Ok(raw) = std::env::var("EXCLUDED_TRADER_ADDRESSES") else {
+            return EXCHANGE_CONTRACTS.iter().map(|a| a.to_string()).collect();
+        };
+        let mut addrs = Vec::new();
+        for entry in raw.split(',').map(str::trim).filter(|s| !s.is_empty()) {
+            if middleware::validate_eth_address(entry).is_ok() {
+                addrs.push(entry.to_string());
+            } else {
+                tracing::warn!("Ignoring invalid EXCLUDED_TRADER_ADDRESSES entry: {entry}");
+            }
+        }
+        if addrs.is_empty() {
+            tracing::warn!("EXCLUDED_TRADER_ADDRESSES has no valid address, using defaults");
+            return EXCHANGE_CONTRACTS.iter().map(|a| a.to_string()).collect();
+        }
+        addrs
+    })
+}
+
+/// SQL list of `excluded_traders`, for `trader NOT IN (...)` filters.
 pub(crate) fn exclude_clause() -> String {
-    EXCHANGE_CONTRACTS
+    excluded_traders()
         .iter()
         .map(|a| format!("'{a}'"))
         .collect::<Vec<_>>()
@@ -47,7 +73,7 @@ pub async fn warm_leaderboard(state: &AppState) -> Result<(), String> {
     let query = format!(
         "WITH resolved AS (
             SELECT asset_id, toNullable(toFloat64(resolved_price)) AS resolved_price
-            FROM poly_dearboard.resolved_prices FINAL
+            FROM resolved_prices FINAL
         )
         SELECT
             toString(p.trader) AS address,
@@ -58,8 +84,8 @@ pub async fn warm_leaderboard(state: &AppState) -> Result<(), String> {
             toString(sum(p.total_fee)) AS total_fees,
             ifNull(toString(min(p.first_ts)), '') AS first_trade,
             ifNull(toString(max(p.last_ts)), '') AS last_trade
-        FROM poly_dearboard.trader_positions p
-        LEFT JOIN (SELECT asset_id, latest_price FROM poly_dearboard.asset_latest_price FINAL) AS lp ON p.asset_id = lp.asset_id
+        FROM trader_positions p
+        LEFT JOIN (SELECT asset_id, latest_price FROM asset_latest_price FINAL) AS lp ON p.asset_id = lp.asset_id
         LEFT JOIN resolved rp ON p.asset_id = rp.asset_id
         WHERE p.trader NOT IN ({exclude})
         GROUP BY p.trader
@@ -78,7 +104,7 @@ pub async fn warm_leaderboard(state: &AppState) -> Result<(), String> {
 
     let total: u64 = state
         .db
-        .query("SELECT uniqExactMerge(unique_traders) FROM poly_dearboard.global_stats")
+        .query("SELECT uniqExactMerge(unique_traders) FROM global_stats")
         .fetch_one()
         .await
         .map_err(|e| e.to_string())?;
@@ -141,6 +167,60 @@ pub async fn leaderboard(
         }
     }
 
+    let (traders, total) = query_leaderboard(&state, sort, order, limit, offset, timeframe).await?;
+
+    // Batch-compute labels for the current page of traders (with timeout)
+    let addresses: Vec<String> = traders.iter().map(|t| t.address.to_lowercase()).collect();
+    let (labels, label_details) = match tokio::time::timeout(
+        std::time::Duration::from_secs(2),
+        batch_compute_labels(&state, &addresses),
+    )
+    .await
+    {
+        Ok(pair) => pair,
+        Err(_) => {
+            tracing::warn!("batch_compute_labels timed out after 2s");
+            (
+                std::collections::HashMap::new(),
+                std::collections::HashMap::new(),
+            )
+        }
+    };
+
+    let response = LeaderboardResponse {
+        traders,
+        total,
+        limit,
+        offset,
+        labels,
+        label_details,
+    };
+
+    // Cache for 30 seconds
+    {
+        let mut cache = state.leaderboard_cache.write().await;
+        cache.insert(
+            cache_key,
+            super::server::CachedResponse {
+                data: response.clone(),
+                expires: std::time::Instant::now() + std::time::Duration::from_secs(30),
+            },
+        );
+    }
+
+    Ok(Json(response))
+}
+
+/// Runs the leaderboard query for one page (no labels, no cache).
+/// Shared by the leaderboard endpoint and the add-from-leaderboard list action.
+async fn query_leaderboard(
+    state: &AppState,
+    sort: &str,
+    order: &str,
+    limit: u32,
+    offset: u32,
+    timeframe: &str,
+) -> Result<(Vec<TraderSummary>, u64), (StatusCode, String)> {
     if !ALLOWED_SORT_COLUMNS.contains(&sort) {
         return Err((
             StatusCode::BAD_REQUEST,
@@ -170,7 +250,7 @@ pub async fn leaderboard(
         let query = format!(
             "WITH resolved AS (
                 SELECT asset_id, toNullable(toFloat64(resolved_price)) AS resolved_price
-                FROM poly_dearboard.resolved_prices FINAL
+                FROM resolved_prices FINAL
             )
             SELECT
                 toString(p.trader) AS address,
@@ -181,8 +261,8 @@ pub async fn leaderboard(
                 toString(sum(p.total_fee)) AS total_fees,
                 ifNull(toString(min(p.first_ts)), '') AS first_trade,
                 ifNull(toString(max(p.last_ts)), '') AS last_trade
-            FROM poly_dearboard.trader_positions p
-            LEFT JOIN (SELECT asset_id, latest_price FROM poly_dearboard.asset_latest_price FINAL) AS lp ON p.asset_id = lp.asset_id
+            FROM trader_positions p
+            LEFT JOIN (SELECT asset_id, latest_price FROM asset_latest_price FINAL) AS lp ON p.asset_id = lp.asset_id
             LEFT JOIN resolved rp ON p.asset_id = rp.asset_id
             WHERE p.trader NOT IN ({exclude})
             GROUP BY p.trader
@@ -201,7 +281,7 @@ pub async fn leaderboard(
 
         let total: u64 = state
             .db
-            .query("SELECT uniqExactMerge(unique_traders) FROM poly_dearboard.global_stats")
+            .query("SELECT uniqExactMerge(unique_traders) FROM global_stats")
             .fetch_one()
             .await
             .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
@@ -228,7 +308,7 @@ pub async fn leaderboard(
             "WITH
                 resolved AS (
                     SELECT asset_id, toNullable(toFloat64(resolved_price)) AS resolved_price
-                    FROM poly_dearboard.resolved_prices FINAL
+                    FROM resolved_prices FINAL
                 ),
                 positions AS (
                     SELECT trader, asset_id,
@@ -239,7 +319,7 @@ pub async fn leaderboard(
                            sum(fee) AS fees,
                            min(if(block_timestamp = toDateTime('1970-01-01 00:00:00'), NULL, block_timestamp)) AS first_ts,
                            max(if(block_timestamp = toDateTime('1970-01-01 00:00:00'), NULL, block_timestamp)) AS last_ts
-                    FROM poly_dearboard.trades
+                    FROM trades
                     {prewhere}
                     WHERE trader NOT IN ({exclude})
                     GROUP BY trader, asset_id
@@ -254,7 +334,7 @@ pub async fn leaderboard(
                 ifNull(toString(min(p.first_ts)), '') AS first_trade,
                 ifNull(toString(max(p.last_ts)), '') AS last_trade
             FROM positions p
-            LEFT JOIN (SELECT asset_id, latest_price FROM poly_dearboard.asset_latest_price FINAL) AS lp ON p.asset_id = lp.asset_id
+            LEFT JOIN (SELECT asset_id, latest_price FROM asset_latest_price FINAL) AS lp ON p.asset_id = lp.asset_id
             LEFT JOIN resolved rp ON p.asset_id = rp.asset_id
             GROUP BY p.trader
             ORDER BY {sort_expr} {order}
@@ -273,7 +353,7 @@ pub async fn leaderboard(
         let total: u64 = state
             .db
             .query(&format!(
-                "SELECT uniqExact(trader) FROM poly_dearboard.trades {prewhere} WHERE trader NOT IN ({exclude})"
+                "SELECT uniqExact(trader) FROM trades {prewhere} WHERE trader NOT IN ({exclude})"
             ))
             .fetch_one()
             .await
@@ -282,46 +362,7 @@ pub async fn leaderboard(
         (traders, total)
     };
 
-    // Batch-compute labels for the current page of traders (with timeout)
-    let addresses: Vec<String> = traders.iter().map(|t| t.address.to_lowercase()).collect();
-    let (labels, label_details) = match tokio::time::timeout(
-        std::time::Duration::from_secs(2),
-        batch_compute_labels(&state, &addresses),
-    )
-    .await
-    {
-        Ok(pair) => pair,
-        Err(_) => {
-            tracing::warn!("batch_compute_labels timed out after 2s");
-            (
-                std::collections::HashMap::new(),
-                std::collections::HashMap::new(),
-            )
-        }
-    };
-
-    let response = LeaderboardResponse {
-        traders,
-        total,
-        limit,
-        offset,
-        labels,
-        label_details,
-    };
-
-    // Cache for 30 seconds
-    {
-        let mut cache = state.leaderboard_cache.write().await;
-        cache.insert(
-            cache_key,
-            super::server::CachedResponse {
-                data: response.clone(),
-                expires: std::time::Instant::now() + std::time::Duration::from_secs(30),
-            },
-        );
-    }
-
-    Ok(Json(response))
+    Ok((traders, total))
 }
 
 pub async fn trader_stats(
@@ -335,7 +376,7 @@ pub async fn trader_stats(
         .query(
             "WITH resolved AS (
                 SELECT asset_id, toNullable(toFloat64(resolved_price)) AS resolved_price
-                FROM poly_dearboard.resolved_prices FINAL
+                FROM resolved_prices FINAL
             )
             SELECT
                 toString(p.trader) AS address,
@@ -346,8 +387,8 @@ pub async fn trader_stats(
                 toString(sum(p.total_fee)) AS total_fees,
                 ifNull(toString(min(p.first_ts)), '') AS first_trade,
                 ifNull(toString(max(p.last_ts)), '') AS last_trade
-            FROM poly_dearboard.trader_positions p
-            LEFT JOIN (SELECT asset_id, latest_price FROM poly_dearboard.asset_latest_price FINAL) AS lp ON p.asset_id = lp.asset_id
+            FROM trader_positions p
+            LEFT JOIN (SELECT asset_id, latest_price FROM asset_latest_price FINAL) AS lp ON p.asset_id = lp.asset_id
             LEFT JOIN resolved rp ON p.asset_id = rp.asset_id
             WHERE lower(p.trader) = ?
             GROUP BY p.trader",
@@ -363,6 +404,64 @@ pub async fn trader_stats(
     }
 }
 
+/// Max addresses per `POST /traders/stats-batch`
+const MAX_STATS_BATCH: usize = 100;
+
+/// `trader_stats` for many addresses in one query. Unknown (or excluded) addresses are
+/// simply absent from the response.
+pub async fn trader_stats_batch(
+    State(state): State<AppState>,
+    Json(req): Json<TraderStatsBatchRequest>,
+) -> Result<impl IntoResponse, (StatusCode, String)> {
+    if req.addresses.is_empty() || req.addresses.len() > MAX_STATS_BATCH {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            format!("addresses must hold 1-{MAX_STATS_BATCH} entries"),
+        ));
+    }
+    let mut addresses = req
+        .addresses
+        .iter()
+        .map(|addr| {
+            middleware::validate_eth_address(addr)
+                .map_err(|_| (StatusCode::BAD_REQUEST, format!("Invalid address: {addr}")))
+        })
+        .collect::<Result<Vec<_>, (StatusCode, String)>>()?;
+    addresses.sort();
+    addresses.dedup();
+
+    let exclude = exclude_clause();
+    let stats = state
+        .db
+        .query(&format!(
+            "WITH resolved AS (
+                SELECT asset_id, toNullable(toFloat64(resolved_price)) AS resolved_price
+                FROM resolved_prices FINAL
+            )
+            SELECT
+                lower(toString(p.trader)) AS address,
+                toString(sum(p.total_volume)) AS total_volume,
+                sum(p.trade_count) AS trade_count,
+                count() AS markets_traded,
+                toString(ROUND(sum((p.sell_usdc - p.buy_usdc) + (p.buy_amount - p.sell_amount) * coalesce(rp.resolved_price, toFloat64(lp.latest_price))), 6)) AS realized_pnl,
+                toString(sum(p.total_fee)) AS total_fees,
+                ifNull(toString(min(p.first_ts)), '') AS first_trade,
+                ifNull(toString(max(p.last_ts)), '') AS last_trade
+            FROM trader_positions p
+            LEFT JOIN (SELECT asset_id, latest_price FROM asset_latest_price FINAL) AS lp ON p.asset_id = lp.asset_id
+            LEFT JOIN resolved rp ON p.asset_id = rp.asset_id
+            WHERE has(?, lower(toString(p.trader)))
+              AND p.trader NOT IN ({exclude})
+            GROUP BY address"
+        ))
+        .bind(&addresses)
+        .fetch_all::<TraderSummary>()
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    Ok(Json(stats))
+}
+
 pub async fn trader_trades(
     State(state): State<AppState>,
     Path(address): Path<String>,
@@ -394,7 +493,7 @@ pub async fn trader_trades(
                 toString(price) AS price,
                 toString(usdc_amount) AS usdc_amount,
                 toString(fee) AS fee
-            FROM poly_dearboard.trades
+            FROM trades
             WHERE lower(trader) = ?
               AND (side = ? OR ? = '')
             ORDER BY block_number DESC, log_index DESC
@@ -424,9 +523,7 @@ pub async fn trader_trades(
 
     let total: u64 = state
         .db
-        .query(
-            "SELECT count() FROM poly_dearboard.trades WHERE lower(trader) = ? AND (side = ? OR ? = '')",
-        )
+        .query("SELECT count() FROM trades WHERE lower(trader) = ? AND (side = ? OR ? = '')")
         .bind(&address)
         .bind(side_filter)
         .bind(side_filter)
@@ -442,6 +539,159 @@ pub async fn trader_trades(
     }))
 }
 
+/// Book levels are reused for this long to spare the CLOB API on repeated views
+const BOOK_CACHE_TTL: std::time::Duration = std::time::Duration::from_secs(3);
+const DEFAULT_BOOK_DEPTH_CENTS: u32 = 2;
+const MAX_BOOK_DEPTH_CENTS: u32 = 50;
+
+/// GET /api/markets/:asset_id/book — CLOB L2 book with mid, spread and the shares /
+/// USDC resting within `depth_cents` of the mid on each side.
+pub async fn market_book(
+    State(state): State<AppState>,
+    Path(asset_id): Path<String>,
+    Query(params): Query<OrderBookParams>,
+) -> Result<impl IntoResponse, (StatusCode, String)> {
+    if !super::copytrade::valid_token_id(&asset_id) {
+        return Err((StatusCode::BAD_REQUEST, "Invalid asset_id".into()));
+    }
+    let depth_cents = params.depth_cents.unwrap_or(DEFAULT_BOOK_DEPTH_CENTS);
+    if depth_cents == 0 || depth_cents > MAX_BOOK_DEPTH_CENTS {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            format!("depth_cents must be between 1 and {MAX_BOOK_DEPTH_CENTS}"),
+        ));
+    }
+
+    let cached = {
+        let cache = state.book_cache.read().await;
+        cache
+            .get(&asset_id)
+            .filter(|b| b.expires > std::time::Instant::now())
+            .map(|b| (b.bids.clone(), b.asks.clone()))
+    };
+    let (bids, asks) = match cached {
+        Some(levels) => levels,
+        None => {
+            let (bids, asks) = super::copytrade::fetch_clob_book(&state.http, &asset_id)
+                .await
+                .map_err(|e| (StatusCode::BAD_GATEWAY, e))?
+                .ok_or((StatusCode::NOT_FOUND, "No order book for asset".to_string()))?;
+            let now = std::time::Instant::now();
+            let mut cache = state.book_cache.write().await;
+            cache.retain(|_, b| b.expires > now);
+            cache.insert(
+                asset_id.clone(),
+                super::server::CachedBook {
+                    bids: bids.clone(),
+                    asks: asks.clone(),
+                    expires: now + BOOK_CACHE_TTL,
+                },
+            );
+            (bids, asks)
+        }
+    };
+
+    let best_bid = bids.first().map(|l| l.price);
+    let best_ask = asks.first().map(|l| l.price);
+    let (mid, spread) = match (best_bid, best_ask) {
+        (Some(b), Some(a)) => (Some((a + b) / 2.0), Some(a - b)),
+        (Some(p), None) | (None, Some(p)) => (Some(p), None),
+        (None, None) => (None, None),
+    };
+
+    // Small epsilon so a level exactly N cents away counts despite float error
+    let band = depth_cents as f64 / 100.0 + 1e-9;
+    let depth = |levels: &[BookLevel], in_band: &dyn Fn(f64) -> bool| {
+        levels
+            .iter()
+            .filter(|l| in_band(l.price))
+            .fold((0.0, 0.0), |(shares, usdc), l| {
+                (shares + l.size, usdc + l.size * l.price)
+            })
+    };
+    let (bid_depth_shares, bid_depth_usdc, ask_depth_shares, ask_depth_usdc) = match mid {
+        Some(m) => {
+            let (bs, bu) = depth(&bids, &|p| p >= m - band);
+            let (a_s, au) = depth(&asks, &|p| p <= m + band);
+            (bs, bu, a_s, au)
+        }
+        None => (0.0, 0.0, 0.0, 0.0),
+    };
+
+    Ok(Json(OrderBookResponse {
+        asset_id,
+        bids,
+        asks,
+        mid,
+        spread,
+        depth_cents,
+        bid_depth_shares,
+        bid_depth_usdc,
+        ask_depth_shares,
+        ask_depth_usdc,
+    }))
+}
+
+const MAX_RESOLVED_STATUS_BATCH: usize = 200;
+
+/// POST /api/markets/resolved-status — on-chain resolution of each asset from
+/// `resolved_prices`, in request order. Unresolved assets (still trading, or not yet
+/// indexed) come back with `resolved: false`.
+pub async fn resolved_status(
+    State(state): State<AppState>,
+    Json(req): Json<ResolvedStatusRequest>,
+) -> Result<impl IntoResponse, (StatusCode, String)> {
+    if req.asset_ids.is_empty() || req.asset_ids.len() > MAX_RESOLVED_STATUS_BATCH {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            format!("asset_ids must hold 1-{MAX_RESOLVED_STATUS_BATCH} entries"),
+        ));
+    }
+    let mut asset_ids: Vec<String> = Vec::with_capacity(req.asset_ids.len());
+    for id in &req.asset_ids {
+        let id = id.trim().to_string();
+        if !super::copytrade::valid_token_id(&id) {
+            return Err((StatusCode::BAD_REQUEST, format!("Invalid asset_id: {id}")));
+        }
+        if !asset_ids.contains(&id) {
+            asset_ids.push(id);
+        }
+    }
+
+    let rows = state
+        .db
+        .query(
+            "SELECT asset_id, resolved_price, condition_id
+             FROM resolved_prices FINAL
+             WHERE has(?, asset_id)",
+        )
+        .bind(&asset_ids)
+        .fetch_all::<ResolvedStatusRow>()
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+    let mut by_asset: std::collections::HashMap<String, ResolvedStatusRow> =
+        rows.into_iter().map(|r| (r.asset_id.clone(), r)).collect();
+
+    let statuses: Vec<AssetResolution> = asset_ids
+        .into_iter()
+        .map(|asset_id| match by_asset.remove(&asset_id) {
+            Some(row) => AssetResolution {
+                asset_id,
+                resolved: true,
+                resolved_price: row.resolved_price.parse::<f64>().ok(),
+                condition_id: Some(row.condition_id),
+            },
+            None => AssetResolution {
+                asset_id,
+                resolved: false,
+                resolved_price: None,
+                condition_id: None,
+            },
+        })
+        .collect();
+    Ok(Json(statuses))
+}
+
 pub async fn hot_markets(
     State(state): State<AppState>,
     Query(params): Query<HotMarketsParams>,
@@ -464,7 +714,7 @@ pub async fn hot_markets(
                     uniqExactMerge(unique_traders) AS unique_traders,
                     toString(argMaxMerge(last_price_state)) AS last_price,
                     ifNull(toString(max(last_trade)), '') AS last_trade
-                FROM poly_dearboard.asset_stats_daily AS asd
+                FROM asset_stats_daily AS asd
                 WHERE day >= today() - 7
                 GROUP BY asset_id
                 ORDER BY sum(asd.volume) DESC
@@ -490,7 +740,7 @@ pub async fn hot_markets(
                 uniqExact(trader) AS unique_traders,
                 toString(argMax(price, block_number * 1000000 + log_index)) AS last_price,
                 ifNull(toString(max(block_timestamp)), '') AS last_trade
-            FROM poly_dearboard.trades
+            FROM trades
             PREWHERE block_timestamp >= now() - INTERVAL {interval}
             WHERE trader NOT IN ({exclude})
             GROUP BY asset_id
@@ -617,7 +867,7 @@ pub async fn recent_trades(
                 toString(amount) AS amount,
                 toString(price) AS price,
                 toString(usdc_amount) AS usdc_amount
-            FROM poly_dearboard.trades
+            FROM trades
             WHERE trader NOT IN ({exclude})
             ORDER BY block_number DESC, log_index DESC
             LIMIT ?"
@@ -638,7 +888,7 @@ pub async fn recent_trades(
                 toString(amount) AS amount,
                 toString(price) AS price,
                 toString(usdc_amount) AS usdc_amount
-            FROM poly_dearboard.trades
+            FROM trades
             WHERE trader NOT IN ({exclude})
               AND asset_id IN ({in_list})
             ORDER BY block_number DESC, log_index DESC
@@ -700,7 +950,7 @@ pub async fn health(
                 sum(trade_count) AS trade_count,
                 uniqExactMerge(unique_traders) AS trader_count,
                 max(latest_block) AS latest_block
-            FROM poly_dearboard.global_stats",
+            FROM global_stats",
         )
         .fetch_one::<HealthStats>()
         .await
@@ -725,7 +975,7 @@ pub async fn trader_positions(
         .query(
             "WITH resolved AS (
                 SELECT asset_id, toNullable(toFloat64(resolved_price)) AS resolved_price
-                FROM poly_dearboard.resolved_prices FINAL
+                FROM resolved_prices FINAL
             )
             SELECT
                 p.asset_id,
@@ -740,8 +990,8 @@ pub async fn trader_positions(
                 toString(p.total_volume) AS volume,
                 p.trade_count AS trade_count,
                 if(rp.resolved_price IS NOT NULL, 1, 0) AS on_chain_resolved
-            FROM poly_dearboard.trader_positions p
-            LEFT JOIN (SELECT asset_id, latest_price FROM poly_dearboard.asset_latest_price FINAL) AS lp ON p.asset_id = lp.asset_id
+            FROM trader_positions p
+            LEFT JOIN (SELECT asset_id, latest_price FROM asset_latest_price FINAL) AS lp ON p.asset_id = lp.asset_id
             LEFT JOIN resolved rp ON p.asset_id = rp.asset_id
             WHERE lower(p.trader) = ?
             ORDER BY abs((p.buy_amount - p.sell_amount) * coalesce(rp.resolved_price, toFloat64(lp.latest_price))) DESC",
@@ -791,6 +1041,9 @@ pub async fn trader_positions(
             pnl: r.pnl,
             volume: r.volume,
             trade_count: r.trade_count,
+            live_price: None,
+            current_value: None,
+            live_pnl: None,
         };
 
         if settled {
@@ -800,6 +1053,21 @@ pub async fn trader_positions(
         }
     }
 
+    // Mark open positions to live CLOB midpoints (settled ones already use resolved prices)
+    let open_ids: Vec<String> = open.iter().map(|p| p.asset_id.clone()).collect();
+    let midpoints = super::copytrade::fetch_clob_midpoints(&state.http, &open_ids).await;
+    for pos in &mut open {
+        let Some(&live) = midpoints.get(&pos.asset_id) else {
+            continue;
+        };
+        let net = pos.net_tokens.parse::<f64>().unwrap_or(0.0);
+        let last = pos.latest_price.parse::<f64>().unwrap_or(0.0);
+        let pnl = pos.pnl.parse::<f64>().unwrap_or(0.0);
+        pos.live_price = Some(live);
+        pos.current_value = Some(net * live);
+        pos.live_pnl = Some(pnl + net * (live - last));
+    }
+
     Ok(Json(PositionsResponse { open, closed }))
 }
 
@@ -837,7 +1105,7 @@ pub async fn pnl_chart(
                         toString(sum(buy_amount) - sum(sell_amount)) AS net_tokens,
                         toString(sum(sell_usdc) - sum(buy_usdc)) AS cash_flow,
                         toString(argMaxMerge(last_price_state)) AS last_price
-                    FROM poly_dearboard.pnl_daily
+                    FROM pnl_daily
                     WHERE lower(trader) = ?
                       AND day < today() - {days}
                     GROUP BY asset_id"
@@ -869,7 +1137,7 @@ pub async fn pnl_chart(
                     toString(sum(buy_amount) - sum(sell_amount)) AS net_token_delta,
                     toString(sum(sell_usdc) - sum(buy_usdc)) AS cash_flow_delta,
                     toString(argMaxMerge(last_price_state)) AS last_price
-                FROM poly_dearboard.pnl_daily
+                FROM pnl_daily
                 WHERE lower(trader) = ?
                   {day_where}
                 GROUP BY day, asset_id
@@ -898,7 +1166,7 @@ pub async fn pnl_chart(
                 toString(sumIf(toFloat64(amount), side='buy') - sumIf(toFloat64(amount), side='sell')) AS net_tokens,
                 toString(sumIf(toFloat64(usdc_amount), side='sell') - sumIf(toFloat64(usdc_amount), side='buy')) AS cash_flow,
                 toString(argMax(toFloat64(price), block_number * 1000000 + log_index)) AS last_price
-            FROM poly_dearboard.trades
+            FROM trades
             PREWHERE block_timestamp > toDateTime('1970-01-01 00:00:00')
               AND block_timestamp < now() - INTERVAL 24 HOUR
             WHERE lower(trader) = ?
@@ -925,7 +1193,7 @@ pub async fn pnl_chart(
                 toString(sumIf(toFloat64(amount), side = 'buy') - sumIf(toFloat64(amount), side = 'sell')) AS net_token_delta,
                 toString(sumIf(toFloat64(usdc_amount), side = 'sell') - sumIf(toFloat64(usdc_amount), side = 'buy')) AS cash_flow_delta,
                 toString(argMax(toFloat64(price), block_number * 1000000 + log_index)) AS last_price
-            FROM poly_dearboard.trades
+            FROM trades
             PREWHERE block_timestamp >= now() - INTERVAL 24 HOUR
             WHERE lower(trader) = ?
               AND block_timestamp > toDateTime('1970-01-01 00:00:00')
@@ -950,7 +1218,7 @@ pub async fn pnl_chart(
 async fn fetch_resolved_prices(state: &AppState) -> std::collections::HashMap<String, f64> {
     state
         .db
-        .query("SELECT asset_id, resolved_price FROM poly_dearboard.resolved_prices FINAL")
+        .query("SELECT asset_id, resolved_price FROM resolved_prices FINAL")
         .fetch_all::<ResolvedPriceLookup>()
         .await
         .unwrap_or_default()
@@ -1142,13 +1410,13 @@ pub async fn smart_money(
             "WITH
                 resolved AS (
                     SELECT asset_id, toNullable(toFloat64(resolved_price)) AS resolved_price
-                    FROM poly_dearboard.resolved_prices FINAL
+                    FROM resolved_prices FINAL
                 ),
                 trader_pnl AS (
                     SELECT p.trader,
                            sum((p.sell_usdc - p.buy_usdc) + (p.buy_amount - p.sell_amount) * coalesce(rp.resolved_price, toFloat64(lp.latest_price))) AS total_pnl
-                    FROM poly_dearboard.trader_positions p
-                    LEFT JOIN (SELECT asset_id, latest_price FROM poly_dearboard.asset_latest_price FINAL) AS lp ON p.asset_id = lp.asset_id
+                    FROM trader_positions p
+                    LEFT JOIN (SELECT asset_id, latest_price FROM asset_latest_price FINAL) AS lp ON p.asset_id = lp.asset_id
                     LEFT JOIN resolved rp ON p.asset_id = rp.asset_id
                     WHERE p.trader NOT IN ({exclude})
                     GROUP BY p.trader
@@ -1160,8 +1428,8 @@ pub async fn smart_money(
                            (p.buy_amount - p.sell_amount) AS net_tokens,
                            toFloat64(lp.latest_price) AS price,
                            toFloat64(p.buy_amount - p.sell_amount) * toFloat64(lp.latest_price) AS exposure
-                    FROM poly_dearboard.trader_positions p
-                    LEFT JOIN (SELECT asset_id, latest_price FROM poly_dearboard.asset_latest_price FINAL) AS lp ON p.asset_id = lp.asset_id
+                    FROM trader_positions p
+                    LEFT JOIN (SELECT asset_id, latest_price FROM asset_latest_price FINAL) AS lp ON p.asset_id = lp.asset_id
                     LEFT JOIN resolved rp ON p.asset_id = rp.asset_id
                     WHERE p.trader IN (SELECT trader FROM trader_pnl)
                       AND rp.resolved_price IS NULL
@@ -1201,7 +1469,7 @@ pub async fn smart_money(
             "WITH
                 resolved AS (
                     SELECT asset_id, toNullable(toFloat64(resolved_price)) AS resolved_price
-                    FROM poly_dearboard.resolved_prices FINAL
+                    FROM resolved_prices FINAL
                 ),
                 trader_pnl AS (
                     SELECT trader,
@@ -1210,12 +1478,12 @@ pub async fn smart_money(
                         SELECT trader, asset_id,
                                sumIf(amount, side = 'buy') - sumIf(amount, side = 'sell') AS net_tokens,
                                sumIf(usdc_amount, side = 'sell') - sumIf(usdc_amount, side = 'buy') AS cash_flow
-                        FROM poly_dearboard.trades
+                        FROM trades
                         {prewhere}
                         WHERE trader NOT IN ({exclude})
                         GROUP BY trader, asset_id
                     ) p
-                    LEFT JOIN (SELECT asset_id, latest_price FROM poly_dearboard.asset_latest_price FINAL) AS lp ON p.asset_id = lp.asset_id
+                    LEFT JOIN (SELECT asset_id, latest_price FROM asset_latest_price FINAL) AS lp ON p.asset_id = lp.asset_id
                     LEFT JOIN resolved rp ON p.asset_id = rp.asset_id
                     GROUP BY trader
                     ORDER BY total_pnl DESC
@@ -1229,12 +1497,12 @@ pub async fn smart_money(
                     FROM (
                         SELECT trader, asset_id,
                                sumIf(amount, side = 'buy') - sumIf(amount, side = 'sell') AS net_tokens
-                        FROM poly_dearboard.trades
+                        FROM trades
                         WHERE trader IN (SELECT trader FROM trader_pnl)
                         GROUP BY trader, asset_id
                         HAVING abs(net_tokens) > 0.01
                     ) p
-                    LEFT JOIN (SELECT asset_id, latest_price FROM poly_dearboard.asset_latest_price FINAL) AS lp ON p.asset_id = lp.asset_id
+                    LEFT JOIN (SELECT asset_id, latest_price FROM asset_latest_price FINAL) AS lp ON p.asset_id = lp.asset_id
                     LEFT JOIN resolved rp ON p.asset_id = rp.asset_id
                     WHERE rp.resolved_price IS NULL
                       AND toFloat64(lp.latest_price) > 0.01
@@ -1342,7 +1610,7 @@ pub async fn trader_profile(
         .db
         .query(
             "WITH resolved AS (
-                SELECT asset_id, resolved_price FROM poly_dearboard.resolved_prices FINAL
+                SELECT asset_id, resolved_price FROM resolved_prices FINAL
             )
             SELECT
                 toString(ROUND(avg(tp.total_volume), 6)) AS avg_position_size,
@@ -1350,7 +1618,7 @@ pub async fn trader_profile(
                     dateDiff('hour', tp.first_ts, tp.last_ts), 0)) AS avg_hold_time_hours,
                 count() AS total_positions,
                 countIf(rp.asset_id != '') AS resolved_positions
-            FROM poly_dearboard.trader_positions tp FINAL
+            FROM trader_positions tp FINAL
             LEFT JOIN resolved rp ON tp.asset_id = rp.asset_id
             WHERE lower(tp.trader) = ?",
         )
@@ -1370,7 +1638,7 @@ pub async fn trader_profile(
         .query(
             "WITH resolved AS (
                 SELECT asset_id, toNullable(toFloat64(resolved_price)) AS resolved_price
-                FROM poly_dearboard.resolved_prices FINAL
+                FROM resolved_prices FINAL
             )
             SELECT
                 tp.asset_id,
@@ -1388,8 +1656,8 @@ pub async fn trader_profile(
                 toString(tp.buy_usdc) AS buy_usdc,
                 toString(tp.sell_usdc) AS sell_usdc,
                 toString(tp.buy_amount) AS buy_amount
-            FROM poly_dearboard.trader_positions tp FINAL
-            LEFT JOIN (SELECT asset_id, latest_price FROM poly_dearboard.asset_latest_price FINAL) lp
+            FROM trader_positions tp FINAL
+            LEFT JOIN (SELECT asset_id, latest_price FROM asset_latest_price FINAL) lp
                 ON tp.asset_id = lp.asset_id
             LEFT JOIN resolved rp ON tp.asset_id = rp.asset_id
             WHERE lower(tp.trader) = ?",
@@ -1522,6 +1790,206 @@ pub async fn trader_profile(
     }))
 }
 
+/// Positions last traded within this many days make up a copy score's P&L component.
+const COPY_SCORE_WINDOW_DAYS: u32 = 30;
+/// Recent P&L (USDC) at which the P&L component reaches ~88/100; losses mirror below 50.
+const COPY_SCORE_PNL_SCALE: f64 = 10_000.0;
+/// Days without a trade after which the recency component has decayed to ~37/100.
+const COPY_SCORE_RECENCY_DAYS: f64 = 14.0;
+const COPY_SCORE_TTL: std::time::Duration = std::time::Duration::from_secs(600);
+
+const DEFAULT_COPY_SCORE_WEIGHTS: CopyScoreWeights = CopyScoreWeights {
+    pnl: 0.35,
+    win_rate: 0.25,
+    volume: 0.15,
+    diversity: 0.10,
+    recency: 0.15,
+};
+
+/// Copy-score weights from COPY_SCORE_WEIGHTS, e.g.
+/// `pnl=0.35,win_rate=0.25,volume=0.15,diversity=0.1,recency=0.15`.
+/// Components left out weigh 0. Weights are normalised to sum to 1.
+fn copy_score_weights() -> CopyScoreWeights {
+    static WEIGHTS: std::sync::OnceLock<CopyScoreWeights> = std::sync::OnceLock::new();
+    *WEIGHTS.get_or_init(|| match std::env::var("COPY_SCORE_WEIGHTS") {
+        Ok(v) => match parse_copy_score_weights(&v) {
+            Some(weights) => weights,
+            None => {
+                tracing::warn!("Invalid COPY_SCORE_WEIGHTS={v}, using default weights");
+                DEFAULT_COPY_SCORE_WEIGHTS
+            }
+        },
+        Err(_) => DEFAULT_COPY_SCORE_WEIGHTS,
+    })
+}
+
+fn parse_copy_score_weights(s: &str) -> Option<CopyScoreWeights> {
+    let mut w = CopyScoreWeights {
+        pnl: 0.0,
+        win_rate: 0.0,
+        volume: 0.0,
+        diversity: 0.0,
+        recency: 0.0,
+    };
+    for part in s.split(',').map(str::trim).filter(|p| !p.is_empty()) {
+        let (name, value) = part.split_once('=')?;
+        let value: f64 = value.trim().parse().ok()?;
+        if !value.is_finite() || value < 0.0 {
+            return None;
+        }
+        let slot = match name.trim() {
+            "pnl" => &mut w.pnl,
+            "win_rate" => &mut w.win_rate,
+            "volume" => &mut w.volume,
+            "diversity" => &mut w.diversity,
+            "recency" => &mut w.recency,
+            _ => return None,
+        };
+        *slot = value;
+    }
+    let total = w.pnl + w.win_rate + w.volume + w.diversity + w.recency;
+    if total <= 0.0 {
+        return None;
+    }
+    Some(CopyScoreWeights {
+        pnl: w.pnl / total,
+        win_rate: w.win_rate / total,
+        volume: w.volume / total,
+        diversity: w.diversity / total,
+        recency: w.recency / total,
+    })
+}
+
+fn round1(v: f64) -> f64 {
+    (v * 10.0).round() / 10.0
+}
+
+/// Single 0-100 "how worth copying is this trader" score. Each component is
+/// normalised to 0-100, then combined with `copy_score_weights()`:
+/// - pnl: 50 + 50 * tanh(recent P&L / $10k), over positions traded in the last 30 days
+/// - win_rate: winning share of positions with non-zero P&L, smoothed toward 50% (+1/+2)
+/// - volume: log10 of lifetime volume, $1M and up scores 100
+/// - diversity: log10 of distinct markets traded, ~100 markets scores 100
+/// - recency: exponential decay over days since the last trade (14-day time constant)
+pub async fn trader_copy_score(
+    State(state): State<AppState>,
+    Path(address): Path<String>,
+) -> Result<impl IntoResponse, (StatusCode, String)> {
+    let address = middleware::validate_eth_address(&address).map_err(|_| {
+        (
+            StatusCode::BAD_REQUEST,
+            format!("Invalid address: {address}"),
+        )
+    })?;
+
+    {
+        let cache = state.copy_score_cache.read().await;
+        if let Some(entry) = cache.get(&address)
+            && entry.expires > std::time::Instant::now()
+        {
+            return Ok(Json(entry.score.clone()));
+        }
+    }
+
+    let row = state
+        .db
+        .query(
+            "WITH resolved AS (
+                SELECT asset_id, toNullable(toFloat64(resolved_price)) AS resolved_price
+                FROM resolved_prices FINAL
+            ),
+            positions AS (
+                SELECT
+                    tp.asset_id,
+                    tp.total_volume,
+                    tp.last_ts,
+                    (tp.sell_usdc - tp.buy_usdc)
+                        + (tp.buy_amount - tp.sell_amount)
+                        * coalesce(rp.resolved_price, toFloat64(lp.latest_price)) AS pnl
+                FROM trader_positions tp FINAL
+                LEFT JOIN (SELECT asset_id, latest_price FROM asset_latest_price FINAL) lp
+                    ON tp.asset_id = lp.asset_id
+                LEFT JOIN resolved rp ON tp.asset_id = rp.asset_id
+                WHERE lower(tp.trader) = ?
+            )
+            SELECT
+                toFloat64(sumIf(pnl, last_ts >= now() - INTERVAL ? DAY)) AS recent_pnl,
+                countIf(pnl > 0) AS wins,
+                countIf(pnl != 0) AS decided,
+                toFloat64(sum(total_volume)) AS total_volume,
+                uniqExact(asset_id) AS markets_traded,
+                toInt64(ifNull(dateDiff('second', max(last_ts), now()), -1)) AS secs_since_last_trade
+            FROM positions",
+        )
+        .bind(&address)
+        .bind(COPY_SCORE_WINDOW_DAYS)
+        .fetch_one::<CopyScoreRow>()
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    if row.markets_traded == 0 {
+        return Err((StatusCode::NOT_FOUND, "Trader not found".into()));
+    }
+
+    let win_rate = if row.decided > 0 {
+        row.wins as f64 / row.decided as f64
+    } else {
+        0.0
+    };
+    let days_since_last_trade = if row.secs_since_last_trade >= 0 {
+        row.secs_since_last_trade as f64 / 86_400.0
+    } else {
+        f64::INFINITY
+    };
+    let components = CopyScoreComponents {
+        pnl: round1(50.0 + 50.0 * (row.recent_pnl / COPY_SCORE_PNL_SCALE).tanh()),
+        win_rate: round1(100.0 * (row.wins as f64 + 1.0) / (row.decided as f64 + 2.0)),
+        volume: round1(100.0 * ((row.total_volume.max(0.0) + 1.0).log10() / 6.0).clamp(0.0, 1.0)),
+        diversity: round1(
+            100.0 * ((row.markets_traded as f64 + 1.0).log10() / 2.0).clamp(0.0, 1.0),
+        ),
+        recency: round1(100.0 * (-days_since_last_trade / COPY_SCORE_RECENCY_DAYS).exp()),
+    };
+    let weights = copy_score_weights();
+    let score = components.pnl * weights.pnl
+        + components.win_rate * weights.win_rate
+        + components.volume * weights.volume
+        + components.diversity * weights.diversity
+        + components.recency * weights.recency;
+
+    let response = TraderCopyScore {
+        address: address.clone(),
+        score: round1(score.clamp(0.0, 100.0)),
+        components,
+        weights,
+        window_days: COPY_SCORE_WINDOW_DAYS,
+        recent_pnl: row.recent_pnl,
+        win_rate,
+        total_volume: row.total_volume,
+        markets_traded: row.markets_traded,
+        days_since_last_trade: if days_since_last_trade.is_finite() {
+            round1(days_since_last_trade)
+        } else {
+            -1.0
+        },
+    };
+
+    {
+        let mut cache = state.copy_score_cache.write().await;
+        let now = std::time::Instant::now();
+        cache.retain(|_, entry| entry.expires > now);
+        cache.insert(
+            address,
+            super::server::CachedCopyScore {
+                score: response.clone(),
+                expires: now + COPY_SCORE_TTL,
+            },
+        );
+    }
+
+    Ok(Json(response))
+}
+
 /// Batch-compute labels for a list of traders (used by leaderboard).
 /// Returns empty map on error — leaderboard still works without labels.
 async fn batch_compute_labels(
@@ -1549,7 +2017,7 @@ async fn batch_compute_labels(
         .query(&format!(
             "WITH resolved AS (
                 SELECT asset_id, toNullable(toFloat64(resolved_price)) AS resolved_price
-                FROM poly_dearboard.resolved_prices FINAL
+                FROM resolved_prices FINAL
             ),
             filtered AS (
                 SELECT trader, asset_id,
@@ -1557,7 +2025,7 @@ async fn batch_compute_labels(
                        sum(buy_amount) AS buy_amount, sum(sell_amount) AS sell_amount,
                        sum(total_volume) AS total_volume, sum(trade_count) AS trade_count,
                        min(first_ts) AS first_ts, max(last_ts) AS last_ts
-                FROM poly_dearboard.trader_positions
+                FROM trader_positions
                 WHERE lower(trader) IN ({in_list})
                 GROUP BY trader, asset_id
             )
@@ -1579,7 +2047,7 @@ async fn batch_compute_labels(
                 toString(tp.sell_usdc) AS sell_usdc,
                 toString(tp.buy_amount) AS buy_amount
             FROM filtered tp
-            LEFT JOIN (SELECT asset_id, latest_price FROM poly_dearboard.asset_latest_price FINAL) lp
+            LEFT JOIN (SELECT asset_id, latest_price FROM asset_latest_price FINAL) lp
                 ON tp.asset_id = lp.asset_id
             LEFT JOIN resolved rp ON tp.asset_id = rp.asset_id"
         ))
@@ -1587,7 +2055,11 @@ async fn batch_compute_labels(
         .await
     {
         Ok(rows) => {
-            tracing::debug!("batch labels: CH query returned {} rows in {:?}", rows.len(), t0.elapsed());
+            tracing::debug!(
+                "batch labels: CH query returned {} rows in {:?}",
+                rows.len(),
+                t0.elapsed()
+            );
             rows
         }
         Err(e) => {
@@ -2037,11 +2509,11 @@ pub async fn backtest(
         let top_query = format!(
             "WITH resolved AS (
                 SELECT asset_id, toNullable(toFloat64(resolved_price)) AS resolved_price
-                FROM poly_dearboard.resolved_prices FINAL
+                FROM resolved_prices FINAL
             )
             SELECT toString(p.trader) AS address
-            FROM poly_dearboard.trader_positions p
-            LEFT JOIN (SELECT asset_id, latest_price FROM poly_dearboard.asset_latest_price FINAL) AS lp ON p.asset_id = lp.asset_id
+            FROM trader_positions p
+            LEFT JOIN (SELECT asset_id, latest_price FROM asset_latest_price FINAL) AS lp ON p.asset_id = lp.asset_id
             LEFT JOIN resolved rp ON p.asset_id = rp.asset_id
             WHERE p.trader NOT IN ({exclude})
             GROUP BY p.trader
@@ -2110,7 +2582,7 @@ pub async fn backtest(
             toString(p.trader) AS address,
             toString(ROUND(sum(p.buy_usdc) / count(), 6)) AS avg_position_size,
             count() AS market_count
-        FROM poly_dearboard.trader_positions p
+        FROM trader_positions p
         WHERE lower(p.trader) IN ({in_list})
         GROUP BY p.trader"
         ))
@@ -2147,7 +2619,7 @@ pub async fn backtest(
                 toString(sum(buy_amount) - sum(sell_amount)) AS net_tokens,
                 toString(sum(sell_usdc) - sum(buy_usdc)) AS cash_flow,
                 toString(argMaxMerge(last_price_state)) AS last_price
-            FROM poly_dearboard.pnl_daily
+            FROM pnl_daily
             WHERE lower(trader) IN ({in_list})
               AND day < today() - {days}
             GROUP BY trader, asset_id"
@@ -2188,7 +2660,7 @@ pub async fn backtest(
             toString(sum(buy_amount) - sum(sell_amount)) AS net_token_delta,
             toString(sum(sell_usdc) - sum(buy_usdc)) AS cash_flow_delta,
             toString(argMaxMerge(last_price_state)) AS last_price
-        FROM poly_dearboard.pnl_daily
+        FROM pnl_daily
         WHERE lower(trader) IN ({in_list})
           {day_where}
         GROUP BY trader, day, asset_id
@@ -2262,13 +2734,13 @@ pub async fn backtest(
     let wr = state.db.query(&format!(
         "WITH resolved AS (
             SELECT asset_id, toNullable(toFloat64(resolved_price)) AS resolved_price
-            FROM poly_dearboard.resolved_prices FINAL
+            FROM resolved_prices FINAL
         )
         SELECT
             count() AS total,
             countIf((p.sell_usdc - p.buy_usdc) + (p.buy_amount - p.sell_amount) * coalesce(rp.resolved_price, toFloat64(lp.latest_price)) > 0) AS wins
-        FROM poly_dearboard.trader_positions p
-        LEFT JOIN (SELECT asset_id, latest_price FROM poly_dearboard.asset_latest_price FINAL) AS lp ON p.asset_id = lp.asset_id
+        FROM trader_positions p
+        LEFT JOIN (SELECT asset_id, latest_price FROM asset_latest_price FINAL) AS lp ON p.asset_id = lp.asset_id
         LEFT JOIN resolved rp ON p.asset_id = rp.asset_id
         WHERE lower(p.trader) IN ({in_list})"
     ))
@@ -2292,14 +2764,14 @@ pub async fn backtest(
     let trader_pnls = state.db.query(&format!(
         "WITH resolved AS (
             SELECT asset_id, toNullable(toFloat64(resolved_price)) AS resolved_price
-            FROM poly_dearboard.resolved_prices FINAL
+            FROM resolved_prices FINAL
         )
         SELECT
             toString(p.trader) AS address,
             toString(ROUND(sum((p.sell_usdc - p.buy_usdc) + (p.buy_amount - p.sell_amount) * coalesce(rp.resolved_price, toFloat64(lp.latest_price))), 6)) AS pnl,
             count() AS markets_traded
-        FROM poly_dearboard.trader_positions p
-        LEFT JOIN (SELECT asset_id, latest_price FROM poly_dearboard.asset_latest_price FINAL) AS lp ON p.asset_id = lp.asset_id
+        FROM trader_positions p
+        LEFT JOIN (SELECT asset_id, latest_price FROM asset_latest_price FINAL) AS lp ON p.asset_id = lp.asset_id
         LEFT JOIN resolved rp ON p.asset_id = rp.asset_id
         WHERE lower(p.trader) IN ({in_list})
         GROUP BY p.trader
@@ -2523,7 +2995,7 @@ pub async fn copy_portfolio(
             "WITH
                 resolved AS (
                     SELECT asset_id, toNullable(toFloat64(resolved_price)) AS resolved_price
-                    FROM poly_dearboard.resolved_prices FINAL
+                    FROM resolved_prices FINAL
                 )
             SELECT
                 toString(p.trader) AS trader,
@@ -2535,8 +3007,8 @@ pub async fn copy_portfolio(
                 toString(toFloat64(lp.latest_price)) AS latest_price,
                 toString(abs(toFloat64(p.buy_amount - p.sell_amount)) * toFloat64(lp.latest_price)) AS exposure,
                 toString(ROUND((p.sell_usdc - p.buy_usdc) + (p.buy_amount - p.sell_amount) * toFloat64(lp.latest_price), 6)) AS pnl
-            FROM poly_dearboard.trader_positions p
-            LEFT JOIN (SELECT asset_id, latest_price FROM poly_dearboard.asset_latest_price FINAL) AS lp ON p.asset_id = lp.asset_id
+            FROM trader_positions p
+            LEFT JOIN (SELECT asset_id, latest_price FROM asset_latest_price FINAL) AS lp ON p.asset_id = lp.asset_id
             LEFT JOIN resolved rp ON p.asset_id = rp.asset_id
             WHERE p.trader IN ({trader_filter})
               AND rp.resolved_price IS NULL
@@ -2553,13 +3025,13 @@ pub async fn copy_portfolio(
             "WITH
                 resolved AS (
                     SELECT asset_id, toNullable(toFloat64(resolved_price)) AS resolved_price
-                    FROM poly_dearboard.resolved_prices FINAL
+                    FROM resolved_prices FINAL
                 ),
                 trader_pnl AS (
                     SELECT p.trader,
                            sum((p.sell_usdc - p.buy_usdc) + (p.buy_amount - p.sell_amount) * coalesce(rp.resolved_price, toFloat64(lp.latest_price))) AS total_pnl
-                    FROM poly_dearboard.trader_positions p
-                    LEFT JOIN (SELECT asset_id, latest_price FROM poly_dearboard.asset_latest_price FINAL) AS lp ON p.asset_id = lp.asset_id
+                    FROM trader_positions p
+                    LEFT JOIN (SELECT asset_id, latest_price FROM asset_latest_price FINAL) AS lp ON p.asset_id = lp.asset_id
                     LEFT JOIN resolved rp ON p.asset_id = rp.asset_id
                     WHERE p.trader NOT IN ({exclude})
                     GROUP BY p.trader
@@ -2576,8 +3048,8 @@ pub async fn copy_portfolio(
                 toString(toFloat64(lp.latest_price)) AS latest_price,
                 toString(abs(toFloat64(p.buy_amount - p.sell_amount)) * toFloat64(lp.latest_price)) AS exposure,
                 toString(ROUND((p.sell_usdc - p.buy_usdc) + (p.buy_amount - p.sell_amount) * toFloat64(lp.latest_price), 6)) AS pnl
-            FROM poly_dearboard.trader_positions p
-            LEFT JOIN (SELECT asset_id, latest_price FROM poly_dearboard.asset_latest_price FINAL) AS lp ON p.asset_id = lp.asset_id
+            FROM trader_positions p
+            LEFT JOIN (SELECT asset_id, latest_price FROM asset_latest_price FINAL) AS lp ON p.asset_id = lp.asset_id
             LEFT JOIN resolved rp ON p.asset_id = rp.asset_id
             WHERE p.trader IN (SELECT trader FROM trader_pnl)
               AND rp.resolved_price IS NULL
@@ -2795,6 +3267,10 @@ pub async fn delete_trader_list(
     Ok(StatusCode::NO_CONTENT)
 }
 
+/// Largest copy weight a list member may carry; weights are relative, so this
+/// only bounds how lopsided one list can be.
+const MAX_MEMBER_WEIGHT: f64 = 1000.0;
+
 pub async fn add_list_members(
     State(state): State<AppState>,
     AuthUser(owner): AuthUser,
@@ -2809,8 +3285,9 @@ pub async fn add_list_members(
     }
 
     let labels = req.labels.unwrap_or_default();
+    let weights = req.weights.unwrap_or_default();
 
-    let members: Vec<(String, Option<String>)> = req
+    let members: Vec<(String, Option<String>, f64)> = req
         .addresses
         .iter()
         .enumerate()
@@ -2818,15 +3295,74 @@ pub async fn add_list_members(
             let validated = middleware::validate_eth_address(addr)
                 .map_err(|_| (StatusCode::BAD_REQUEST, format!("Invalid address: {addr}")))?;
             let label = labels.get(i).and_then(|l| l.clone());
-            Ok((validated, label))
+            let weight = weights.get(i).copied().flatten().unwrap_or(1.0);
+            if !weight.is_finite() || weight <= 0.0 || weight > MAX_MEMBER_WEIGHT {
+                return Err((
+                    StatusCode::BAD_REQUEST,
+                    format!("Invalid weight for {addr}: must be in (0, {MAX_MEMBER_WEIGHT}]"),
+                ));
+            }
+            Ok((validated, label, weight))
         })
         .collect::<Result<Vec<_>, (StatusCode, String)>>()?;
 
-    let conn = state.user_db.lock().unwrap_or_else(|p| p.into_inner());
-    db::add_list_members(&conn, &id, &owner, &members).map_err(map_list_error)?;
+    {
+        let conn = state.user_db.lock().unwrap_or_else(|p| p.into_inner());
+        db::add_list_members(&conn, &id, &owner, &members).map_err(map_list_error)?;
+    }
+    refresh_list_sessions(&state, &id, &owner).await;
     Ok(StatusCode::NO_CONTENT)
 }
 
+/// Adds one leaderboard page (same sort/order/limit/offset/timeframe params) to a list.
+/// Existing members are skipped, and the page is truncated to the list's remaining capacity.
+pub async fn add_list_members_from_leaderboard(
+    State(state): State<AppState>,
+    AuthUser(owner): AuthUser,
+    Path(id): Path<String>,
+    Query(params): Query<LeaderboardParams>,
+) -> Result<impl IntoResponse, (StatusCode, String)> {
+    let sort = params.sort.as_deref().unwrap_or("realized_pnl");
+    let order = params.order.as_deref().unwrap_or("desc");
+    let limit = params.limit.unwrap_or(10).min(db::MAX_MEMBERS_PER_LIST);
+    let offset = params.offset.unwrap_or(0);
+    let timeframe = params.timeframe.as_deref().unwrap_or("all");
+
+    let (traders, _) = query_leaderboard(&state, sort, order, limit, offset, timeframe).await?;
+    let candidates: Vec<String> = traders.iter().map(|t| t.address.to_lowercase()).collect();
+
+    let to_add: Vec<String> = {
+        let conn = state.user_db.lock().unwrap_or_else(|p| p.into_inner());
+        let list = db::get_trader_list(&conn, &id, &owner).map_err(map_list_error)?;
+        let existing: std::collections::HashSet<&str> =
+            list.members.iter().map(|m| m.address.as_str()).collect();
+        let capacity = (db::MAX_MEMBERS_PER_LIST as usize).saturating_sub(list.members.len());
+
+        let to_add: Vec<String> = candidates
+            .iter()
+            .filter(|a| !existing.contains(a.as_str()))
+            .take(capacity)
+            .cloned()
+            .collect();
+
+        if !to_add.is_empty() {
+            let members: Vec<(String, Option<String>, f64)> =
+                to_add.iter().map(|a| (a.clone(), None, 1.0)).collect();
+            db::add_list_members(&conn, &id, &owner, &members).map_err(map_list_error)?;
+        }
+        to_add
+    };
+    if !to_add.is_empty() {
+        refresh_list_sessions(&state, &id, &owner).await;
+    }
+
+    Ok(Json(AddFromLeaderboardResponse {
+        added: to_add.len(),
+        skipped: candidates.len() - to_add.len(),
+        addresses: to_add,
+    }))
+}
+
 pub async fn remove_list_members(
     State(state): State<AppState>,
     AuthUser(owner): AuthUser,
@@ -2835,7 +3371,24 @@ pub async fn remove_list_members(
 ) -> Result<impl IntoResponse, (StatusCode, String)> {
     let addresses: Vec<String> = req.addresses.iter().map(|a| a.to_lowercase()).collect();
 
-    let conn = state.user_db.lock().unwrap_or_else(|p| p.into_inner());
-    db::remove_list_members(&conn, &id, &owner, &addresses).map_err(map_list_error)?;
+    {
+        let conn = state.user_db.lock().unwrap_or_else(|p| p.into_inner());
+        db::remove_list_members(&conn, &id, &owner, &addresses).map_err(map_list_error)?;
+    }
+    refresh_list_sessions(&state, &id, &owner).await;
     Ok(StatusCode::NO_CONTENT)
 }
+
+/// Tells the engine to re-resolve traders for sessions copying an edited list.
+async fn refresh_list_sessions(state: &AppState, list_id: &str, owner: &str) {
+    let session_ids = {
+        let conn = state.user_db.lock().unwrap_or_else(|p| p.into_inner());
+        db::get_list_session_ids(&conn, list_id, owner).unwrap_or_default()
+    };
+    for session_id in session_ids {
+        let _ = state
+            .copytrade_cmd_tx
+            .send(super::engine::CopyTradeCommand::RefreshTraders { session_id })
+            .await;
+    }
+}
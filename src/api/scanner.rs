@@ -84,12 +84,92 @@ struct Receipt {
 // JSON-RPC helpers
 // ---------------------------------------------------------------------------
 
-async fn rpc_call<T: serde::de::DeserializeOwned>(
+/// Polygon JSON-RPC endpoints: `POLYGON_RPC_URL` first, then the comma-separated
+/// `POLYGON_RPC_FALLBACK_URLS` in order. Calls move on to the next URL when one
+/// fails, so block timestamps and the scanner survive a single provider outage.
+#[derive(Clone)]
+pub struct RpcUrls {
+    urls: Vec<String>,
+}
+
+impl RpcUrls {
+    pub fn from_env() -> Self {
+        let primary = std::env::var("POLYGON_RPC_URL")
+            .unwrap_or_else(|_| "http://erpc:4000/main/evm/137".into());
+        let mut urls = vec![primary];
+        if let Ok(list) = std::env::var("POLYGON_RPC_FALLBACK_URLS") {
+            for url in list.split(',').map(str::trim).filter(|u| !u.is_empty()) {
+                if !urls.iter().any(|u| u == url) {
+                    urls.push(url.to_string());
+                }
+            }
+        }
+        Self { urls }
+    }
+}
+
+#[cfg(test)]
+impl RpcUrls {
+    pub(crate) fn from_urls(urls: &[&str]) -> Self {
+        Self {
+            urls: urls.iter().map(|u| u.to_string()).collect(),
+        }
+    }
+}
+
+impl std::fmt::Display for RpcUrls {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.urls[0])?;
+        if self.urls.len() > 1 {
+            write!(f, " + {} fallback(s)", self.urls.len() - 1)?;
+        }
+        Ok(())
+    }
+}
+
+pub(crate) async fn rpc_call<T: serde::de::DeserializeOwned>(
     http: &reqwest::Client,
-    url: &str,
+    rpc: &RpcUrls,
     method: &str,
     params: serde_json::Value,
 ) -> Result<T, String> {
+    rpc_call_opt(http, rpc, method, params)
+        .await?
+        .ok_or_else(|| "RPC returned null result".into())
+}
+
+/// Like `rpc_call`, but a null result (e.g. unknown tx hash) is `Ok(None)`.
+/// Each URL is tried in order until one answers; a null result is an answer.
+async fn rpc_call_opt<T: serde::de::DeserializeOwned>(
+    http: &reqwest::Client,
+    rpc: &RpcUrls,
+    method: &str,
+    params: serde_json::Value,
+) -> Result<Option<T>, String> {
+    let mut last_err = String::from("no RPC URL configured");
+    for (i, url) in rpc.urls.iter().enumerate() {
+        match rpc_call_one(http, url, method, params.clone()).await {
+            Ok(result) => {
+                tracing::debug!("RPC {method} served by {url}");
+                return Ok(result);
+            }
+            Err(e) => {
+                if i + 1 < rpc.urls.len() {
+                    tracing::debug!("RPC {method} failed on {url}: {e}, trying next");
+                }
+                last_err = e;
+            }
+        }
+    }
+    Err(last_err)
+}
+
+async fn rpc_call_one<T: serde::de::DeserializeOwned>(
+    http: &reqwest::Client,
+    url: &str,
+    method: &str,
+    params: serde_json::Value,
+) -> Result<Option<T>, String> {
     let req = RpcRequest {
         jsonrpc: "2.0",
         method,
@@ -122,30 +202,51 @@ async fn rpc_call<T: serde::de::DeserializeOwned>(
         return Err(format!("RPC error: {err}"));
     }
 
-    body.result.ok_or_else(|| "RPC returned null result".into())
+    Ok(body.result)
 }
 
-async fn get_block_number(http: &reqwest::Client, url: &str) -> Result<u64, String> {
-    let hex: String = rpc_call(http, url, "eth_blockNumber", serde_json::json!([])).await?;
+pub(crate) async fn get_block_number(http: &reqwest::Client, rpc: &RpcUrls) -> Result<u64, String> {
+    let hex: String = rpc_call(http, rpc, "eth_blockNumber", serde_json::json!([])).await?;
     u64::from_str_radix(hex.trim_start_matches("0x"), 16)
         .map_err(|e| format!("Invalid block number: {e}"))
 }
 
-async fn get_block(http: &reqwest::Client, url: &str, number: u64) -> Result<Block, String> {
+async fn get_block(http: &reqwest::Client, rpc: &RpcUrls, number: u64) -> Result<Block, String> {
     let hex = format!("0x{number:x}");
     rpc_call(
         http,
-        url,
+        rpc,
         "eth_getBlockByNumber",
         serde_json::json!([hex, true]),
     )
     .await
 }
 
-async fn get_receipt(http: &reqwest::Client, url: &str, tx_hash: &str) -> Result<Receipt, String> {
+/// Whether a transaction is mined and succeeded. `Ok(None)` when the node doesn't
+/// know the hash (not mined, or reorged out).
+pub(crate) async fn tx_succeeded(
+    http: &reqwest::Client,
+    rpc: &RpcUrls,
+    tx_hash: &str,
+) -> Result<Option<bool>, String> {
+    let receipt: Option<Receipt> = rpc_call_opt(
+        http,
+        rpc,
+        "eth_getTransactionReceipt",
+        serde_json::json!([tx_hash]),
+    )
+    .await?;
+    Ok(receipt.map(|r| r.status.as_deref() == Some("0x1")))
+}
+
+async fn get_receipt(
+    http: &reqwest::Client,
+    rpc: &RpcUrls,
+    tx_hash: &str,
+) -> Result<Receipt, String> {
     rpc_call(
         http,
-        url,
+        rpc,
         "eth_getTransactionReceipt",
         serde_json::json!([tx_hash]),
     )
@@ -160,14 +261,14 @@ fn hex_to_u64(hex: &str) -> u64 {
 // Main scan loop
 // ---------------------------------------------------------------------------
 
-pub async fn run(http: reqwest::Client, rpc_url: String, alert_tx: broadcast::Sender<Alert>) {
-    tracing::info!("Phantom fill scanner starting (RPC: {rpc_url})");
+pub async fn run(http: reqwest::Client, rpc: RpcUrls, alert_tx: broadcast::Sender<Alert>) {
+    tracing::info!("Phantom fill scanner starting (RPC: {rpc})");
 
     // Wait for RPC to be available
     tokio::time::sleep(std::time::Duration::from_secs(5)).await;
 
     let mut last_block = loop {
-        match get_block_number(&http, &rpc_url).await {
+        match get_block_number(&http, &rpc).await {
             Ok(n) => break n.saturating_sub(STARTUP_LOOKBACK),
             Err(e) => {
                 tracing::warn!("Scanner: waiting for RPC: {e}");
@@ -182,7 +283,7 @@ pub async fn run(http: reqwest::Client, rpc_url: String, alert_tx: broadcast::Se
     loop {
         interval.tick().await;
 
-        let head = match get_block_number(&http, &rpc_url).await {
+        let head = match get_block_number(&http, &rpc).await {
             Ok(n) => n,
             Err(e) => {
                 tracing::warn!("Scanner: eth_blockNumber failed: {e}");
@@ -195,7 +296,7 @@ pub async fn run(http: reqwest::Client, rpc_url: String, alert_tx: broadcast::Se
 
         while last_block < target {
             last_block += 1;
-            if let Err(e) = scan_block(&http, &rpc_url, last_block, &alert_tx).await {
+            if let Err(e) = scan_block(&http, &rpc, last_block, &alert_tx).await {
                 tracing::warn!("Scanner: block {last_block} failed: {e}");
                 last_block -= 1;
                 break;
@@ -206,11 +307,11 @@ pub async fn run(http: reqwest::Client, rpc_url: String, alert_tx: broadcast::Se
 
 async fn scan_block(
     http: &reqwest::Client,
-    rpc_url: &str,
+    rpc: &RpcUrls,
     block_number: u64,
     alert_tx: &broadcast::Sender<Alert>,
 ) -> Result<(), String> {
-    let block = get_block(http, rpc_url, block_number).await?;
+    let block = get_block(http, rpc, block_number).await?;
 
     // Filter TXs targeting exchange contracts
     let exchange_txs: Vec<&Tx> = block
@@ -233,7 +334,7 @@ async fn scan_block(
 
     for tx in exchange_txs {
         let tx_hash = tx.hash.as_deref().unwrap_or("");
-        let receipt = get_receipt(http, rpc_url, tx_hash).await?;
+        let receipt = get_receipt(http, rpc, tx_hash).await?;
 
         // status "0x0" = reverted
         if receipt.status.as_deref() == Some("0x0") {
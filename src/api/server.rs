@@ -1,13 +1,14 @@
 use axum::Router;
-use axum::routing::{delete, get, post};
+use axum::routing::{get, patch, post};
 use std::collections::{HashMap, HashSet};
 use std::sync::{Arc, Mutex};
 use tokio::sync::{RwLock, broadcast};
 use tower_http::cors::{Any, CorsLayer};
 
 use super::{
-    alerts, contracts, copytrade, db, engine, markets, routes, scanner, types::LeaderboardResponse,
-    wallet, ws_subscriber,
+    alerts, confirmations, contracts, copytrade, db, deposit_watch, digest, engine, markets,
+    order_mirror, routes, scanner, types::LeaderboardResponse, update_replay, wallet,
+    ws_subscriber,
 };
 
 /// Cached leaderboard response with expiry.
@@ -18,6 +19,23 @@ pub struct CachedResponse {
 
 pub type LeaderboardCache = Arc<RwLock<HashMap<String, CachedResponse>>>;
 
+/// CLOB order book levels for one token, best first.
+pub struct CachedBook {
+    pub bids: Vec<super::types::BookLevel>,
+    pub asks: Vec<super::types::BookLevel>,
+    pub expires: std::time::Instant,
+}
+
+pub type BookCache = Arc<RwLock<HashMap<String, CachedBook>>>;
+
+/// Computed copy score for one trader address.
+pub struct CachedCopyScore {
+    pub score: super::types::TraderCopyScore,
+    pub expires: std::time::Instant,
+}
+
+pub type CopyScoreCache = Arc<RwLock<HashMap<String, CachedCopyScore>>>;
+
 /// Per-wallet balance + approval state (ephemeral, not persisted).
 #[derive(Clone)]
 pub struct WalletBalanceState {
@@ -34,13 +52,18 @@ pub type WalletBalances = Arc<RwLock<HashMap<String, WalletBalanceState>>>;
 
 #[derive(Clone)]
 pub struct AppState {
+    /// Queries use unqualified table names, resolved against `clickhouse_db`
     pub db: clickhouse::Client,
+    /// CLICKHOUSE_DB; the indexer's per-contract databases are `{clickhouse_db}_<contract>`
+    pub clickhouse_db: Arc<String>,
     pub http: reqwest::Client,
     pub market_cache: markets::MarketCache,
     pub alert_tx: broadcast::Sender<alerts::Alert>,
     pub trade_tx: broadcast::Sender<alerts::LiveTrade>,
     pub metadata_tx: tokio::sync::mpsc::Sender<(String, markets::MarketInfo)>,
     pub leaderboard_cache: LeaderboardCache,
+    pub book_cache: BookCache,
+    pub copy_score_cache: CopyScoreCache,
     pub user_db: Arc<Mutex<rusqlite::Connection>>,
     pub jwt_secret: Arc<Vec<u8>>,
     pub copytrade_live_tx: broadcast::Sender<alerts::LiveTrade>,
@@ -48,9 +71,20 @@ pub struct AppState {
     pub encryption_key: Arc<[u8; 32]>,
     pub erpc_url: Arc<String>,
     pub wallet_balances: WalletBalances,
+    /// wallet_id → last manual balance refresh (the poller's checks don't count)
+    pub wallet_refreshes: Arc<Mutex<HashMap<String, std::time::Instant>>>,
     pub copytrade_cmd_tx: tokio::sync::mpsc::Sender<engine::CopyTradeCommand>,
     pub copytrade_update_tx: broadcast::Sender<super::types::CopyTradeUpdate>,
+    /// `copytrade_update_tx` stamped with replay cursors, as sent on /ws/copytrade
+    pub copytrade_sequenced_tx: broadcast::Sender<Arc<update_replay::SequencedUpdate>>,
+    pub copytrade_replay: update_replay::UpdateLog,
     pub clob_client: Arc<RwLock<Option<engine::ClobClientState>>>,
+    /// Lowercase wallet addresses allowed to call /api/admin/* routes
+    pub admin_addresses: Arc<HashSet<String>>,
+    pub engine_metrics: Arc<engine::EngineMetrics>,
+    /// Copy-trade orders are mirrored to ClickHouse (COPYTRADE_CH_MIRROR)
+    pub order_mirror_enabled: bool,
+    pub deposit_watch_tx: tokio::sync::mpsc::Sender<deposit_watch::WatchRequest>,
 }
 
 async fn metadata_writer(
@@ -96,7 +130,7 @@ async fn flush_metadata_batch(
     db: &clickhouse::Client,
     batch: &mut Vec<super::types::MarketMetadataRow>,
 ) {
-    let mut inserter = match db.insert("poly_dearboard.market_metadata") {
+    let mut inserter = match db.insert("market_metadata") {
         Ok(i) => i,
         Err(e) => {
             tracing::warn!("market_metadata batch insert failed: {e}");
@@ -116,17 +150,46 @@ async fn flush_metadata_batch(
     }
 }
 
-pub async fn run(client: clickhouse::Client, port: u16) {
+/// Reads a channel capacity from the environment, falling back to `default`
+/// when unset or invalid (zero is rejected — tokio panics on empty channels).
+fn env_capacity(name: &str, default: usize) -> usize {
+    match std::env::var(name) {
+        Ok(v) => match v.trim().parse::<usize>() {
+            Ok(n) if n > 0 => n,
+            _ => {
+                tracing::warn!("Invalid {name}={v}, using default {default}");
+                default
+            }
+        },
+        Err(_) => default,
+    }
+}
+
+/// Reads a secret from `name`, or when unset from the file at `{name}_FILE`
+/// (Docker/Kubernetes secrets convention). File contents are trimmed of the
+/// trailing newline editors and `echo` leave behind.
+fn env_secret(name: &str) -> Option<String> {
+    if let Ok(v) = std::env::var(name) {
+        return Some(v);
+    }
+    let path = std::env::var(format!("{name}_FILE")).ok()?;
+    let contents = std::fs::read_to_string(&path)
+        .unwrap_or_else(|e| panic!("{name}_FILE={path} could not be read: {e}"));
+    Some(contents.trim().to_string())
+}
+
+pub async fn run(client: clickhouse::Client, clickhouse_db: String, port: u16) {
     let cors = CorsLayer::new()
         .allow_origin(Any)
         .allow_methods(Any)
         .allow_headers(Any);
 
-    let jwt_secret = std::env::var("JWT_SECRET")
-        .expect("JWT_SECRET env var is required for wallet authentication");
+    let jwt_secret = env_secret("JWT_SECRET")
+        .expect("JWT_SECRET (or JWT_SECRET_FILE) is required for wallet authentication");
 
-    let encryption_key_hex = std::env::var("WALLET_ENCRYPTION_KEY")
-        .expect("WALLET_ENCRYPTION_KEY env var is required (64 hex chars = 32 bytes)");
+    let encryption_key_hex = env_secret("WALLET_ENCRYPTION_KEY").expect(
+        "WALLET_ENCRYPTION_KEY (or WALLET_ENCRYPTION_KEY_FILE) is required (64 hex chars = 32 bytes)",
+    );
     let encryption_key_bytes =
         hex::decode(encryption_key_hex.trim()).expect("WALLET_ENCRYPTION_KEY must be valid hex");
     let encryption_key: [u8; 32] = encryption_key_bytes
@@ -136,27 +199,65 @@ pub async fn run(client: clickhouse::Client, port: u16) {
     let erpc_url = std::env::var("POLYGON_RPC_URL")
         .unwrap_or_else(|_| "http://localhost:4000/main/evm/137".into());
 
+    let admin_addresses: HashSet<String> = std::env::var("ADMIN_ADDRESSES")
+        .unwrap_or_default()
+        .split(',')
+        .map(|s| s.trim().to_lowercase())
+        .filter(|s| !s.is_empty())
+        .collect();
+
+    // Resolve the leaderboard exclusion list up front so a bad env value shows at boot
+    tracing::info!(
+        "Excluding {} protocol address(es) from trader rankings",
+        routes::excluded_traders().len()
+    );
+
     let user_conn = db::init_user_db("data/users.db");
 
-    let (alert_tx, _) = broadcast::channel::<alerts::Alert>(256);
-    let (trade_tx, _) = broadcast::channel::<alerts::LiveTrade>(512);
+    // Channel capacities. Broadcast receivers that fall further behind than the
+    // capacity get `RecvError::Lagged` and silently lose messages. For WS feeds
+    // that only means a slow browser misses some rows, but the copy-trade engine
+    // is the sole consumer of COPYTRADE_LIVE_CAPACITY: a lag there drops whale
+    // trades that will never be copied. Size it to cover the largest burst of
+    // tracked-trader fills expected while one order round-trips to the CLOB
+    // (roughly orders/sec x seconds per order, with generous headroom).
+    let alert_cap = env_capacity("ALERT_CHANNEL_CAPACITY", 256);
+    let trade_cap = env_capacity("TRADE_CHANNEL_CAPACITY", 512);
+    let metadata_cap = env_capacity("METADATA_CHANNEL_CAPACITY", 1024);
+    let cmd_cap = env_capacity("COPYTRADE_CMD_CAPACITY", 64);
+    let update_cap = env_capacity("COPYTRADE_UPDATE_CAPACITY", 256);
+    let live_cap = env_capacity("COPYTRADE_LIVE_CAPACITY", 128);
+    tracing::info!(
+        "Channel capacities: alerts={alert_cap} trades={trade_cap} metadata={metadata_cap} \
+         copytrade_cmd={cmd_cap} copytrade_updates={update_cap} copytrade_live={live_cap}"
+    );
+
+    let (alert_tx, _) = broadcast::channel::<alerts::Alert>(alert_cap);
+    let (trade_tx, _) = broadcast::channel::<alerts::LiveTrade>(trade_cap);
     let (metadata_tx, metadata_rx) =
-        tokio::sync::mpsc::channel::<(String, markets::MarketInfo)>(1024);
+        tokio::sync::mpsc::channel::<(String, markets::MarketInfo)>(metadata_cap);
     let (copytrade_cmd_tx, copytrade_cmd_rx) =
-        tokio::sync::mpsc::channel::<engine::CopyTradeCommand>(64);
-    let (copytrade_update_tx, _) = broadcast::channel::<super::types::CopyTradeUpdate>(256);
-    let (copytrade_live_tx, _) = broadcast::channel::<alerts::LiveTrade>(128);
+        tokio::sync::mpsc::channel::<engine::CopyTradeCommand>(cmd_cap);
+    let (copytrade_update_tx, _) = broadcast::channel::<super::types::CopyTradeUpdate>(update_cap);
+    let (copytrade_sequenced_tx, _) =
+        broadcast::channel::<Arc<update_replay::SequencedUpdate>>(update_cap);
+    let (copytrade_live_tx, _) = broadcast::channel::<alerts::LiveTrade>(live_cap);
     let (trader_watch_tx, trader_watch_rx) =
         tokio::sync::watch::channel::<HashSet<String>>(HashSet::new());
+    let (deposit_watch_tx, deposit_watch_rx) =
+        tokio::sync::mpsc::channel::<deposit_watch::WatchRequest>(64);
 
     let state = AppState {
         db: client,
+        clickhouse_db: Arc::new(clickhouse_db),
         http: reqwest::Client::new(),
         market_cache: markets::new_cache(),
         alert_tx,
         trade_tx,
         metadata_tx,
         leaderboard_cache: Arc::new(RwLock::new(HashMap::new())),
+        book_cache: Arc::new(RwLock::new(HashMap::new())),
+        copy_score_cache: Arc::new(RwLock::new(HashMap::new())),
         user_db: Arc::new(Mutex::new(user_conn)),
         jwt_secret: Arc::new(jwt_secret.into_bytes()),
         copytrade_live_tx,
@@ -164,9 +265,16 @@ pub async fn run(client: clickhouse::Client, port: u16) {
         encryption_key: Arc::new(encryption_key),
         erpc_url: Arc::new(erpc_url),
         wallet_balances: Arc::new(RwLock::new(HashMap::new())),
+        wallet_refreshes: Arc::new(Mutex::new(HashMap::new())),
         copytrade_cmd_tx,
         copytrade_update_tx,
+        copytrade_sequenced_tx,
+        copytrade_replay: Arc::new(Mutex::new(update_replay::ReplayLog::from_env())),
         clob_client: Arc::new(RwLock::new(None)),
+        admin_addresses: Arc::new(admin_addresses),
+        engine_metrics: Arc::new(engine::EngineMetrics::default()),
+        order_mirror_enabled: order_mirror::enabled_from_env(),
+        deposit_watch_tx,
     };
 
     // Pre-warm the market name cache in the background, then refresh periodically
@@ -174,10 +282,11 @@ pub async fn run(client: clickhouse::Client, port: u16) {
         let http = state.http.clone();
         let db = state.db.clone();
         let cache = state.market_cache.clone();
+        let database = state.clickhouse_db.clone();
         tokio::spawn(async move {
             markets::warm_cache(&http, &db, &cache).await;
             markets::persist_cache_to_clickhouse(&db, &cache).await;
-            markets::populate_resolved_prices(&db, &cache).await;
+            markets::populate_resolved_prices(&db, &database, &cache).await;
             // Re-warm every 10 minutes to catch new markets + resolutions
             let mut interval = tokio::time::interval(std::time::Duration::from_secs(600));
             interval.tick().await; // skip immediate tick
@@ -186,7 +295,7 @@ pub async fn run(client: clickhouse::Client, port: u16) {
                 tracing::info!("Refreshing market cache...");
                 markets::warm_cache(&http, &db, &cache).await;
                 markets::persist_cache_to_clickhouse(&db, &cache).await;
-                markets::populate_resolved_prices(&db, &cache).await;
+                markets::populate_resolved_prices(&db, &database, &cache).await;
             }
         });
     }
@@ -212,11 +321,9 @@ pub async fn run(client: clickhouse::Client, port: u16) {
 
     // Phantom fill scanner: polls Polygon blocks for reverted exchange TXs
     {
-        let rpc_url = std::env::var("POLYGON_RPC_URL")
-            .unwrap_or_else(|_| "http://erpc:4000/main/evm/137".into());
         let http = state.http.clone();
         let alert_tx = state.alert_tx.clone();
-        tokio::spawn(scanner::run(http, rpc_url, alert_tx));
+        tokio::spawn(scanner::run(http, scanner::RpcUrls::from_env(), alert_tx));
     }
 
     // Balance polling: checks USDC.e balance + allowances for all trading wallets
@@ -225,17 +332,59 @@ pub async fn run(client: clickhouse::Client, port: u16) {
         tokio::spawn(balance_poll_task(state));
     }
 
-    // Copy-trade engine: subscribes to copytrade_live_tx (targeted WS trades), places CLOB orders
+    // Update replay: sequences copy-trade updates and keeps recent ones for reconnects
+    {
+        let update_rx = state.copytrade_update_tx.subscribe();
+        let log = state.copytrade_replay.clone();
+        let sequenced_tx = state.copytrade_sequenced_tx.clone();
+        tokio::spawn(update_replay::run(update_rx, log, sequenced_tx));
+    }
+
+    // Deposit watcher: pushes DepositConfirmed once a bridged deposit lands
+    {
+        let http = state.http.clone();
+        let update_tx = state.copytrade_update_tx.clone();
+        let balances = state.wallet_balances.clone();
+        tokio::spawn(deposit_watch::run(
+            http,
+            deposit_watch_rx,
+            update_tx,
+            balances,
+        ));
+    }
+
+    // Copy-trade engine: subscribes to copytrade_live_tx (targeted WS trades), places CLOB orders.
+    // With COPYTRADE_MIN_CONFIRMATIONS > 0, trades pass through the confirmation gate first.
     {
-        let trade_rx = state.copytrade_live_tx.subscribe();
+        let trade_rx = match confirmations::min_confirmations_from_env() {
+            0 => state.copytrade_live_tx.subscribe(),
+            n => {
+                let (confirmed_tx, confirmed_rx) =
+                    broadcast::channel::<alerts::LiveTrade>(live_cap);
+                tokio::spawn(confirmations::run(
+                    state.http.clone(),
+                    scanner::RpcUrls::from_env(),
+                    n,
+                    state.copytrade_live_tx.subscribe(),
+                    confirmed_tx,
+                    state.copytrade_cmd_tx.clone(),
+                ));
+                confirmed_rx
+            }
+        };
         let update_tx = state.copytrade_update_tx.clone();
         let clob = state.clob_client.clone();
         let udb = state.user_db.clone();
         let enc = state.encryption_key.clone();
         let ch = state.db.clone();
         let watch_tx = state.trader_watch_tx.clone();
+        let http = state.http.clone();
+        let metrics = state.engine_metrics.clone();
+        let market_cache = state.market_cache.clone();
+        let alert_rx = state.alert_tx.subscribe();
         tokio::spawn(engine::copytrade_engine_loop(
             trade_rx,
+            alert_rx,
             copytrade_cmd_rx,
             update_tx,
             clob,
@@ -243,22 +392,38 @@ pub async fn run(client: clickhouse::Client, port: u16) {
             enc,
             ch,
             watch_tx,
+            http,
+            metrics,
+            market_cache,
         ));
     }
 
+    // Per-session summary digests for sessions with a webhook configured
+    {
+        let http = state.http.clone();
+        let udb = state.user_db.clone();
+        tokio::spawn(digest::run(http, udb));
+    }
+
+    // Optional ClickHouse mirror of copy-trade orders (analytics sink; SQLite stays authoritative)
+    if state.order_mirror_enabled {
+        let db = state.db.clone();
+        let udb = state.user_db.clone();
+        tokio::spawn(order_mirror::run(db, udb));
+    }
+
     // Targeted eth_subscribe for copy-trade sessions only (zero CU when no sessions active)
     {
         let copytrade_tx = state.copytrade_live_tx.clone();
         let cache = state.market_cache.clone();
         let http = state.http.clone();
-        let rpc_url = std::env::var("POLYGON_RPC_URL")
-            .unwrap_or_else(|_| "http://erpc:4000/main/evm/137".into());
         tokio::spawn(ws_subscriber::run(
             copytrade_tx,
             trader_watch_rx,
             cache,
             http,
-            rpc_url,
+            scanner::RpcUrls::from_env(),
+            state.engine_metrics.clone(),
         ));
     }
 
@@ -272,14 +437,21 @@ pub async fn run(client: clickhouse::Client, port: u16) {
     let protected_api = Router::new()
         .route("/leaderboard", get(routes::leaderboard))
         .route("/trader/{address}", get(routes::trader_stats))
+        .route("/traders/stats-batch", post(routes::trader_stats_batch))
         .route("/trader/{address}/trades", get(routes::trader_trades))
         .route("/trader/{address}/positions", get(routes::trader_positions))
         .route("/trader/{address}/pnl-chart", get(routes::pnl_chart))
         .route("/markets/hot", get(routes::hot_markets))
+        .route("/markets/{asset_id}/book", get(routes::market_book))
+        .route("/markets/resolved-status", post(routes::resolved_status))
         .route("/trades/recent", get(routes::recent_trades))
         .route("/market/resolve", get(routes::resolve_market))
         .route("/smart-money", get(routes::smart_money))
         .route("/trader/{address}/profile", get(routes::trader_profile))
+        .route(
+            "/trader/{address}/copy-score",
+            get(routes::trader_copy_score),
+        )
         .route("/lab/backtest", post(routes::backtest))
         .route("/lab/copy-portfolio", get(routes::copy_portfolio))
         // Trader Lists CRUD
@@ -297,6 +469,10 @@ pub async fn run(client: clickhouse::Client, port: u16) {
             "/lists/{id}/members",
             post(routes::add_list_members).delete(routes::remove_list_members),
         )
+        .route(
+            "/lists/{id}/add-from-leaderboard",
+            post(routes::add_list_members_from_leaderboard),
+        )
         // Trading Wallets (multi-wallet, up to 3 per user)
         .route("/wallets", get(wallet::get_wallets))
         .route("/wallets/generate", post(wallet::generate_wallet))
@@ -306,6 +482,10 @@ pub async fn run(client: clickhouse::Client, port: u16) {
             post(wallet::derive_credentials),
         )
         .route("/wallets/{id}/balance", get(wallet::get_balance))
+        .route(
+            "/wallets/{id}/refresh-balance",
+            post(wallet::refresh_balance),
+        )
         .route("/wallets/{id}/approve", post(wallet::approve_exchanges))
         .route(
             "/wallets/{id}/deposit-address",
@@ -315,7 +495,10 @@ pub async fn run(client: clickhouse::Client, port: u16) {
             "/wallets/{id}/deposit-status",
             get(wallet::get_deposit_status),
         )
-        .route("/wallets/{id}", delete(wallet::delete_wallet))
+        .route(
+            "/wallets/{id}",
+            patch(wallet::update_wallet).delete(wallet::delete_wallet),
+        )
         // Copy-Trade Engine
         .route(
             "/copytrade/sessions",
@@ -327,10 +510,39 @@ pub async fn run(client: clickhouse::Client, port: u16) {
                 .patch(copytrade::update_session)
                 .delete(copytrade::delete_session),
         )
+        .route(
+            "/copytrade/sessions/{id}/clone",
+            post(copytrade::clone_session),
+        )
+        .route("/copytrade/sessions/{id}/what-if", post(copytrade::what_if))
+        .route(
+            "/copytrade/templates",
+            get(copytrade::list_templates).post(copytrade::save_template),
+        )
+        .route(
+            "/copytrade/templates/{id}",
+            get(copytrade::get_template).delete(copytrade::delete_template),
+        )
+        .route(
+            "/copytrade/capacity-estimate",
+            post(copytrade::capacity_estimate),
+        )
+        .route(
+            "/copytrade/sessions/preflight",
+            post(copytrade::session_preflight),
+        )
         .route(
             "/copytrade/sessions/{id}/orders",
             get(copytrade::list_session_orders),
         )
+        .route(
+            "/copytrade/sessions/{id}/open-orders",
+            get(copytrade::list_open_orders),
+        )
+        .route(
+            "/copytrade/sessions/{id}/orders/{order_id}/cancel",
+            post(copytrade::cancel_order),
+        )
         .route(
             "/copytrade/sessions/{id}/stats",
             get(copytrade::get_session_stats),
@@ -339,12 +551,66 @@ pub async fn run(client: clickhouse::Client, port: u16) {
             "/copytrade/sessions/{id}/positions",
             get(copytrade::get_session_positions),
         )
+        .route(
+            "/copytrade/sessions/{id}/stale-positions",
+            get(copytrade::get_stale_positions),
+        )
+        .route(
+            "/copytrade/sessions/{id}/liquidation-preview",
+            get(copytrade::get_liquidation_preview),
+        )
+        .route(
+            "/copytrade/sessions/{id}/fidelity",
+            get(copytrade::get_session_fidelity),
+        )
+        .route(
+            "/copytrade/sessions/{id}/throughput",
+            get(copytrade::get_session_throughput),
+        )
+        .route(
+            "/copytrade/sessions/{id}/diagnosis",
+            get(copytrade::diagnose_session),
+        )
+        .route(
+            "/copytrade/sessions/{id}/tax-report",
+            get(copytrade::get_tax_report),
+        )
+        .route("/copytrade/compare", get(copytrade::compare_sessions))
+        .route("/copytrade/orders", get(copytrade::list_recent_orders))
+        .route("/copytrade/orders/{order_id}", get(copytrade::get_order))
         .route("/copytrade/summary", get(copytrade::get_summary))
         .route(
             "/copytrade/active-traders",
             get(copytrade::get_active_traders),
         )
-        .route("/copytrade/close-position", post(copytrade::close_position));
+        .route(
+            "/copytrade/tracked-addresses",
+            get(copytrade::get_tracked_addresses),
+        )
+        .route("/copytrade/close-position", post(copytrade::close_position))
+        .route("/copytrade/stop-all", post(copytrade::stop_all_sessions))
+        // Admin (ADMIN_ADDRESSES only — AdminUser extractor on each handler)
+        .route(
+            "/admin/copytrade/tracked-addresses",
+            get(copytrade::admin_tracked_addresses),
+        )
+        .route(
+            "/admin/copytrade/overview",
+            get(copytrade::admin_copytrade_overview),
+        )
+        .route(
+            "/admin/engine/metrics",
+            get(copytrade::admin_engine_metrics),
+        )
+        .route(
+            "/admin/copytrade/analytics/volume",
+            get(copytrade::admin_copytrade_volume),
+        )
+        .route(
+            "/admin/copytrade/analytics/markets",
+            get(copytrade::admin_copied_markets),
+        )
+        .route("/admin/copytrade/audit", get(copytrade::admin_order_audit));
 
     let app = Router::new()
         .nest("/api", public_api.merge(protected_api))
@@ -366,28 +632,56 @@ pub async fn run(client: clickhouse::Client, port: u16) {
     axum::serve(listener, app).await.expect("Server failed");
 }
 
+const BALANCE_POLL_INTERVAL: std::time::Duration = std::time::Duration::from_secs(30);
+/// Wallets polled at once (BALANCE_POLL_CONCURRENCY)
+const DEFAULT_BALANCE_POLL_CONCURRENCY: usize = 8;
+
+fn balance_poll_concurrency_from_env() -> usize {
+    match std::env::var("BALANCE_POLL_CONCURRENCY") {
+        Ok(v) => match v.trim().parse::<usize>() {
+            Ok(n) if n > 0 => n,
+            _ => {
+                tracing::warn!(
+                    "Invalid BALANCE_POLL_CONCURRENCY={v}, using default {DEFAULT_BALANCE_POLL_CONCURRENCY}"
+                );
+                DEFAULT_BALANCE_POLL_CONCURRENCY
+            }
+        },
+        Err(_) => DEFAULT_BALANCE_POLL_CONCURRENCY,
+    }
+}
+
 /// Background task: polls USDC.e balance + allowances for all trading wallets every 30s.
+///
+/// Each wallet's four reads go out as one Multicall3 `aggregate3` (falling back to
+/// individual calls if the batch itself fails), and up to BALANCE_POLL_CONCURRENCY
+/// wallets are polled at once. A failing wallet only loses its own update.
 async fn balance_poll_task(state: AppState) {
-    use alloy::primitives::Address;
-    use alloy::providers::Provider;
+    use futures_util::StreamExt;
 
     // Wait for eRPC and other services to be ready
     tokio::time::sleep(std::time::Duration::from_secs(5)).await;
 
-    let mut interval = tokio::time::interval(std::time::Duration::from_secs(30));
+    let concurrency = balance_poll_concurrency_from_env();
+    let mut interval = tokio::time::interval(BALANCE_POLL_INTERVAL);
     interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
 
     loop {
         interval.tick().await;
 
         // Collect all wallet addresses + IDs from SQLite
-        // proxy_address holds USDC.e + allowances; wallet_address (EOA) holds POL for gas
+        // The funds address (proxy, or the EOA in eoa mode) holds USDC.e;
+        // wallet_address (EOA) holds allowances + POL for gas
         let wallets = {
             let state = state.clone();
             match tokio::task::spawn_blocking(move || {
                 let conn = state.user_db.lock().expect("user_db lock");
                 let mut stmt = conn
-                    .prepare("SELECT id, wallet_address, proxy_address FROM trading_wallets")
+                    .prepare(
+                        "SELECT id, wallet_address,
+                                CASE WHEN signature_type = 'eoa' THEN NULL ELSE proxy_address END
+                         FROM trading_wallets",
+                    )
                     .ok()?;
                 let rows: Vec<(String, String, Option<String>)> = stmt
                     .query_map([], |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)))
@@ -403,71 +697,140 @@ async fn balance_poll_task(state: AppState) {
             }
         };
 
+        let started = std::time::Instant::now();
+        let wallet_count = wallets.len();
         let provider = contracts::create_provider(&state.erpc_url);
-        let usdc = contracts::IERC20::new(contracts::USDC_ADDRESS, &provider);
-
-        for (wallet_id, eoa_str, proxy_str) in &wallets {
-            let eoa = match eoa_str.parse::<Address>() {
-                Ok(a) => a,
-                Err(_) => continue,
-            };
-            // USDC.e balance lives on the proxy; allowances + POL on the EOA
-            let proxy = proxy_str
-                .as_deref()
-                .and_then(|s| s.parse::<Address>().ok())
-                .unwrap_or(eoa);
-
-            let bal_call = usdc.balanceOf(proxy);
+        let polled: Vec<(String, WalletBalanceState)> = futures_util::stream::iter(wallets)
+            .map(|(wallet_id, eoa_str, proxy_str)| {
+                let provider = &provider;
+                async move {
+                    let entry =
+                        poll_wallet_balance(provider, &eoa_str, proxy_str.as_deref()).await?;
+                    Some((wallet_id, entry))
+                }
+            })
+            .buffer_unordered(concurrency)
+            .filter_map(std::future::ready)
+            .collect()
+            .await;
+
+        let updated = polled.len();
+        {
+            let mut balances = state.wallet_balances.write().await;
+            for (wallet_id, entry) in polled {
+                balances.insert(wallet_id, entry);
+            }
+        }
+
+        let elapsed = started.elapsed();
+        if elapsed > BALANCE_POLL_INTERVAL {
+            tracing::warn!(
+                "Balance poll took {:.1}s for {wallet_count} wallets (interval {}s); consider raising BALANCE_POLL_CONCURRENCY",
+                elapsed.as_secs_f64(),
+                BALANCE_POLL_INTERVAL.as_secs()
+            );
+        } else {
+            tracing::debug!(
+                "Balance poll: {updated}/{wallet_count} wallets in {}ms",
+                elapsed.as_millis()
+            );
+        }
+    }
+}
+
+/// One on-chain read (balance, allowance or gas) with its error stringified
+type BalanceRead = Result<alloy::primitives::U256, String>;
+
+/// Reads one wallet's USDC.e balance, both exchange allowances and POL. None when the
+/// address is invalid or the balance read failed (the previous state is kept).
+async fn poll_wallet_balance<P: alloy::providers::Provider>(
+    provider: &P,
+    eoa_str: &str,
+    proxy_str: Option<&str>,
+) -> Option<WalletBalanceState> {
+    use alloy::primitives::{Address, U256};
+
+    let eoa = eoa_str.parse::<Address>().ok()?;
+    // USDC.e balance lives on the proxy (EOA if none); allowances + POL on the EOA
+    let proxy = proxy_str
+        .and_then(|s| s.parse::<Address>().ok())
+        .unwrap_or(eoa);
+    let usdc = contracts::IERC20::new(contracts::USDC_ADDRESS, provider);
+
+    let batched = provider
+        .multicall()
+        .add(usdc.balanceOf(proxy))
+        .add(usdc.allowance(eoa, contracts::CTF_EXCHANGE))
+        .add(usdc.allowance(eoa, contracts::NEG_RISK_EXCHANGE))
+        .get_eth_balance(eoa)
+        .aggregate3()
+        .await;
+    let (balance_res, ctf_allow_res, neg_allow_res, pol_gas_res): (
+        BalanceRead,
+        BalanceRead,
+        BalanceRead,
+        BalanceRead,
+    ) = match batched {
+        Ok((balance, ctf, neg, pol)) => (
+            balance.map_err(|f| format!("{f:?}")),
+            ctf.map_err(|f| format!("{f:?}")),
+            neg.map_err(|f| format!("{f:?}")),
+            pol.map_err(|f| format!("{f:?}")),
+        ),
+        Err(e) => {
+            tracing::debug!("Balance multicall failed for {eoa_str}, reading individually: {e}");
+            let balance_call = usdc.balanceOf(proxy);
             let ctf_call = usdc.allowance(eoa, contracts::CTF_EXCHANGE);
             let neg_call = usdc.allowance(eoa, contracts::NEG_RISK_EXCHANGE);
-            let (balance_res, ctf_allow_res, neg_allow_res, pol_gas_res) = tokio::join!(
-                bal_call.call(),
+            let pol_call = provider.get_balance(eoa);
+            let (balance, ctf, neg, pol) = tokio::join!(
+                balance_call.call(),
                 ctf_call.call(),
                 neg_call.call(),
-                provider.get_balance(eoa),
+                pol_call.into_future(),
             );
+            (
+                balance.map_err(|e| e.to_string()),
+                ctf.map_err(|e| e.to_string()),
+                neg.map_err(|e| e.to_string()),
+                pol.map_err(|e| e.to_string()),
+            )
+        }
+    };
 
-            let usdc_raw = match balance_res {
-                Ok(raw) => raw,
-                Err(e) => {
-                    tracing::error!("Balance poll failed for {eoa_str}: {e}");
-                    continue;
-                }
-            };
-            let ctf_allowance = ctf_allow_res
-                .inspect_err(|e| {
-                    tracing::error!("CTF allowance poll failed for {eoa_str}: {e}");
-                })
-                .unwrap_or_default();
-            let neg_allowance = neg_allow_res
-                .inspect_err(|e| {
-                    tracing::error!("NegRisk allowance poll failed for {eoa_str}: {e}");
-                })
-                .unwrap_or_default();
-            let pol_wei = pol_gas_res.unwrap_or_default();
-
-            if usdc_raw > alloy::primitives::U256::ZERO && usdc_raw < contracts::LOW_BALANCE_RAW {
-                tracing::warn!(
-                    "Low USDC.e balance for wallet {eoa_str}: {}",
-                    contracts::format_usdc(usdc_raw)
-                );
-            }
-
-            let entry = WalletBalanceState {
-                usdc_balance: contracts::format_usdc(usdc_raw),
-                usdc_raw: usdc_raw.to_string(),
-                pol_balance: contracts::format_pol(pol_wei),
-                pol_raw: pol_wei.to_string(),
-                ctf_approved: !ctf_allowance.is_zero(),
-                neg_risk_approved: !neg_allowance.is_zero(),
-                last_checked: std::time::Instant::now(),
-            };
-
-            state
-                .wallet_balances
-                .write()
-                .await
-                .insert(wallet_id.clone(), entry);
+    let usdc_raw = match balance_res {
+        Ok(raw) => raw,
+        Err(e) => {
+            tracing::error!("Balance poll failed for {eoa_str}: {e}");
+            return None;
         }
+    };
+    let ctf_allowance = ctf_allow_res
+        .inspect_err(|e| {
+            tracing::error!("CTF allowance poll failed for {eoa_str}: {e}");
+        })
+        .unwrap_or_default();
+    let neg_allowance = neg_allow_res
+        .inspect_err(|e| {
+            tracing::error!("NegRisk allowance poll failed for {eoa_str}: {e}");
+        })
+        .unwrap_or_default();
+    let pol_wei = pol_gas_res.unwrap_or_default();
+
+    if usdc_raw > U256::ZERO && usdc_raw < contracts::LOW_BALANCE_RAW {
+        tracing::warn!(
+            "Low USDC.e balance for wallet {eoa_str}: {}",
+            contracts::format_usdc(usdc_raw)
+        );
     }
+
+    Some(WalletBalanceState {
+        usdc_balance: contracts::format_usdc(usdc_raw),
+        usdc_raw: usdc_raw.to_string(),
+        pol_balance: contracts::format_pol(pol_wei),
+        pol_raw: pol_wei.to_string(),
+        ctf_approved: !ctf_allowance.is_zero(),
+        neg_risk_approved: !neg_allowance.is_zero(),
+        last_checked: std::time::Instant::now(),
+    })
 }
@@ -23,6 +23,11 @@ pub struct TraderSummary {
     pub last_trade: String,
 }
 
+#[derive(Deserialize)]
+pub struct TraderStatsBatchRequest {
+    pub addresses: Vec<String>,
+}
+
 #[derive(Serialize)]
 pub struct TradesResponse {
     pub trades: Vec<TradeRecord>,
@@ -113,6 +118,40 @@ pub struct HotMarketsParams {
     pub limit: Option<u32>,
 }
 
+// -- Order Book --
+
+#[derive(Deserialize)]
+pub struct OrderBookParams {
+    /// Depth is summed over levels within this many cents of the mid (default 2)
+    pub depth_cents: Option<u32>,
+}
+
+#[derive(Serialize, Clone, Copy)]
+pub struct BookLevel {
+    pub price: f64,
+    pub size: f64,
+}
+
+/// CLOB L2 book for one outcome token, best levels first
+#[derive(Serialize)]
+pub struct OrderBookResponse {
+    pub asset_id: String,
+    pub bids: Vec<BookLevel>,
+    pub asks: Vec<BookLevel>,
+    #[serde(serialize_with = "super::precision::price_opt")]
+    pub mid: Option<f64>,
+    #[serde(serialize_with = "super::precision::price_opt")]
+    pub spread: Option<f64>,
+    pub depth_cents: u32,
+    /// Shares and USDC resting within `depth_cents` of the mid
+    pub bid_depth_shares: f64,
+    #[serde(serialize_with = "super::precision::usdc")]
+    pub bid_depth_usdc: f64,
+    pub ask_depth_shares: f64,
+    #[serde(serialize_with = "super::precision::usdc")]
+    pub ask_depth_usdc: f64,
+}
+
 // -- Live Feed --
 
 #[derive(Row, Deserialize)]
@@ -180,6 +219,12 @@ pub struct OpenPosition {
     pub pnl: String,
     pub volume: String,
     pub trade_count: u64,
+    /// Live CLOB midpoint (open positions only, when the book has a price)
+    pub live_price: Option<f64>,
+    /// net_tokens × live_price
+    pub current_value: Option<f64>,
+    /// `pnl` re-marked at live_price instead of the last traded price
+    pub live_pnl: Option<f64>,
 }
 
 #[derive(Serialize)]
@@ -221,6 +266,29 @@ pub struct ResolvedPriceLookup {
     pub resolved_price: String,
 }
 
+/// resolved_prices row for POST /api/markets/resolved-status
+#[derive(Row, Deserialize)]
+pub struct ResolvedStatusRow {
+    pub asset_id: String,
+    pub resolved_price: String,
+    pub condition_id: String,
+}
+
+#[derive(Deserialize)]
+pub struct ResolvedStatusRequest {
+    pub asset_ids: Vec<String>,
+}
+
+/// One asset's on-chain resolution (POST /api/markets/resolved-status)
+#[derive(Serialize)]
+pub struct AssetResolution {
+    pub asset_id: String,
+    pub resolved: bool,
+    /// Payout per share once resolved: 1 for the winning outcome, 0 otherwise
+    pub resolved_price: Option<f64>,
+    pub condition_id: Option<String>,
+}
+
 #[derive(Serialize)]
 pub struct PnlChartPoint {
     pub date: String,
@@ -344,6 +412,52 @@ pub struct TraderProfile {
     pub label_details: LabelDetails,
 }
 
+#[derive(Row, Deserialize)]
+pub struct CopyScoreRow {
+    pub recent_pnl: f64,
+    pub wins: u64,
+    pub decided: u64,
+    pub total_volume: f64,
+    pub markets_traded: u64,
+    pub secs_since_last_trade: i64,
+}
+
+/// Relative weight of each copy-score component (COPY_SCORE_WEIGHTS).
+#[derive(Clone, Copy, Serialize)]
+pub struct CopyScoreWeights {
+    pub pnl: f64,
+    pub win_rate: f64,
+    pub volume: f64,
+    pub diversity: f64,
+    pub recency: f64,
+}
+
+/// Each component normalised to 0-100 before weighting.
+#[derive(Clone, Serialize)]
+pub struct CopyScoreComponents {
+    pub pnl: f64,
+    pub win_rate: f64,
+    pub volume: f64,
+    pub diversity: f64,
+    pub recency: f64,
+}
+
+#[derive(Clone, Serialize)]
+pub struct TraderCopyScore {
+    pub address: String,
+    /// Weighted 0-100 score
+    pub score: f64,
+    pub components: CopyScoreComponents,
+    pub weights: CopyScoreWeights,
+    pub window_days: u32,
+    pub recent_pnl: f64,
+    /// Winning share of positions with non-zero P&L, 0-1
+    pub win_rate: f64,
+    pub total_volume: f64,
+    pub markets_traded: u64,
+    pub days_since_last_trade: f64,
+}
+
 #[derive(Clone, Serialize)]
 #[serde(rename_all = "snake_case")]
 pub enum BehavioralLabel {
@@ -440,6 +554,8 @@ pub struct TraderListMember {
     pub address: String,
     pub label: Option<String>,
     pub added_at: String,
+    /// Relative share of a session's per-trader budget (default 1.0)
+    pub weight: f64,
 }
 
 #[derive(Deserialize)]
@@ -456,6 +572,8 @@ pub struct RenameListRequest {
 pub struct AddMembersRequest {
     pub addresses: Vec<String>,
     pub labels: Option<Vec<Option<String>>>,
+    /// Copy weight per address, parallel to `addresses` (missing or null = 1.0)
+    pub weights: Option<Vec<Option<f64>>>,
 }
 
 #[derive(Deserialize)]
@@ -463,6 +581,14 @@ pub struct RemoveMembersRequest {
     pub addresses: Vec<String>,
 }
 
+#[derive(Serialize)]
+pub struct AddFromLeaderboardResponse {
+    pub added: usize,
+    /// Already members, or dropped because the list hit its member limit
+    pub skipped: usize,
+    pub addresses: Vec<String>,
+}
+
 // -- PolyLab Backtest --
 
 #[derive(Deserialize)]
@@ -606,11 +732,55 @@ pub struct TradingWalletInfo {
     pub id: String,
     pub address: String,
     pub proxy_address: Option<String>,
+    pub signature_type: WalletSignatureType,
     pub status: String,
     pub has_clob_credentials: bool,
     pub created_at: String,
 }
 
+/// Polymarket account model a wallet trades under. `Proxy` signs orders on behalf
+/// of the CREATE2 proxy wallet (which holds the USDC.e); `Eoa` trades directly
+/// from the key's own address.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum WalletSignatureType {
+    Proxy,
+    Eoa,
+}
+
+impl WalletSignatureType {
+    pub fn from_str(s: &str) -> Option<Self> {
+        match s {
+            "proxy" => Some(Self::Proxy),
+            "eoa" => Some(Self::Eoa),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Proxy => "proxy",
+            Self::Eoa => "eoa",
+        }
+    }
+}
+
+impl Serialize for WalletSignatureType {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[derive(Deserialize)]
+pub struct GenerateWalletRequest {
+    /// "proxy" (default) or "eoa"
+    pub signature_type: Option<String>,
+}
+
+#[derive(Deserialize)]
+pub struct UpdateWalletRequest {
+    pub signature_type: String,
+}
+
 #[derive(Serialize)]
 pub struct WalletGenerateResponse {
     pub id: String,
@@ -622,6 +792,8 @@ pub struct WalletGenerateResponse {
 #[derive(Deserialize)]
 pub struct ImportWalletRequest {
     pub private_key: String,
+    /// "proxy" (default) or "eoa"
+    pub signature_type: Option<String>,
 }
 
 #[derive(Serialize)]
@@ -702,7 +874,10 @@ pub struct MarketMetadataRow {
 #[derive(Deserialize)]
 pub struct CreateSessionRequest {
     pub list_id: Option<String>,
+    /// Top N traders by P&L; combined with `list_id`, the list's top N members
     pub top_n: Option<u32>,
+    /// Follow the top X% (0-100] of traders by P&L instead of a fixed count
+    pub top_pct: Option<f64>,
     pub copy_pct: f64,
     #[serde(default = "default_max_position")]
     pub max_position_usdc: f64,
@@ -714,6 +889,122 @@ pub struct CreateSessionRequest {
     #[serde(default)]
     pub simulate: bool,
     pub max_loss_pct: Option<f64>,
+    /// Skip any order whose price moved against us (slippage > 0), regardless of max_slippage_bps
+    #[serde(default)]
+    pub only_favorable: bool,
+    /// Per-side overrides of `order_type` (e.g. GTC entries, FOK exits)
+    pub buy_order_type: Option<String>,
+    pub sell_order_type: Option<String>,
+    /// Price used to mark open positions in the max_loss_pct breaker: "last_fill" or "live"
+    #[serde(default = "default_mark_price_source")]
+    pub mark_price_source: String,
+    /// Skip buys from traders whose recent P&L (USDC) falls below this
+    pub min_trader_pnl: Option<f64>,
+    /// Skip buys from traders whose recent win rate (0-100) falls below this
+    pub min_trader_winrate: Option<f64>,
+    /// Auto-pause after this many consecutive slippage rejections (None = warn only)
+    pub slippage_pause_after: Option<u32>,
+    /// Skip buys into assets whose recent on-chain volume (USDC) is below this
+    pub min_liquidity_usdc: Option<f64>,
+    /// Lookback in minutes for min_liquidity_usdc (default 60)
+    pub liquidity_window_mins: Option<u32>,
+    /// Sell positions opened only by traders later removed from the session's list
+    #[serde(default)]
+    pub close_on_trader_removal: bool,
+    /// Cap on combined exposure (USDC) across all outcome tokens of one market
+    pub max_market_usdc: Option<f64>,
+    /// On start, buy into the traders' current open positions before copying new trades
+    #[serde(default)]
+    pub seed_existing_positions: bool,
+    /// How buys are sized from the source trade: "dollar" or "portfolio"
+    #[serde(default = "default_sizing_mode")]
+    pub sizing_mode: String,
+    /// HTTPS endpoint that receives the session's periodic summary digest
+    pub webhook_url: Option<String>,
+    /// Hours between digests; requires `webhook_url` (None = no digest)
+    pub digest_interval_hours: Option<u32>,
+    /// Capital the per-trader buy budget is computed from: "remaining" or "initial"
+    #[serde(default = "default_sizing_base")]
+    pub sizing_base: String,
+    /// Auto-stop the session this many seconds after it was created
+    pub max_runtime_secs: Option<u32>,
+    /// Copy only trades in these token ids (None = every market)
+    pub asset_allowlist: Option<Vec<String>>,
+    /// Skip buys priced at or beyond this (or 1 - this), or in closed markets.
+    /// E.g. 0.98 refuses entries above 0.98 and below 0.02. None disables the guard.
+    pub near_resolution_price: Option<f64>,
+    /// GTC limit price reference: "source" (the copied fill) or "current" (live midpoint)
+    #[serde(default = "default_gtc_price_ref")]
+    pub gtc_price_ref: String,
+    /// Bps added to the GTC reference price in the order's favour-of-filling
+    /// direction (above for buys, below for sells); negative rests further back
+    #[serde(default)]
+    pub gtc_price_offset_bps: i32,
+    /// How source sells are copied: "proportional" or "full_exit"
+    #[serde(default = "default_sell_behavior")]
+    pub sell_behavior: String,
+    /// Trailing stop: arm once a position's peak is this % above its average entry.
+    /// Set together with trail_distance_pct; None disables the trailing stop.
+    pub trail_activate_pct: Option<f64>,
+    /// Trailing stop: sell the whole position once price falls this % below the peak
+    pub trail_distance_pct: Option<f64>,
+    /// With mark_price_source "last_fill": re-mark positions whose last fill is older
+    /// than this at the live midpoint in the max_loss_pct breaker. Reacts faster to a
+    /// crashing position at the cost of CLOB calls in the health check. None keeps last fills.
+    pub mark_stale_secs: Option<u32>,
+    /// Where trades to copy come from: "traders" (list_id / top_n / top_pct) or
+    /// "whale_follow" (every whale alert at or above `min_whale_usdc`)
+    #[serde(default = "default_signal_source")]
+    pub signal_source: String,
+    pub min_whale_usdc: Option<f64>,
+    /// Expected trading fee folded into the slippage gate, so max_slippage_bps bounds the
+    /// all-in cost. E.g. source buy at 0.50, ask 0.502, fee 100bps: fee-exclusive
+    /// slippage is 40bps, fee-inclusive prices at 0.502 × 1.01 = 0.50702, i.e. 140bps.
+    /// None (default) compares raw prices.
+    pub slippage_fee_bps: Option<u32>,
+    /// Skip source trades whose block is older than this when the engine gets to them
+    /// (backlog after a lag or a resume). None uses the engine default (300s).
+    pub max_signal_age_secs: Option<u32>,
+    /// How top_n / top_pct rank traders: "pnl", "roi", "volume" or "consistency"
+    #[serde(default = "default_top_n_metric")]
+    pub top_n_metric: String,
+    /// Hold each position at least this long before a copied sell or trailing stop may
+    /// exit it (deferred sells are skipped as min_hold). Manual closes ignore it. 0 = off.
+    #[serde(default)]
+    pub min_hold_secs: u32,
+    /// Live sessions only: send any missing exchange approvals from the session's
+    /// wallet before it starts. Not stored with the session.
+    #[serde(default)]
+    pub auto_approve: bool,
+    /// Per-asset max_slippage_bps, keyed by token id: tighter bounds for liquid
+    /// markets, looser for thin ones. Unlisted assets use max_slippage_bps.
+    pub slippage_overrides: Option<std::collections::BTreeMap<String, u32>>,
+    /// Orders this session may place per minute (default 10, at most 60). All
+    /// sessions together stay under the engine-wide ceiling as well.
+    pub max_orders_per_minute: Option<u32>,
+    /// How live order sizes are rounded to the CLOB's precision: "down" or "nearest"
+    #[serde(default = "default_size_rounding")]
+    pub size_rounding: String,
+    /// Sell a whole position once its mark is this % above the average entry
+    pub take_profit_pct: Option<f64>,
+    /// Sell a whole position once its mark is this % below the average entry
+    pub stop_loss_pct: Option<f64>,
+    /// "copy" mirrors the source side, "fade" takes the opposite one
+    #[serde(default = "default_copy_mode")]
+    pub mode: String,
+    /// Comma-separated market categories to copy exclusively (case-insensitive)
+    pub allowed_categories: Option<String>,
+    /// Comma-separated market categories never to copy; wins over the allowlist
+    pub blocked_categories: Option<String>,
+    /// Whether trades whose market category is unknown pass the category filters
+    #[serde(default = "default_true")]
+    pub copy_uncategorized: bool,
+    /// Which source sides to copy: "both", "buy" (entries only) or "sell" (exits only)
+    #[serde(default = "default_copy_sides")]
+    pub copy_sides: String,
+    /// When a FOK order finds no fill, rest a GTC limit at the source price instead
+    #[serde(default)]
+    pub fok_fallback_gtc: bool,
 }
 
 fn default_max_position() -> f64 {
@@ -725,10 +1016,187 @@ fn default_max_slippage() -> u32 {
 fn default_order_type() -> String {
     "FOK".to_string()
 }
+fn default_mark_price_source() -> String {
+    "last_fill".to_string()
+}
+fn default_sizing_mode() -> String {
+    "dollar".to_string()
+}
+fn default_sizing_base() -> String {
+    "remaining".to_string()
+}
+fn default_gtc_price_ref() -> String {
+    "source".to_string()
+}
+fn default_sell_behavior() -> String {
+    "proportional".to_string()
+}
+fn default_size_rounding() -> String {
+    "down".to_string()
+}
+fn default_true() -> bool {
+    true
+}
+fn default_copy_sides() -> String {
+    "both".to_string()
+}
+fn default_copy_mode() -> String {
+    "copy".to_string()
+}
+fn default_signal_source() -> String {
+    "traders".to_string()
+}
+fn default_top_n_metric() -> String {
+    "pnl".to_string()
+}
+
+#[derive(Deserialize)]
+pub struct SaveTemplateRequest {
+    pub name: String,
+    /// `CreateSessionRequest` fields; `initial_capital` is dropped
+    pub config: serde_json::Value,
+}
+
+/// Named, owner-scoped session config; `template_id` on create pre-fills from it
+#[derive(Serialize)]
+pub struct SessionTemplate {
+    pub id: String,
+    pub name: String,
+    pub config: serde_json::Value,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+#[derive(Deserialize)]
+pub struct CloneSessionRequest {
+    pub initial_capital: f64,
+    /// Defaults to the source session's mode
+    pub simulate: Option<bool>,
+}
+
+#[derive(Deserialize)]
+pub struct WhatIfRequest {
+    pub asset_id: String,
+    /// "buy" or "sell"
+    pub side: String,
+    pub source_price: f64,
+    pub source_usdc: f64,
+    /// Source trader, needed to size buys in portfolio sizing mode
+    pub source_trader: Option<String>,
+    /// Source trade's block time (unix seconds), checked against max_signal_age_secs
+    pub source_timestamp: Option<i64>,
+}
+
+#[derive(Deserialize)]
+pub struct CapacityEstimateRequest {
+    pub list_id: String,
+    pub copy_pct: f64,
+    #[serde(default = "default_max_position")]
+    pub max_position_usdc: f64,
+    pub min_liquidity_usdc: Option<f64>,
+    #[serde(default = "default_sizing_mode")]
+    pub sizing_mode: String,
+    /// History window in days (default 30, max 90)
+    pub days: Option<u32>,
+    /// Capital levels to evaluate (USDC); defaults to a log-spaced grid
+    pub capital_levels: Option<Vec<f64>>,
+}
+
+/// How much of a capital level the sizing formula would actually deploy, replayed
+/// over the list's historical buys
+#[derive(Serialize)]
+pub struct CapacityPoint {
+    pub capital: f64,
+    /// Sized orders / capital-proportional budget, summed over sampled trades (0-1)
+    pub deployment_fraction: f64,
+    pub avg_order_usdc: f64,
+    /// Share of sampled trades sized below their budget (trade size, max_position_usdc
+    /// or the order minimum bound instead of capital)
+    pub capped_pct: f64,
+}
+
+/// What still blocks a planned session from running; `issues` is empty when ready
+#[derive(Serialize)]
+pub struct SessionPreflight {
+    pub ready: bool,
+    pub simulate: bool,
+    /// Validation error create would reject the config with
+    pub config_error: Option<String>,
+    /// Wallet the engine would trade from (live sessions only)
+    pub wallet_id: Option<String>,
+    pub wallet_address: Option<String>,
+    pub credentialed: bool,
+    pub ctf_exchange_approved: Option<bool>,
+    pub neg_risk_exchange_approved: Option<bool>,
+    pub usdc_balance: Option<f64>,
+    pub required_usdc: f64,
+    /// USDC still to deposit to cover `initial_capital` (0 when covered)
+    pub usdc_shortfall: Option<f64>,
+    pub pol_balance: Option<String>,
+    /// Approvals are pending and the wallet lacks POL to pay their gas
+    pub needs_gas: bool,
+    pub balance_checked_secs_ago: Option<u64>,
+    /// Human-readable blockers, in the order to resolve them
+    pub issues: Vec<String>,
+}
+
+#[derive(Serialize)]
+pub struct CapacityEstimate {
+    pub trader_count: usize,
+    pub days: u32,
+    /// Historical buys replayed at each capital level
+    pub sampled_trades: usize,
+    /// Buys excluded because their asset traded below min_liquidity_usdc per hour
+    pub liquidity_skipped: usize,
+    /// Lowest evaluated capital at which less than half the budget gets deployed
+    pub saturation_capital: Option<f64>,
+    pub curve: Vec<CapacityPoint>,
+}
+
+/// Dry run of the engine's sizing and slippage steps for a hypothetical source trade
+#[derive(Serialize)]
+pub struct WhatIfResponse {
+    /// "copy" or "skip"
+    pub decision: String,
+    /// Side the session would trade: the source side, flipped in fade mode
+    pub side: String,
+    pub skip_reason: Option<String>,
+    pub order_usdc: Option<f64>,
+    pub current_price: Option<f64>,
+    pub slippage_bps: Option<f64>,
+    pub remaining_capital: f64,
+    pub held_shares: f64,
+    pub trader_count: usize,
+    /// Share of the copy_pct budget the order was sized with
+    pub trader_weight: f64,
+    pub market_exposure: Option<f64>,
+    /// Source trader's book (portfolio sizing); None sizes by the dollar amount
+    pub source_portfolio: Option<f64>,
+}
+
+#[derive(Serialize)]
+pub struct StopAllResult {
+    pub session_id: String,
+    /// "running" or "paused"
+    pub previous_status: String,
+    pub stopped: bool,
+    pub error: Option<String>,
+}
+
+#[derive(Serialize)]
+pub struct StopAllResponse {
+    pub stopped: usize,
+    pub results: Vec<StopAllResult>,
+}
 
 #[derive(Deserialize)]
 pub struct SessionPatchRequest {
-    pub action: String,
+    /// pause, resume or stop
+    pub action: Option<String>,
+    /// Category filters; each one given replaces the stored value ("" clears a list)
+    pub allowed_categories: Option<String>,
+    pub blocked_categories: Option<String>,
+    pub copy_uncategorized: Option<bool>,
 }
 
 #[derive(Deserialize)]
@@ -743,9 +1211,19 @@ pub struct SessionOrdersParams {
     pub offset: Option<u32>,
 }
 
+#[derive(Deserialize)]
+pub struct RecentOrdersParams {
+    pub limit: Option<u32>,
+    /// Opaque `next_cursor` from the previous page
+    pub cursor: Option<String>,
+}
+
+/// `FOK` fills the whole order or nothing; `FAK` (fill-and-kill) takes whatever
+/// liquidity is there and cancels the rest; `GTC` rests on the book.
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum CopyOrderType {
     FOK,
+    FAK,
     GTC,
 }
 
@@ -753,6 +1231,7 @@ impl CopyOrderType {
     pub fn from_str(s: &str) -> Option<Self> {
         match s.to_uppercase().as_str() {
             "FOK" => Some(Self::FOK),
+            "FAK" => Some(Self::FAK),
             "GTC" => Some(Self::GTC),
             _ => None,
         }
@@ -761,6 +1240,7 @@ impl CopyOrderType {
     pub fn as_str(&self) -> &'static str {
         match self {
             Self::FOK => "FOK",
+            Self::FAK => "FAK",
             Self::GTC => "GTC",
         }
     }
@@ -772,148 +1252,675 @@ impl Serialize for CopyOrderType {
     }
 }
 
+/// How the circuit breaker values open positions.
+/// `LastFill` is free but stale for positions that haven't re-traded (`mark_stale_secs`
+/// re-marks just the stale ones live); `Live` fetches public CLOB midpoints every health
+/// cycle (one HTTP call per side per asset), trading latency and API load for a true
+/// mark-to-market.
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
-pub enum SessionStatus {
-    Running,
-    Paused,
-    Stopped,
+pub enum MarkPriceSource {
+    LastFill,
+    Live,
 }
 
-impl SessionStatus {
+impl MarkPriceSource {
     pub fn from_str(s: &str) -> Option<Self> {
         match s {
-            "running" => Some(Self::Running),
-            "paused" => Some(Self::Paused),
-            "stopped" => Some(Self::Stopped),
+            "last_fill" => Some(Self::LastFill),
+            "live" => Some(Self::Live),
             _ => None,
         }
     }
 
     pub fn as_str(&self) -> &'static str {
         match self {
-            Self::Running => "running",
-            Self::Paused => "paused",
-            Self::Stopped => "stopped",
+            Self::LastFill => "last_fill",
+            Self::Live => "live",
         }
     }
 }
 
-impl Serialize for SessionStatus {
+impl Serialize for MarkPriceSource {
     fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
         serializer.serialize_str(self.as_str())
     }
 }
 
+/// How a buy is sized from the source trade.
+///
+/// `Dollar` scales the source's USDC amount by `copy_pct`. `Portfolio` sizes by the
+/// share of the source trader's book the trade represents: the trader's open
+/// positions marked at the latest price (ClickHouse `trader_positions` ×
+/// `asset_latest_price`), cached per trader for a few minutes. That fraction is
+/// applied to our `initial_capital` (times `copy_pct`). Sells always mirror the
+/// source's share count.
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
-pub enum OrderStatus {
-    Pending,
-    Submitted,
-    Filled,
-    Partial,
-    Failed,
-    Canceled,
-    Simulated,
+pub enum SizingMode {
+    Dollar,
+    Portfolio,
 }
 
-impl OrderStatus {
+impl SizingMode {
     pub fn from_str(s: &str) -> Option<Self> {
         match s {
-            "pending" => Some(Self::Pending),
-            "submitted" => Some(Self::Submitted),
-            "filled" => Some(Self::Filled),
-            "partial" => Some(Self::Partial),
-            "failed" => Some(Self::Failed),
-            "canceled" => Some(Self::Canceled),
-            "simulated" => Some(Self::Simulated),
+            "dollar" => Some(Self::Dollar),
+            "portfolio" => Some(Self::Portfolio),
             _ => None,
         }
     }
 
     pub fn as_str(&self) -> &'static str {
         match self {
-            Self::Pending => "pending",
-            Self::Submitted => "submitted",
-            Self::Filled => "filled",
-            Self::Partial => "partial",
-            Self::Failed => "failed",
-            Self::Canceled => "canceled",
-            Self::Simulated => "simulated",
+            Self::Dollar => "dollar",
+            Self::Portfolio => "portfolio",
         }
     }
 }
 
-impl Serialize for OrderStatus {
+impl Serialize for SizingMode {
     fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
         serializer.serialize_str(self.as_str())
     }
 }
 
-#[derive(Serialize)]
-pub struct CopyTradeSession {
-    pub id: String,
-    pub list_id: Option<String>,
-    pub top_n: Option<u32>,
-    pub copy_pct: f64,
-    pub max_position_usdc: f64,
-    pub max_slippage_bps: u32,
-    pub order_type: CopyOrderType,
-    pub initial_capital: f64,
-    pub remaining_capital: f64,
-    /// Estimated value of open positions (shares × avg entry price)
-    pub positions_value: f64,
-    pub simulate: bool,
-    pub max_loss_pct: Option<f64>,
-    pub status: SessionStatus,
-    pub created_at: String,
-    pub updated_at: String,
+/// Capital the per-trader buy budget (`capital × copy_pct / traders`) is based on.
+///
+/// `remaining` compounds: realized gains grow `remaining_capital` and with it every
+/// later buy, losses shrink them. `initial` keeps the budget fixed at
+/// `initial_capital`. Example with $1,000, copy_pct 0.5 and 5 traders: the budget
+/// starts at $100 either way; after the book grows to $1,500 it is $150 under
+/// `remaining` and stays $100 under `initial`. Buys can never spend more than
+/// `remaining_capital` in either mode.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SizingBase {
+    Remaining,
+    Initial,
 }
 
-#[derive(Serialize)]
-pub struct CopyTradeOrder {
-    pub id: String,
-    pub session_id: String,
-    pub source_tx_hash: String,
-    pub source_trader: String,
-    pub clob_order_id: Option<String>,
-    pub asset_id: String,
-    pub side: String,
-    pub price: f64,
-    pub source_price: f64,
-    pub size_usdc: f64,
-    pub size_shares: Option<f64>,
-    pub status: OrderStatus,
-    pub error_message: Option<String>,
-    pub fill_price: Option<f64>,
-    pub slippage_bps: Option<f64>,
-    pub tx_hash: Option<String>,
-    pub created_at: String,
-    pub updated_at: String,
+impl SizingBase {
+    pub fn from_str(s: &str) -> Option<Self> {
+        match s {
+            "remaining" => Some(Self::Remaining),
+            "initial" => Some(Self::Initial),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Remaining => "remaining",
+            Self::Initial => "initial",
+        }
+    }
 }
 
-#[derive(Clone, Serialize)]
-pub struct CopyTradeOrderSummary {
-    pub id: String,
-    pub asset_id: String,
-    pub side: String,
-    pub size_usdc: f64,
-    pub price: f64,
-    pub source_trader: String,
-    pub simulate: bool,
+impl Serialize for SizingBase {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
 }
 
-#[derive(Clone, Serialize)]
-#[serde(tag = "kind")]
-pub enum CopyTradeUpdate {
-    OrderPlaced {
-        session_id: String,
-        order: CopyTradeOrderSummary,
-        #[serde(skip)]
-        owner: String,
-    },
-    OrderFilled {
-        session_id: String,
-        order_id: String,
-        fill_price: f64,
+/// Price a GTC copy order's limit is derived from. `Source` rests at the copied
+/// fill's price; `Current` uses the live CLOB midpoint at execution time, for
+/// signals that arrive after the book has already moved. Either way the limit is
+/// clamped to the session's slippage bound around the source price.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GtcPriceRef {
+    Source,
+    Current,
+}
+
+impl GtcPriceRef {
+    pub fn from_str(s: &str) -> Option<Self> {
+        match s {
+            "source" => Some(Self::Source),
+            "current" => Some(Self::Current),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Source => "source",
+            Self::Current => "current",
+        }
+    }
+}
+
+impl Serialize for GtcPriceRef {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+/// How a tracked trader's sell is copied. `Proportional` sells the same share of
+/// the source sell (× copy_pct), capped at our holdings; `FullExit` closes our
+/// whole position in the asset on any source sell.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SellBehavior {
+    Proportional,
+    FullExit,
+}
+
+impl SellBehavior {
+    pub fn from_str(s: &str) -> Option<Self> {
+        match s {
+            "proportional" => Some(Self::Proportional),
+            "full_exit" => Some(Self::FullExit),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Proportional => "proportional",
+            Self::FullExit => "full_exit",
+        }
+    }
+}
+
+impl Serialize for SellBehavior {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+/// How live order sizes are rounded to the CLOB's precision. `Down` truncates, so
+/// orders never exceed the sized amount but under-fill it slightly on average;
+/// `Nearest` rounds half up, falling back to down when rounding up would break
+/// max_position_usdc, the remaining capital (buys) or the held shares (sells).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SizeRounding {
+    Down,
+    Nearest,
+}
+
+impl SizeRounding {
+    pub fn from_str(s: &str) -> Option<Self> {
+        match s {
+            "down" => Some(Self::Down),
+            "nearest" => Some(Self::Nearest),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Down => "down",
+            Self::Nearest => "nearest",
+        }
+    }
+}
+
+impl Serialize for SizeRounding {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+/// Which side a session trades relative to its source. `Fade` buys when the
+/// trader sells and sells when they buy, for wallets that are reliably wrong.
+///
+/// Fading only makes sense while you hold inventory: a fade-sell (the trader
+/// bought) sells shares the session already holds in that asset, sized like a
+/// copied sell, and is skipped without a position just like a normal sell. A
+/// fade-buy (the trader sold) is sized from the capital budget like any buy.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CopyMode {
+    Copy,
+    Fade,
+}
+
+impl CopyMode {
+    pub fn from_str(s: &str) -> Option<Self> {
+        match s {
+            "copy" => Some(Self::Copy),
+            "fade" => Some(Self::Fade),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Copy => "copy",
+            Self::Fade => "fade",
+        }
+    }
+}
+
+impl Serialize for CopyMode {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+/// Which sides a session copies. `Buy` mirrors entries only, leaving exits to the
+/// session's own take-profit, stop-loss or trailing stop; `Sell` mirrors exits only.
+/// The filter applies to the side the session would trade, so after a fade flip.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CopySides {
+    Both,
+    Buy,
+    Sell,
+}
+
+impl CopySides {
+    pub fn from_str(s: &str) -> Option<Self> {
+        match s {
+            "both" => Some(Self::Both),
+            "buy" => Some(Self::Buy),
+            "sell" => Some(Self::Sell),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Both => "both",
+            Self::Buy => "buy",
+            Self::Sell => "sell",
+        }
+    }
+}
+
+impl Serialize for CopySides {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+/// Where a session's signals come from. `Traders` copies the resolved trader set
+/// (list, top N or top %); `WhaleFollow` copies any trader's fill from the whale
+/// alert feed above the session's `min_whale_usdc`.
+///
+/// WhaleFollow is unbounded by a trader set: every $25k+ fill across all markets is
+/// a candidate, so expect far more orders than a list session. Cap it with
+/// max_position_usdc, max_market_usdc, an asset allowlist or the confidence filters.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SignalSource {
+    Traders,
+    WhaleFollow,
+}
+
+impl SignalSource {
+    pub fn from_str(s: &str) -> Option<Self> {
+        match s {
+            "traders" => Some(Self::Traders),
+            "whale_follow" => Some(Self::WhaleFollow),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Traders => "traders",
+            Self::WhaleFollow => "whale_follow",
+        }
+    }
+}
+
+impl Serialize for SignalSource {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+/// Ranking behind `top_n` / `top_pct`, over all-time positions.
+///
+/// - `Pnl`: total P&L, realized plus open positions marked to market (the leaderboard default)
+/// - `Roi`: P&L per USDC traded, among traders above a volume floor
+/// - `Volume`: total USDC traded
+/// - `Consistency`: share of positions closed or marked in profit, among traders
+///   with enough positions to judge
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TopNMetric {
+    Pnl,
+    Roi,
+    Volume,
+    Consistency,
+}
+
+impl TopNMetric {
+    pub fn from_str(s: &str) -> Option<Self> {
+        match s {
+            "pnl" => Some(Self::Pnl),
+            "roi" => Some(Self::Roi),
+            "volume" => Some(Self::Volume),
+            "consistency" => Some(Self::Consistency),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Pnl => "pnl",
+            Self::Roi => "roi",
+            Self::Volume => "volume",
+            Self::Consistency => "consistency",
+        }
+    }
+}
+
+impl Serialize for TopNMetric {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SessionStatus {
+    Running,
+    Paused,
+    Stopped,
+}
+
+impl SessionStatus {
+    pub fn from_str(s: &str) -> Option<Self> {
+        match s {
+            "running" => Some(Self::Running),
+            "paused" => Some(Self::Paused),
+            "stopped" => Some(Self::Stopped),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Running => "running",
+            Self::Paused => "paused",
+            Self::Stopped => "stopped",
+        }
+    }
+}
+
+impl Serialize for SessionStatus {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum OrderStatus {
+    Pending,
+    Submitted,
+    Filled,
+    Partial,
+    Failed,
+    Canceled,
+    Simulated,
+}
+
+impl OrderStatus {
+    pub fn from_str(s: &str) -> Option<Self> {
+        match s {
+            "pending" => Some(Self::Pending),
+            "submitted" => Some(Self::Submitted),
+            "filled" => Some(Self::Filled),
+            "partial" => Some(Self::Partial),
+            "failed" => Some(Self::Failed),
+            "canceled" => Some(Self::Canceled),
+            "simulated" => Some(Self::Simulated),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Pending => "pending",
+            Self::Submitted => "submitted",
+            Self::Filled => "filled",
+            Self::Partial => "partial",
+            Self::Failed => "failed",
+            Self::Canceled => "canceled",
+            Self::Simulated => "simulated",
+        }
+    }
+}
+
+impl Serialize for OrderStatus {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+/// What placed a copy-trade order. Stored in `copy_trade_orders.origin` so P&L from the
+/// copied strategy can be told apart from the owner's interventions.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum OrderOrigin {
+    /// Copy of a source trader's fill
+    Signal,
+    /// Order placed by hand by the owner
+    Manual,
+    /// Owner-triggered position close
+    Close,
+    /// Redemption of a resolved position
+    Redeem,
+    /// Engine-initiated exit (e.g. trader removed from the list)
+    Auto,
+}
+
+impl OrderOrigin {
+    pub fn from_str(s: &str) -> Option<Self> {
+        match s {
+            "signal" => Some(Self::Signal),
+            "manual" => Some(Self::Manual),
+            "close" => Some(Self::Close),
+            "redeem" => Some(Self::Redeem),
+            "auto" => Some(Self::Auto),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Signal => "signal",
+            Self::Manual => "manual",
+            Self::Close => "close",
+            Self::Redeem => "redeem",
+            Self::Auto => "auto",
+        }
+    }
+
+    /// Maps the sentinel `source_tx_hash` values of non-copied orders; a real hash is a signal.
+    /// Mirrors the backfill in `db::init_user_db`.
+    pub fn from_source_tx_hash(hash: &str) -> Self {
+        match hash {
+            "manual" => Self::Manual,
+            "close-position" => Self::Close,
+            "redeem" => Self::Redeem,
+            "trader-removed" | "trailing-stop" | "stop-loss" | "take-profit" => Self::Auto,
+            _ => Self::Signal,
+        }
+    }
+}
+
+impl Serialize for OrderOrigin {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[derive(Serialize)]
+pub struct CopyTradeSession {
+    pub id: String,
+    pub list_id: Option<String>,
+    pub top_n: Option<u32>,
+    pub top_pct: Option<f64>,
+    pub copy_pct: f64,
+    pub max_position_usdc: f64,
+    pub max_slippage_bps: u32,
+    pub order_type: CopyOrderType,
+    pub initial_capital: f64,
+    pub remaining_capital: f64,
+    /// Estimated value of open positions (shares × avg entry price)
+    pub positions_value: f64,
+    /// Fill costs above the sized orders, clamped out of `remaining_capital`
+    pub fees_paid: f64,
+    pub simulate: bool,
+    pub max_loss_pct: Option<f64>,
+    pub only_favorable: bool,
+    pub buy_order_type: Option<CopyOrderType>,
+    pub sell_order_type: Option<CopyOrderType>,
+    pub mark_price_source: MarkPriceSource,
+    pub min_trader_pnl: Option<f64>,
+    pub min_trader_winrate: Option<f64>,
+    pub slippage_pause_after: Option<u32>,
+    pub min_liquidity_usdc: Option<f64>,
+    pub liquidity_window_mins: Option<u32>,
+    pub close_on_trader_removal: bool,
+    pub max_market_usdc: Option<f64>,
+    pub seed_existing_positions: bool,
+    pub sizing_mode: SizingMode,
+    pub webhook_url: Option<String>,
+    pub digest_interval_hours: Option<u32>,
+    pub sizing_base: SizingBase,
+    pub max_runtime_secs: Option<u32>,
+    pub asset_allowlist: Option<Vec<String>>,
+    pub near_resolution_price: Option<f64>,
+    pub gtc_price_ref: GtcPriceRef,
+    pub gtc_price_offset_bps: i32,
+    pub sell_behavior: SellBehavior,
+    pub trail_activate_pct: Option<f64>,
+    pub trail_distance_pct: Option<f64>,
+    pub mark_stale_secs: Option<u32>,
+    pub signal_source: SignalSource,
+    pub min_whale_usdc: Option<f64>,
+    pub slippage_fee_bps: Option<u32>,
+    pub max_signal_age_secs: Option<u32>,
+    pub top_n_metric: TopNMetric,
+    pub min_hold_secs: u32,
+    pub slippage_overrides: Option<std::collections::BTreeMap<String, u32>>,
+    pub max_orders_per_minute: Option<u32>,
+    pub size_rounding: SizeRounding,
+    pub take_profit_pct: Option<f64>,
+    pub stop_loss_pct: Option<f64>,
+    pub mode: CopyMode,
+    pub allowed_categories: Option<String>,
+    pub blocked_categories: Option<String>,
+    pub copy_uncategorized: bool,
+    pub copy_sides: CopySides,
+    pub fok_fallback_gtc: bool,
+    pub status: SessionStatus,
+    pub created_at: String,
+    pub updated_at: String,
+    /// Approvals sent by `auto_approve` (create response only)
+    pub approval: Option<ApprovalResult>,
+}
+
+#[derive(Serialize)]
+pub struct CopyTradeOrder {
+    pub id: String,
+    pub session_id: String,
+    pub source_tx_hash: String,
+    pub source_trader: String,
+    pub clob_order_id: Option<String>,
+    pub asset_id: String,
+    pub side: String,
+    #[serde(serialize_with = "super::precision::price")]
+    pub price: f64,
+    #[serde(serialize_with = "super::precision::price")]
+    pub source_price: f64,
+    #[serde(serialize_with = "super::precision::usdc")]
+    pub size_usdc: f64,
+    pub size_shares: Option<f64>,
+    pub status: OrderStatus,
+    pub error_message: Option<String>,
+    #[serde(serialize_with = "super::precision::price_opt")]
+    pub fill_price: Option<f64>,
+    #[serde(serialize_with = "super::precision::bps_opt")]
+    pub slippage_bps: Option<f64>,
+    pub tx_hash: Option<String>,
+    pub created_at: String,
+    pub updated_at: String,
+    pub origin: OrderOrigin,
+    /// Slippage bound the order was gated on (session default or per-asset override)
+    pub max_slippage_bps: Option<u32>,
+    /// Set on a GTC placed by `fok_fallback_gtc`: the id of the FOK order it replaced
+    pub fallback_for: Option<String>,
+}
+
+/// A resting order (GET /api/copytrade/sessions/:id/open-orders)
+#[derive(Serialize)]
+pub struct OpenOrder {
+    #[serde(flatten)]
+    pub order: CopyTradeOrder,
+    pub age_secs: i64,
+    /// Capital held back for the order — buys only; resting sells reserve nothing
+    #[serde(serialize_with = "super::precision::usdc")]
+    pub reserved_usdc: f64,
+    /// Present in the engine's resting-order set (watched for fills and expiry)
+    pub tracked: bool,
+}
+
+#[derive(Serialize)]
+pub struct SessionOpenOrders {
+    pub session_id: String,
+    /// The engine holds the session (running or paused); when false, `tracked` is
+    /// false for every order
+    pub engine_loaded: bool,
+    #[serde(serialize_with = "super::precision::usdc")]
+    pub total_reserved_usdc: f64,
+    pub orders: Vec<OpenOrder>,
+}
+
+/// Cross-session activity feed entry: an order plus its market
+#[derive(Serialize)]
+pub struct RecentOrder {
+    #[serde(flatten)]
+    pub order: CopyTradeOrder,
+    pub question: String,
+    pub outcome: String,
+}
+
+/// Live CLOB view of a resting or matched order
+#[derive(Serialize)]
+pub struct ClobOrderStatus {
+    pub status: String,
+    pub original_size: f64,
+    pub size_matched: f64,
+}
+
+#[derive(Serialize)]
+pub struct OrderDetail {
+    #[serde(flatten)]
+    pub order: CopyTradeOrder,
+    pub question: String,
+    pub outcome: String,
+    /// Current CLOB status; None for simulated orders, orders never posted, or when
+    /// the CLOB can't be reached
+    pub clob_status: Option<ClobOrderStatus>,
+}
+
+#[derive(Serialize)]
+pub struct RecentOrdersResponse {
+    pub orders: Vec<RecentOrder>,
+    pub next_cursor: Option<String>,
+}
+
+#[derive(Clone, Serialize)]
+pub struct CopyTradeOrderSummary {
+    pub id: String,
+    pub asset_id: String,
+    pub side: String,
+    pub size_usdc: f64,
+    pub price: f64,
+    pub source_trader: String,
+    pub simulate: bool,
+}
+
+#[derive(Clone, Serialize)]
+#[serde(tag = "kind")]
+pub enum CopyTradeUpdate {
+    OrderPlaced {
+        session_id: String,
+        order: CopyTradeOrderSummary,
+        #[serde(skip)]
+        owner: String,
+    },
+    OrderFilled {
+        session_id: String,
+        order_id: String,
+        fill_price: f64,
         slippage_bps: f64,
         #[serde(skip)]
         owner: String,
@@ -925,8 +1932,19 @@ pub enum CopyTradeUpdate {
         #[serde(skip)]
         owner: String,
     },
+    /// A source trade was deliberately not copied (filter, not failure)
+    OrderSkipped {
+        session_id: String,
+        source_tx_hash: String,
+        source_trader: String,
+        asset_id: String,
+        reason: String,
+        #[serde(skip)]
+        owner: String,
+    },
     SessionPaused {
         session_id: String,
+        reason: Option<String>,
         #[serde(skip)]
         owner: String,
     },
@@ -941,6 +1959,35 @@ pub enum CopyTradeUpdate {
         #[serde(skip)]
         owner: String,
     },
+    /// The engine sold out of a position on its own (not mirroring a source sell)
+    PositionClosed {
+        session_id: String,
+        order_id: String,
+        asset_id: String,
+        shares: f64,
+        reason: String,
+        #[serde(skip)]
+        owner: String,
+    },
+    /// The engine fell behind a burst and lost source signals before evaluating them,
+    /// so any copies among them were missed
+    SignalsDropped {
+        session_id: String,
+        count: u64,
+        at: String,
+        #[serde(skip)]
+        owner: String,
+    },
+    /// A bridged deposit to one of the owner's trading wallets completed
+    DepositConfirmed {
+        wallet_id: String,
+        /// Base units of the source token
+        amount: String,
+        token: String,
+        tx_hash: Option<String>,
+        #[serde(skip)]
+        owner: String,
+    },
     #[allow(dead_code)]
     BalanceUpdate {
         balance: String,
@@ -955,9 +2002,13 @@ impl CopyTradeUpdate {
             Self::OrderPlaced { owner, .. }
             | Self::OrderFilled { owner, .. }
             | Self::OrderFailed { owner, .. }
+            | Self::OrderSkipped { owner, .. }
             | Self::SessionPaused { owner, .. }
             | Self::SessionResumed { owner, .. }
             | Self::SessionStopped { owner, .. }
+            | Self::PositionClosed { owner, .. }
+            | Self::SignalsDropped { owner, .. }
+            | Self::DepositConfirmed { owner, .. }
             | Self::BalanceUpdate { owner, .. } => owner,
         }
     }
@@ -974,19 +2025,36 @@ pub struct SessionStats {
     pub failed_orders: u32,
     pub pending_orders: u32,
     pub canceled_orders: u32,
+    #[serde(serialize_with = "super::precision::usdc")]
     pub total_invested: f64,
+    #[serde(serialize_with = "super::precision::usdc")]
     pub total_returned: f64,
+    #[serde(serialize_with = "super::precision::usdc")]
     pub realized_pnl: f64,
+    /// Realized P&L keyed by the origin of the closing order (signal, manual, close, ...)
+    #[serde(serialize_with = "super::precision::usdc_map")]
+    pub realized_pnl_by_origin: std::collections::BTreeMap<String, f64>,
+    #[serde(serialize_with = "super::precision::usdc")]
     pub unrealized_pnl: f64,
+    #[serde(serialize_with = "super::precision::usdc")]
     pub total_pnl: f64,
+    #[serde(serialize_with = "super::precision::usdc")]
     pub return_pct: f64,
     pub win_count: u32,
     pub loss_count: u32,
+    #[serde(serialize_with = "super::precision::usdc")]
     pub win_rate: f64,
+    #[serde(serialize_with = "super::precision::bps")]
     pub avg_slippage_bps: f64,
+    #[serde(serialize_with = "super::precision::bps")]
     pub max_slippage_bps: f64,
+    #[serde(serialize_with = "super::precision::usdc")]
     pub capital_utilization: f64,
+    #[serde(serialize_with = "super::precision::usdc")]
+    pub fees_paid: f64,
     pub runtime_seconds: i64,
+    /// Positions still holding shares
+    pub open_positions: u32,
 }
 
 #[derive(Serialize)]
@@ -998,18 +2066,348 @@ pub struct CopyTradePosition {
     pub buy_shares: f64,
     pub sell_shares: f64,
     pub net_shares: f64,
+    #[serde(serialize_with = "super::precision::price")]
     pub avg_entry_price: f64,
+    #[serde(serialize_with = "super::precision::price")]
     pub current_price: f64,
+    #[serde(serialize_with = "super::precision::price")]
     pub last_fill_price: f64,
+    #[serde(serialize_with = "super::precision::usdc")]
     pub cost_basis: f64,
+    #[serde(serialize_with = "super::precision::usdc")]
     pub current_value: f64,
+    #[serde(serialize_with = "super::precision::usdc")]
     pub unrealized_pnl: f64,
+    #[serde(serialize_with = "super::precision::usdc")]
     pub realized_pnl: f64,
     pub order_count: u32,
     pub source_traders: Vec<String>,
+    pub first_order_at: String,
     pub last_order_at: String,
 }
 
+/// What selling one open position into the current book would return
+#[derive(Serialize)]
+pub struct LiquidationPosition {
+    pub asset_id: String,
+    pub question: String,
+    pub outcome: String,
+    pub net_shares: f64,
+    #[serde(serialize_with = "super::precision::price")]
+    pub avg_entry_price: f64,
+    /// Live midpoint, else the last fill price
+    #[serde(serialize_with = "super::precision::price")]
+    pub mark_price: f64,
+    /// Average price of walking the bids for the held size; None without a book
+    #[serde(serialize_with = "super::precision::price_opt")]
+    pub vwap_price: Option<f64>,
+    /// Shares the bids absorb (all of them when priced off the mark)
+    pub fillable_shares: f64,
+    #[serde(serialize_with = "super::precision::usdc")]
+    pub expected_proceeds: f64,
+    /// Proceeds vs the fillable shares at mark, in bps (positive = worse than mark)
+    #[serde(serialize_with = "super::precision::bps")]
+    pub slippage_bps: f64,
+    /// Proceeds minus the cost basis of the fillable shares
+    #[serde(serialize_with = "super::precision::usdc")]
+    pub realized_pnl: f64,
+    /// Proceeds minus the fillable shares' value at mark
+    #[serde(serialize_with = "super::precision::usdc")]
+    pub pnl_vs_mark: f64,
+    /// False when the book couldn't be fetched and the position was priced at mark
+    pub from_book: bool,
+}
+
+/// GET /api/copytrade/sessions/:id/liquidation-preview — close-all estimate, no orders placed
+#[derive(Serialize)]
+pub struct LiquidationPreview {
+    pub session_id: String,
+    #[serde(serialize_with = "super::precision::usdc")]
+    pub total_expected_proceeds: f64,
+    #[serde(serialize_with = "super::precision::usdc")]
+    pub total_realized_pnl: f64,
+    #[serde(serialize_with = "super::precision::usdc")]
+    pub total_mark_value: f64,
+    #[serde(serialize_with = "super::precision::usdc")]
+    pub total_pnl_vs_mark: f64,
+    /// Shares the current books can't absorb, summed across positions
+    pub unfillable_shares: f64,
+    pub positions: Vec<LiquidationPosition>,
+}
+
+#[derive(Deserialize)]
+pub struct CompareSessionsParams {
+    pub a: String,
+    pub b: String,
+}
+
+/// Differences between two sessions' stats, always `a` minus `b`
+#[derive(Serialize)]
+pub struct SessionStatsDelta {
+    pub total_pnl: f64,
+    pub realized_pnl: f64,
+    pub unrealized_pnl: f64,
+    pub return_pct: f64,
+    pub win_rate: f64,
+    pub avg_slippage_bps: f64,
+    pub capital_utilization: f64,
+    pub total_orders: i64,
+}
+
+#[derive(Serialize)]
+pub struct ComparedSession {
+    pub session_id: String,
+    pub simulate: bool,
+    pub status: String,
+    pub stats: SessionStats,
+}
+
+#[derive(Serialize)]
+pub struct SessionComparison {
+    pub a: ComparedSession,
+    pub b: ComparedSession,
+    pub delta: SessionStatsDelta,
+}
+
+#[derive(Deserialize)]
+pub struct TaxReportParams {
+    /// Calendar year (UTC) of the disposals to report (default: current year)
+    pub year: Option<i32>,
+}
+
+#[derive(Deserialize)]
+pub struct ThroughputParams {
+    /// Lookback in minutes (default 60, max 7 days)
+    pub window: Option<u32>,
+}
+
+#[derive(Serialize)]
+pub struct SessionThroughput {
+    pub window_minutes: u32,
+    /// Orders created in the window, failed ones included
+    pub orders: u32,
+    pub failed_orders: u32,
+    /// Source trades not copied (filters, market cap, rate limit)
+    pub skipped: u32,
+    pub orders_per_minute: f64,
+    pub skipped_per_minute: f64,
+    /// Mean size of non-failed orders, None without any
+    pub avg_order_usdc: Option<f64>,
+    /// Skip reason → count
+    pub skip_reasons: std::collections::BTreeMap<String, u32>,
+}
+
+/// Why a session may not be trading (GET /copytrade/sessions/:id/diagnosis)
+#[derive(Serialize)]
+pub struct SessionDiagnosis {
+    pub session_id: String,
+    pub status: SessionStatus,
+    pub simulate: bool,
+    pub signal_source: SignalSource,
+    /// The engine's live view of the session; None when it isn't loaded
+    pub engine: Option<EngineSessionState>,
+    pub tracked_addresses: usize,
+    /// Tracked addresses currently subscribed on the trade feed
+    pub subscribed_addresses: usize,
+    /// Live sessions only: whether the shared CLOB client is initialized
+    pub clob_client_ready: Option<bool>,
+    pub remaining_capital: f64,
+    pub min_order_usdc: f64,
+    pub window_hours: u32,
+    /// Orders created in the window, failed ones included
+    pub recent_orders: u32,
+    pub recent_failed_orders: u32,
+    /// Skip reason → count over the window
+    pub skip_reasons: std::collections::BTreeMap<String, u32>,
+    /// Fills by the tracked traders over the window; None for whale_follow or when
+    /// ClickHouse couldn't be queried
+    pub source_trades: Option<u64>,
+    pub last_source_trade_at: Option<String>,
+    /// Problems found, most blocking first; empty when nothing looks wrong
+    pub issues: Vec<String>,
+}
+
+#[derive(Deserialize)]
+pub struct StalePositionsParams {
+    /// Hours since the last order on the asset (default 72)
+    pub older_than: Option<u32>,
+}
+
+#[derive(Serialize)]
+pub struct StalePosition {
+    pub asset_id: String,
+    pub question: String,
+    pub outcome: String,
+    pub net_shares: f64,
+    pub last_fill_price: f64,
+    pub estimated_value: f64,
+    pub source_traders: Vec<String>,
+    pub first_order_at: String,
+    pub last_order_at: String,
+    pub idle_hours: f64,
+}
+
+/// How closely a session mirrored its source traders over the session window
+#[derive(Serialize)]
+pub struct SessionFidelity {
+    pub session_id: String,
+    /// Clamped to the raw trades retention (3 days)
+    pub window_start: String,
+    pub window_end: String,
+    pub source_trades: u64,
+    /// Source trades we placed at least one order for
+    pub copied_trades: u64,
+    /// Source trades with a filled (or simulated) copy order
+    pub filled_trades: u64,
+    pub copy_rate: f64,
+    pub fill_rate: f64,
+    /// Mean of copy size / source size (USDC) over filled copies
+    pub avg_size_ratio: Option<f64>,
+    /// Mean seconds from source block time to our fill
+    pub avg_lag_secs: Option<f64>,
+    pub traders: Vec<TraderFidelity>,
+}
+
+#[derive(Serialize)]
+pub struct TraderFidelity {
+    pub address: String,
+    pub source_trades: u64,
+    pub copied_trades: u64,
+    pub filled_trades: u64,
+}
+
+#[derive(Deserialize)]
+pub struct CopyTradeAnalyticsParams {
+    /// Lookback for the volume series (default 30)
+    pub days: Option<u32>,
+    /// Rows for the markets ranking (default 20, max 100)
+    pub limit: Option<u32>,
+}
+
+#[derive(Deserialize)]
+pub struct OrderAuditParams {
+    pub session_id: Option<String>,
+    pub order_id: Option<String>,
+    /// RFC 3339 lower bound on the entry time
+    pub from: Option<String>,
+    /// RFC 3339 upper bound on the entry time
+    pub to: Option<String>,
+    /// Max entries (default 200, max 1000)
+    pub limit: Option<u32>,
+}
+
+/// One append-only lifecycle record from `order_audit`.
+#[derive(Serialize)]
+pub struct OrderAuditEntry {
+    pub id: i64,
+    pub session_id: String,
+    pub order_id: Option<String>,
+    /// decision | slippage | result | skipped | canceled
+    pub stage: String,
+    pub context: serde_json::Value,
+    pub created_at: String,
+}
+
+#[derive(clickhouse::Row, Serialize, Deserialize)]
+pub struct CopyTradeVolumeDay {
+    pub day: String,
+    pub orders: u64,
+    pub filled_orders: u64,
+    pub volume_usdc: f64,
+    pub sessions: u64,
+    pub owners: u64,
+}
+
+#[derive(Serialize)]
+pub struct CopiedMarket {
+    pub asset_id: String,
+    pub question: String,
+    pub outcome: String,
+    pub orders: u64,
+    pub volume_usdc: f64,
+    pub sessions: u64,
+    pub source_traders: u64,
+}
+
+/// Owner's view of the ws_subscriber filter: which of their session traders
+/// are actually in the published address set.
+#[derive(Serialize)]
+pub struct TrackedAddresses {
+    pub tracked: Vec<String>,
+    pub untracked: Vec<String>,
+    pub total_tracked: usize,
+}
+
+/// One entry of a session's in-memory resting GTC orders, answered by
+/// `CopyTradeCommand::OpenOrders`
+pub struct EngineOpenOrder {
+    pub order_id: String,
+    pub age_secs: u64,
+    pub usdc: f64,
+}
+
+/// One session's in-memory engine state, answered by `CopyTradeCommand::Diagnose`
+#[derive(Serialize)]
+pub struct EngineSessionState {
+    pub status: String,
+    /// Resolved trader set (reported as a count)
+    #[serde(skip)]
+    pub traders: Vec<String>,
+    pub remaining_capital: f64,
+    pub consecutive_failures: u32,
+    pub consecutive_slippage_rejections: u32,
+    /// Seconds left of a failure cooldown, None when not cooling down
+    pub cooldown_remaining_secs: Option<u64>,
+    /// Seconds since capital fell below the minimum order (pending auto-pause)
+    pub low_balance_secs: Option<u64>,
+    pub orders_last_minute: usize,
+    pub max_orders_per_minute: u32,
+    pub open_positions: usize,
+    pub open_gtc_orders: usize,
+}
+
+/// Engine in-memory state, answered by `CopyTradeCommand::Inspect`
+#[derive(Serialize)]
+pub struct EngineStateSnapshot {
+    /// Sessions held by the engine (running or paused)
+    pub loaded_sessions: usize,
+    pub running_sessions: usize,
+    pub paused_sessions: usize,
+    pub sessions_in_cooldown: usize,
+    /// Below the minimum order size, waiting out the empty-balance grace
+    pub sessions_low_balance: usize,
+    pub clob_clients: usize,
+    /// Orders counted against the global rate limit
+    pub orders_last_minute: usize,
+    pub max_orders_per_minute: usize,
+}
+
+/// Whole copy-trade subsystem across all owners (GET /api/admin/copytrade/overview)
+#[derive(Serialize)]
+pub struct CopyTradeOverview {
+    pub owners: u32,
+    pub running_sessions: u32,
+    pub paused_sessions: u32,
+    pub stopped_sessions: u32,
+    pub tracked_addresses: usize,
+    pub orders_last_hour: u32,
+    pub failed_orders_last_hour: u32,
+    pub skipped_last_hour: u32,
+    pub engine: EngineStateSnapshot,
+}
+
+#[derive(Serialize)]
+pub struct EngineMetricsSnapshot {
+    pub trades_received: u64,
+    pub trades_dropped: u64,
+    pub trades_processed: u64,
+    pub avg_process_ms: f64,
+    /// Whale alerts lost to lag before whale_follow sessions saw them
+    pub whale_alerts_dropped: u64,
+    /// Mint/merge fills with no USDC leg, skipped by the trade feeds
+    pub token_swap_fills_skipped: u64,
+}
+
 #[derive(Serialize)]
 pub struct CopyTradeSummary {
     pub active_sessions: u32,
@@ -0,0 +1,140 @@
+//! Short-term memory of the copy-trade update stream, so a /ws/copytrade client
+//! that reconnects with `?since=<seq>` gets the updates it missed while away.
+//!
+//! Every update is stamped with a `seq` as it passes through `run` and kept per
+//! owner, up to COPYTRADE_REPLAY_CAPACITY updates no older than
+//! COPYTRADE_REPLAY_RETENTION_SECS. Delivery is at-least-once: a client resuming
+//! from an older cursor than it has seen gets duplicates, and a client that was
+//! gone longer than the retention silently misses the oldest updates and should
+//! refetch state. Sequence numbers start at the process start time in
+//! microseconds, so cursors from before a restart stay below every new update.
+
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use tokio::sync::broadcast;
+
+use super::types::CopyTradeUpdate;
+
+const DEFAULT_CAPACITY: usize = 200;
+const DEFAULT_RETENTION: Duration = Duration::from_secs(600);
+
+/// One update as sent over the socket: the update JSON with its `seq` added.
+pub struct SequencedUpdate {
+    pub seq: u64,
+    pub owner: String,
+    pub json: String,
+}
+
+pub type UpdateLog = Arc<Mutex<ReplayLog>>;
+
+pub struct ReplayLog {
+    next_seq: u64,
+    capacity: usize,
+    retention: Duration,
+    by_owner: HashMap<String, VecDeque<(Instant, Arc<SequencedUpdate>)>>,
+}
+
+impl ReplayLog {
+    pub fn from_env() -> Self {
+        let capacity = match std::env::var("COPYTRADE_REPLAY_CAPACITY") {
+            Ok(v) => match v.trim().parse::<usize>() {
+                Ok(n) => n,
+                _ => {
+                    tracing::warn!(
+                        "Invalid COPYTRADE_REPLAY_CAPACITY={v}, using default {DEFAULT_CAPACITY}"
+                    );
+                    DEFAULT_CAPACITY
+                }
+            },
+            Err(_) => DEFAULT_CAPACITY,
+        };
+        let retention = match std::env::var("COPYTRADE_REPLAY_RETENTION_SECS") {
+            Ok(v) => match v.trim().parse::<u64>() {
+                Ok(secs) => Duration::from_secs(secs),
+                _ => {
+                    tracing::warn!(
+                        "Invalid COPYTRADE_REPLAY_RETENTION_SECS={v}, using default {}",
+                        DEFAULT_RETENTION.as_secs()
+                    );
+                    DEFAULT_RETENTION
+                }
+            },
+            Err(_) => DEFAULT_RETENTION,
+        };
+        let start_micros = chrono::Utc::now().timestamp_micros().max(0) as u64;
+        Self {
+            next_seq: start_micros,
+            capacity,
+            retention,
+            by_owner: HashMap::new(),
+        }
+    }
+
+    fn record(&mut self, update: &CopyTradeUpdate) -> Option<Arc<SequencedUpdate>> {
+        let mut value = serde_json::to_value(update).ok()?;
+        self.next_seq += 1;
+        let seq = self.next_seq;
+        value.as_object_mut()?.insert("seq".to_string(), seq.into());
+        let entry = Arc::new(SequencedUpdate {
+            seq,
+            owner: update.owner().to_string(),
+            json: value.to_string(),
+        });
+
+        if self.capacity > 0 && !self.retention.is_zero() {
+            let now = Instant::now();
+            let queue = self.by_owner.entry(entry.owner.clone()).or_default();
+            queue.push_back((now, entry.clone()));
+            while queue.len() > self.capacity
+                || queue
+                    .front()
+                    .is_some_and(|(at, _)| now.duration_since(*at) > self.retention)
+            {
+                queue.pop_front();
+            }
+        }
+        Some(entry)
+    }
+
+    /// The owner's retained updates after `since`, oldest first.
+    pub fn since(&self, owner: &str, since: u64) -> Vec<Arc<SequencedUpdate>> {
+        let Some(queue) = self.by_owner.get(owner) else {
+            return Vec::new();
+        };
+        queue
+            .iter()
+            .filter(|(at, u)| u.seq > since && at.elapsed() <= self.retention)
+            .map(|(_, u)| u.clone())
+            .collect()
+    }
+}
+
+/// Stamps every update from `update_rx` with a seq, retains it in `log` and
+/// republishes it on `sequenced_tx` for the WS handlers.
+pub async fn run(
+    mut update_rx: broadcast::Receiver<CopyTradeUpdate>,
+    log: UpdateLog,
+    sequenced_tx: broadcast::Sender<Arc<SequencedUpdate>>,
+) {
+    loop {
+        match update_rx.recv().await {
+            Ok(update) => {
+                let entry = log
+                    .lock()
+                    .unwrap_or_else(|p| p.into_inner())
+                    .record(&update);
+                if let Some(entry) = entry {
+                    let _ = sequenced_tx.send(entry);
+                }
+            }
+            Err(broadcast::error::RecvError::Lagged(n)) => {
+                tracing::warn!(
+                    "Copytrade update replay lagged, {n} update(s) were neither sent nor retained"
+                );
+            }
+            Err(_) => break,
+        }
+    }
+}
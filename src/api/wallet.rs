@@ -8,7 +8,9 @@ use axum::{
     response::IntoResponse,
 };
 use secrecy::ExposeSecret;
+use std::collections::HashMap;
 use std::str::FromStr;
+use std::time::Instant;
 
 use super::contracts;
 use super::db::{self, WalletError};
@@ -16,8 +18,9 @@ use super::middleware::AuthUser;
 use super::server::AppState;
 use super::types::{
     ApprovalResult, DepositAddresses, DepositStatus, DeriveCredentialsResponse,
-    ImportWalletRequest, ImportWalletResponse, PendingDeposit, TradingWalletInfo, WalletBalance,
-    WalletGenerateResponse,
+    GenerateWalletRequest, ImportWalletRequest, ImportWalletResponse, PendingDeposit,
+    TradingWalletInfo, UpdateWalletRequest, WalletBalance, WalletGenerateResponse,
+    WalletSignatureType,
 };
 
 /// Derives proxy wallet address using the SDK's official CREATE2 computation.
@@ -29,6 +32,33 @@ fn proxy_address_for(eoa: &[u8; 20]) -> String {
     }
 }
 
+/// Checks that `proxy` is the proxy wallet the SDK derives for `eoa`. Proxy-mode
+/// orders are signed for that address, so a stale or foreign value would be rejected.
+pub(crate) fn verify_proxy_address(eoa: &str, proxy: Option<&str>) -> Result<(), String> {
+    let eoa: alloy_primitives::Address = eoa.parse().map_err(|_| "Invalid wallet address")?;
+    let expected = polymarket_client_sdk::derive_proxy_wallet(eoa, polymarket_client_sdk::POLYGON)
+        .ok_or("Could not derive proxy wallet")?;
+    let stored = proxy
+        .and_then(|p| p.parse::<alloy_primitives::Address>().ok())
+        .ok_or("Wallet has no proxy address")?;
+    if stored != expected {
+        return Err(format!(
+            "Proxy address mismatch: stored {stored}, derived {expected}"
+        ));
+    }
+    Ok(())
+}
+
+fn parse_signature_type(s: Option<&str>) -> Result<WalletSignatureType, (StatusCode, String)> {
+    match s {
+        None => Ok(WalletSignatureType::Proxy),
+        Some(s) => WalletSignatureType::from_str(s).ok_or((
+            StatusCode::BAD_REQUEST,
+            "signature_type must be proxy or eoa".into(),
+        )),
+    }
+}
+
 /// Derives an Ethereum address from a secp256k1 signing key.
 fn address_from_signing_key(signing_key: &k256::ecdsa::SigningKey) -> [u8; 20] {
     let verify_key = signing_key.verifying_key();
@@ -81,6 +111,8 @@ pub async fn get_wallets(
             id: w.id,
             address: w.wallet_address,
             proxy_address: w.proxy_address,
+            signature_type: WalletSignatureType::from_str(&w.signature_type)
+                .unwrap_or(WalletSignatureType::Proxy),
             status: w.status,
             has_clob_credentials: w.clob_api_key.is_some(),
             created_at: w.created_at,
@@ -97,8 +129,29 @@ pub async fn get_wallets(
 pub async fn generate_wallet(
     State(state): State<AppState>,
     AuthUser(owner): AuthUser,
+    body: Option<Json<GenerateWalletRequest>>,
 ) -> Result<impl IntoResponse, (StatusCode, String)> {
     let owner = owner.to_lowercase();
+    let signature_type = parse_signature_type(
+        body.as_ref()
+            .and_then(|Json(b)| b.signature_type.as_deref()),
+    )?;
+
+    // Check the cap before a key exists: a key is only ever returned once it is stored
+    tokio::task::spawn_blocking({
+        let state = state.clone();
+        let owner = owner.clone();
+        move || {
+            let conn = state.user_db.lock().expect("user_db lock");
+            match db::count_trading_wallets(&conn, &owner)? {
+                n if n >= db::MAX_WALLETS_PER_USER => Err(WalletError::LimitReached),
+                _ => Ok(()),
+            }
+        }
+    })
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
+    .map_err(map_wallet_error)?;
 
     // Generate a random secp256k1 signing key
     let signing_key = k256::ecdsa::SigningKey::random(&mut k256::elliptic_curve::rand_core::OsRng);
@@ -117,7 +170,8 @@ pub async fn generate_wallet(
     let wallet_addr = format_address(&address);
     let private_key_hex = format!("0x{}", hex::encode(&private_key_bytes));
 
-    // Store in SQLite (count check happens inside create_trading_wallet)
+    // Store in SQLite; create_trading_wallet re-checks the cap so a concurrent request
+    // can't slip past it. On failure the key is dropped without ever being exposed.
     let wallet_id = tokio::task::spawn_blocking({
         let state = state.clone();
         let owner = owner.clone();
@@ -132,6 +186,7 @@ pub async fn generate_wallet(
                 &proxy_addr,
                 &encrypted_key,
                 &key_nonce,
+                signature_type.as_str(),
             )
         }
     })
@@ -165,6 +220,7 @@ pub async fn import_wallet(
     Json(body): Json<ImportWalletRequest>,
 ) -> Result<impl IntoResponse, (StatusCode, String)> {
     let owner = owner.to_lowercase();
+    let signature_type = parse_signature_type(body.signature_type.as_deref())?;
 
     // Validate and parse private key
     let key_hex = body
@@ -221,6 +277,7 @@ pub async fn import_wallet(
                 &proxy_addr,
                 &encrypted_key,
                 &key_nonce,
+                signature_type.as_str(),
             )
         }
     })
@@ -269,12 +326,7 @@ pub async fn derive_credentials(
         &row.key_nonce,
         owner.as_bytes(),
     )
-    .map_err(|e| {
-        (
-            StatusCode::INTERNAL_SERVER_ERROR,
-            format!("Decryption failed: {e}"),
-        )
-    })?;
+    .map_err(|e| decrypt_failed(&state, &owner, &row.id, e))?;
 
     let private_key_hex = format!("0x{}", hex::encode(&private_key_bytes));
 
@@ -354,6 +406,65 @@ pub async fn derive_credentials(
     }))
 }
 
+// ---------------------------------------------------------------------------
+// PATCH /api/wallets/:id
+// ---------------------------------------------------------------------------
+
+pub async fn update_wallet(
+    State(state): State<AppState>,
+    AuthUser(owner): AuthUser,
+    Path(wallet_id): Path<String>,
+    Json(body): Json<UpdateWalletRequest>,
+) -> Result<Json<TradingWalletInfo>, (StatusCode, String)> {
+    let owner = owner.to_lowercase();
+    let signature_type = parse_signature_type(Some(&body.signature_type))?;
+    let row = load_wallet(&state, &owner, &wallet_id).await?;
+
+    if signature_type == WalletSignatureType::Proxy {
+        verify_proxy_address(&row.wallet_address, row.proxy_address.as_deref())
+            .map_err(|e| (StatusCode::BAD_REQUEST, e))?;
+    }
+
+    // Switching account model mid-session would move funds out from under the engine
+    {
+        let conn = state.user_db.lock().unwrap_or_else(|p| p.into_inner());
+        let has_active = db::has_active_copytrade_session(&conn, &owner)
+            .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+        if has_active {
+            return Err((
+                StatusCode::CONFLICT,
+                "Cannot change signature type while a copy-trade session is active. Stop the session first.".into(),
+            ));
+        }
+    }
+
+    tokio::task::spawn_blocking({
+        let state = state.clone();
+        let owner = owner.clone();
+        let wallet_id = wallet_id.clone();
+        move || {
+            let conn = state.user_db.lock().expect("user_db lock");
+            db::update_wallet_signature_type(&conn, &owner, &wallet_id, signature_type.as_str())
+        }
+    })
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
+    .map_err(map_wallet_error)?;
+
+    // Balance now lives on a different address
+    state.wallet_balances.write().await.remove(&wallet_id);
+
+    Ok(Json(TradingWalletInfo {
+        id: row.id,
+        address: row.wallet_address,
+        proxy_address: row.proxy_address,
+        signature_type,
+        status: row.status,
+        has_clob_credentials: row.clob_api_key.is_some(),
+        created_at: row.created_at,
+    }))
+}
+
 // ---------------------------------------------------------------------------
 // DELETE /api/wallets/:id
 // ---------------------------------------------------------------------------
@@ -407,14 +518,22 @@ pub async fn get_balance(
     // Verify wallet ownership
     let row = load_wallet(&state, &owner, &wallet_id).await?;
 
-    // Try cache first (balance poll updates every 30s)
-    let cached = state.wallet_balances.read().await.get(&wallet_id).cloned();
+    current_balance(&state, &row).await.map(Json)
+}
+
+/// Cached balance + allowances (the balance poll refreshes every 30s), falling
+/// back to a live RPC query on a cache miss.
+pub(crate) async fn current_balance(
+    state: &AppState,
+    row: &db::TradingWalletRow,
+) -> Result<WalletBalance, (StatusCode, String)> {
+    let cached = state.wallet_balances.read().await.get(&row.id).cloned();
 
     if let Some(entry) = cached {
         let secs_ago = entry.last_checked.elapsed().as_secs();
         let pol_wei: U256 = entry.pol_raw.parse().unwrap_or_default();
         let pol_low = pol_wei < contracts::MIN_POL_WEI;
-        return Ok(Json(WalletBalance {
+        return Ok(WalletBalance {
             usdc_balance: entry.usdc_balance,
             usdc_raw: entry.usdc_raw,
             ctf_exchange_approved: entry.ctf_approved,
@@ -422,22 +541,80 @@ pub async fn get_balance(
             pol_balance: entry.pol_balance,
             needs_gas: pol_low,
             last_checked_secs_ago: Some(secs_ago),
-        }));
+        });
     }
 
     // Cache miss — do a live RPC query
-    // USDC.e balance on proxy; allowances + POL on EOA
+    live_balance(state, row).await
+}
+
+// ---------------------------------------------------------------------------
+// POST /api/wallets/:id/refresh-balance
+// ---------------------------------------------------------------------------
+
+/// Minimum time between manual balance refreshes of one wallet
+const REFRESH_COOLDOWN_SECS: u64 = 10;
+
+/// Claims a manual refresh of `wallet_id` at `now`, or returns how many seconds ago the
+/// last one ran. Tracked apart from the poller's `last_checked`, which would otherwise
+/// block a user right after every poll.
+fn claim_refresh(
+    refreshes: &mut HashMap<String, Instant>,
+    wallet_id: &str,
+    now: Instant,
+) -> Result<(), u64> {
+    if let Some(last) = refreshes.get(wallet_id) {
+        let secs_ago = now.duration_since(*last).as_secs();
+        if secs_ago < REFRESH_COOLDOWN_SECS {
+            return Err(secs_ago);
+        }
+    }
+    refreshes.insert(wallet_id.to_string(), now);
+    Ok(())
+}
+
+pub async fn refresh_balance(
+    State(state): State<AppState>,
+    AuthUser(owner): AuthUser,
+    Path(wallet_id): Path<String>,
+) -> Result<Json<WalletBalance>, (StatusCode, String)> {
+    let owner = owner.to_lowercase();
+    let row = load_wallet(&state, &owner, &wallet_id).await?;
+
+    // Per-wallet rate limit on manual refreshes
+    let claimed = {
+        let mut refreshes = state
+            .wallet_refreshes
+            .lock()
+            .unwrap_or_else(|p| p.into_inner());
+        claim_refresh(&mut refreshes, &wallet_id, Instant::now())
+    };
+    if let Err(secs_ago) = claimed {
+        return Err((
+            StatusCode::TOO_MANY_REQUESTS,
+            format!(
+                "Balance refreshed {secs_ago}s ago. Retry in {}s.",
+                REFRESH_COOLDOWN_SECS - secs_ago
+            ),
+        ));
+    }
+
+    live_balance(&state, &row).await.map(Json)
+}
+
+/// Queries balance + allowances over RPC, bypassing the cache, and stores the result.
+async fn live_balance(
+    state: &AppState,
+    row: &db::TradingWalletRow,
+) -> Result<WalletBalance, (StatusCode, String)> {
+    // USDC.e balance on the funds address (proxy or EOA); allowances + POL on EOA
     let eoa: Address = row.wallet_address.parse().map_err(|_| {
         (
             StatusCode::INTERNAL_SERVER_ERROR,
             "Invalid wallet address in DB".into(),
         )
     })?;
-    let proxy = row
-        .proxy_address
-        .as_deref()
-        .and_then(|s| s.parse::<Address>().ok())
-        .unwrap_or(eoa);
+    let proxy = row.funds_address().parse::<Address>().unwrap_or(eoa);
 
     let provider = contracts::create_provider(&state.erpc_url);
     let usdc = contracts::IERC20::new(contracts::USDC_ADDRESS, &provider);
@@ -465,15 +642,15 @@ pub async fn get_balance(
         pol_raw: pol_wei.to_string(),
         ctf_approved: !ctf_allowance.is_zero(),
         neg_risk_approved: !neg_allowance.is_zero(),
-        last_checked: std::time::Instant::now(),
+        last_checked: Instant::now(),
     };
     state
         .wallet_balances
         .write()
         .await
-        .insert(wallet_id, entry.clone());
+        .insert(row.id.clone(), entry.clone());
 
-    Ok(Json(WalletBalance {
+    Ok(WalletBalance {
         usdc_balance: entry.usdc_balance,
         usdc_raw: entry.usdc_raw,
         ctf_exchange_approved: entry.ctf_approved,
@@ -481,7 +658,7 @@ pub async fn get_balance(
         pol_balance: entry.pol_balance,
         needs_gas: pol_wei < contracts::MIN_POL_WEI,
         last_checked_secs_ago: Some(0),
-    }))
+    })
 }
 
 // ---------------------------------------------------------------------------
@@ -495,7 +672,17 @@ pub async fn approve_exchanges(
 ) -> Result<Json<ApprovalResult>, (StatusCode, String)> {
     let owner = owner.to_lowercase();
     let row = load_wallet(&state, &owner, &wallet_id).await?;
+    approve_wallet(&state, &owner, &row).await.map(Json)
+}
 
+/// Sends any missing USDC.e approvals for the CTF and NegRisk exchanges from the
+/// wallet's EOA. Fails with 400 when the EOA lacks the POL to pay for gas.
+pub(crate) async fn approve_wallet(
+    state: &AppState,
+    owner: &str,
+    row: &db::TradingWalletRow,
+) -> Result<ApprovalResult, (StatusCode, String)> {
+    let wallet_id = &row.id;
     let eoa: Address = row.wallet_address.parse().map_err(|_| {
         (
             StatusCode::INTERNAL_SERVER_ERROR,
@@ -530,27 +717,22 @@ pub async fn approve_exchanges(
     let neg_allowance = neg_res.unwrap_or_default();
 
     if !ctf_allowance.is_zero() && !neg_allowance.is_zero() {
-        return Ok(Json(ApprovalResult {
+        return Ok(ApprovalResult {
             ctf_tx_hash: None,
             neg_risk_tx_hash: None,
             already_approved: true,
-        }));
+        });
     }
 
     // Decrypt private key and create signing provider
-    let encryption_key = super::crypto::derive_user_key(&state.encryption_key, &owner);
+    let encryption_key = super::crypto::derive_user_key(&state.encryption_key, owner);
     let private_key_bytes = super::crypto::decrypt_secret(
         &encryption_key,
         &row.encrypted_key,
         &row.key_nonce,
         owner.as_bytes(),
     )
-    .map_err(|e| {
-        (
-            StatusCode::INTERNAL_SERVER_ERROR,
-            format!("Decryption failed: {e}"),
-        )
-    })?;
+    .map_err(|e| decrypt_failed(state, owner, &row.id, e))?;
 
     let private_key_hex = format!("0x{}", hex::encode(&private_key_bytes));
     let signer = alloy::signers::local::PrivateKeySigner::from_str(&private_key_hex)
@@ -580,7 +762,7 @@ pub async fn approve_exchanges(
                     ctf_tx_hash = Some(receipt.transaction_hash.to_string());
                 }
                 Err(e) => {
-                    state.wallet_balances.write().await.remove(&wallet_id);
+                    state.wallet_balances.write().await.remove(wallet_id);
                     return Err((
                         StatusCode::INTERNAL_SERVER_ERROR,
                         format!("CTF approve receipt failed: {e}"),
@@ -609,7 +791,7 @@ pub async fn approve_exchanges(
                 }
                 Err(e) => {
                     // CTF may have succeeded — invalidate cache so poll picks up partial state
-                    state.wallet_balances.write().await.remove(&wallet_id);
+                    state.wallet_balances.write().await.remove(wallet_id);
                     return Err((
                         StatusCode::INTERNAL_SERVER_ERROR,
                         format!(
@@ -620,7 +802,7 @@ pub async fn approve_exchanges(
                 }
             },
             Err(e) => {
-                state.wallet_balances.write().await.remove(&wallet_id);
+                state.wallet_balances.write().await.remove(wallet_id);
                 return Err((
                     StatusCode::INTERNAL_SERVER_ERROR,
                     format!(
@@ -633,13 +815,13 @@ pub async fn approve_exchanges(
     }
 
     // Invalidate balance cache so next poll picks up new allowances
-    state.wallet_balances.write().await.remove(&wallet_id);
+    state.wallet_balances.write().await.remove(wallet_id);
 
-    Ok(Json(ApprovalResult {
+    Ok(ApprovalResult {
         ctf_tx_hash,
         neg_risk_tx_hash,
         already_approved: false,
-    }))
+    })
 }
 
 // ---------------------------------------------------------------------------
@@ -654,9 +836,8 @@ pub async fn get_deposit_address(
     let owner = owner.to_lowercase();
     let row = load_wallet(&state, &owner, &wallet_id).await?;
 
-    let proxy_address = row
-        .proxy_address
-        .unwrap_or_else(|| row.wallet_address.clone());
+    let proxy_address = row.funds_address().to_string();
+    watch_deposits(&state, &row);
 
     // Call Polymarket Bridge API (POST /deposit with JSON body)
     let resp = state
@@ -705,32 +886,39 @@ pub async fn get_deposit_status(
     let owner = owner.to_lowercase();
     let row = load_wallet(&state, &owner, &wallet_id).await?;
 
-    let proxy_address = row
-        .proxy_address
-        .unwrap_or_else(|| row.wallet_address.clone());
+    let proxy_address = row.funds_address().to_string();
+    watch_deposits(&state, &row);
 
+    let pending = fetch_bridge_deposits(&state.http, &proxy_address)
+        .await
+        .map_err(|e| (StatusCode::BAD_GATEWAY, e))?;
+
+    Ok(Json(DepositStatus { pending }))
+}
+
+/// Fetches bridge transactions for a deposit address. A non-success status from
+/// the bridge means nothing is known for the address, i.e. no deposits.
+pub(crate) async fn fetch_bridge_deposits(
+    http: &reqwest::Client,
+    address: &str,
+) -> Result<Vec<PendingDeposit>, String> {
     // GET /status/{address} — path param, not query
-    let resp = state
-        .http
-        .get(format!(
-            "https://bridge.polymarket.com/status/{proxy_address}"
-        ))
+    let resp = http
+        .get(format!("https://bridge.polymarket.com/status/{address}"))
         .send()
         .await
-        .map_err(|e| (StatusCode::BAD_GATEWAY, format!("Bridge API error: {e}")))?;
+        .map_err(|e| format!("Bridge API error: {e}"))?;
 
     if !resp.status().is_success() {
-        return Ok(Json(DepositStatus { pending: vec![] }));
+        return Ok(vec![]);
     }
 
-    let data: serde_json::Value = resp.json().await.map_err(|e| {
-        (
-            StatusCode::BAD_GATEWAY,
-            format!("Bridge API parse error: {e}"),
-        )
-    })?;
+    let data: serde_json::Value = resp
+        .json()
+        .await
+        .map_err(|e| format!("Bridge API parse error: {e}"))?;
 
-    let pending = data["transactions"]
+    Ok(data["transactions"]
         .as_array()
         .map(|txs| {
             txs.iter()
@@ -746,15 +934,55 @@ pub async fn get_deposit_status(
                 })
                 .collect()
         })
-        .unwrap_or_default();
+        .unwrap_or_default())
+}
 
-    Ok(Json(DepositStatus { pending }))
+/// Asks the deposit watcher to push a notification when a deposit to this wallet lands.
+fn watch_deposits(state: &AppState, row: &db::TradingWalletRow) {
+    let _ = state
+        .deposit_watch_tx
+        .try_send(super::deposit_watch::WatchRequest {
+            wallet_id: row.id.clone(),
+            owner: row.owner.clone(),
+            address: row.funds_address().to_string(),
+        });
 }
 
 // ---------------------------------------------------------------------------
 // Helpers
 // ---------------------------------------------------------------------------
 
+/// Maps a private-key decryption failure to an API error. An authentication
+/// failure means the key can never be recovered with the current server key, so
+/// the wallet is flagged `decrypt_error` and the caller gets a 409 explaining why.
+fn decrypt_failed(
+    state: &AppState,
+    owner: &str,
+    wallet_id: &str,
+    e: super::crypto::DecryptError,
+) -> (StatusCode, String) {
+    match e {
+        super::crypto::DecryptError::KeyMismatch => {
+            tracing::error!(
+                "Wallet {wallet_id} ({owner}): private key failed to decrypt. \
+                 WALLET_ENCRYPTION_KEY differs from the one it was stored with, or the row is corrupted"
+            );
+            let conn = state.user_db.lock().unwrap_or_else(|p| p.into_inner());
+            let _ = db::update_wallet_status(&conn, owner, wallet_id, "decrypt_error");
+            (
+                StatusCode::CONFLICT,
+                "Wallet key cannot be decrypted with the server's current encryption key. \
+                 Restore the original WALLET_ENCRYPTION_KEY, or delete this wallet and re-import it"
+                    .into(),
+            )
+        }
+        super::crypto::DecryptError::Malformed(_) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            format!("Decryption failed: {e}"),
+        ),
+    }
+}
+
 /// Loads a wallet by owner + ID, returning 404 if not found.
 async fn load_wallet(
     state: &AppState,
@@ -774,3 +1002,25 @@ async fn load_wallet(
     .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
     .ok_or_else(|| (StatusCode::NOT_FOUND, "Trading wallet not found".into()))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn manual_refreshes_are_limited_per_wallet() {
+        let mut refreshes = HashMap::new();
+        let start = Instant::now();
+        assert_eq!(claim_refresh(&mut refreshes, "w1", start), Ok(()));
+        assert_eq!(
+            claim_refresh(&mut refreshes, "w1", start + Duration::from_secs(3)),
+            Err(3)
+        );
+        // Another wallet has its own window
+        assert_eq!(claim_refresh(&mut refreshes, "w2", start), Ok(()));
+
+        let later = start + Duration::from_secs(REFRESH_COOLDOWN_SECS);
+        assert_eq!(claim_refresh(&mut refreshes, "w1", later), Ok(()));
+    }
+}
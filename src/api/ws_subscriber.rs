@@ -1,4 +1,5 @@
 use std::collections::HashSet;
+use std::sync::Arc;
 use std::time::{Duration, Instant};
 
 use alloy_primitives::B256;
@@ -9,7 +10,9 @@ use tokio::sync::{broadcast, watch};
 use tokio_tungstenite::tungstenite::Message;
 
 use super::alerts::LiveTrade;
+use super::engine::EngineMetrics;
 use super::markets;
+use super::scanner::{self, RpcUrls};
 
 // ---------------------------------------------------------------------------
 // Constants
@@ -17,10 +20,161 @@ use super::markets;
 
 const CTF_EXCHANGE: &str = "0x4bFb41d5B3570DeFd03C39a9A4D8dE6Bd8B8982E";
 const NEGRISK_EXCHANGE: &str = "0xC5d563A36AE78145C45a50134d48A1215220f80a";
+/// Defaults; override with WS_RECONNECT_BASE_MS / WS_RECONNECT_MAX_MS
 const RECONNECT_BASE_DELAY: Duration = Duration::from_secs(2);
 const RECONNECT_MAX_DELAY: Duration = Duration::from_secs(60);
 const HEALTH_LOG_INTERVAL: Duration = Duration::from_secs(60);
-const MAX_TRACKED_ADDRESSES_WARN: usize = 200;
+/// Default; override with WS_FILL_AGGREGATE_MS (0 emits every fill as it arrives)
+const FILL_AGGREGATE_WINDOW: Duration = Duration::from_millis(250);
+pub(crate) const MAX_TRACKED_ADDRESSES_WARN: usize = 200;
+
+// ---------------------------------------------------------------------------
+// Reconnect backoff
+// ---------------------------------------------------------------------------
+
+/// Exponential reconnect backoff. With jitter enabled each sleep is drawn from
+/// [delay/2, delay] so several instances don't reconnect in lockstep.
+#[derive(Clone, Copy)]
+struct ReconnectPolicy {
+    base: Duration,
+    max: Duration,
+    jitter: bool,
+}
+
+impl ReconnectPolicy {
+    fn from_env() -> Self {
+        let ms = |name: &str, default: Duration| {
+            std::env::var(name)
+                .ok()
+                .and_then(|v| v.trim().parse::<u64>().ok())
+                .map(Duration::from_millis)
+                .unwrap_or(default)
+        };
+        let base = ms("WS_RECONNECT_BASE_MS", RECONNECT_BASE_DELAY).max(Duration::from_millis(100));
+        let max = ms("WS_RECONNECT_MAX_MS", RECONNECT_MAX_DELAY).max(base);
+        let jitter = std::env::var("WS_RECONNECT_JITTER")
+            .map(|v| matches!(v.trim(), "1" | "true" | "yes"))
+            .unwrap_or(false);
+        Self { base, max, jitter }
+    }
+
+    fn delay(&self, backoff: Duration) -> Duration {
+        if self.jitter {
+            backoff.mul_f64(0.5 + rand::random::<f64>() * 0.5)
+        } else {
+            backoff
+        }
+    }
+
+    /// Sleeps for the (jittered) current backoff and returns the next one.
+    async fn sleep(&self, backoff: Duration) -> Duration {
+        tokio::time::sleep(self.delay(backoff)).await;
+        (backoff * 2).min(self.max)
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Fill aggregation
+// ---------------------------------------------------------------------------
+
+/// Coalesces the `OrderFilled` logs one `matchOrders` transaction emits for the
+/// same maker order (partial matches against several takers) into one trade.
+///
+/// Logs of a transaction arrive back to back, so fills are held until a log from
+/// another transaction shows up or `window` passes, then emitted with summed
+/// amounts and the volume-weighted price.
+struct FillAggregator {
+    window: Duration,
+    pending: Vec<PendingFill>,
+}
+
+struct PendingFill {
+    trade: LiveTrade,
+    usdc_raw: u128,
+    token_raw: u128,
+    fills: u32,
+    since: tokio::time::Instant,
+}
+
+impl PendingFill {
+    fn matches(&self, trade: &LiveTrade) -> bool {
+        self.trade.tx_hash == trade.tx_hash
+            && self.trade.trader == trade.trader
+            && self.trade.asset_id == trade.asset_id
+            && self.trade.side == trade.side
+    }
+
+    fn into_trade(mut self) -> LiveTrade {
+        if self.fills > 1 {
+            tracing::debug!(
+                "WS subscriber: coalesced {} fills for {} in {}",
+                self.fills,
+                self.trade.trader,
+                self.trade.tx_hash
+            );
+            set_amounts(&mut self.trade, self.usdc_raw, self.token_raw);
+        }
+        self.trade
+    }
+}
+
+impl FillAggregator {
+    fn from_env() -> Self {
+        let window = std::env::var("WS_FILL_AGGREGATE_MS")
+            .ok()
+            .and_then(|v| v.trim().parse::<u64>().ok())
+            .map(Duration::from_millis)
+            .unwrap_or(FILL_AGGREGATE_WINDOW);
+        Self {
+            window,
+            pending: Vec::new(),
+        }
+    }
+
+    /// Adds a fill; returns the trades that are complete because this log belongs
+    /// to a later transaction.
+    fn push(&mut self, trade: LiveTrade, usdc_raw: u128, token_raw: u128) -> Vec<LiveTrade> {
+        if self.window.is_zero() {
+            return vec![trade];
+        }
+        let ready = self.take_where(|p| p.trade.tx_hash != trade.tx_hash);
+        match self.pending.iter_mut().find(|p| p.matches(&trade)) {
+            Some(p) => {
+                p.usdc_raw += usdc_raw;
+                p.token_raw += token_raw;
+                p.fills += 1;
+            }
+            None => self.pending.push(PendingFill {
+                trade,
+                usdc_raw,
+                token_raw,
+                fills: 1,
+                since: tokio::time::Instant::now(),
+            }),
+        }
+        ready
+    }
+
+    /// When the oldest held fill is due, if any.
+    fn next_deadline(&self) -> Option<tokio::time::Instant> {
+        self.pending.iter().map(|p| p.since + self.window).min()
+    }
+
+    fn flush_due(&mut self) -> Vec<LiveTrade> {
+        let window = self.window;
+        self.take_where(|p| p.since.elapsed() >= window)
+    }
+
+    fn flush_all(&mut self) -> Vec<LiveTrade> {
+        self.take_where(|_| true)
+    }
+
+    fn take_where(&mut self, pred: impl Fn(&PendingFill) -> bool) -> Vec<LiveTrade> {
+        let (ready, keep): (Vec<_>, Vec<_>) = self.pending.drain(..).partition(pred);
+        self.pending = keep;
+        ready.into_iter().map(PendingFill::into_trade).collect()
+    }
+}
 
 // ---------------------------------------------------------------------------
 // ABI
@@ -76,11 +230,6 @@ struct LogEntry {
 // RPC helper for eth_getBlockByNumber (block timestamp resolution)
 // ---------------------------------------------------------------------------
 
-#[derive(Deserialize)]
-struct RpcResponse<T> {
-    result: Option<T>,
-}
-
 #[derive(Deserialize)]
 struct BlockResult {
     timestamp: String,
@@ -88,25 +237,19 @@ struct BlockResult {
 
 async fn get_block_timestamp(
     http: &reqwest::Client,
-    rpc_url: &str,
+    rpc: &RpcUrls,
     block_hex: &str,
 ) -> Option<u64> {
-    let body = serde_json::json!({
-        "jsonrpc": "2.0",
-        "method": "eth_getBlockByNumber",
-        "params": [block_hex, false],
-        "id": 1
-    });
-    let resp = http
-        .post(rpc_url)
-        .json(&body)
-        .timeout(Duration::from_secs(5))
-        .send()
-        .await
-        .ok()?;
-    let rpc: RpcResponse<BlockResult> = resp.json().await.ok()?;
-    let ts_hex = rpc.result?.timestamp;
-    u64::from_str_radix(ts_hex.trim_start_matches("0x"), 16).ok()
+    let block: BlockResult = scanner::rpc_call(
+        http,
+        rpc,
+        "eth_getBlockByNumber",
+        serde_json::json!([block_hex, false]),
+    )
+    .await
+    .map_err(|e| tracing::debug!("WS subscriber: block timestamp for {block_hex} failed: {e}"))
+    .ok()?;
+    u64::from_str_radix(block.timestamp.trim_start_matches("0x"), 16).ok()
 }
 
 // ---------------------------------------------------------------------------
@@ -118,11 +261,22 @@ pub async fn run(
     mut trader_watch_rx: watch::Receiver<HashSet<String>>,
     market_cache: markets::MarketCache,
     http: reqwest::Client,
-    rpc_url: String,
+    rpc: RpcUrls,
+    metrics: Arc<EngineMetrics>,
 ) {
-    let ws_url = std::env::var("POLYGON_WS_URL").unwrap_or_else(|_| {
-        "".into()
-    });
+    let ws_url = std::env::var("POLYGON_WS_URL").unwrap_or_else(|_| "".into());
+    let policy = ReconnectPolicy::from_env();
+    tracing::info!(
+        "WS subscriber: reconnect backoff {}ms..{}ms (jitter={})",
+        policy.base.as_millis(),
+        policy.max.as_millis(),
+        policy.jitter
+    );
+    let mut aggregator = FillAggregator::from_env();
+    tracing::info!(
+        "WS subscriber: fill aggregation window {}ms",
+        aggregator.window.as_millis()
+    );
 
     // Wait for market cache to warm before subscribing
     tokio::time::sleep(Duration::from_secs(10)).await;
@@ -158,8 +312,11 @@ pub async fn run(
             &mut trader_watch_rx,
             &market_cache,
             &http,
-            &rpc_url,
+            &rpc,
             &ws_url,
+            policy,
+            &metrics,
+            &mut aggregator,
         )
         .await;
     }
@@ -169,16 +326,20 @@ pub async fn run(
 // Subscribe and process loop
 // ---------------------------------------------------------------------------
 
+#[allow(clippy::too_many_arguments)]
 async fn subscribe_and_process(
     addrs: &HashSet<String>,
     copytrade_tx: &broadcast::Sender<LiveTrade>,
     trader_watch_rx: &mut watch::Receiver<HashSet<String>>,
     market_cache: &markets::MarketCache,
     http: &reqwest::Client,
-    rpc_url: &str,
+    rpc: &RpcUrls,
     ws_url: &str,
+    policy: ReconnectPolicy,
+    metrics: &EngineMetrics,
+    aggregator: &mut FillAggregator,
 ) {
-    let mut backoff = RECONNECT_BASE_DELAY;
+    let mut backoff = policy.base;
 
     loop {
         // Check if address set changed while reconnecting
@@ -199,7 +360,7 @@ async fn subscribe_and_process(
 
         match tokio_tungstenite::connect_async(ws_url).await {
             Ok((ws_stream, _)) => {
-                backoff = RECONNECT_BASE_DELAY;
+                backoff = policy.base;
                 let (mut write, mut read) = ws_stream.split();
 
                 // Build topic filter with maker addresses (topic[2])
@@ -223,8 +384,7 @@ async fn subscribe_and_process(
 
                 if let Err(e) = write.send(Message::Text(subscribe_msg.to_string())).await {
                     tracing::warn!("WS subscriber: failed to send subscribe: {e}");
-                    tokio::time::sleep(backoff).await;
-                    backoff = (backoff * 2).min(RECONNECT_MAX_DELAY);
+                    backoff = policy.sleep(backoff).await;
                     continue;
                 }
 
@@ -245,22 +405,19 @@ async fn subscribe_and_process(
                                     "WS subscriber: subscription rejected: {:?}",
                                     resp.error
                                 );
-                                tokio::time::sleep(backoff).await;
-                                backoff = (backoff * 2).min(RECONNECT_MAX_DELAY);
+                                backoff = policy.sleep(backoff).await;
                                 continue;
                             }
                             Err(e) => {
                                 tracing::warn!("WS subscriber: unexpected response: {e} — {text}");
-                                tokio::time::sleep(backoff).await;
-                                backoff = (backoff * 2).min(RECONNECT_MAX_DELAY);
+                                backoff = policy.sleep(backoff).await;
                                 continue;
                             }
                         }
                     }
                     other => {
                         tracing::warn!("WS subscriber: no subscription response: {other:?}");
-                        tokio::time::sleep(backoff).await;
-                        backoff = (backoff * 2).min(RECONNECT_MAX_DELAY);
+                        backoff = policy.sleep(backoff).await;
                         continue;
                     }
                 };
@@ -272,7 +429,13 @@ async fn subscribe_and_process(
                 let mut cached_block: Option<(u64, u64)> = None;
 
                 loop {
+                    let flush_at = aggregator.next_deadline();
                     tokio::select! {
+                        _ = tokio::time::sleep_until(flush_at.unwrap_or_else(tokio::time::Instant::now)), if flush_at.is_some() => {
+                            for trade in aggregator.flush_due() {
+                                let _ = copytrade_tx.send(trade);
+                            }
+                        }
                         msg = read.next() => {
                             match msg {
                                 Some(Ok(Message::Text(text))) => {
@@ -308,14 +471,17 @@ async fn subscribe_and_process(
 
                                     event_count += 1;
 
-                                    if let Some((trade, _usdc_raw)) = decode_order_filled(
+                                    if let Some((trade, usdc_raw, token_raw)) = decode_order_filled(
                                         &log_entry,
                                         market_cache,
                                         http,
-                                        rpc_url,
+                                        rpc,
                                         &mut cached_block,
+                                        metrics,
                                     ).await {
-                                        let _ = copytrade_tx.send(trade);
+                                        for trade in aggregator.push(trade, usdc_raw, token_raw) {
+                                            let _ = copytrade_tx.send(trade);
+                                        }
                                     }
                                 }
                                 Some(Ok(Message::Ping(data))) => {
@@ -358,12 +524,18 @@ async fn subscribe_and_process(
                                 "params": [sub_id]
                             });
                             let _ = write.send(Message::Text(unsub_msg.to_string())).await;
+                            for trade in aggregator.flush_all() {
+                                let _ = copytrade_tx.send(trade);
+                            }
                             return;
                         }
                     }
                 }
 
-                // WS disconnected — outer loop will reconnect
+                // WS disconnected — emit what was held, outer loop will reconnect
+                for trade in aggregator.flush_all() {
+                    let _ = copytrade_tx.send(trade);
+                }
             }
             Err(e) => {
                 tracing::warn!("WS subscriber: connection failed: {e}");
@@ -371,8 +543,7 @@ async fn subscribe_and_process(
         }
 
         tracing::info!("WS subscriber: reconnecting in {}s", backoff.as_secs());
-        tokio::time::sleep(backoff).await;
-        backoff = (backoff * 2).min(RECONNECT_MAX_DELAY);
+        backoff = policy.sleep(backoff).await;
     }
 }
 
@@ -399,9 +570,10 @@ async fn decode_order_filled(
     log_entry: &LogEntry,
     market_cache: &markets::MarketCache,
     http: &reqwest::Client,
-    rpc_url: &str,
+    rpc: &RpcUrls,
     cached_block: &mut Option<(u64, u64)>,
-) -> Option<(LiveTrade, u128)> {
+    metrics: &EngineMetrics,
+) -> Option<(LiveTrade, u128, u128)> {
     let topics: Vec<B256> = log_entry
         .topics
         .iter()
@@ -427,7 +599,9 @@ async fn decode_order_filled(
     } else if taker_asset_id.is_zero() {
         ("sell", maker_asset_id, taker_amount, maker_amount)
     } else {
-        tracing::debug!("WS subscriber: both asset IDs non-zero, skipping");
+        // Mint/merge settlement: no USDC leg to copy (see EngineMetrics::record_token_swap_fill)
+        metrics.record_token_swap_fill();
+        tracing::debug!("WS subscriber: both asset IDs non-zero (mint/merge), skipping");
         return None;
     };
 
@@ -440,7 +614,7 @@ async fn decode_order_filled(
     let block_timestamp = match cached_block {
         Some((cached_num, cached_ts)) if *cached_num == block_number => *cached_ts,
         _ => {
-            let ts = get_block_timestamp(http, rpc_url, &log_entry.block_number)
+            let ts = get_block_timestamp(http, rpc, &log_entry.block_number)
                 .await
                 .unwrap_or_else(|| chrono::Utc::now().timestamp() as u64);
             *cached_block = Some((block_number, ts));
@@ -448,26 +622,12 @@ async fn decode_order_filled(
         }
     };
 
-    let usdc_whole = usdc_raw_u128 / 1_000_000;
-    let usdc_frac = usdc_raw_u128 % 1_000_000;
-    let usdc_str = format!("{usdc_whole}.{usdc_frac:06}");
-
-    let token_whole = token_raw_u128 / 1_000_000;
-    let token_frac = token_raw_u128 % 1_000_000;
-    let token_str = format!("{token_whole}.{token_frac:06}");
-
-    let price = if token_raw_u128 > 0 {
-        usdc_raw_u128 as f64 / token_raw_u128 as f64
-    } else {
-        0.0
-    };
-
     let asset_id_str = asset_id.to_string();
     let cache_key = markets::cache_key(&asset_id_str);
     let cache = market_cache.read().await;
     let info = cache.get(&cache_key);
 
-    let trade = LiveTrade {
+    let mut trade = LiveTrade {
         tx_hash: log_entry.transaction_hash.clone(),
         block_timestamp: block_timestamp.to_string(),
         trader: format!("{:?}", maker),
@@ -475,9 +635,9 @@ async fn decode_order_filled(
         asset_id: info
             .map(|i| i.gamma_token_id.clone())
             .unwrap_or_else(|| markets::to_integer_id(&asset_id_str)),
-        amount: token_str,
-        price: format!("{price:.6}"),
-        usdc_amount: usdc_str,
+        amount: String::new(),
+        price: String::new(),
+        usdc_amount: String::new(),
         question: info.map(|i| i.question.clone()).unwrap_or_default(),
         outcome: info.map(|i| i.outcome.clone()).unwrap_or_default(),
         category: info.map(|i| i.category.clone()).unwrap_or_default(),
@@ -485,5 +645,87 @@ async fn decode_order_filled(
         cache_key,
     };
 
-    Some((trade, usdc_raw_u128))
+    set_amounts(&mut trade, usdc_raw_u128, token_raw_u128);
+
+    Some((trade, usdc_raw_u128, token_raw_u128))
+}
+
+/// Fills the amount/price strings from raw 6-decimal USDC and token amounts.
+fn set_amounts(trade: &mut LiveTrade, usdc_raw: u128, token_raw: u128) {
+    let usdc_whole = usdc_raw / 1_000_000;
+    let usdc_frac = usdc_raw % 1_000_000;
+    trade.usdc_amount = format!("{usdc_whole}.{usdc_frac:06}");
+
+    let token_whole = token_raw / 1_000_000;
+    let token_frac = token_raw % 1_000_000;
+    trade.amount = format!("{token_whole}.{token_frac:06}");
+
+    let price = if token_raw > 0 {
+        usdc_raw as f64 / token_raw as f64
+    } else {
+        0.0
+    };
+    trade.price = format!("{price:.6}");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fill(tx_hash: &str, usdc_raw: u128, token_raw: u128) -> (LiveTrade, u128, u128) {
+        let mut trade = LiveTrade {
+            tx_hash: tx_hash.into(),
+            block_timestamp: String::new(),
+            trader: "0xmaker".into(),
+            side: "buy".into(),
+            asset_id: "asset".into(),
+            amount: String::new(),
+            price: String::new(),
+            usdc_amount: String::new(),
+            question: String::new(),
+            outcome: String::new(),
+            category: String::new(),
+            block_number: 1,
+            cache_key: String::new(),
+        };
+        set_amounts(&mut trade, usdc_raw, token_raw);
+        (trade, usdc_raw, token_raw)
+    }
+
+    #[test]
+    fn partial_fills_of_one_tx_are_coalesced() {
+        let mut aggregator = FillAggregator {
+            window: FILL_AGGREGATE_WINDOW,
+            pending: Vec::new(),
+        };
+        let (trade, usdc, tokens) = fill("0xa", 4_000_000, 10_000_000);
+        assert!(aggregator.push(trade, usdc, tokens).is_empty());
+        let (trade, usdc, tokens) = fill("0xa", 6_000_000, 10_000_000);
+        assert!(aggregator.push(trade, usdc, tokens).is_empty());
+
+        // A log from the next transaction releases the merged trade
+        let (trade, usdc, tokens) = fill("0xb", 1_000_000, 2_000_000);
+        let ready = aggregator.push(trade, usdc, tokens);
+        assert_eq!(ready.len(), 1);
+        assert_eq!(ready[0].usdc_amount, "10.000000");
+        assert_eq!(ready[0].amount, "20.000000");
+        assert_eq!(ready[0].price, "0.500000");
+
+        let rest = aggregator.flush_all();
+        assert_eq!(rest.len(), 1);
+        assert_eq!(rest[0].tx_hash, "0xb");
+    }
+
+    #[test]
+    fn zero_window_emits_every_fill() {
+        let mut aggregator = FillAggregator {
+            window: Duration::ZERO,
+            pending: Vec::new(),
+        };
+        let (trade, usdc, tokens) = fill("0xa", 4_000_000, 10_000_000);
+        assert_eq!(aggregator.push(trade, usdc, tokens).len(), 1);
+        let (trade, usdc, tokens) = fill("0xa", 6_000_000, 10_000_000);
+        assert_eq!(aggregator.push(trade, usdc, tokens).len(), 1);
+        assert!(aggregator.next_deadline().is_none());
+    }
 }
@@ -27,5 +27,5 @@ async fn main() {
         .with_password(&clickhouse_password)
         .with_database(&clickhouse_db);
 
-    api::server::run(client, port).await;
+    api::server::run(client, clickhouse_db, port).await;
 }
@@ -0,0 +1,1419 @@
+import { describe, test, expect, beforeAll } from "bun:test";
+import { api, waitForServer, testUser } from "./helpers";
+
+// ---------------------------------------------------------------------------
+// Types (mirrored from frontend/src/types.ts — kept minimal for tests)
+// ---------------------------------------------------------------------------
+
+interface TraderList {
+  id: string;
+  name: string;
+}
+
+interface CopyTradeSession {
+  id: string;
+  list_id: string | null;
+  copy_pct: number;
+  initial_capital: number;
+  simulate: boolean;
+  status: string;
+}
+
+interface SessionComparison {
+  a: { session_id: string; stats: { total_orders: number; total_pnl: number } };
+  b: { session_id: string; stats: { total_orders: number; total_pnl: number } };
+  delta: { total_pnl: number; win_rate: number; avg_slippage_bps: number; total_orders: number };
+}
+
+/** Helper: create a one-member trader list and return its id */
+async function createList(token: string): Promise<string> {
+  const list = await api<TraderList>("POST", "/api/lists", {
+    token,
+    body: { name: `sim-${Date.now()}` },
+  });
+  expect(list.status).toBe(201);
+  await api("POST", `/api/lists/${list.data.id}/members`, {
+    token,
+    body: { addresses: ["0x00000000000000000000000000000000000000aa"] },
+  });
+  return list.data.id;
+}
+
+const sleep = (ms: number) => new Promise((r) => setTimeout(r, ms));
+
+// ---------------------------------------------------------------------------
+// Setup
+// ---------------------------------------------------------------------------
+
+beforeAll(async () => {
+  await waitForServer();
+});
+
+// ---------------------------------------------------------------------------
+// Simulation sessions without any trading wallet
+// ---------------------------------------------------------------------------
+
+describe("simulate session with zero wallets", () => {
+  test("starts, pauses, resumes and stops without CLOB credentials", async () => {
+    const { token } = testUser();
+    const listId = await createList(token);
+
+    const created = await api<CopyTradeSession>("POST", "/api/copytrade/sessions", {
+      token,
+      body: {
+        list_id: listId,
+        copy_pct: 0.5,
+        initial_capital: 100,
+        simulate: true,
+      },
+    });
+    expect(created.status).toBe(200);
+    expect(created.data.simulate).toBe(true);
+    const id = created.data.id;
+
+    // Engine would mark the session stopped if it tried (and failed) to init a CLOB client
+    await sleep(500);
+    let session = await api<CopyTradeSession>("GET", `/api/copytrade/sessions/${id}`, { token });
+    expect(session.data.status).toBe("running");
+
+    const paused = await api<CopyTradeSession>("PATCH", `/api/copytrade/sessions/${id}`, {
+      token,
+      body: { action: "pause" },
+    });
+    expect(paused.data.status).toBe("paused");
+
+    const resumed = await api<CopyTradeSession>("PATCH", `/api/copytrade/sessions/${id}`, {
+      token,
+      body: { action: "resume" },
+    });
+    expect(resumed.data.status).toBe("running");
+
+    await sleep(500);
+    session = await api<CopyTradeSession>("GET", `/api/copytrade/sessions/${id}`, { token });
+    expect(session.data.status).toBe("running");
+
+    const stopped = await api<CopyTradeSession>("PATCH", `/api/copytrade/sessions/${id}`, {
+      token,
+      body: { action: "stop" },
+    });
+    expect(stopped.data.status).toBe("stopped");
+
+    const deleted = await api("DELETE", `/api/copytrade/sessions/${id}`, { token });
+    expect(deleted.status).toBe(204);
+  });
+
+  test("preflight reports config and wallet blockers without creating a session", async () => {
+    const { token } = testUser();
+    const listId = await createList(token);
+    type Preflight = { ready: boolean; config_error: string | null; credentialed: boolean; issues: string[] };
+
+    const sim = await api<Preflight>("POST", "/api/copytrade/sessions/preflight", {
+      token,
+      body: { list_id: listId, copy_pct: 0.5, initial_capital: 100, simulate: true },
+    });
+    expect(sim.status).toBe(200);
+    expect(sim.data.ready).toBe(true);
+    expect(sim.data.issues).toEqual([]);
+
+    const badConfig = await api<Preflight>("POST", "/api/copytrade/sessions/preflight", {
+      token,
+      body: { list_id: listId, copy_pct: 5, initial_capital: 100, simulate: true },
+    });
+    expect(badConfig.status).toBe(200);
+    expect(badConfig.data.ready).toBe(false);
+    expect(badConfig.data.config_error).toContain("copy_pct");
+
+    // No credentialed, funded wallet in the test environment
+    const live = await api<Preflight>("POST", "/api/copytrade/sessions/preflight", {
+      token,
+      body: { list_id: listId, copy_pct: 0.5, initial_capital: 100, simulate: false },
+    });
+    expect(live.status).toBe(200);
+    expect(live.data.ready).toBe(false);
+    expect(live.data.issues.length).toBeGreaterThan(0);
+  });
+
+  test("creates a whale_follow session without a trader source", async () => {
+    const { token } = testUser();
+    const listId = await createList(token);
+    const base = { copy_pct: 0.5, initial_capital: 100, simulate: true, signal_source: "whale_follow" };
+
+    for (const bad of [
+      {},
+      { min_whale_usdc: 1000 },
+      { min_whale_usdc: 50000, list_id: listId },
+    ]) {
+      const res = await api("POST", "/api/copytrade/sessions", { token, body: { ...base, ...bad } });
+      expect(res.status).toBe(400);
+    }
+    const mixed = await api("POST", "/api/copytrade/sessions", {
+      token,
+      body: { list_id: listId, copy_pct: 0.5, initial_capital: 100, simulate: true, min_whale_usdc: 50000 },
+    });
+    expect(mixed.status).toBe(400);
+
+    const created = await api<CopyTradeSession>("POST", "/api/copytrade/sessions", {
+      token,
+      body: { ...base, min_whale_usdc: 50000 },
+    });
+    expect(created.status).toBe(200);
+    expect(created.data.signal_source).toBe("whale_follow");
+    expect(created.data.min_whale_usdc).toBe(50000);
+    expect(created.data.list_id).toBeNull();
+
+    await api("PATCH", `/api/copytrade/sessions/${created.data.id}`, { token, body: { action: "stop" } });
+    await api("DELETE", `/api/copytrade/sessions/${created.data.id}`, { token });
+  });
+
+  test("caps active sessions per user (default MAX_ACTIVE_SESSIONS_PER_USER=10)", async () => {
+    const { token } = testUser();
+    const listId = await createList(token);
+    const base = { list_id: listId, copy_pct: 0.5, initial_capital: 100, simulate: true };
+
+    const ids: string[] = [];
+    for (let i = 0; i < 10; i++) {
+      const created = await api<CopyTradeSession>("POST", "/api/copytrade/sessions", { token, body: base });
+      expect(created.status).toBe(200);
+      ids.push(created.data.id);
+    }
+
+    const over = await api("POST", "/api/copytrade/sessions", { token, body: base });
+    expect(over.status).toBe(409);
+
+    // Stopping one frees a slot
+    await api("PATCH", `/api/copytrade/sessions/${ids[0]}`, { token, body: { action: "stop" } });
+    const again = await api<CopyTradeSession>("POST", "/api/copytrade/sessions", { token, body: base });
+    expect(again.status).toBe(200);
+    ids.push(again.data.id);
+
+    for (const id of ids) {
+      await api("PATCH", `/api/copytrade/sessions/${id}`, { token, body: { action: "stop" } });
+      await api("DELETE", `/api/copytrade/sessions/${id}`, { token });
+    }
+  });
+
+  test("rejects auto_approve on a simulated session", async () => {
+    const { token } = testUser();
+    const listId = await createList(token);
+    const res = await api("POST", "/api/copytrade/sessions", {
+      token,
+      body: { list_id: listId, copy_pct: 0.5, initial_capital: 100, simulate: true, auto_approve: true },
+    });
+    expect(res.status).toBe(400);
+  });
+
+  test("live session is rejected without a credentialed wallet", async () => {
+    const { token } = testUser();
+    const listId = await createList(token);
+
+    const res = await api("POST", "/api/copytrade/sessions", {
+      token,
+      body: { list_id: listId, copy_pct: 0.5, initial_capital: 100 },
+    });
+    expect(res.status).toBe(400);
+  });
+});
+
+// ---------------------------------------------------------------------------
+// list_id + top_n hybrid ("top N within a list")
+// ---------------------------------------------------------------------------
+
+describe("list_id + top_n sessions", () => {
+  test("tracks at most N traders, all drawn from the list", async () => {
+    const { token } = testUser();
+    const members = [
+      "0x00000000000000000000000000000000000000b1",
+      "0x00000000000000000000000000000000000000b2",
+      "0x00000000000000000000000000000000000000b3",
+    ];
+    const list = await api<TraderList>("POST", "/api/lists", {
+      token,
+      body: { name: `hybrid-${Date.now()}` },
+    });
+    await api("POST", `/api/lists/${list.data.id}/members`, {
+      token,
+      body: { addresses: members },
+    });
+
+    const session = await api<CopyTradeSession>("POST", "/api/copytrade/sessions", {
+      token,
+      body: { list_id: list.data.id, top_n: 2, copy_pct: 0.5, initial_capital: 100, simulate: true },
+    });
+    expect(session.status).toBe(200);
+    expect(session.data.list_id).toBe(list.data.id);
+
+    const tracked = await api<string[]>("GET", "/api/copytrade/active-traders", { token });
+    expect(tracked.status).toBe(200);
+    expect(tracked.data.length).toBeLessThanOrEqual(2);
+    for (const addr of tracked.data) {
+      expect(members).toContain(addr.toLowerCase());
+    }
+
+    await api("PATCH", `/api/copytrade/sessions/${session.data.id}`, { token, body: { action: "stop" } });
+    await api("DELETE", `/api/copytrade/sessions/${session.data.id}`, { token });
+  });
+
+  test("rejects top_pct combined with a list", async () => {
+    const { token } = testUser();
+    const listId = await createList(token);
+    const res = await api("POST", "/api/copytrade/sessions", {
+      token,
+      body: { list_id: listId, top_pct: 5, copy_pct: 0.5, initial_capital: 100, simulate: true },
+    });
+    expect(res.status).toBe(400);
+  });
+
+  test("validates top_n_metric and ranks the list by it", async () => {
+    const { token } = testUser();
+    const listId = await createList(token);
+    const base = { copy_pct: 0.5, initial_capital: 100, simulate: true };
+
+    for (const bad of [
+      { list_id: listId, top_n: 2, top_n_metric: "sharpe" },
+      { list_id: listId, top_n_metric: "roi" },
+    ]) {
+      const res = await api("POST", "/api/copytrade/sessions", { token, body: { ...base, ...bad } });
+      expect(res.status).toBe(400);
+    }
+
+    const session = await api<CopyTradeSession & { top_n_metric: string }>(
+      "POST",
+      "/api/copytrade/sessions",
+      { token, body: { ...base, list_id: listId, top_n: 2, top_n_metric: "consistency" } },
+    );
+    expect(session.status).toBe(200);
+    expect(session.data.top_n_metric).toBe("consistency");
+
+    await api("PATCH", `/api/copytrade/sessions/${session.data.id}`, { token, body: { action: "stop" } });
+    await api("DELETE", `/api/copytrade/sessions/${session.data.id}`, { token });
+  });
+});
+
+// ---------------------------------------------------------------------------
+// POST /api/copytrade/sessions/:id/clone
+// ---------------------------------------------------------------------------
+
+describe("POST /api/copytrade/sessions/:id/clone", () => {
+  test("copies config with a new capital allocation", async () => {
+    const { token } = testUser();
+    const listId = await createList(token);
+
+    const source = await api<CopyTradeSession>("POST", "/api/copytrade/sessions", {
+      token,
+      body: { list_id: listId, copy_pct: 0.25, initial_capital: 100, simulate: true },
+    });
+    expect(source.status).toBe(200);
+
+    const clone = await api<CopyTradeSession>(
+      "POST",
+      `/api/copytrade/sessions/${source.data.id}/clone`,
+      { token, body: { initial_capital: 500 } },
+    );
+    expect(clone.status).toBe(200);
+    expect(clone.data.id).not.toBe(source.data.id);
+    expect(clone.data.list_id).toBe(listId);
+    expect(clone.data.copy_pct).toBe(0.25);
+    expect(clone.data.initial_capital).toBe(500);
+    expect(clone.data.simulate).toBe(true);
+    expect(clone.data.status).toBe("running");
+
+    for (const id of [source.data.id, clone.data.id]) {
+      await api("PATCH", `/api/copytrade/sessions/${id}`, { token, body: { action: "stop" } });
+      await api("DELETE", `/api/copytrade/sessions/${id}`, { token });
+    }
+  });
+
+  test("returns 404 for another user's session", async () => {
+    const owner = testUser();
+    const other = testUser();
+    const listId = await createList(owner.token);
+
+    const source = await api<CopyTradeSession>("POST", "/api/copytrade/sessions", {
+      token: owner.token,
+      body: { list_id: listId, copy_pct: 0.5, initial_capital: 100, simulate: true },
+    });
+    const res = await api("POST", `/api/copytrade/sessions/${source.data.id}/clone`, {
+      token: other.token,
+      body: { initial_capital: 100 },
+    });
+    expect(res.status).toBe(404);
+  });
+});
+
+// ---------------------------------------------------------------------------
+// /api/copytrade/templates
+// ---------------------------------------------------------------------------
+
+describe("session templates", () => {
+  test("creates a session from a template with per-request overrides", async () => {
+    const { token } = testUser();
+    const listId = await createList(token);
+
+    const saved = await api<{ id: string; name: string; config: Record<string, unknown> }>(
+      "POST",
+      "/api/copytrade/templates",
+      { token, body: { name: "conservative", config: { list_id: listId, copy_pct: 0.2, simulate: true, initial_capital: 999 } } },
+    );
+    expect(saved.status).toBe(200);
+    expect(saved.data.config.initial_capital).toBeUndefined();
+
+    const session = await api<CopyTradeSession>("POST", "/api/copytrade/sessions", {
+      token,
+      body: { template_id: saved.data.id, initial_capital: 250, copy_pct: 0.3 },
+    });
+    expect(session.status).toBe(200);
+    expect(session.data.list_id).toBe(listId);
+    expect(session.data.copy_pct).toBe(0.3);
+    expect(session.data.initial_capital).toBe(250);
+
+    await api("PATCH", `/api/copytrade/sessions/${session.data.id}`, { token, body: { action: "stop" } });
+    await api("DELETE", `/api/copytrade/sessions/${session.data.id}`, { token });
+    const deleted = await api("DELETE", `/api/copytrade/templates/${saved.data.id}`, { token });
+    expect(deleted.status).toBe(204);
+  });
+
+  test("merged config goes through normal validation", async () => {
+    const { token } = testUser();
+    const listId = await createList(token);
+    const saved = await api<{ id: string }>("POST", "/api/copytrade/templates", {
+      token,
+      body: { name: "aggressive", config: { list_id: listId, copy_pct: 0.5, simulate: true } },
+    });
+
+    const res = await api("POST", "/api/copytrade/sessions", {
+      token,
+      body: { template_id: saved.data.id, initial_capital: 100, copy_pct: 5 },
+    });
+    expect(res.status).toBe(400);
+  });
+
+  test("templates are owner-scoped", async () => {
+    const { token: ownerToken } = testUser();
+    const saved = await api<{ id: string }>("POST", "/api/copytrade/templates", {
+      token: ownerToken,
+      body: { name: "mine", config: { top_n: 5, copy_pct: 0.5 } },
+    });
+    expect(saved.status).toBe(200);
+
+    const { token } = testUser();
+    const res = await api("POST", "/api/copytrade/sessions", {
+      token,
+      body: { template_id: saved.data.id, initial_capital: 100 },
+    });
+    expect(res.status).toBe(404);
+    const get = await api("GET", `/api/copytrade/templates/${saved.data.id}`, { token });
+    expect(get.status).toBe(404);
+  });
+});
+
+// ---------------------------------------------------------------------------
+// GET /api/copytrade/orders
+// ---------------------------------------------------------------------------
+
+describe("GET /api/copytrade/orders", () => {
+  test("returns an empty page for a user without orders", async () => {
+    const { token } = testUser();
+    const res = await api<{ orders: unknown[]; next_cursor: string | null }>(
+      "GET",
+      "/api/copytrade/orders?limit=10",
+      { token },
+    );
+    expect(res.status).toBe(200);
+    expect(res.data.orders).toEqual([]);
+    expect(res.data.next_cursor).toBeNull();
+  });
+
+  test("rejects a malformed cursor", async () => {
+    const { token } = testUser();
+    const res = await api("GET", "/api/copytrade/orders?cursor=garbage", { token });
+    expect(res.status).toBe(400);
+  });
+});
+
+// ---------------------------------------------------------------------------
+// GET /api/copytrade/orders/:order_id
+// ---------------------------------------------------------------------------
+
+describe("GET /api/copytrade/orders/:order_id", () => {
+  test("returns 404 for an unknown order", async () => {
+    const { token } = testUser();
+    const res = await api("GET", `/api/copytrade/orders/${crypto.randomUUID()}`, { token });
+    expect(res.status).toBe(404);
+  });
+
+  test("requires authentication", async () => {
+    const res = await api("GET", `/api/copytrade/orders/${crypto.randomUUID()}`);
+    expect(res.status).toBe(401);
+  });
+});
+
+// ---------------------------------------------------------------------------
+// POST /api/copytrade/sessions/:id/what-if
+// ---------------------------------------------------------------------------
+
+describe("POST /api/copytrade/sessions/:id/what-if", () => {
+  test("skips a sell when the session holds no position", async () => {
+    const { token } = testUser();
+    const listId = await createList(token);
+    const session = await api<CopyTradeSession>("POST", "/api/copytrade/sessions", {
+      token,
+      body: { list_id: listId, copy_pct: 0.5, initial_capital: 100, simulate: true },
+    });
+
+    const res = await api<{ decision: string; skip_reason: string | null; order_usdc: number | null }>(
+      "POST",
+      `/api/copytrade/sessions/${session.data.id}/what-if`,
+      { token, body: { asset_id: "123", side: "sell", source_price: 0.5, source_usdc: 50 } },
+    );
+    expect(res.status).toBe(200);
+    expect(res.data.decision).toBe("skip");
+    expect(res.data.skip_reason).toBe("no_position");
+    expect(res.data.order_usdc).toBeNull();
+
+    await api("PATCH", `/api/copytrade/sessions/${session.data.id}`, { token, body: { action: "stop" } });
+    await api("DELETE", `/api/copytrade/sessions/${session.data.id}`, { token });
+  });
+
+  test("guards extreme outcome prices", async () => {
+    const { token } = testUser();
+    const listId = await createList(token);
+    const session = await api<CopyTradeSession>("POST", "/api/copytrade/sessions", {
+      token,
+      body: { list_id: listId, copy_pct: 0.5, initial_capital: 100, simulate: true },
+    });
+    const whatIf = (source_price: number) =>
+      api<{ decision: string; skip_reason: string | null }>(
+        "POST",
+        `/api/copytrade/sessions/${session.data.id}/what-if`,
+        { token, body: { asset_id: "123", side: "sell", source_price, source_usdc: 50 } },
+      );
+
+    // The bounds themselves are valid prices: sizing proceeds to the position check
+    for (const price of [0.001, 0.999]) {
+      const res = await whatIf(price);
+      expect(res.status).toBe(200);
+      expect(res.data.skip_reason).toBe("no_position");
+    }
+    for (const price of [0.0005, 0.9995]) {
+      const res = await whatIf(price);
+      expect(res.status).toBe(200);
+      expect(res.data.decision).toBe("skip");
+      expect(res.data.skip_reason).toBe("price_out_of_range");
+    }
+
+    await api("PATCH", `/api/copytrade/sessions/${session.data.id}`, { token, body: { action: "stop" } });
+    await api("DELETE", `/api/copytrade/sessions/${session.data.id}`, { token });
+  });
+
+  test("skips a signal older than max_signal_age_secs", async () => {
+    const { token } = testUser();
+    const listId = await createList(token);
+    const bad = await api("POST", "/api/copytrade/sessions", {
+      token,
+      body: { list_id: listId, copy_pct: 0.5, initial_capital: 100, simulate: true, max_signal_age_secs: 0 },
+    });
+    expect(bad.status).toBe(400);
+
+    const session = await api<CopyTradeSession>("POST", "/api/copytrade/sessions", {
+      token,
+      body: { list_id: listId, copy_pct: 0.5, initial_capital: 100, simulate: true, max_signal_age_secs: 60 },
+    });
+    expect(session.status).toBe(200);
+    const whatIf = (source_timestamp: number) =>
+      api<{ decision: string; skip_reason: string | null }>(
+        "POST",
+        `/api/copytrade/sessions/${session.data.id}/what-if`,
+        { token, body: { asset_id: "123", side: "sell", source_price: 0.5, source_usdc: 50, source_timestamp } },
+      );
+    const now = Math.floor(Date.now() / 1000);
+
+    const backdated = await whatIf(now - 600);
+    expect(backdated.status).toBe(200);
+    expect(backdated.data.decision).toBe("skip");
+    expect(backdated.data.skip_reason).toBe("stale_signal");
+
+    // Within the window the pipeline carries on to the position check
+    const fresh = await whatIf(now - 5);
+    expect(fresh.status).toBe(200);
+    expect(fresh.data.skip_reason).toBe("no_position");
+
+    await api("PATCH", `/api/copytrade/sessions/${session.data.id}`, { token, body: { action: "stop" } });
+    await api("DELETE", `/api/copytrade/sessions/${session.data.id}`, { token });
+  });
+
+  test("applies the session's signal filters for the side it would trade", async () => {
+    const { token } = testUser();
+    const listId = await createList(token);
+    const create = (extra: Record<string, unknown>) =>
+      api<CopyTradeSession>("POST", "/api/copytrade/sessions", {
+        token,
+        body: { list_id: listId, copy_pct: 0.5, initial_capital: 100, simulate: true, ...extra },
+      });
+    const whatIf = (id: string, side: string) =>
+      api<{ decision: string; side: string; skip_reason: string | null }>(
+        "POST",
+        `/api/copytrade/sessions/${id}/what-if`,
+        { token, body: { asset_id: "123", side, source_price: 0.5, source_usdc: 50 } },
+      );
+
+    // Fade turns a source buy into a sell, which needs a position
+    const fade = await create({ mode: "fade" });
+    const faded = await whatIf(fade.data.id, "buy");
+    expect(faded.status).toBe(200);
+    expect(faded.data.side).toBe("sell");
+    expect(faded.data.skip_reason).toBe("no_position");
+
+    const sellsOnly = await create({ copy_sides: "sell" });
+    const buy = await whatIf(sellsOnly.data.id, "buy");
+    expect(buy.data.decision).toBe("skip");
+    expect(buy.data.skip_reason).toBe("side_not_copied");
+
+    // "123" isn't in the market cache, so it has no category
+    const categorized = await create({ copy_uncategorized: false });
+    const uncategorized = await whatIf(categorized.data.id, "sell");
+    expect(uncategorized.data.skip_reason).toBe("uncategorized");
+
+    for (const s of [fade, sellsOnly, categorized]) {
+      await api("PATCH", `/api/copytrade/sessions/${s.data.id}`, { token, body: { action: "stop" } });
+      await api("DELETE", `/api/copytrade/sessions/${s.data.id}`, { token });
+    }
+  });
+
+  test("rejects an invalid side", async () => {
+    const { token } = testUser();
+    const listId = await createList(token);
+    const session = await api<CopyTradeSession>("POST", "/api/copytrade/sessions", {
+      token,
+      body: { list_id: listId, copy_pct: 0.5, initial_capital: 100, simulate: true },
+    });
+
+    const res = await api("POST", `/api/copytrade/sessions/${session.data.id}/what-if`, {
+      token,
+      body: { asset_id: "123", side: "hold", source_price: 0.5, source_usdc: 50 },
+    });
+    expect(res.status).toBe(400);
+  });
+});
+
+// ---------------------------------------------------------------------------
+// POST /api/copytrade/capacity-estimate
+// ---------------------------------------------------------------------------
+
+describe("POST /api/copytrade/capacity-estimate", () => {
+  test("returns one curve point per capital level", async () => {
+    const { token } = testUser();
+    const listId = await createList(token);
+    const res = await api<{ trader_count: number; curve: { capital: number; deployment_fraction: number }[] }>(
+      "POST",
+      "/api/copytrade/capacity-estimate",
+      { token, body: { list_id: listId, copy_pct: 0.5, days: 7, capital_levels: [1000, 100] } },
+    );
+    expect(res.status).toBe(200);
+    expect(res.data.trader_count).toBe(1);
+    expect(res.data.curve.map((p) => p.capital)).toEqual([100, 1000]);
+    for (const point of res.data.curve) {
+      expect(point.deployment_fraction).toBeGreaterThanOrEqual(0);
+      expect(point.deployment_fraction).toBeLessThanOrEqual(1);
+    }
+  });
+
+  test("returns 404 for another user's list", async () => {
+    const { token: ownerToken } = testUser();
+    const listId = await createList(ownerToken);
+    const { token } = testUser();
+    const res = await api("POST", "/api/copytrade/capacity-estimate", {
+      token,
+      body: { list_id: listId, copy_pct: 0.5 },
+    });
+    expect(res.status).toBe(404);
+  });
+
+  test("rejects an out-of-range window", async () => {
+    const { token } = testUser();
+    const listId = await createList(token);
+    const res = await api("POST", "/api/copytrade/capacity-estimate", {
+      token,
+      body: { list_id: listId, copy_pct: 0.5, days: 365 },
+    });
+    expect(res.status).toBe(400);
+  });
+});
+
+// ---------------------------------------------------------------------------
+// GET /api/copytrade/sessions/:id/throughput
+// ---------------------------------------------------------------------------
+
+describe("GET /api/copytrade/sessions/:id/throughput", () => {
+  test("reports zero activity for a fresh session", async () => {
+    const { token } = testUser();
+    const listId = await createList(token);
+    const session = await api<CopyTradeSession>("POST", "/api/copytrade/sessions", {
+      token,
+      body: { list_id: listId, copy_pct: 0.5, initial_capital: 100, simulate: true },
+    });
+
+    const res = await api<{
+      window_minutes: number;
+      orders: number;
+      skipped: number;
+      orders_per_minute: number;
+      avg_order_usdc: number | null;
+    }>("GET", `/api/copytrade/sessions/${session.data.id}/throughput?window=30`, { token });
+    expect(res.status).toBe(200);
+    expect(res.data.window_minutes).toBe(30);
+    expect(res.data.orders).toBe(0);
+    expect(res.data.skipped).toBe(0);
+    expect(res.data.orders_per_minute).toBe(0);
+    expect(res.data.avg_order_usdc).toBeNull();
+
+    await api("PATCH", `/api/copytrade/sessions/${session.data.id}`, { token, body: { action: "stop" } });
+    await api("DELETE", `/api/copytrade/sessions/${session.data.id}`, { token });
+  });
+
+  test("returns 404 for another user's session", async () => {
+    const owner = testUser();
+    const other = testUser();
+    const listId = await createList(owner.token);
+    const session = await api<CopyTradeSession>("POST", "/api/copytrade/sessions", {
+      token: owner.token,
+      body: { list_id: listId, copy_pct: 0.5, initial_capital: 100, simulate: true },
+    });
+
+    const res = await api("GET", `/api/copytrade/sessions/${session.data.id}/throughput`, {
+      token: other.token,
+    });
+    expect(res.status).toBe(404);
+  });
+});
+
+// ---------------------------------------------------------------------------
+// GET /api/copytrade/sessions/:id/diagnosis
+// ---------------------------------------------------------------------------
+
+describe("GET /api/copytrade/sessions/:id/diagnosis", () => {
+  test("reports engine state and issues for a simulated session", async () => {
+    const { token } = testUser();
+    const listId = await createList(token);
+    const session = await api<CopyTradeSession>("POST", "/api/copytrade/sessions", {
+      token,
+      body: { list_id: listId, copy_pct: 0.5, initial_capital: 100, simulate: true },
+    });
+
+    const res = await api<{
+      session_id: string;
+      status: string;
+      simulate: boolean;
+      engine: { remaining_capital: number; max_orders_per_minute: number } | null;
+      tracked_addresses: number;
+      clob_client_ready: boolean | null;
+      window_hours: number;
+      recent_orders: number;
+      skip_reasons: Record<string, number>;
+      issues: string[];
+    }>("GET", `/api/copytrade/sessions/${session.data.id}/diagnosis`, { token });
+    expect(res.status).toBe(200);
+    expect(res.data.session_id).toBe(session.data.id);
+    expect(res.data.status).toBe("running");
+    expect(res.data.simulate).toBe(true);
+    expect(res.data.clob_client_ready).toBeNull();
+    expect(res.data.engine).not.toBeNull();
+    expect(res.data.engine!.remaining_capital).toBe(100);
+    expect(res.data.window_hours).toBe(24);
+    expect(res.data.recent_orders).toBe(0);
+    expect(Array.isArray(res.data.issues)).toBe(true);
+
+    await api("PATCH", `/api/copytrade/sessions/${session.data.id}`, { token, body: { action: "stop" } });
+    const stopped = await api<{ engine: unknown; issues: string[] }>(
+      "GET",
+      `/api/copytrade/sessions/${session.data.id}/diagnosis`,
+      { token },
+    );
+    expect(stopped.status).toBe(200);
+    expect(stopped.data.engine).toBeNull();
+    expect(stopped.data.issues[0]).toBe("Session is stopped");
+
+    await api("DELETE", `/api/copytrade/sessions/${session.data.id}`, { token });
+  });
+
+  test("returns 404 for another user's session", async () => {
+    const owner = testUser();
+    const other = testUser();
+    const listId = await createList(owner.token);
+    const session = await api<CopyTradeSession>("POST", "/api/copytrade/sessions", {
+      token: owner.token,
+      body: { list_id: listId, copy_pct: 0.5, initial_capital: 100, simulate: true },
+    });
+
+    const res = await api("GET", `/api/copytrade/sessions/${session.data.id}/diagnosis`, {
+      token: other.token,
+    });
+    expect(res.status).toBe(404);
+  });
+});
+
+// ---------------------------------------------------------------------------
+// GET /api/copytrade/sessions/:id/stats
+// ---------------------------------------------------------------------------
+
+describe("GET /api/copytrade/sessions/:id/stats", () => {
+  test("reports no fees and bounded utilization for a fresh session", async () => {
+    const { token } = testUser();
+    const listId = await createList(token);
+    const session = await api<CopyTradeSession & { fees_paid: number }>(
+      "POST",
+      "/api/copytrade/sessions",
+      { token, body: { list_id: listId, copy_pct: 0.5, initial_capital: 100, simulate: true } },
+    );
+    expect(session.status).toBe(200);
+    expect(session.data.fees_paid).toBe(0);
+
+    const res = await api<{ fees_paid: number; capital_utilization: number; return_pct: number }>(
+      "GET",
+      `/api/copytrade/sessions/${session.data.id}/stats`,
+      { token },
+    );
+    expect(res.status).toBe(200);
+    expect(res.data.fees_paid).toBe(0);
+    expect(res.data.capital_utilization).toBeGreaterThanOrEqual(0);
+    expect(res.data.capital_utilization).toBeLessThanOrEqual(1);
+    expect(res.data.return_pct).toBe(0);
+
+    await api("PATCH", `/api/copytrade/sessions/${session.data.id}`, { token, body: { action: "stop" } });
+    await api("DELETE", `/api/copytrade/sessions/${session.data.id}`, { token });
+  });
+});
+
+// ---------------------------------------------------------------------------
+// GET /api/copytrade/sessions/:id/open-orders
+// ---------------------------------------------------------------------------
+
+describe("GET /api/copytrade/sessions/:id/open-orders", () => {
+  test("returns no resting orders for a fresh session", async () => {
+    const { token } = testUser();
+    const listId = await createList(token);
+    const session = await api<CopyTradeSession>("POST", "/api/copytrade/sessions", {
+      token,
+      body: { list_id: listId, copy_pct: 0.5, initial_capital: 100, simulate: true },
+    });
+
+    const res = await api<{
+      session_id: string;
+      engine_loaded: boolean;
+      total_reserved_usdc: number;
+      orders: unknown[];
+    }>("GET", `/api/copytrade/sessions/${session.data.id}/open-orders`, { token });
+    expect(res.status).toBe(200);
+    expect(res.data.session_id).toBe(session.data.id);
+    expect(res.data.orders).toEqual([]);
+    expect(res.data.total_reserved_usdc).toBe(0);
+
+    await api("PATCH", `/api/copytrade/sessions/${session.data.id}`, { token, body: { action: "stop" } });
+    const stopped = await api<{ engine_loaded: boolean }>(
+      "GET",
+      `/api/copytrade/sessions/${session.data.id}/open-orders`,
+      { token },
+    );
+    expect(stopped.status).toBe(200);
+    expect(stopped.data.engine_loaded).toBe(false);
+    await api("DELETE", `/api/copytrade/sessions/${session.data.id}`, { token });
+  });
+
+  test("returns 404 for another user's session", async () => {
+    const owner = testUser();
+    const other = testUser();
+    const listId = await createList(owner.token);
+    const session = await api<CopyTradeSession>("POST", "/api/copytrade/sessions", {
+      token: owner.token,
+      body: { list_id: listId, copy_pct: 0.5, initial_capital: 100, simulate: true },
+    });
+
+    const res = await api("GET", `/api/copytrade/sessions/${session.data.id}/open-orders`, {
+      token: other.token,
+    });
+    expect(res.status).toBe(404);
+  });
+});
+
+// ---------------------------------------------------------------------------
+// GET /api/copytrade/sessions/:id/tax-report
+// ---------------------------------------------------------------------------
+
+describe("GET /api/copytrade/sessions/:id/tax-report", () => {
+  test("returns a header-only CSV for a session without disposals", async () => {
+    const { token } = testUser();
+    const listId = await createList(token);
+    const session = await api<CopyTradeSession>("POST", "/api/copytrade/sessions", {
+      token,
+      body: { list_id: listId, copy_pct: 0.5, initial_capital: 100, simulate: true },
+    });
+
+    const res = await api("GET", `/api/copytrade/sessions/${session.data.id}/tax-report?year=2025`, { token });
+    expect(res.status).toBe(200);
+    expect(res.text.trim()).toBe(
+      "asset_id,market,outcome,acquired_at,disposed_at,shares,proceeds_usdc,cost_basis_usdc,gain_usdc",
+    );
+
+    const bad = await api("GET", `/api/copytrade/sessions/${session.data.id}/tax-report?year=1999`, { token });
+    expect(bad.status).toBe(400);
+
+    await api("PATCH", `/api/copytrade/sessions/${session.data.id}`, { token, body: { action: "stop" } });
+    await api("DELETE", `/api/copytrade/sessions/${session.data.id}`, { token });
+  });
+
+  test("returns 404 for another user's session", async () => {
+    const owner = testUser();
+    const other = testUser();
+    const listId = await createList(owner.token);
+    const session = await api<CopyTradeSession>("POST", "/api/copytrade/sessions", {
+      token: owner.token,
+      body: { list_id: listId, copy_pct: 0.5, initial_capital: 100, simulate: true },
+    });
+
+    const res = await api("GET", `/api/copytrade/sessions/${session.data.id}/tax-report`, {
+      token: other.token,
+    });
+    expect(res.status).toBe(404);
+  });
+});
+
+// ---------------------------------------------------------------------------
+// GET /api/copytrade/compare
+// ---------------------------------------------------------------------------
+
+describe("GET /api/copytrade/compare", () => {
+  test("returns both sessions' stats and their deltas", async () => {
+    const { token } = testUser();
+    const listId = await createList(token);
+    const create = (copy_pct: number) =>
+      api<CopyTradeSession>("POST", "/api/copytrade/sessions", {
+        token,
+        body: { list_id: listId, copy_pct, initial_capital: 100, simulate: true },
+      });
+    const a = await create(0.5);
+    const b = await create(0.25);
+
+    const res = await api<SessionComparison>("GET", `/api/copytrade/compare?a=${a.data.id}&b=${b.data.id}`, { token });
+    expect(res.status).toBe(200);
+    expect(res.data.a.session_id).toBe(a.data.id);
+    expect(res.data.b.session_id).toBe(b.data.id);
+    expect(res.data.a.stats.total_orders).toBe(0);
+    expect(res.data.delta.total_pnl).toBe(0);
+    expect(res.data.delta.total_orders).toBe(0);
+
+    for (const s of [a, b]) {
+      await api("PATCH", `/api/copytrade/sessions/${s.data.id}`, { token, body: { action: "stop" } });
+      await api("DELETE", `/api/copytrade/sessions/${s.data.id}`, { token });
+    }
+  });
+
+  test("returns 404 when either session belongs to another user", async () => {
+    const owner = testUser();
+    const other = testUser();
+    const ownerList = await createList(owner.token);
+    const otherList = await createList(other.token);
+    const mine = await api<CopyTradeSession>("POST", "/api/copytrade/sessions", {
+      token: owner.token,
+      body: { list_id: ownerList, copy_pct: 0.5, initial_capital: 100, simulate: true },
+    });
+    const theirs = await api<CopyTradeSession>("POST", "/api/copytrade/sessions", {
+      token: other.token,
+      body: { list_id: otherList, copy_pct: 0.5, initial_capital: 100, simulate: true },
+    });
+
+    const res = await api("GET", `/api/copytrade/compare?a=${mine.data.id}&b=${theirs.data.id}`, {
+      token: owner.token,
+    });
+    expect(res.status).toBe(404);
+  });
+});
+
+// ---------------------------------------------------------------------------
+// GET /api/copytrade/sessions/:id/liquidation-preview
+// ---------------------------------------------------------------------------
+
+describe("GET /api/copytrade/sessions/:id/liquidation-preview", () => {
+  test("is empty for a session without positions and 404s for other owners", async () => {
+    const owner = testUser();
+    const other = testUser();
+    const listId = await createList(owner.token);
+    const created = await api<CopyTradeSession>("POST", "/api/copytrade/sessions", {
+      token: owner.token,
+      body: { list_id: listId, copy_pct: 0.5, initial_capital: 100, simulate: true },
+    });
+    expect(created.status).toBe(200);
+
+    const preview = await api<{ total_expected_proceeds: number; positions: unknown[] }>(
+      "GET",
+      `/api/copytrade/sessions/${created.data.id}/liquidation-preview`,
+      { token: owner.token },
+    );
+    expect(preview.status).toBe(200);
+    expect(preview.data.positions).toEqual([]);
+    expect(preview.data.total_expected_proceeds).toBe(0);
+
+    const foreign = await api("GET", `/api/copytrade/sessions/${created.data.id}/liquidation-preview`, {
+      token: other.token,
+    });
+    expect(foreign.status).toBe(404);
+
+    await api("PATCH", `/api/copytrade/sessions/${created.data.id}`, { token: owner.token, body: { action: "stop" } });
+    await api("DELETE", `/api/copytrade/sessions/${created.data.id}`, { token: owner.token });
+  });
+});
+
+// ---------------------------------------------------------------------------
+// GET /api/admin/copytrade/overview
+// ---------------------------------------------------------------------------
+
+describe("GET /api/admin/copytrade/overview", () => {
+  test("is forbidden for non-admin users", async () => {
+    const { token } = testUser();
+    const res = await api("GET", "/api/admin/copytrade/overview", { token });
+    expect(res.status).toBe(403);
+  });
+});
+
+// ---------------------------------------------------------------------------
+// GET /api/admin/copytrade/audit
+// ---------------------------------------------------------------------------
+
+describe("GET /api/admin/copytrade/audit", () => {
+  test("is forbidden for non-admin users", async () => {
+    const { token } = testUser();
+    const res = await api("GET", "/api/admin/copytrade/audit", { token });
+    expect(res.status).toBe(403);
+  });
+});
+
+// ---------------------------------------------------------------------------
+// POST /api/markets/resolved-status
+// ---------------------------------------------------------------------------
+
+interface AssetResolution {
+  asset_id: string;
+  resolved: boolean;
+  resolved_price: number | null;
+}
+
+describe("POST /api/markets/resolved-status", () => {
+  test("rejects an empty or malformed id list", async () => {
+    const { token } = testUser();
+    const empty = await api("POST", "/api/markets/resolved-status", {
+      token,
+      body: { asset_ids: [] },
+    });
+    expect(empty.status).toBe(400);
+    const bad = await api("POST", "/api/markets/resolved-status", {
+      token,
+      body: { asset_ids: ["not-a-token"] },
+    });
+    expect(bad.status).toBe(400);
+  });
+
+  test("reports unknown assets as unresolved, deduplicated in request order", async () => {
+    const { token } = testUser();
+    const ids = ["999999999999999999999999999999", "888888888888888888888888888888"];
+    const res = await api<AssetResolution[]>("POST", "/api/markets/resolved-status", {
+      token,
+      body: { asset_ids: [...ids, ids[0]] },
+    });
+    expect(res.status).toBe(200);
+    expect(res.data.map((r) => r.asset_id)).toEqual(ids);
+    for (const r of res.data) {
+      expect(r.resolved).toBe(false);
+      expect(r.resolved_price).toBeNull();
+    }
+  });
+});
+
+// ---------------------------------------------------------------------------
+// GET /api/trader/:address/copy-score
+// ---------------------------------------------------------------------------
+
+describe("GET /api/trader/:address/copy-score", () => {
+  test("rejects a malformed address", async () => {
+    const { token } = testUser();
+    const res = await api("GET", "/api/trader/not-an-address/copy-score", { token });
+    expect(res.status).toBe(400);
+  });
+
+  test("returns 404 for an address with no positions", async () => {
+    const { token } = testUser();
+    const unknown = "0x" + "0".repeat(39) + "1";
+    const res = await api("GET", `/api/trader/${unknown}/copy-score`, { token });
+    expect(res.status).toBe(404);
+  });
+});
+
+// ---------------------------------------------------------------------------
+// Per-trader copy weights (trader_list_members.weight)
+// ---------------------------------------------------------------------------
+
+describe("list member copy weights", () => {
+  const heavy = "0x00000000000000000000000000000000000000b1";
+  const light = "0x00000000000000000000000000000000000000b2";
+
+  test("rejects a non-positive weight", async () => {
+    const { token } = testUser();
+    const list = await api<TraderList>("POST", "/api/lists", { token, body: { name: `w-${Date.now()}` } });
+    const res = await api("POST", `/api/lists/${list.data.id}/members`, {
+      token,
+      body: { addresses: [heavy], weights: [0] },
+    });
+    expect(res.status).toBe(400);
+  });
+
+  test("stores weights and splits the sizing budget by them", async () => {
+    const { token } = testUser();
+    const list = await api<TraderList>("POST", "/api/lists", { token, body: { name: `w-${Date.now()}` } });
+    const added = await api("POST", `/api/lists/${list.data.id}/members`, {
+      token,
+      body: { addresses: [heavy, light], weights: [3, null] },
+    });
+    expect(added.status).toBe(204);
+
+    const detail = await api<{ members: { address: string; weight: number }[] }>(
+      "GET",
+      `/api/lists/${list.data.id}`,
+      { token },
+    );
+    const weights = Object.fromEntries(detail.data.members.map((m) => [m.address, m.weight]));
+    expect(weights[heavy]).toBe(3);
+    expect(weights[light]).toBe(1);
+
+    const session = await api<CopyTradeSession>("POST", "/api/copytrade/sessions", {
+      token,
+      body: { list_id: list.data.id, copy_pct: 0.5, initial_capital: 100, simulate: true },
+    });
+    expect(session.status).toBe(200);
+    const whatIf = (source_trader: string) =>
+      api<{ trader_count: number; trader_weight: number }>(
+        "POST",
+        `/api/copytrade/sessions/${session.data.id}/what-if`,
+        { token, body: { asset_id: "123", side: "sell", source_price: 0.5, source_usdc: 50, source_trader } },
+      );
+    const h = await whatIf(heavy);
+    expect(h.status).toBe(200);
+    expect(h.data.trader_count).toBe(2);
+    expect(h.data.trader_weight).toBeCloseTo(0.75);
+    const l = await whatIf(light);
+    expect(l.data.trader_weight).toBeCloseTo(0.25);
+
+    await api("PATCH", `/api/copytrade/sessions/${session.data.id}`, { token, body: { action: "stop" } });
+    await api("DELETE", `/api/copytrade/sessions/${session.data.id}`, { token });
+  });
+});
+
+// ---------------------------------------------------------------------------
+// POST /api/copytrade/stop-all
+// ---------------------------------------------------------------------------
+
+describe("POST /api/copytrade/stop-all", () => {
+  test("stops every running and paused session, leaving stopped ones alone", async () => {
+    const { token } = testUser();
+    const listId = await createList(token);
+    const create = () =>
+      api<CopyTradeSession>("POST", "/api/copytrade/sessions", {
+        token,
+        body: { list_id: listId, copy_pct: 0.5, initial_capital: 100, simulate: true },
+      });
+    const running = await create();
+    const paused = await create();
+    const stopped = await create();
+    await api("PATCH", `/api/copytrade/sessions/${paused.data.id}`, { token, body: { action: "pause" } });
+    await api("PATCH", `/api/copytrade/sessions/${stopped.data.id}`, { token, body: { action: "stop" } });
+
+    const res = await api<{
+      stopped: number;
+      results: { session_id: string; previous_status: string; stopped: boolean }[];
+    }>("POST", "/api/copytrade/stop-all", { token });
+    expect(res.status).toBe(200);
+    expect(res.data.stopped).toBe(2);
+    const byId = Object.fromEntries(res.data.results.map((r) => [r.session_id, r]));
+    expect(byId[running.data.id].previous_status).toBe("running");
+    expect(byId[paused.data.id].previous_status).toBe("paused");
+    expect(byId[stopped.data.id]).toBeUndefined();
+
+    for (const s of [running, paused]) {
+      const after = await api<CopyTradeSession>("GET", `/api/copytrade/sessions/${s.data.id}`, { token });
+      expect(after.data.status).toBe("stopped");
+      // Stopped is final, unlike pause
+      const resume = await api("PATCH", `/api/copytrade/sessions/${s.data.id}`, { token, body: { action: "resume" } });
+      expect(resume.status).toBe(400);
+    }
+
+    const again = await api<{ stopped: number }>("POST", "/api/copytrade/stop-all", { token });
+    expect(again.data.stopped).toBe(0);
+
+    for (const s of [running, paused, stopped]) {
+      await api("DELETE", `/api/copytrade/sessions/${s.data.id}`, { token });
+    }
+  });
+});
+
+// ---------------------------------------------------------------------------
+// allowed_categories / blocked_categories / copy_uncategorized
+// ---------------------------------------------------------------------------
+
+describe("category filters", () => {
+  test("normalize on create and can be edited with PATCH", async () => {
+    const { token } = testUser();
+    const listId = await createList(token);
+    type Filtered = CopyTradeSession & {
+      allowed_categories: string | null;
+      blocked_categories: string | null;
+      copy_uncategorized: boolean;
+    };
+
+    const session = await api<Filtered>("POST", "/api/copytrade/sessions", {
+      token,
+      body: {
+        list_id: listId,
+        copy_pct: 0.5,
+        initial_capital: 100,
+        simulate: true,
+        allowed_categories: " Politics, politics ,,Crypto",
+        blocked_categories: "Sports",
+      },
+    });
+    expect(session.status).toBe(200);
+    expect(session.data.allowed_categories).toBe("Politics,Crypto");
+    expect(session.data.blocked_categories).toBe("Sports");
+    expect(session.data.copy_uncategorized).toBe(true);
+
+    const patched = await api<Filtered>("PATCH", `/api/copytrade/sessions/${session.data.id}`, {
+      token,
+      body: { allowed_categories: "", copy_uncategorized: false },
+    });
+    expect(patched.status).toBe(200);
+    expect(patched.data.allowed_categories).toBeNull();
+    expect(patched.data.blocked_categories).toBe("Sports");
+    expect(patched.data.copy_uncategorized).toBe(false);
+    expect(patched.data.status).toBe("running");
+
+    const empty = await api("PATCH", `/api/copytrade/sessions/${session.data.id}`, { token, body: {} });
+    expect(empty.status).toBe(400);
+
+    await api("PATCH", `/api/copytrade/sessions/${session.data.id}`, { token, body: { action: "stop" } });
+    await api("DELETE", `/api/copytrade/sessions/${session.data.id}`, { token });
+  });
+});
+
+// ---------------------------------------------------------------------------
+// Session field validation
+// ---------------------------------------------------------------------------
+
+interface FieldCase {
+  name: string;
+  /** Extra body fields every request in the case starts from */
+  base?: Record<string, unknown>;
+  /** Each one must be rejected with 400 */
+  bad: Record<string, unknown>[];
+  /** Each body must create a session echoing `expect` */
+  good: { body: Record<string, unknown>; expect: Record<string, unknown> }[];
+}
+
+const FIELD_CASES: FieldCase[] = [
+  {
+    name: "defaults",
+    bad: [],
+    good: [
+      {
+        body: {},
+        expect: {
+          sizing_mode: "dollar",
+          sell_behavior: "proportional",
+          sizing_base: "remaining",
+          max_orders_per_minute: null,
+          size_rounding: "down",
+          mode: "copy",
+          copy_sides: "both",
+          fok_fallback_gtc: false,
+          liquidity_window_mins: null,
+        },
+      },
+    ],
+  },
+  {
+    name: "order_type",
+    bad: [{ order_type: "IOC" }],
+    good: [{ body: { order_type: "FAK" }, expect: { order_type: "FAK" } }],
+  },
+  {
+    name: "sizing_mode",
+    bad: [{ sizing_mode: "kelly" }],
+    good: [{ body: { sizing_mode: "portfolio" }, expect: { sizing_mode: "portfolio" } }],
+  },
+  {
+    name: "sell_behavior",
+    bad: [{ sell_behavior: "half" }],
+    good: [{ body: { sell_behavior: "full_exit" }, expect: { sell_behavior: "full_exit" } }],
+  },
+  {
+    name: "gtc_price_ref",
+    base: { order_type: "GTC" },
+    bad: [{ gtc_price_ref: "mid" }, { gtc_price_offset_bps: 5000 }],
+    good: [
+      { body: {}, expect: { gtc_price_ref: "source", gtc_price_offset_bps: 0 } },
+      {
+        body: { gtc_price_ref: "current", gtc_price_offset_bps: 25 },
+        expect: { gtc_price_ref: "current", gtc_price_offset_bps: 25 },
+      },
+    ],
+  },
+  {
+    name: "sizing_base",
+    bad: [{ sizing_base: "peak" }],
+    good: [{ body: { sizing_base: "initial" }, expect: { sizing_base: "initial" } }],
+  },
+  {
+    name: "asset_allowlist",
+    bad: [{ asset_allowlist: ["0xabc"] }],
+    good: [
+      {
+        body: { asset_allowlist: ["123456789", " 987654321 "] },
+        expect: { asset_allowlist: ["123456789", "987654321"] },
+      },
+    ],
+  },
+  {
+    name: "near_resolution_price",
+    bad: [{ near_resolution_price: 0.5 }, { near_resolution_price: 1 }, { near_resolution_price: 1.5 }],
+    good: [{ body: { near_resolution_price: 0.98 }, expect: { near_resolution_price: 0.98 } }],
+  },
+  {
+    name: "trailing stop",
+    bad: [
+      { trail_activate_pct: 20 },
+      { trail_distance_pct: 10 },
+      { trail_activate_pct: 0, trail_distance_pct: 10 },
+      { trail_activate_pct: 20, trail_distance_pct: 100 },
+    ],
+    good: [
+      {
+        body: { trail_activate_pct: 20, trail_distance_pct: 10 },
+        expect: { trail_activate_pct: 20, trail_distance_pct: 10 },
+      },
+    ],
+  },
+  {
+    name: "mark_stale_secs",
+    bad: [{ mark_stale_secs: 0 }],
+    good: [{ body: { mark_stale_secs: 3600 }, expect: { mark_stale_secs: 3600 } }],
+  },
+  {
+    name: "slippage_fee_bps",
+    bad: [{ slippage_fee_bps: 2000 }],
+    good: [{ body: { slippage_fee_bps: 100 }, expect: { slippage_fee_bps: 100 } }],
+  },
+  {
+    name: "min_hold_secs",
+    bad: [{ min_hold_secs: 30 * 24 * 3600 }],
+    good: [{ body: { min_hold_secs: 600 }, expect: { min_hold_secs: 600 } }],
+  },
+  {
+    name: "slippage_overrides",
+    bad: [{ slippage_overrides: { "0xabc": 50 } }, { slippage_overrides: { "12345": 20000 } }],
+    good: [
+      {
+        body: { slippage_overrides: { "12345": 50, "67890": 800 } },
+        expect: { slippage_overrides: { "12345": 50, "67890": 800 } },
+      },
+    ],
+  },
+  {
+    name: "max_runtime_secs",
+    bad: [{ max_runtime_secs: 0 }],
+    good: [{ body: { max_runtime_secs: 3600 }, expect: { max_runtime_secs: 3600 } }],
+  },
+  {
+    name: "digest webhook",
+    bad: [
+      { digest_interval_hours: 24 },
+      { webhook_url: "http://example.com/hook", digest_interval_hours: 24 },
+    ],
+    good: [
+      {
+        body: { webhook_url: "https://example.com/hook", digest_interval_hours: 24 },
+        expect: { webhook_url: "https://example.com/hook", digest_interval_hours: 24 },
+      },
+    ],
+  },
+  {
+    name: "max_orders_per_minute",
+    bad: [{ max_orders_per_minute: 0 }, { max_orders_per_minute: 61 }],
+    good: [
+      { body: { max_orders_per_minute: 3 }, expect: { max_orders_per_minute: 3 } },
+      { body: { max_orders_per_minute: 60 }, expect: { max_orders_per_minute: 60 } },
+    ],
+  },
+  {
+    name: "liquidity_window_mins",
+    bad: [{ liquidity_window_mins: 0 }, { liquidity_window_mins: 1441 }],
+    good: [
+      {
+        body: { min_liquidity_usdc: 500, liquidity_window_mins: 15 },
+        expect: { min_liquidity_usdc: 500, liquidity_window_mins: 15 },
+      },
+    ],
+  },
+  {
+    name: "size_rounding",
+    bad: [{ size_rounding: "up" }],
+    good: [{ body: { size_rounding: "nearest" }, expect: { size_rounding: "nearest" } }],
+  },
+  {
+    name: "take_profit_pct and stop_loss_pct",
+    bad: [{ take_profit_pct: 0 }, { take_profit_pct: -5 }, { stop_loss_pct: 0 }, { stop_loss_pct: 100 }],
+    good: [
+      {
+        body: { take_profit_pct: 50, stop_loss_pct: 20 },
+        expect: { take_profit_pct: 50, stop_loss_pct: 20 },
+      },
+    ],
+  },
+  {
+    name: "mode",
+    bad: [{ mode: "inverse" }],
+    good: [{ body: { mode: "fade" }, expect: { mode: "fade" } }],
+  },
+  {
+    name: "copy_sides",
+    bad: [{ copy_sides: "entries" }],
+    good: [
+      { body: { copy_sides: "buy" }, expect: { copy_sides: "buy" } },
+      { body: { copy_sides: "sell" }, expect: { copy_sides: "sell" } },
+    ],
+  },
+  {
+    name: "fok_fallback_gtc",
+    bad: [],
+    good: [{ body: { fok_fallback_gtc: true }, expect: { fok_fallback_gtc: true } }],
+  },
+];
+
+describe("session field validation", () => {
+  for (const c of FIELD_CASES) {
+    test(c.name, async () => {
+      const { token } = testUser();
+      const listId = await createList(token);
+      const base = { list_id: listId, copy_pct: 0.5, initial_capital: 100, simulate: true, ...c.base };
+
+      for (const bad of c.bad) {
+        const res = await api("POST", "/api/copytrade/sessions", { token, body: { ...base, ...bad } });
+        expect(res.status).toBe(400);
+      }
+
+      for (const good of c.good) {
+        const res = await api<CopyTradeSession & Record<string, unknown>>("POST", "/api/copytrade/sessions", {
+          token,
+          body: { ...base, ...good.body },
+        });
+        expect(res.status).toBe(200);
+        expect(res.data).toMatchObject(good.expect);
+        await api("PATCH", `/api/copytrade/sessions/${res.data.id}`, { token, body: { action: "stop" } });
+        await api("DELETE", `/api/copytrade/sessions/${res.data.id}`, { token });
+      }
+    });
+  }
+});
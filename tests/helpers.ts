@@ -39,7 +39,7 @@ export function mintJwt(
 // API client
 // ---------------------------------------------------------------------------
 
-type Method = "GET" | "POST" | "DELETE";
+type Method = "GET" | "POST" | "PATCH" | "DELETE";
 
 interface ApiResponse<T = unknown> {
   status: number;
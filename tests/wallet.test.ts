@@ -9,6 +9,7 @@ interface WalletInfo {
   id: string;
   address: string;
   proxy_address: string | null;
+  signature_type: string;
   status: string;
   has_clob_credentials: boolean;
   created_at: string;
@@ -146,6 +147,66 @@ describe("POST /api/wallets/import", () => {
     });
     expect(res.status).toBe(400);
   });
+
+  test("imports in eoa mode", async () => {
+    const { token } = testUser();
+    const testKey =
+      "0xac0974bec39a17e36ba4a6b4d238ff944bacb478cbed5efcae784d7bf4f2ff80";
+
+    const res = await api<ImportResponse>("POST", "/api/wallets/import", {
+      token,
+      body: { private_key: testKey, signature_type: "eoa" },
+    });
+    expect(res.status).toBe(200);
+
+    const list = await api<WalletInfo[]>("GET", "/api/wallets", { token });
+    expect(list.data[0].signature_type).toBe("eoa");
+
+    await cleanupWallets(token);
+  });
+
+  test("rejects unknown signature_type", async () => {
+    const { token } = testUser();
+    const res = await api("POST", "/api/wallets/import", {
+      token,
+      body: {
+        private_key:
+          "0xac0974bec39a17e36ba4a6b4d238ff944bacb478cbed5efcae784d7bf4f2ff80",
+        signature_type: "safe",
+      },
+    });
+    expect(res.status).toBe(400);
+  });
+});
+
+// ---------------------------------------------------------------------------
+// PATCH /api/wallets/:id
+// ---------------------------------------------------------------------------
+
+describe("PATCH /api/wallets/:id", () => {
+  test("switches signature type between proxy and eoa", async () => {
+    const { token } = testUser();
+    const gen = await api<GenerateResponse>("POST", "/api/wallets/generate", { token });
+
+    const list = await api<WalletInfo[]>("GET", "/api/wallets", { token });
+    expect(list.data[0].signature_type).toBe("proxy");
+
+    const eoa = await api<WalletInfo>("PATCH", `/api/wallets/${gen.data.id}`, {
+      token,
+      body: { signature_type: "eoa" },
+    });
+    expect(eoa.status).toBe(200);
+    expect(eoa.data.signature_type).toBe("eoa");
+
+    const proxy = await api<WalletInfo>("PATCH", `/api/wallets/${gen.data.id}`, {
+      token,
+      body: { signature_type: "proxy" },
+    });
+    expect(proxy.status).toBe(200);
+    expect(proxy.data.signature_type).toBe("proxy");
+
+    await cleanupWallets(token);
+  });
 });
 
 // ---------------------------------------------------------------------------
@@ -257,6 +318,24 @@ describe("multi-wallet support", () => {
     await cleanupWallets(token);
   });
 
+  test("no private key is returned when at the wallet cap", async () => {
+    const { token } = testUser();
+
+    for (let i = 0; i < 3; i++) {
+      await api("POST", "/api/wallets/generate", { token });
+    }
+
+    const fourth = await api<GenerateResponse>("POST", "/api/wallets/generate", { token });
+    expect(fourth.status).toBe(409);
+    expect(fourth.text).not.toContain("private_key");
+    expect(fourth.text).not.toMatch(/0x[0-9a-f]{64}/);
+
+    const list = await api<WalletInfo[]>("GET", "/api/wallets", { token });
+    expect(list.data.length).toBe(3);
+
+    await cleanupWallets(token);
+  });
+
   test("import also counts toward limit", async () => {
     const { token } = testUser();
     const testKey =